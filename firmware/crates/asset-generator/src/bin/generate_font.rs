@@ -0,0 +1,76 @@
+//! Converts a TTF, OTF or BDF font into the Rust source of a `BitmapFont` of the firmware, written to the standard
+//! output.
+//!
+//! Usage: `generate_font <font file> <pixel size> <bits per pixel> <name> [characters]`
+//!
+//! The pixel size is ignored for BDF fonts, which have a single size. The bits per pixel can be `1`, `2` or `4` (with
+//! more bits the glyphs are anti-aliased), and the characters are the printable ASCII characters and the degree sign
+//! if they aren't specified.
+
+use std::{env, fs, path::Path, process::ExitCode};
+
+use asset_generator::font::{RasterizedFont, DEFAULT_CHARACTERS};
+
+fn main() -> ExitCode {
+	let arguments = env::args().skip(1).collect::<Vec<_>>();
+	let (path, pixel_size, bits_per_pixel, name, characters) = match arguments.as_slice() {
+		[path, pixel_size, bits_per_pixel, name] => (path, pixel_size, bits_per_pixel, name, DEFAULT_CHARACTERS),
+		[path, pixel_size, bits_per_pixel, name, characters] => {
+			(path, pixel_size, bits_per_pixel, name, characters.as_str())
+		},
+		_ => {
+			eprintln!("Usage: generate_font <font file> <pixel size> <bits per pixel> <name> [characters]");
+			return ExitCode::FAILURE;
+		},
+	};
+
+	match generate(Path::new(path), pixel_size, bits_per_pixel, name, characters) {
+		Ok(source) => {
+			print!("{source}");
+			ExitCode::SUCCESS
+		},
+		Err(error) => {
+			eprintln!("Error: {error}");
+			ExitCode::FAILURE
+		},
+	}
+}
+
+fn generate(
+	path: &Path, pixel_size: &str, bits_per_pixel: &str, name: &str, characters: &str,
+) -> Result<String, String> {
+	let pixel_size = pixel_size
+		.parse::<f32>()
+		.map_err(|error| format!("Invalid pixel size: {error}"))?;
+	let bits_per_pixel = match bits_per_pixel {
+		"1" => 1,
+		"2" => 2,
+		"4" => 4,
+		_ => return Err("The bits per pixel must be 1, 2 or 4".into()),
+	};
+	let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+	let (font, description) = match path.extension().and_then(|extension| extension.to_str()) {
+		Some("bdf") => {
+			let source = fs::read_to_string(path).map_err(|error| format!("Can't read {file_name}: {error}"))?;
+			(
+				RasterizedFont::from_bdf(&source, characters)?,
+				format!("The {file_name} font"),
+			)
+		},
+		_ => {
+			let data = fs::read(path).map_err(|error| format!("Can't read {file_name}: {error}"))?;
+			(
+				RasterizedFont::from_ttf(&data, pixel_size, characters)?,
+				format!("The {file_name} font at {pixel_size} pixels"),
+			)
+		},
+	};
+
+	Ok(font.to_rust(
+		name,
+		bits_per_pixel,
+		'?',
+		&format!("{description}, with {bits_per_pixel} bits per pixel."),
+	))
+}
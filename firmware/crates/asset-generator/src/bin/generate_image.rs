@@ -0,0 +1,50 @@
+//! Converts a PNG image into the Rust source of a `CompressedImage` of the firmware, written to the standard output.
+//!
+//! Usage: `generate_image <image file> <name> [background color]`
+//!
+//! The transparent pixels of the image are blended with the background color, which is written in hexadecimal like
+//! `RRGGBB` and is black if it isn't specified (like the background of the user interface).
+
+use std::{env, fs, path::Path, process::ExitCode};
+
+use asset_generator::image::Image;
+
+fn main() -> ExitCode {
+	let arguments = env::args().skip(1).collect::<Vec<_>>();
+	let (path, name, background) = match arguments.as_slice() {
+		[path, name] => (path, name, "000000"),
+		[path, name, background] => (path, name, background.as_str()),
+		_ => {
+			eprintln!("Usage: generate_image <image file> <name> [background color]");
+			return ExitCode::FAILURE;
+		},
+	};
+
+	match generate(Path::new(path), name, background) {
+		Ok(source) => {
+			print!("{source}");
+			ExitCode::SUCCESS
+		},
+		Err(error) => {
+			eprintln!("Error: {error}");
+			ExitCode::FAILURE
+		},
+	}
+}
+
+fn generate(path: &Path, name: &str, background: &str) -> Result<String, String> {
+	let background = match u32::from_str_radix(background, 16) {
+		Ok(color) if background.len() == 6 => color.to_be_bytes(),
+		_ => {
+			return Err(format!(
+				"Invalid background color {background:?}: it must be written like RRGGBB"
+			))
+		},
+	};
+	let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+	let data = fs::read(path).map_err(|error| format!("Can't read {file_name}: {error}"))?;
+	let image = Image::from_png(&data, [background[1], background[2], background[3]])?;
+
+	Ok(image.to_rust(name, &format!("The {file_name} image.")))
+}
@@ -0,0 +1,326 @@
+//! Rasterization of TTF/OTF and BDF fonts into the atlas of a `BitmapFont` of the firmware.
+
+use std::fmt::Write;
+
+use crate::format_bytes;
+
+/// The characters put in a font when none are specified: the printable ASCII characters and the degree sign.
+pub const DEFAULT_CHARACTERS: &str =
+	" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~°";
+
+/// A glyph whose pixels have a coverage from `0` (empty) to `255` (completely covered).
+#[derive(Clone, Debug)]
+pub struct RasterizedGlyph {
+	/// The character of the firmware (a single byte, check [`to_firmware_char`]).
+	pub char: u8,
+	pub width: usize,
+	pub height: usize,
+	/// The horizontal distance from the start of the character to the left of the image.
+	pub left: i32,
+	/// The vertical distance from the top of the line to the top of the image.
+	pub top: i32,
+	pub advance: i32,
+	/// The coverage of the pixels of the image, row by row.
+	pub coverage: Vec<u8>,
+}
+
+impl RasterizedGlyph {
+	/// Removes the parts of the image outside the cell of the character (which is `advance` wide and `line_height`
+	/// tall) and the empty rows and columns on its borders.
+	fn crop(&mut self, line_height: i32) {
+		let is_visible = |x: usize, y: usize| {
+			let (cell_x, cell_y) = (self.left + x as i32, self.top + y as i32);
+			self.coverage[y * self.width + x] > 0
+				&& (0..self.advance.max(1)).contains(&cell_x)
+				&& (0..line_height).contains(&cell_y)
+		};
+		let visible = (0..self.height).flat_map(|y| (0..self.width).map(move |x| (x, y)));
+		let visible = visible.filter(|&(x, y)| is_visible(x, y));
+
+		let Some((min_x, min_y, max_x, max_y)) = visible.fold(None, |bounds, (x, y)| {
+			let (min_x, min_y, max_x, max_y) = bounds.unwrap_or((x, y, x, y));
+			Some((min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)))
+		}) else {
+			*self = Self {
+				width: 0,
+				height: 0,
+				left: 0,
+				top: 0,
+				coverage: Vec::new(),
+				..*self
+			};
+			return;
+		};
+
+		let (width, height) = (max_x - min_x + 1, max_y - min_y + 1);
+		let mut coverage = Vec::with_capacity(width * height);
+		for y in min_y..=max_y {
+			coverage.extend((min_x..=max_x).map(|x| {
+				if is_visible(x, y) {
+					self.coverage[y * self.width + x]
+				} else {
+					0
+				}
+			}));
+		}
+		*self = Self {
+			width,
+			height,
+			left: self.left + min_x as i32,
+			top: self.top + min_y as i32,
+			coverage,
+			..*self
+		};
+	}
+}
+
+/// A font whose glyphs have been rasterized at a single size.
+#[derive(Clone, Debug)]
+pub struct RasterizedFont {
+	pub line_height: i32,
+	/// The vertical distance from the top of the line to the baseline.
+	pub baseline: i32,
+	/// The glyphs sorted by character.
+	pub glyphs: Vec<RasterizedGlyph>,
+}
+
+impl RasterizedFont {
+	/// Rasterizes the provided `characters` of the TTF or OTF font in `data` at `pixel_size` pixels per em.
+	pub fn from_ttf(data: &[u8], pixel_size: f32, characters: &str) -> Result<Self, String> {
+		let font = fontdue::Font::from_bytes(data, fontdue::FontSettings::default())?;
+		let metrics = font
+			.horizontal_line_metrics(pixel_size)
+			.ok_or("The font doesn't have horizontal line metrics")?;
+		let baseline = metrics.ascent.ceil() as i32;
+		let line_height = (metrics.ascent - metrics.descent).ceil() as i32;
+
+		let mut glyphs = Vec::new();
+		for character in characters.chars() {
+			if !font.has_glyph(character) {
+				return Err(format!("The font doesn't have the character {character:?}"));
+			}
+			let (metrics, coverage) = font.rasterize(character, pixel_size);
+			glyphs.push(RasterizedGlyph {
+				char: to_firmware_char(character)?,
+				width: metrics.width,
+				height: metrics.height,
+				left: metrics.xmin,
+				top: baseline - metrics.ymin - metrics.height as i32,
+				advance: metrics.advance_width.round() as i32,
+				coverage,
+			});
+		}
+
+		Self::new(line_height, baseline, glyphs)
+	}
+
+	/// Reads the provided `characters` of the BDF font in `source`.
+	pub fn from_bdf(source: &str, characters: &str) -> Result<Self, String> {
+		let mut ascent = None;
+		let mut descent = None;
+		let mut glyphs = Vec::new();
+
+		let mut lines = source.lines().map(str::trim);
+		while let Some(line) = lines.next() {
+			let (keyword, arguments) = line.split_once(' ').unwrap_or((line, ""));
+			match keyword {
+				"FONT_ASCENT" => ascent = Some(parse_numbers::<1>(arguments)?[0]),
+				"FONT_DESCENT" => descent = Some(parse_numbers::<1>(arguments)?[0]),
+				"STARTCHAR" => {
+					let Some(ascent) = ascent else {
+						return Err("FONT_ASCENT must come before the characters".into());
+					};
+					if let Some(glyph) = parse_bdf_char(&mut lines, ascent, characters)? {
+						glyphs.push(glyph);
+					}
+				},
+				_ => {},
+			}
+		}
+
+		let (Some(ascent), Some(descent)) = (ascent, descent) else {
+			return Err("The font doesn't have FONT_ASCENT and FONT_DESCENT".into());
+		};
+		if let Some(missing) = characters
+			.chars()
+			.find(|&character| glyphs.iter().all(|glyph| Ok(glyph.char) != to_firmware_char(character)))
+		{
+			return Err(format!("The font doesn't have the character {missing:?}"));
+		}
+
+		Self::new(ascent + descent, ascent, glyphs)
+	}
+
+	fn new(line_height: i32, baseline: i32, mut glyphs: Vec<RasterizedGlyph>) -> Result<Self, String> {
+		if !(1..=u16::MAX as i32).contains(&line_height) {
+			return Err(format!("The line height {line_height} is not supported"));
+		}
+		for glyph in &mut glyphs {
+			glyph.crop(line_height);
+			if glyph.width > u8::MAX as usize || glyph.height > u8::MAX as usize || glyph.advance > u8::MAX as i32 {
+				return Err(format!("The glyph of the character 0x{:02X} is too big", glyph.char));
+			}
+		}
+		glyphs.sort_by_key(|glyph| glyph.char);
+		glyphs.dedup_by_key(|glyph| glyph.char);
+
+		Ok(Self {
+			line_height,
+			baseline,
+			glyphs,
+		})
+	}
+
+	/// Returns the Rust source of a module with a `BitmapFont` called `name`, whose atlas stores the coverage of the
+	/// pixels in `bits_per_pixel` bits.
+	///
+	/// The `description` is written in the documentation of the module.
+	pub fn to_rust(&self, name: &str, bits_per_pixel: u8, replacement: char, description: &str) -> String {
+		let max_coverage = (1u16 << bits_per_pixel) - 1;
+		let mut atlas = Vec::new();
+		let mut glyphs = String::new();
+		for glyph in &self.glyphs {
+			writeln!(
+				glyphs,
+				"\t\tGlyph {{ char: {}, offset: {}, width: {}, height: {}, left: {}, top: {}, advance: {} }},",
+				format_char(glyph.char),
+				atlas.len(),
+				glyph.width,
+				glyph.height,
+				glyph.left,
+				glyph.top,
+				glyph.advance
+			)
+			.unwrap();
+
+			// Each glyph starts at the beginning of a byte, and its pixels are packed from the most significant bits
+			for (i, &coverage) in glyph.coverage.iter().enumerate() {
+				let bit = i * bits_per_pixel as usize;
+				if bit.is_multiple_of(8) {
+					atlas.push(0);
+				}
+				let coverage = (coverage as u16 * max_coverage + 127) / 255;
+				*atlas.last_mut().unwrap() |= (coverage << (8 - bits_per_pixel as usize - bit % 8)) as u8;
+			}
+		}
+		let bits_per_pixel = match bits_per_pixel {
+			1 => "One",
+			2 => "Two",
+			_ => "Four",
+		};
+		let replacement = format_char(to_firmware_char(replacement).unwrap_or(b'?'));
+
+		format!(
+			"//! {description}\n\
+			//!\n\
+			//! Generated by the `asset-generator` tool: don't edit it by hand.\n\
+			\n\
+			use super::super::{{BitmapFont, BitsPerPixel, Glyph}};\n\
+			\n\
+			#[rustfmt::skip]\n\
+			pub static {name}: BitmapFont = BitmapFont {{\n\
+			\tline_height: {},\n\
+			\tbaseline: {},\n\
+			\tbits_per_pixel: BitsPerPixel::{bits_per_pixel},\n\
+			\tglyphs: &[\n\
+			{glyphs}\
+			\t],\n\
+			\tatlas: &[\n\
+			{}\
+			\t],\n\
+			\treplacement: {replacement},\n\
+			}};\n",
+			self.line_height,
+			self.baseline,
+			format_bytes(&atlas, 2),
+		)
+	}
+}
+
+/// Returns the character of the firmware for the provided `character`.
+///
+/// The firmware stores each character in a byte: the ASCII characters are stored as they are, and the characters from
+/// `U+0080` to `U+00BF` (like the degree sign) are stored as the last byte of their UTF-8 encoding, which is their code
+/// point.
+pub fn to_firmware_char(character: char) -> Result<u8, String> {
+	match character as u32 {
+		code_point @ 0x00..=0xBF => Ok(code_point as u8),
+		_ => Err(format!("The character {character:?} can't be stored in a byte")),
+	}
+}
+
+fn format_char(char: u8) -> String {
+	match char {
+		b' '..=b'~' => format!("b'{}'", char.escape_ascii()),
+		_ => format!("0x{char:02X}"),
+	}
+}
+
+fn parse_numbers<const N: usize>(arguments: &str) -> Result<[i32; N], String> {
+	let mut numbers = arguments.split_whitespace().map(str::parse);
+	let mut result = [0; N];
+	for number in &mut result {
+		*number = numbers
+			.next()
+			.and_then(Result::ok)
+			.ok_or_else(|| format!("Expected {N} numbers instead of {arguments:?}"))?;
+	}
+
+	Ok(result)
+}
+
+/// Reads the lines of a character of a BDF font after `STARTCHAR`, returning its glyph if it's one of the provided
+/// `characters`.
+fn parse_bdf_char<'a>(
+	lines: &mut impl Iterator<Item = &'a str>, ascent: i32, characters: &str,
+) -> Result<Option<RasterizedGlyph>, String> {
+	let mut encoding = None;
+	let mut advance = None;
+	let mut bounding_box = None;
+	for line in lines.by_ref() {
+		let (keyword, arguments) = line.split_once(' ').unwrap_or((line, ""));
+		match keyword {
+			"ENCODING" => encoding = Some(parse_numbers::<1>(arguments)?[0]),
+			"DWIDTH" => advance = Some(parse_numbers::<2>(arguments)?[0]),
+			"BBX" => bounding_box = Some(parse_numbers::<4>(arguments)?),
+			"BITMAP" => break,
+			_ => {},
+		}
+	}
+	let (Some(encoding), Some(advance), Some([width, height, x_offset, y_offset])) = (encoding, advance, bounding_box)
+	else {
+		return Err("A character doesn't have ENCODING, DWIDTH and BBX".into());
+	};
+
+	let (width, height) = (width.max(0) as usize, height.max(0) as usize);
+	let mut coverage = Vec::with_capacity(width * height);
+	for line in lines.by_ref().take(height) {
+		let row = (0..line.len())
+			.step_by(2)
+			.map(|i| line.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+			.collect::<Option<Vec<_>>>()
+			.ok_or_else(|| format!("Invalid BITMAP line {line:?}"))?;
+		coverage.extend((0..width).map(
+			|x| match row.get(x / 8).is_some_and(|byte| byte & (0x80 >> (x % 8)) != 0) {
+				true => u8::MAX,
+				false => 0,
+			},
+		));
+	}
+	if coverage.len() != width * height {
+		return Err(format!("The BITMAP of the character {encoding} is too short"));
+	}
+
+	let Some(character) = char::from_u32(encoding as u32).filter(|&character| characters.contains(character)) else {
+		return Ok(None);
+	};
+	Ok(Some(RasterizedGlyph {
+		char: to_firmware_char(character)?,
+		width,
+		height,
+		left: x_offset,
+		top: ascent - y_offset - height as i32,
+		advance,
+		coverage,
+	}))
+}
@@ -0,0 +1,191 @@
+//! Compression of PNG images into the data of a `CompressedImage` of the firmware.
+
+use crate::format_bytes;
+
+/// How many of the colors seen most recently are kept by the codec (the `INDEX_LENGTH` of a `CompressedImage`).
+const INDEX_LENGTH: usize = 64;
+/// The maximum number of times a color can be repeated by a single operation.
+const MAX_RUN: u8 = 63;
+const OP_INDEX: u8 = 0b0000_0000;
+const OP_DIFF: u8 = 0b0100_0000;
+const OP_LUMA: u8 = 0b1000_0000;
+const OP_RUN: u8 = 0b1100_0000;
+const OP_LITERAL: u8 = 0xFF;
+
+/// An image whose pixels are RGB565 colors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+	pub width: u16,
+	pub height: u16,
+	/// The colors of the pixels, row by row.
+	pub pixels: Vec<u16>,
+}
+
+impl Image {
+	/// Reads the PNG image in `data`, whose transparent pixels are blended with the provided `background` color (whose
+	/// channels are red, green and blue).
+	pub fn from_png(data: &[u8], background: [u8; 3]) -> Result<Self, String> {
+		let mut decoder = png::Decoder::new(data);
+		decoder.set_transformations(png::Transformations::normalize_to_color8());
+		let mut reader = decoder.read_info().map_err(|error| format!("Invalid PNG: {error}"))?;
+		let mut buffer = vec![0; reader.output_buffer_size()];
+		let info = reader
+			.next_frame(&mut buffer)
+			.map_err(|error| format!("Invalid PNG: {error}"))?;
+
+		let (Ok(width), Ok(height)) = (u16::try_from(info.width), u16::try_from(info.height)) else {
+			return Err(format!("The image is too big ({}x{} pixels)", info.width, info.height));
+		};
+		let channels = match info.color_type {
+			png::ColorType::Grayscale => 1,
+			png::ColorType::GrayscaleAlpha => 2,
+			png::ColorType::Rgb => 3,
+			png::ColorType::Rgba => 4,
+			png::ColorType::Indexed => return Err("The palette of the image can't be expanded".into()),
+		};
+
+		let pixels = buffer[..info.buffer_size()]
+			.chunks_exact(channels)
+			.map(|pixel| {
+				let (color, alpha) = match *pixel {
+					[luma] => ([luma; 3], u8::MAX),
+					[luma, alpha] => ([luma; 3], alpha),
+					[red, green, blue] => ([red, green, blue], u8::MAX),
+					[red, green, blue, alpha] => ([red, green, blue], alpha),
+					_ => unreachable!(),
+				};
+				let blend = |channel: usize| {
+					let mixed =
+						color[channel] as u16 * alpha as u16 + background[channel] as u16 * (255 - alpha as u16);
+					((mixed + 127) / 255) as u8
+				};
+
+				to_rgb565(blend(0), blend(1), blend(2))
+			})
+			.collect();
+
+		Ok(Self { width, height, pixels })
+	}
+
+	/// Returns the pixels of the image compressed with the codec of a `CompressedImage` of the firmware (whose
+	/// documentation describes the format).
+	pub fn compress(&self) -> Vec<u8> {
+		let mut data = Vec::new();
+		let mut previous = 0;
+		let mut index = [0; INDEX_LENGTH];
+		let mut run = 0;
+		for (i, &color) in self.pixels.iter().enumerate() {
+			if color == previous {
+				run += 1;
+				if run == MAX_RUN || i + 1 == self.pixels.len() {
+					data.push(OP_RUN | (run - 1));
+					run = 0;
+				}
+				continue;
+			}
+			if run > 0 {
+				data.push(OP_RUN | (run - 1));
+				run = 0;
+			}
+
+			let position = get_index_of(color);
+			if index[position] == color {
+				data.push(OP_INDEX | position as u8);
+			} else {
+				data.extend(encode_change(previous, color));
+			}
+
+			index[position] = color;
+			previous = color;
+		}
+
+		data
+	}
+
+	/// Returns the Rust source of a module with a `CompressedImage` called `name` with the pixels of the image.
+	///
+	/// The `description` is written in the documentation of the module.
+	pub fn to_rust(&self, name: &str, description: &str) -> String {
+		let data = self.compress();
+
+		format!(
+			"//! {description}\n\
+			//!\n\
+			//! {}x{} pixels compressed in {} bytes ({} bytes uncompressed).\n\
+			//!\n\
+			//! Generated by the `asset-generator` tool: don't edit it by hand.\n\
+			\n\
+			use micromath::vector::U16x2;\n\
+			\n\
+			use super::super::CompressedImage;\n\
+			\n\
+			#[rustfmt::skip]\n\
+			pub static {name}: CompressedImage = CompressedImage {{\n\
+			\tsize: U16x2 {{ x: {}, y: {} }},\n\
+			\tdata: &[\n\
+			{}\
+			\t],\n\
+			}};\n",
+			self.width,
+			self.height,
+			data.len(),
+			self.pixels.len() * 2,
+			self.width,
+			self.height,
+			format_bytes(&data, 2),
+		)
+	}
+}
+
+/// Returns the RGB565 color closest to the one with the provided 8-bit channels.
+pub fn to_rgb565(red: u8, green: u8, blue: u8) -> u16 {
+	let scale = |channel: u8, max: u16| (channel as u16 * max + 127) / 255;
+
+	(scale(red, 0x1F) << 11) | (scale(green, 0x3F) << 5) | scale(blue, 0x1F)
+}
+
+/// Returns the red, green and blue channels of an RGB565 `color`.
+fn split_channels(color: u16) -> [i32; 3] {
+	[
+		(color >> 11) as i32,
+		((color >> 5) & 0x3F) as i32,
+		(color & 0x1F) as i32,
+	]
+}
+
+/// Returns the position of the provided `color` in the array of the colors seen most recently.
+fn get_index_of(color: u16) -> usize {
+	let [red, green, blue] = split_channels(color);
+
+	(red as usize * 3 + green as usize * 5 + blue as usize * 7) % INDEX_LENGTH
+}
+
+/// Returns the shortest operation that changes the `previous` color into the provided `color`.
+fn encode_change(previous: u16, color: u16) -> Vec<u8> {
+	let [red, green, blue] = split_channels(color);
+	let [previous_red, previous_green, previous_blue] = split_channels(previous);
+	// The differences wrap around, like the channels when they are decoded
+	let difference = |channel: i32, previous: i32, bits: u32| {
+		let max = 1 << bits;
+		(channel - previous + max / 2).rem_euclid(max) - max / 2
+	};
+	let (red, green, blue) = (
+		difference(red, previous_red, 5),
+		difference(green, previous_green, 6),
+		difference(blue, previous_blue, 5),
+	);
+
+	let is_small = |difference: i32| (-2..=1).contains(&difference);
+	if is_small(red) && is_small(green) && is_small(blue) {
+		return vec![OP_DIFF | ((red + 2) << 4 | (green + 2) << 2 | (blue + 2)) as u8];
+	}
+
+	let half_green = green >> 1;
+	let (red, blue) = (difference(red, half_green, 5), difference(blue, half_green, 5));
+	if (-8..=7).contains(&red) && (-8..=7).contains(&blue) {
+		return vec![OP_LUMA | (green + 32) as u8, ((red + 8) << 4 | (blue + 8)) as u8];
+	}
+
+	let [high, low] = color.to_be_bytes();
+	vec![OP_LITERAL, high, low]
+}
@@ -0,0 +1,22 @@
+//! Tools that run on the host to convert fonts and images into Rust source files that are compiled in the firmware,
+//! so that the firmware doesn't need to parse them (and doesn't need an allocator).
+
+pub mod font;
+pub mod image;
+
+use std::fmt::Write;
+
+/// Returns the Rust source of the provided `bytes`, with 16 bytes per line indented by `indentation` tabs.
+pub fn format_bytes(bytes: &[u8], indentation: usize) -> String {
+	let mut source = String::new();
+	for line in bytes.chunks(16) {
+		source.push_str(&"\t".repeat(indentation));
+		for (i, byte) in line.iter().enumerate() {
+			let separator = if i + 1 == line.len() { "," } else { ", " };
+			write!(source, "0x{byte:02X}{separator}").unwrap();
+		}
+		source.push('\n');
+	}
+
+	source
+}
@@ -4,20 +4,88 @@ pub struct Configuration {
 	pub fan_min_duty_cycle_to_move: Percentage,
 
 	pub pid: temperature::PidConfig,
+
+	pub reflow: reflow::ReflowConfig,
+
+	pub ui: ui::UiConfig,
+}
+
+pub mod reflow {
+	use crate::hot_plate::process::{GuaranteedDwellConfig, Interpolation};
+
+	pub struct ReflowConfig {
+		/// If it's `Some`, the clock of the reflow process is held while the plate lags behind the profile.
+		///
+		/// Check [`GuaranteedDwell`](crate::hot_plate::process::GuaranteedDwell).
+		pub guaranteed_dwell: Option<GuaranteedDwellConfig>,
+		/// How the target temperature is computed between the points of the reflow profiles.
+		pub interpolation: Interpolation,
+	}
+}
+
+pub mod ui {
+	use crate::hot_plate::{
+		drivers::{ili9341::Orientation, xpt2046::Xpt2046Config},
+		input::ButtonConfig,
+		temperature::autotune::{AutotuneConfig, TuningRule},
+	};
+
+	pub struct UiConfig {
+		/// How the image is rotated on the display. The user interface is drawn for a landscape display, so it should
+		/// be [`Orientation::Landscape`] (or [`Orientation::LandscapeFlipped`] if the display is mounted upside down).
+		pub display_orientation: Orientation,
+		/// If `true`, what changes on the screen is composited in a [`LineBuffer`] before being sent to the display, so
+		/// that the overlapping widgets don't flicker and each pixel is sent only once.
+		///
+		/// [`LineBuffer`]: `crate::hot_plate::screen::line_buffer::LineBuffer`
+		pub use_line_buffer: bool,
+		/// The parameters of the touch panel placed over the screen (including its calibration).
+		pub touch: Xpt2046Config,
+		/// The parameters of the autotunes started from the settings menu (the target temperature is chosen by the
+		/// user).
+		pub autotune: AutotuneConfig,
+		/// The rule used to compute the gains at the end of the autotunes started from the settings menu.
+		pub autotune_tuning_rule: TuningRule,
+		/// The parameters of the [`Next`] button. Its long press is an emergency abort.
+		///
+		/// [`Next`]: `crate::hot_plate::screen::ui::NavigationButton::Next`
+		pub next_button: ButtonConfig,
+		/// The parameters of the [`Select`] button.
+		///
+		/// [`Select`]: `crate::hot_plate::screen::ui::NavigationButton::Select`
+		pub select_button: ButtonConfig,
+	}
 }
 
 pub mod temperature {
 	use core::ops::RangeInclusive;
 
 	use crate::{
-		hot_plate::temperature::{safety::temperature_change::TemperatureChangeConfig, TemperaturePidGains},
+		hot_plate::temperature::{
+			control::PidControlConfig, feed_forward::FeedForwardConfig, fusion::SensorFusionConfig,
+			gain_schedule::GainSchedule, safety::temperature_change::TemperatureChangeConfig, TemperaturePidGains,
+		},
 		utils::measurement::temperature::Temperature,
 	};
 
 	pub struct PidConfig {
 		pub pid_gains: TemperaturePidGains,
+		/// The gains to use at different target temperatures. If it's empty, [`Self::pid_gains`] are used.
+		pub gain_schedule: GainSchedule,
+		/// The output limits, anti-windup and derivative options of the PID.
+		pub control: PidControlConfig,
+		/// The parameters of the 2 thermistors attached to the plate.
 		pub thermistor: ThermistorConfig,
+		pub board_thermistor: ThermistorConfig,
+		/// The SMBus address of the [`MLX90614`] infrared thermometer (if it's mounted).
+		///
+		/// [`MLX90614`]: `crate::hot_plate::drivers::mlx90614::Mlx90614`
+		pub infrared_thermometer_address: u8,
+		/// Which thermistors are read and how their readings are combined in the temperature used by the PID.
+		pub sensor_fusion: SensorFusionConfig,
 		pub safety: SafetyConfig,
+		/// If it's `Some`, a model of the plate is used to add a feed-forward term to the output of the PID.
+		pub feed_forward: Option<FeedForwardConfig>,
 	}
 
 	pub struct ThermistorConfig {
@@ -1,3 +1,5 @@
+/// A command of the ILI9341, whose value is the byte sent over SPI with the DCX pin low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum Command {
 	NoOperation = 0x00,
@@ -0,0 +1,134 @@
+use embedded_graphics_core::{
+	draw_target::DrawTarget,
+	geometry::{Dimensions, OriginDimensions, Size},
+	pixelcolor::Rgb565,
+	primitives::{PointsIter, Rectangle},
+	Pixel,
+};
+use embedded_hal::digital::OutputPin;
+use micromath::vector::U16x2;
+
+use crate::hot_plate::hal::display_transfer::DisplayTransfer;
+
+use super::{SendError, ILI9341};
+
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> OriginDimensions
+	for ILI9341<DCXPin, ResetPin, Transfer>
+{
+	fn size(&self) -> Size {
+		let size = ILI9341::size(self);
+		Size::new(size.x as u32, size.y as u32)
+	}
+}
+
+/// Makes it possible to draw on the display the drawables of [`embedded-graphics`] (its fonts, shapes and images),
+/// alongside the [`Drawable`]s of this crate.
+///
+/// The areas filled with a single color or with an image are sent with a single window (check [`ILI9341::fill_rect`]
+/// and [`ILI9341::write_pixels`]), while the pixels drawn one by one (like the ones of a text without a background)
+/// are each sent with their own window.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::{drivers::ili9341::ILI9341, hal::display_transfer::DisplayTransfer};
+/// # use embedded_hal::digital::OutputPin;
+/// use embedded_graphics::{
+/// 	mono_font::{ascii::FONT_6X10, MonoTextStyle},
+/// 	pixelcolor::Rgb565,
+/// 	prelude::*,
+/// 	primitives::{Circle, PrimitiveStyle},
+/// 	text::Text,
+/// };
+///
+/// # fn draw<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer>(
+/// # 	ili9341: &mut ILI9341<DCXPin, ResetPin, Transfer>,
+/// # ) {
+/// Circle::new(Point::new(10, 10), 40)
+/// 	.into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+/// 	.draw(ili9341)
+/// 	.unwrap();
+/// Text::new("Reflow", Point::new(60, 30), MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE))
+/// 	.draw(ili9341)
+/// 	.unwrap();
+/// # }
+/// ```
+///
+/// [`embedded-graphics`]: `embedded_graphics_core`
+/// [`Drawable`]: `crate::hot_plate::screen::drawable::Drawable`
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> DrawTarget
+	for ILI9341<DCXPin, ResetPin, Transfer>
+{
+	type Color = Rgb565;
+	type Error = SendError<DCXPin, Transfer>;
+
+	fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+	where
+		I: IntoIterator<Item = Pixel<Self::Color>>,
+	{
+		let bounds = self.bounding_box();
+		for Pixel(point, color) in pixels {
+			if !bounds.contains(point) {
+				continue;
+			}
+
+			let point = U16x2 {
+				x: point.x as u16,
+				y: point.y as u16,
+			};
+			self.set_window(point, point)?;
+			self.send_color(color.into(), 1)?;
+		}
+
+		Ok(())
+	}
+
+	fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+	where
+		I: IntoIterator<Item = Self::Color>,
+	{
+		// The colors of the pixels outside the screen must be skipped one by one
+		if area.intersection(&self.bounding_box()) != *area {
+			return self.draw_iter(area.points().zip(colors).map(|(point, color)| Pixel(point, color)));
+		}
+		let Some(bottom_right) = area.bottom_right() else {
+			return Ok(());
+		};
+
+		self.set_window(
+			U16x2 {
+				x: area.top_left.x as u16,
+				y: area.top_left.y as u16,
+			},
+			U16x2 {
+				x: bottom_right.x as u16,
+				y: bottom_right.y as u16,
+			},
+		)?;
+		self.write_pixels(
+			colors
+				.into_iter()
+				.take(area.size.width as usize * area.size.height as usize)
+				.map(Into::into),
+		)
+	}
+
+	fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+		let area = area.intersection(&self.bounding_box());
+
+		self.fill_rect(
+			U16x2 {
+				x: area.top_left.x as u16,
+				y: area.top_left.y as u16,
+			},
+			U16x2 {
+				x: area.size.width as u16,
+				y: area.size.height as u16,
+			},
+			color.into(),
+		)
+	}
+
+	fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+		self.fill_solid(&self.bounding_box(), color)
+	}
+}
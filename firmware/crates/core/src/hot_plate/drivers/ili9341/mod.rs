@@ -1,95 +1,515 @@
 mod commands;
+mod draw_target;
 
-use embedded_hal::{digital::OutputPin, spi::SpiDevice};
+use core::{ops::RangeInclusive, time::Duration};
+
+use embedded_hal::digital::OutputPin;
 
 pub use commands::*;
 use micromath::vector::U16x2;
 
-use crate::utils::measurement::color::ColorRGB565;
+use crate::{
+	hot_plate::hal::{
+		display_transfer::{DisplayTransfer, MIN_BUFFER_LENGTH},
+		system_time::{Clock, SystemTime},
+	},
+	utils::{math::Percentage, measurement::color::ColorRGB565},
+};
+
+/// The number of columns of pixels of the panel driven by an [`ILI9341`] (which is natively a portrait panel).
+pub const PANEL_WIDTH: u16 = 240;
+/// The number of rows of pixels of the panel driven by an [`ILI9341`].
+pub const PANEL_HEIGHT: u16 = 320;
+
+/// The bits of the parameter of [`Command::MemoryAccessControl`] (`MADCTL`), that choose how the addresses sent to
+/// the display are mapped to the pixels of the panel.
+pub mod memory_access_control {
+	/// Makes the rows be written from the bottom of the panel to the top.
+	pub const ROW_ADDRESS_ORDER: u8 = 1 << 7;
+	/// Makes the columns be written from the right of the panel to the left.
+	pub const COLUMN_ADDRESS_ORDER: u8 = 1 << 6;
+	/// Exchanges the rows with the columns (so that the image is rotated).
+	pub const ROW_COLUMN_EXCHANGE: u8 = 1 << 5;
+	/// Makes the panel be refreshed from the bottom to the top.
+	pub const VERTICAL_REFRESH_ORDER: u8 = 1 << 4;
+	/// Makes the subpixels of each pixel be in the blue, green and red order (instead of red, green and blue).
+	pub const BGR_ORDER: u8 = 1 << 3;
+	/// Makes the panel be refreshed from the right to the left.
+	pub const HORIZONTAL_REFRESH_ORDER: u8 = 1 << 2;
+}
+
+/// The parameter of [`Command::PixelFormatSet`] that makes each pixel be sent as 16 bits (a [`ColorRGB565`]).
+const PIXEL_FORMAT_16_BITS: u8 = 0x55;
+
+/// The commands (with their parameters) that configure the power supply, the frame rate and the gamma of the display
+/// after a reset. The values are the ones recommended for the panels usually sold with an ILI9341.
+const INIT_SEQUENCE: [(Command, &[u8]); 17] = [
+	(Command::PowerControlB, &[0x00, 0xC1, 0x30]),
+	(Command::PowerOnSequenceControl, &[0x64, 0x03, 0x12, 0x81]),
+	(Command::DriverTimingControlA, &[0x85, 0x00, 0x78]),
+	(Command::PowerControlA, &[0x39, 0x2C, 0x00, 0x34, 0x02]),
+	(Command::PumpRatioControl, &[0x20]),
+	(Command::DriverTimingControlB, &[0x00, 0x00]),
+	(Command::PowerControl1, &[0x23]),
+	(Command::PowerControl2, &[0x10]),
+	(Command::VCOMControl1, &[0x3E, 0x28]),
+	(Command::VCOMControl2, &[0x86]),
+	(Command::PixelFormatSet, &[PIXEL_FORMAT_16_BITS]),
+	(Command::FrameRateControlWhileNormal, &[0x00, 0x18]),
+	(Command::DisplayFunctionControl, &[0x08, 0x82, 0x27]),
+	(Command::Enable3G, &[0x00]),
+	(Command::GammaSet, &[0x01]),
+	(
+		Command::PositiveGammaCorrection,
+		&[
+			0x0F, 0x31, 0x2B, 0x0C, 0x0E, 0x08, 0x4E, 0xF1, 0x37, 0x07, 0x10, 0x03, 0x0E, 0x09, 0x00,
+		],
+	),
+	(
+		Command::NegativeGammaCorrection,
+		&[
+			0x00, 0x0E, 0x14, 0x03, 0x11, 0x07, 0x31, 0xC1, 0x48, 0x08, 0x0F, 0x0C, 0x31, 0x36, 0x0F,
+		],
+	),
+];
+
+/// How the image is rotated on the panel of an [`ILI9341`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+	/// The image is [`PANEL_WIDTH`] pixels wide and [`PANEL_HEIGHT`] pixels high.
+	Portrait,
+	/// The image is [`PANEL_HEIGHT`] pixels wide and [`PANEL_WIDTH`] pixels high.
+	#[default]
+	Landscape,
+	/// Like [`Self::Portrait`], but upside down.
+	PortraitFlipped,
+	/// Like [`Self::Landscape`], but upside down.
+	LandscapeFlipped,
+}
+
+impl Orientation {
+	/// Returns the parameter of [`Command::MemoryAccessControl`] that rotates the image in this orientation.
+	///
+	/// The panels sold with an ILI9341 have their subpixels in the BGR order, so [`BGR_ORDER`] is always set.
+	///
+	/// [`BGR_ORDER`]: `memory_access_control::BGR_ORDER`
+	pub const fn memory_access_control(self) -> u8 {
+		use memory_access_control::*;
+
+		BGR_ORDER
+			| match self {
+				Self::Portrait => COLUMN_ADDRESS_ORDER,
+				Self::Landscape => ROW_COLUMN_EXCHANGE,
+				Self::PortraitFlipped => ROW_ADDRESS_ORDER,
+				Self::LandscapeFlipped => ROW_ADDRESS_ORDER | COLUMN_ADDRESS_ORDER | ROW_COLUMN_EXCHANGE,
+			}
+	}
+
+	pub const fn is_landscape(self) -> bool {
+		matches!(self, Self::Landscape | Self::LandscapeFlipped)
+	}
+
+	/// Returns the width and the height in pixels of the image in this orientation.
+	pub const fn size(self) -> U16x2 {
+		match self.is_landscape() {
+			true => U16x2 {
+				x: PANEL_HEIGHT,
+				y: PANEL_WIDTH,
+			},
+			false => U16x2 {
+				x: PANEL_WIDTH,
+				y: PANEL_HEIGHT,
+			},
+		}
+	}
+}
 
-pub struct ILI9341<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice> {
+/// An [`ILI9341`] display controller connected to the microcontroller through SPI, with its `D/CX` pin (which selects
+/// if the bytes sent are a command or its parameters) and its reset pin connected to 2 output pins.
+///
+/// [`ILI9341`]: <https://cdn-shop.adafruit.com/datasheets/ILI9341.pdf>
+pub struct ILI9341<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> {
 	d_cx_pin: DCXPin,
 	reset_pin: ResetPin,
-	spi: Spi,
+	transfer: Transfer,
+	orientation: Orientation,
+	/// The first and the last column of the last window set with [`Self::set_window`] (if it's still valid), so that
+	/// they aren't sent again if they don't change.
+	window_columns: Option<(u16, u16)>,
+	/// Like [`Self::window_columns`], but for the pages.
+	window_pages: Option<(u16, u16)>,
 }
 
-impl<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice> ILI9341<DCXPin, ResetPin, Spi> {
-	pub fn new(d_cx_pin: DCXPin, reset_pin: ResetPin, spi: Spi) -> Result<Self, ResetPin::Error> {
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> ILI9341<DCXPin, ResetPin, Transfer> {
+	/// The minimum time the reset pin must be kept low to trigger an [`hardware reset`].
+	///
+	/// [`hardware reset`]: `Self::hardware_reset`
+	pub const RESET_PULSE_DURATION: Duration = Duration::from_micros(10);
+	/// The time the display needs after an [`hardware reset`] before it can accept commands.
+	///
+	/// [`hardware reset`]: `Self::hardware_reset`
+	pub const RESET_RECOVERY_DURATION: Duration = Duration::from_millis(120);
+	/// The time the display needs after entering the sleep mode before it can accept other commands.
+	pub const SLEEP_IN_DURATION: Duration = Duration::from_millis(5);
+	/// The time the display needs after leaving the sleep mode for its power supply to stabilize.
+	pub const SLEEP_OUT_DURATION: Duration = Duration::from_millis(120);
+
+	/// Returns an [`ILI9341`] that communicates through the provided `transfer`, after having [`reset`] and [`initialized`]
+	/// it (so that it's showing an image with the provided `orientation`).
+	///
+	/// [`reset`]: `Self::hardware_reset`
+	/// [`initialized`]: `Self::initialize`
+	pub fn new<T: SystemTime>(
+		d_cx_pin: DCXPin, reset_pin: ResetPin, transfer: Transfer, clock: &Clock<T>, orientation: Orientation,
+	) -> Result<Self, InitError<DCXPin, ResetPin, Transfer>> {
 		let mut self_ = Self {
 			d_cx_pin,
 			reset_pin,
-			spi,
+			transfer,
+			orientation,
+			window_columns: None,
+			window_pages: None,
 		};
 
-		self_.hardware_reset()?;
+		self_.hardware_reset(clock).map_err(InitError::Reset)?;
+		self_.initialize(clock, orientation).map_err(InitError::Send)?;
 
 		Ok(self_)
 	}
 
-	pub fn hardware_reset(&mut self) -> Result<(), ResetPin::Error> {
+	/// Resets the display pulling the reset pin low, and blocks until the display is ready to accept commands.
+	pub fn hardware_reset<T: SystemTime>(&mut self, clock: &Clock<T>) -> Result<(), ResetPin::Error> {
 		self.reset_pin.set_low()?;
-
-		todo!("Delay");
+		clock.delay(Self::RESET_PULSE_DURATION);
 
 		self.reset_pin.set_high()?;
+		clock.delay(Self::RESET_RECOVERY_DURATION);
+		self.window_columns = None;
+		self.window_pages = None;
 
 		Ok(())
 	}
 
-	pub fn set_window(&mut self, start: U16x2, end: U16x2) -> Result<(), SendError<DCXPin, Spi>> {
-		let mut send_axis = |command, start, end| {
-			self.send_command(command)?;
-			let start = (start as u32) << 16 | end as u32;
-			self.send_data(&start.to_be_bytes())?;
+	/// Configures the display after a reset, wakes it up and turns it on.
+	///
+	/// It blocks for [`Self::SLEEP_OUT_DURATION`].
+	pub fn initialize<T: SystemTime>(
+		&mut self, clock: &Clock<T>, orientation: Orientation,
+	) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.window_columns = None;
+		self.window_pages = None;
+		for (command, parameters) in INIT_SEQUENCE {
+			self.send_command_with_parameters(command, parameters)?;
+		}
+		self.set_orientation(orientation)?;
+		self.set_vertical_scroll(0)?;
 
-			Ok(())
-		};
+		self.wake(clock)?;
+		self.set_display_on(true)
+	}
+
+	pub fn get_orientation(&self) -> Orientation {
+		self.orientation
+	}
 
-		(send_axis)(Command::ColumnAddressSet, start.x, end.x)?;
-		(send_axis)(Command::PageAddressSet, start.y, end.y)?;
+	/// Rotates the image drawn from now on (the pixels already on the panel aren't moved).
+	pub fn set_orientation(&mut self, orientation: Orientation) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command_with_parameters(Command::MemoryAccessControl, &[orientation.memory_access_control()])?;
+		self.orientation = orientation;
 
 		Ok(())
 	}
 
-	pub fn send_color(&mut self, color: ColorRGB565, count: u16) -> Result<(), SendError<DCXPin, Spi>> {
+	/// Returns the width and the height in pixels of the image in the current [`orientation`].
+	///
+	/// [`orientation`]: `Self::get_orientation`
+	pub fn size(&self) -> U16x2 {
+		self.orientation.size()
+	}
+
+	/// Turns the panel on or off. While it's off it shows a blank image, but the memory of the display keeps being
+	/// written.
+	pub fn set_display_on(&mut self, is_on: bool) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(match is_on {
+			true => Command::DisplayOn,
+			false => Command::DisplayOff,
+		})
+	}
+
+	/// Inverts (or stops inverting) the colors of all the pixels shown on the panel.
+	pub fn set_inverted(&mut self, is_inverted: bool) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(match is_inverted {
+			true => Command::DisplayInversionOn,
+			false => Command::DisplayInversionOff,
+		})
+	}
+
+	/// Puts the display in the sleep mode, where it consumes the least power (the panel is off, but the memory keeps
+	/// its content). It blocks for [`Self::SLEEP_IN_DURATION`].
+	pub fn sleep<T: SystemTime>(&mut self, clock: &Clock<T>) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(Command::EnterSleepMode)?;
+		clock.delay(Self::SLEEP_IN_DURATION);
+
+		Ok(())
+	}
+
+	/// Wakes the display up from the sleep mode. It blocks for [`Self::SLEEP_OUT_DURATION`].
+	pub fn wake<T: SystemTime>(&mut self, clock: &Clock<T>) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(Command::SleepOut)?;
+		clock.delay(Self::SLEEP_OUT_DURATION);
+
+		Ok(())
+	}
+
+	/// Sets the brightness of the backlight.
+	///
+	/// The ILI9341 controls it through its `LEDPWM` pin, so this does nothing on the modules where the backlight is
+	/// always on (or driven by a pin of the microcontroller).
+	pub fn set_brightness(&mut self, brightness: Percentage) -> Result<(), SendError<DCXPin, Transfer>> {
+		/// The `BCTRL` and `BL` bits, which enable the control of the brightness and the backlight.
+		const BRIGHTNESS_CONTROL_ON: u8 = 0x24;
+
+		self.send_command_with_parameters(Command::WriteControlDisplay, &[BRIGHTNESS_CONTROL_ON])?;
+		self.send_command_with_parameters(
+			Command::WriteDisplayBrightness,
+			&[(brightness.into_0_to_1() * u8::MAX as f32 + 0.5) as u8],
+		)
+	}
+
+	/// Divides the [`PANEL_HEIGHT`] rows of the panel in a fixed area at the top, a scrolling area and a fixed area
+	/// at the bottom (check [`Self::set_vertical_scroll`]).
+	///
+	/// The rows are the ones of the panel in [`Orientation::Portrait`], so in landscape the areas are vertical stripes
+	/// (and the scrolling is horizontal).
+	pub fn set_vertical_scroll_area(
+		&mut self, top_fixed_rows: u16, bottom_fixed_rows: u16,
+	) -> Result<(), SendError<DCXPin, Transfer>> {
+		let top_fixed_rows = top_fixed_rows.min(PANEL_HEIGHT);
+		let bottom_fixed_rows = bottom_fixed_rows.min(PANEL_HEIGHT - top_fixed_rows);
+		let scrolling_rows = PANEL_HEIGHT - top_fixed_rows - bottom_fixed_rows;
+
+		let mut parameters = [0; 6];
+		parameters[..2].copy_from_slice(&top_fixed_rows.to_be_bytes());
+		parameters[2..4].copy_from_slice(&scrolling_rows.to_be_bytes());
+		parameters[4..].copy_from_slice(&bottom_fixed_rows.to_be_bytes());
+		self.send_command_with_parameters(Command::VerticalScrollDefinition, &parameters)
+	}
+
+	/// Shows the row of the memory at the provided address at the top of the scrolling area (set with
+	/// [`Self::set_vertical_scroll_area`]), followed by the next ones (wrapping around at the end of the area).
+	pub fn set_vertical_scroll(&mut self, first_row: u16) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command_with_parameters(Command::VerticalScrollingStartAddress, &first_row.to_be_bytes())
+	}
+
+	/// Shows only the provided `rows` of the panel (the others are blank), which reduces the consumed power.
+	///
+	/// Like in [`Self::set_vertical_scroll_area`], the rows are the ones of the panel in [`Orientation::Portrait`].
+	pub fn enter_partial_mode(&mut self, rows: RangeInclusive<u16>) -> Result<(), SendError<DCXPin, Transfer>> {
+		let area = (*rows.start() as u32) << 16 | *rows.end() as u32;
+		self.send_command_with_parameters(Command::PartialArea, &area.to_be_bytes())?;
+		self.send_command(Command::PartialModeOn)
+	}
+
+	/// Shows all the rows of the panel again after [`Self::enter_partial_mode`].
+	pub fn enter_normal_mode(&mut self) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(Command::NormalDisplayModeOn)
+	}
+
+	/// Sets the area of the display the next pixels are written in (`start` and `end` are included), from left to
+	/// right and from top to bottom.
+	///
+	/// The columns and the pages are sent only if they are different from the ones of the last window.
+	pub fn set_window(&mut self, start: U16x2, end: U16x2) -> Result<(), SendError<DCXPin, Transfer>> {
+		let columns = Some((start.x, end.x));
+		if self.window_columns != columns {
+			self.send_address_range(Command::ColumnAddressSet, start.x, end.x)?;
+			self.window_columns = columns;
+		}
+		let pages = Some((start.y, end.y));
+		if self.window_pages != pages {
+			self.send_address_range(Command::PageAddressSet, start.y, end.y)?;
+			self.window_pages = pages;
+		}
+
+		Ok(())
+	}
+
+	fn send_address_range(
+		&mut self, command: Command, start: u16, end: u16,
+	) -> Result<(), SendError<DCXPin, Transfer>> {
+		let range = (start as u32) << 16 | end as u32;
+		self.send_command_with_parameters(command, &range.to_be_bytes())
+	}
+
+	/// Fills the rectangle with the top left corner at the provided `position` and the provided `size` with the
+	/// `color`, sending it with a single window.
+	pub fn fill_rect(
+		&mut self, position: U16x2, size: U16x2, color: ColorRGB565,
+	) -> Result<(), SendError<DCXPin, Transfer>> {
+		if size.x == 0 || size.y == 0 {
+			return Ok(());
+		}
+
+		self.set_window(position, position + size - U16x2 { x: 1, y: 1 })?;
+		self.send_color(color, size.x as u32 * size.y as u32)
+	}
+
+	/// Writes `count` pixels of the provided `color` in the current window, filling the buffers of the transfer with
+	/// the color so that as few transfers as possible are made.
+	pub fn send_color(&mut self, color: ColorRGB565, count: u32) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(Command::MemoryWrite)?;
+
+		let color = color.as_bytes();
+		let mut remaining_pixels = count as usize;
+		while remaining_pixels > 0 {
+			let buffer = self.get_transfer_buffer()?;
+			let pixels = remaining_pixels.min(buffer.len() / color.len());
+			for pixel in buffer.chunks_exact_mut(color.len()).take(pixels) {
+				pixel.copy_from_slice(&color);
+			}
+
+			self.transfer.send(pixels * color.len()).map_err(SendError::Transfer)?;
+			remaining_pixels -= pixels;
+		}
+
+		Ok(())
+	}
+
+	/// Writes the provided `colors` in the current window (one for each pixel), packing them in the buffers of the
+	/// transfer.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::{
+	/// # 	hot_plate::{drivers::ili9341::ILI9341, hal::display_transfer::DisplayTransfer},
+	/// # 	utils::measurement::color::ColorRGB565,
+	/// # };
+	/// # use embedded_hal::digital::OutputPin;
+	/// # use micromath::vector::U16x2;
+	/// #
+	/// # fn draw<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer>(
+	/// # 	ili9341: &mut ILI9341<DCXPin, ResetPin, Transfer>,
+	/// # ) {
+	/// // A gradient from black to white
+	/// let colors = [ColorRGB565::BLACK, ColorRGB565::GRAY, ColorRGB565::WHITE];
+	/// ili9341.set_window(U16x2 { x: 0, y: 0 }, U16x2 { x: 2, y: 0 }).unwrap();
+	/// ili9341.write_pixels(colors).unwrap();
+	/// # }
+	/// ```
+	pub fn write_pixels(
+		&mut self, colors: impl IntoIterator<Item = ColorRGB565>,
+	) -> Result<(), SendError<DCXPin, Transfer>> {
 		self.send_command(Command::MemoryWrite)?;
 
-		for _ in 0..count {
-			self.send_data(&color.as_bytes())?;
+		let mut colors = colors.into_iter().peekable();
+		while colors.peek().is_some() {
+			let buffer = self.get_transfer_buffer()?;
+			let mut length = 0;
+			for (pixel, color) in buffer.chunks_exact_mut(2).zip(&mut colors) {
+				pixel.copy_from_slice(&color.as_bytes());
+				length += pixel.len();
+			}
+
+			self.transfer.send(length).map_err(SendError::Transfer)?;
 		}
 
 		Ok(())
 	}
 
-	pub fn send_command(&mut self, command: Command) -> Result<(), SendError<DCXPin, Spi>> {
+	pub fn send_command(&mut self, command: Command) -> Result<(), SendError<DCXPin, Transfer>> {
+		// The D/CX pin can't change while the previous bytes are still being sent
+		self.transfer.flush().map_err(SendError::Transfer)?;
 		self.d_cx_pin.set_low().map_err(SendError::SetDCx)?;
 
-		self.spi.write(&[command as u8]).map_err(SendError::SendOverSPI)?;
+		self.send_bytes(&[command as u8])?;
+		self.transfer.flush().map_err(SendError::Transfer)?;
 
 		self.d_cx_pin.set_high().map_err(SendError::SetDCx)?;
 
 		Ok(())
 	}
 
-	pub fn send_data(&mut self, data: &[u8]) -> Result<(), SendError<DCXPin, Spi>> {
+	/// Sends the provided `command` followed by its `parameters` (if it has any).
+	pub fn send_command_with_parameters(
+		&mut self, command: Command, parameters: &[u8],
+	) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.send_command(command)?;
+		if !parameters.is_empty() {
+			self.send_data(parameters)?;
+		}
+
+		Ok(())
+	}
+
+	pub fn send_data(&mut self, data: &[u8]) -> Result<(), SendError<DCXPin, Transfer>> {
 		self.d_cx_pin.set_high().map_err(SendError::SetDCx)?;
 
-		self.spi.write(data).map_err(SendError::SendOverSPI)?;
+		self.send_bytes(data)
+	}
+
+	/// Blocks until all the bytes sent to the display have been transferred.
+	pub fn flush(&mut self) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.transfer.flush().map_err(SendError::Transfer)
+	}
+
+	/// Sends the `bytes` copying them in as many buffers of the transfer as needed.
+	fn send_bytes(&mut self, mut bytes: &[u8]) -> Result<(), SendError<DCXPin, Transfer>> {
+		while !bytes.is_empty() {
+			let buffer = self.get_transfer_buffer()?;
+			let length = bytes.len().min(buffer.len());
+			buffer[..length].copy_from_slice(&bytes[..length]);
+
+			self.transfer.send(length).map_err(SendError::Transfer)?;
+			bytes = &bytes[length..];
+		}
 
 		Ok(())
 	}
+
+	/// Returns the next buffer of the transfer, or `Err(SendError::BufferTooShort)` if it's shorter than
+	/// [`MIN_BUFFER_LENGTH`] (because not even a pixel would fit in it, so the pixels would never be sent).
+	fn get_transfer_buffer(&mut self) -> Result<&mut [u8], SendError<DCXPin, Transfer>> {
+		let buffer = self.transfer.get_buffer().map_err(SendError::Transfer)?;
+		match buffer.len() >= MIN_BUFFER_LENGTH {
+			true => Ok(buffer),
+			false => Err(SendError::BufferTooShort { length: buffer.len() }),
+		}
+	}
 }
 
-pub enum SendError<DCXPin: OutputPin, Spi: SpiDevice> {
+pub enum SendError<DCXPin: OutputPin, Transfer: DisplayTransfer> {
 	SetDCx(DCXPin::Error),
-	SendOverSPI(Spi::Error),
+	Transfer(Transfer::Error),
+	/// The buffer returned by [`DisplayTransfer::get_buffer`] is only `length` bytes long, which is less than the
+	/// [`MIN_BUFFER_LENGTH`].
+	BufferTooShort {
+		length: usize,
+	},
 }
 
-impl<DCXPin: OutputPin, Spi: SpiDevice> core::fmt::Debug for SendError<DCXPin, Spi> {
+impl<DCXPin: OutputPin, Transfer: DisplayTransfer> core::fmt::Debug for SendError<DCXPin, Transfer> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			Self::SetDCx(arg0) => f.debug_tuple("SetDCx").field(arg0).finish(),
-			Self::SendOverSPI(arg0) => f.debug_tuple("SendOverSPI").field(arg0).finish(),
+			Self::Transfer(arg0) => f.debug_tuple("Transfer").field(arg0).finish(),
+			Self::BufferTooShort { length } => f.debug_struct("BufferTooShort").field("length", length).finish(),
+		}
+	}
+}
+
+/// An error that can occur when you create an [`ILI9341`].
+pub enum InitError<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> {
+	Reset(ResetPin::Error),
+	Send(SendError<DCXPin, Transfer>),
+}
+
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> core::fmt::Debug
+	for InitError<DCXPin, ResetPin, Transfer>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Reset(arg0) => f.debug_tuple("Reset").field(arg0).finish(),
+			Self::Send(arg0) => f.debug_tuple("Send").field(arg0).finish(),
 		}
 	}
 }
@@ -0,0 +1,197 @@
+use core::{ops::RangeInclusive, time::Duration};
+
+use embedded_hal::i2c::{I2c, Operation};
+
+use crate::{
+	hot_plate::hal::system_time::{Clock, SystemTime},
+	utils::measurement::temperature::Temperature,
+};
+
+/// The SMBus address an [`Mlx90614`] has when it leaves the factory.
+pub const DEFAULT_ADDRESS: u8 = 0x5A;
+
+/// An [`MLX90614`] infrared thermometer connected to the microcontroller through the SMBus (which is compatible with
+/// I2C), that measures the temperature of the object it's pointed to without touching it.
+///
+/// Each word read from the sensor is followed by a [`PEC`] byte that is checked to discard the reads corrupted by
+/// noise on the bus.
+///
+/// [`MLX90614`]: <https://www.melexis.com/en/documents/documentation/datasheets/datasheet-mlx90614>
+/// [`PEC`]: `pec`
+pub struct Mlx90614<I2C: I2c> {
+	i2c: I2C,
+	address: u8,
+}
+
+impl<I2C: I2c> Mlx90614<I2C> {
+	/// The kelvins represented by each unit of a temperature read from the sensor.
+	pub const TEMPERATURE_RESOLUTION: f32 = 0.02;
+	/// The range of the emissivity the sensor can be configured with.
+	pub const EMISSIVITY_RANGE: RangeInclusive<f32> = 0.1..=1.;
+	/// The time the sensor needs to complete a write (or an erase) of a cell of its EEPROM.
+	pub const EEPROM_WRITE_DURATION: Duration = Duration::from_millis(10);
+
+	/// Returns an [`Mlx90614`] that communicates through the provided `i2c` bus with the sensor at the provided
+	/// `address` (usually [`DEFAULT_ADDRESS`]).
+	pub fn new(i2c: I2C, address: u8) -> Self {
+		Self { i2c, address }
+	}
+
+	/// Returns the SMBus address of the sensor.
+	pub fn get_address(&self) -> u8 {
+		self.address
+	}
+
+	/// Reads the temperature of the object the sensor is pointed to.
+	pub fn read_object_temperature(&mut self) -> Result<Temperature, Error<I2C>> {
+		self.read_temperature(Register::ObjectTemperature1)
+	}
+
+	/// Reads the temperature of the sensor's package, which is about the one of the air around it.
+	pub fn read_ambient_temperature(&mut self) -> Result<Temperature, Error<I2C>> {
+		self.read_temperature(Register::AmbientTemperature)
+	}
+
+	/// Reads the emissivity the sensor uses to compute the [`object temperature`], in the [`Self::EMISSIVITY_RANGE`].
+	///
+	/// [`object temperature`]: `Self::read_object_temperature`
+	pub fn read_emissivity(&mut self) -> Result<f32, Error<I2C>> {
+		let word = self.read_word(Register::Emissivity)?;
+
+		Ok(word as f32 / u16::MAX as f32)
+	}
+
+	/// Writes the emissivity the sensor uses to compute the [`object temperature`] in its EEPROM, blocking until the
+	/// write is complete.
+	///
+	/// The sensor starts using the new emissivity only after it's powered off and on again.
+	///
+	/// Returns `Err(Error::EmissivityOutOfRange)` if `emissivity` is outside the [`Self::EMISSIVITY_RANGE`].
+	///
+	/// [`object temperature`]: `Self::read_object_temperature`
+	pub fn write_emissivity<T: SystemTime>(&mut self, emissivity: f32, clock: &Clock<T>) -> Result<(), Error<I2C>> {
+		if !Self::EMISSIVITY_RANGE.contains(&emissivity) {
+			return Err(Error::EmissivityOutOfRange);
+		}
+
+		// A cell of the EEPROM must be erased before it can be written
+		self.write_word(Register::Emissivity, 0)?;
+		clock.delay(Self::EEPROM_WRITE_DURATION);
+
+		self.write_word(Register::Emissivity, (emissivity * u16::MAX as f32) as u16)?;
+		clock.delay(Self::EEPROM_WRITE_DURATION);
+
+		Ok(())
+	}
+
+	fn read_temperature(&mut self, register: Register) -> Result<Temperature, Error<I2C>> {
+		let word = self.read_word(register)?;
+		// The most significant bit is set when the sensor couldn't compute the temperature
+		if word & 0x8000 != 0 {
+			return Err(Error::InvalidMeasurement);
+		}
+
+		Ok(Temperature::from_kelvin(word as f32 * Self::TEMPERATURE_RESOLUTION))
+	}
+
+	/// Reads a word from the provided `register` using the "read word" protocol of the SMBus, checking its PEC.
+	fn read_word(&mut self, register: Register) -> Result<u16, Error<I2C>> {
+		let command = register as u8;
+		let mut response = [0; 3];
+		self.i2c
+			.transaction(
+				self.address,
+				&mut [Operation::Write(&[command]), Operation::Read(&mut response)],
+			)
+			.map_err(Error::I2c)?;
+
+		let [low_byte, high_byte, received_pec] = response;
+		let expected_pec = pec(&[self.address << 1, command, self.address << 1 | 1, low_byte, high_byte]);
+		if received_pec != expected_pec {
+			return Err(Error::WrongPec);
+		}
+
+		Ok(u16::from_le_bytes([low_byte, high_byte]))
+	}
+
+	/// Writes a word in the provided `register` using the "write word" protocol of the SMBus, followed by its PEC.
+	fn write_word(&mut self, register: Register, word: u16) -> Result<(), Error<I2C>> {
+		let command = register as u8;
+		let [low_byte, high_byte] = word.to_le_bytes();
+		let pec = pec(&[self.address << 1, command, low_byte, high_byte]);
+
+		self.i2c
+			.write(self.address, &[command, low_byte, high_byte, pec])
+			.map_err(Error::I2c)
+	}
+}
+
+/// The commands used to access the memory of the [`Mlx90614`] (the opcode of the memory combined with the address of
+/// the cell).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Register {
+	/// The temperature of the sensor's package, in the RAM.
+	AmbientTemperature = 0x06,
+	/// The temperature measured by the first infrared sensor, in the RAM.
+	ObjectTemperature1 = 0x07,
+	/// The temperature measured by the second infrared sensor (only on dual zone models), in the RAM.
+	ObjectTemperature2 = 0x08,
+	/// The emissivity, in the EEPROM.
+	Emissivity = 0x24,
+	/// The SMBus address, in the EEPROM.
+	SmbusAddress = 0x2E,
+}
+
+/// Returns the [`Packet Error Code`] of the provided bytes, which is the CRC-8 with polynomial `x^8 + x^2 + x + 1` of
+/// all the bytes of an SMBus message (including the addresses).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::drivers::mlx90614::pec;
+/// #
+/// assert_eq!(pec(&[]), 0);
+/// // The example from the datasheet of the MLX90614: a read of the RAM at 0x07 returning 0x3AD2
+/// assert_eq!(pec(&[0xB4, 0x07, 0xB5, 0xD2, 0x3A]), 0x30);
+/// ```
+///
+/// [`Packet Error Code`]: <https://en.wikipedia.org/wiki/System_Management_Bus#Packet_Error_Checking>
+pub fn pec(bytes: &[u8]) -> u8 {
+	const POLYNOMIAL: u8 = 0x07;
+
+	bytes.iter().fold(0, |mut crc, byte| {
+		crc ^= byte;
+		for _ in 0..8 {
+			crc = match crc & 0x80 != 0 {
+				true => crc << 1 ^ POLYNOMIAL,
+				false => crc << 1,
+			};
+		}
+		crc
+	})
+}
+
+/// An error that occurred while communicating with an [`Mlx90614`].
+pub enum Error<I2C: I2c> {
+	/// The communication on the I2C bus failed.
+	I2c(I2C::Error),
+	/// The [`PEC`] received from the sensor doesn't match the data, so the data has been corrupted.
+	///
+	/// [`PEC`]: `pec`
+	WrongPec,
+	/// The sensor flagged the temperature as invalid.
+	InvalidMeasurement,
+	/// The emissivity is outside [`Mlx90614::EMISSIVITY_RANGE`].
+	EmissivityOutOfRange,
+}
+
+impl<I2C: I2c> core::fmt::Debug for Error<I2C> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::I2c(arg0) => f.debug_tuple("I2c").field(arg0).finish(),
+			Self::WrongPec => write!(f, "WrongPec"),
+			Self::InvalidMeasurement => write!(f, "InvalidMeasurement"),
+			Self::EmissivityOutOfRange => write!(f, "EmissivityOutOfRange"),
+		}
+	}
+}
@@ -1,5 +1,6 @@
 pub mod cartridge_heater;
 pub mod fan;
 pub mod ili9341;
+pub mod mlx90614;
 pub mod thermistor;
 pub mod xpt2046;
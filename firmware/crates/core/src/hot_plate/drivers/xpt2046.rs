@@ -0,0 +1,311 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use embedded_hal::spi::SpiDevice;
+use micromath::vector::{F32x2, U16x2, Vector};
+
+use crate::hot_plate::{
+	hal::interrupt::{InterruptPin, Trigger},
+	screen::{SCREEN_HEIGHT_IN_PIXELS, SCREEN_WIDTH_IN_PIXELS},
+};
+
+/// The maximum number of samples an [`Xpt2046`] can average (or take the median of) for each touch.
+pub const MAX_SAMPLES_COUNT: usize = 16;
+
+/// The maximum value of a raw sample of the 12 bit ADC of the [`Xpt2046`].
+pub const MAX_RAW_VALUE: u16 = (1 << 12) - 1;
+
+/// An [`XPT2046`] resistive touchscreen controller connected to the microcontroller through SPI, with its `PENIRQ`
+/// output connected to an [`InterruptPin`].
+///
+/// When the panel is touched the controller pulls the `PENIRQ` pin low, which sets the `pen_down` flag: only then the
+/// panel is sampled (multiple times, [`filtering`] the samples to remove the noise), until the pressure drops below
+/// the [`threshold`].
+///
+/// [`XPT2046`]: <https://grobotronics.com/images/datasheets/xpt2046-datasheet.pdf>
+/// [`filtering`]: `Xpt2046Config::filter`
+/// [`threshold`]: `Xpt2046Config::pressure_threshold`
+pub struct Xpt2046<Spi: SpiDevice, IrqPin: InterruptPin> {
+	spi: Spi,
+	_pen_irq_pin: IrqPin,
+	pen_down: &'static AtomicBool,
+	config: Xpt2046Config,
+}
+
+impl<Spi: SpiDevice, IrqPin: InterruptPin> Xpt2046<Spi, IrqPin> {
+	/// Returns an [`Xpt2046`] that communicates through the provided `spi`, and that sets the provided `pen_down` flag
+	/// whenever the `pen_irq_pin` signals that the panel has been touched.
+	pub fn new(
+		spi: Spi, mut pen_irq_pin: IrqPin, pen_down: &'static AtomicBool, config: Xpt2046Config,
+	) -> Result<Self, IrqPin::Error> {
+		// SAFETY: the callback only sets an atomic flag, which is safe to do in an ISR
+		unsafe {
+			pen_irq_pin
+				.subscribe_to_interrupt(Trigger::NegativeEdge, move || pen_down.store(true, Ordering::Release))?;
+		}
+
+		Ok(Self {
+			spi,
+			_pen_irq_pin: pen_irq_pin,
+			pen_down,
+			config,
+		})
+	}
+
+	pub fn get_config(&self) -> &Xpt2046Config {
+		&self.config
+	}
+
+	pub fn set_config(&mut self, config: Xpt2046Config) {
+		self.config = config;
+	}
+
+	/// Sets the [`Calibration`] used to map the raw samples to the pixels of the screen.
+	pub fn set_calibration(&mut self, calibration: Calibration) {
+		self.config.calibration = calibration;
+	}
+
+	/// Returns `true` if the panel has been touched and not released yet (as far as the last [`read`] knows).
+	///
+	/// [`read`]: `Self::read_touch`
+	pub fn is_pen_down(&self) -> bool {
+		self.pen_down.load(Ordering::Acquire)
+	}
+
+	/// Returns the point of the screen that is being touched, or `None` if the panel isn't being touched.
+	pub fn read_touch(&mut self) -> Result<Option<Touch>, Spi::Error> {
+		Ok(self.read_raw_touch()?.map(|raw_touch| Touch {
+			position: self.config.calibration.apply(raw_touch.position),
+			pressure: raw_touch.pressure,
+		}))
+	}
+
+	/// Returns the raw (not calibrated) sample of the point that is being touched, or `None` if the panel isn't being
+	/// touched. This is useful to compute a [`Calibration`].
+	pub fn read_raw_touch(&mut self) -> Result<Option<Touch>, Spi::Error> {
+		if !self.is_pen_down() {
+			return Ok(None);
+		}
+
+		let samples_count = (self.config.samples_count as usize).clamp(1, MAX_SAMPLES_COUNT);
+		let mut x_samples = [0; MAX_SAMPLES_COUNT];
+		let mut y_samples = [0; MAX_SAMPLES_COUNT];
+		let mut pressure_samples = [0; MAX_SAMPLES_COUNT];
+		for i in 0..samples_count {
+			x_samples[i] = self.read_channel(Channel::X)?;
+			y_samples[i] = self.read_channel(Channel::Y)?;
+
+			let z1 = self.read_channel(Channel::Z1)?;
+			let z2 = self.read_channel(Channel::Z2)?;
+			pressure_samples[i] = (z1 + MAX_RAW_VALUE).saturating_sub(z2);
+		}
+
+		let pressure = self.config.filter.apply(&mut pressure_samples[..samples_count]);
+		if pressure < self.config.pressure_threshold {
+			// The pen has been lifted: wait for the next interrupt before sampling again
+			self.pen_down.store(false, Ordering::Release);
+			return Ok(None);
+		}
+
+		Ok(Some(Touch {
+			position: U16x2 {
+				x: self.config.filter.apply(&mut x_samples[..samples_count]),
+				y: self.config.filter.apply(&mut y_samples[..samples_count]),
+			},
+			pressure,
+		}))
+	}
+
+	/// Returns a 12 bit sample of the provided `channel`, measured in differential mode. Between the conversions the
+	/// controller is powered down with the `PENIRQ` output enabled.
+	fn read_channel(&mut self, channel: Channel) -> Result<u16, Spi::Error> {
+		const START_BIT: u8 = 0x80;
+
+		let mut response = [0; 3];
+		self.spi
+			.transfer(&mut response, &[START_BIT | ((channel as u8) << 4), 0, 0])?;
+
+		Ok(u16::from_be_bytes([response[1], response[2]]) >> 3)
+	}
+}
+
+/// The inputs of the ADC of the [`Xpt2046`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Channel {
+	X = 0b101,
+	Y = 0b001,
+	Z1 = 0b011,
+	Z2 = 0b100,
+}
+
+/// A touch on the panel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Touch {
+	/// The position of the touch (in pixels of the screen if it has been calibrated, raw otherwise).
+	pub position: U16x2,
+	/// The pressure of the touch, which is higher the harder the panel is pressed.
+	pub pressure: u16,
+}
+
+/// How the multiple samples taken for each touch are combined.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SampleFilter {
+	/// The mean of the samples.
+	Average,
+	/// The [`median`] of the samples, which ignores the spikes.
+	///
+	/// [`median`]: <https://en.wikipedia.org/wiki/Median>
+	#[default]
+	Median,
+}
+
+impl SampleFilter {
+	/// Returns the value obtained combining the provided `samples` (which may be reordered).
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::drivers::xpt2046::SampleFilter;
+	/// #
+	/// assert_eq!(SampleFilter::Average.apply(&mut [100, 110, 4000]), 1403);
+	/// assert_eq!(SampleFilter::Median.apply(&mut [100, 4000, 110]), 110);
+	/// ```
+	pub fn apply(&self, samples: &mut [u16]) -> u16 {
+		if samples.is_empty() {
+			return 0;
+		}
+
+		match self {
+			Self::Average => (samples.iter().map(|&sample| sample as u32).sum::<u32>() / samples.len() as u32) as u16,
+			Self::Median => {
+				samples.sort_unstable();
+				samples[samples.len() / 2]
+			},
+		}
+	}
+}
+
+/// An [`affine transformation`] that maps the raw samples of the [`Xpt2046`] to the pixels of the screen, which takes
+/// into account the offset, the scale and the rotation of the panel compared to the display.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::drivers::xpt2046::Calibration;
+/// # use micromath::vector::U16x2;
+/// #
+/// // The raw x axis of this panel goes from the right to the left of the screen
+/// let calibration = Calibration::from_points([
+/// 	(U16x2 { x: 3800, y: 400 }, U16x2 { x: 20, y: 20 }),
+/// 	(U16x2 { x: 300, y: 400 }, U16x2 { x: 300, y: 20 }),
+/// 	(U16x2 { x: 2050, y: 3600 }, U16x2 { x: 160, y: 220 }),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(calibration.apply(U16x2 { x: 2050, y: 2000 }), U16x2 { x: 160, y: 120 });
+/// // The points outside the screen are clamped to its border
+/// assert_eq!(calibration.apply(U16x2 { x: 4095, y: 0 }), U16x2 { x: 0, y: 0 });
+/// ```
+///
+/// [`affine transformation`]: <https://en.wikipedia.org/wiki/Affine_transformation>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+	/// `screen x = x.x * raw x + x.y * raw y + offset.x`.
+	x: F32x2,
+	/// `screen y = y.x * raw x + y.y * raw y + offset.y`.
+	y: F32x2,
+	offset: F32x2,
+}
+
+impl Calibration {
+	/// A calibration that maps the whole range of the raw samples to the whole screen, without rotations.
+	pub const FULL_RANGE: Self = Self {
+		x: F32x2 {
+			x: SCREEN_WIDTH_IN_PIXELS as f32 / MAX_RAW_VALUE as f32,
+			y: 0.,
+		},
+		y: F32x2 {
+			x: 0.,
+			y: SCREEN_HEIGHT_IN_PIXELS as f32 / MAX_RAW_VALUE as f32,
+		},
+		offset: F32x2 { x: 0., y: 0. },
+	};
+
+	/// Returns the [`Calibration`] that maps each of the 3 raw samples to the respective point of the screen (the
+	/// tuples are `(raw sample, screen point)`).
+	///
+	/// Returns `None` if the points are on the same line, since then the calibration can't be computed.
+	pub fn from_points(points: [(U16x2, U16x2); 3]) -> Option<Self> {
+		let [(raw_a, screen_a), (raw_b, screen_b), (raw_c, screen_c)] = points.map(|(raw, screen)| {
+			(
+				F32x2::from((raw.x as f32, raw.y as f32)),
+				F32x2::from((screen.x as f32, screen.y as f32)),
+			)
+		});
+
+		// Solve the 2 linear systems (one for each axis of the screen) using Cramer's rule
+		let determinant = (raw_a.x - raw_c.x) * (raw_b.y - raw_c.y) - (raw_b.x - raw_c.x) * (raw_a.y - raw_c.y);
+		if determinant.abs() < f32::EPSILON {
+			return None;
+		}
+
+		let solve = |a: f32, b: f32, c: f32| {
+			let x = ((a - c) * (raw_b.y - raw_c.y) - (b - c) * (raw_a.y - raw_c.y)) / determinant;
+			let y = ((raw_a.x - raw_c.x) * (b - c) - (raw_b.x - raw_c.x) * (a - c)) / determinant;
+			let offset = c - x * raw_c.x - y * raw_c.y;
+
+			(F32x2 { x, y }, offset)
+		};
+		let (x, offset_x) = solve(screen_a.x, screen_b.x, screen_c.x);
+		let (y, offset_y) = solve(screen_a.y, screen_b.y, screen_c.y);
+
+		Some(Self {
+			x,
+			y,
+			offset: F32x2 {
+				x: offset_x,
+				y: offset_y,
+			},
+		})
+	}
+
+	/// Returns the point of the screen corresponding to the provided `raw` sample (clamped to the screen).
+	pub fn apply(&self, raw: U16x2) -> U16x2 {
+		let raw = F32x2::from((raw.x as f32, raw.y as f32));
+		let screen_x = self.x.dot(raw) + self.offset.x;
+		let screen_y = self.y.dot(raw) + self.offset.y;
+
+		U16x2 {
+			x: (screen_x + 0.5).clamp(0., SCREEN_WIDTH_IN_PIXELS as f32 - 1.) as u16,
+			y: (screen_y + 0.5).clamp(0., SCREEN_HEIGHT_IN_PIXELS as f32 - 1.) as u16,
+		}
+	}
+}
+
+impl Default for Calibration {
+	fn default() -> Self {
+		Self::FULL_RANGE
+	}
+}
+
+/// The parameters of an [`Xpt2046`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Xpt2046Config {
+	/// How many samples are taken for each touch (at most [`MAX_SAMPLES_COUNT`]).
+	pub samples_count: u8,
+	pub filter: SampleFilter,
+	/// The minimum [`pressure`] for the panel to be considered touched.
+	///
+	/// [`pressure`]: `Touch::pressure`
+	pub pressure_threshold: u16,
+	pub calibration: Calibration,
+}
+
+impl Default for Xpt2046Config {
+	fn default() -> Self {
+		Self {
+			samples_count: 7,
+			filter: SampleFilter::default(),
+			pressure_threshold: 400,
+			calibration: Calibration::default(),
+		}
+	}
+}
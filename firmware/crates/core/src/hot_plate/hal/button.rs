@@ -0,0 +1,38 @@
+use core::fmt::Debug;
+
+use embedded_hal::digital::InputPin;
+
+/// A push button the user can press.
+pub trait Button {
+	type Error: Debug;
+
+	/// Returns `true` if the button is being pressed right now.
+	///
+	/// The contacts of a button bounce, so for some milliseconds after it's pressed or released the returned value can
+	/// change at each read: check [`Debouncer`].
+	///
+	/// [`Debouncer`]: `crate::hot_plate::input::debounce::Debouncer`
+	fn is_pressed(&mut self) -> Result<bool, Self::Error>;
+}
+
+/// A [`Button`] that connects its pin to the ground when it's pressed (so the pin must be pulled up).
+pub struct ActiveLowButton<P: InputPin>(pub P);
+
+impl<P: InputPin> Button for ActiveLowButton<P> {
+	type Error = P::Error;
+
+	fn is_pressed(&mut self) -> Result<bool, Self::Error> {
+		self.0.is_low()
+	}
+}
+
+/// A [`Button`] that connects its pin to the supply voltage when it's pressed (so the pin must be pulled down).
+pub struct ActiveHighButton<P: InputPin>(pub P);
+
+impl<P: InputPin> Button for ActiveHighButton<P> {
+	type Error = P::Error;
+
+	fn is_pressed(&mut self) -> Result<bool, Self::Error> {
+		self.0.is_high()
+	}
+}
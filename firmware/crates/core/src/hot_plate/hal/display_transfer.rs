@@ -0,0 +1,64 @@
+use core::fmt::Debug;
+
+use embedded_hal::spi::SpiDevice;
+
+/// The minimum length in bytes of the buffers of a [`DisplayTransfer`]: the size of one pixel, because the colors of
+/// the pixels can't be split between 2 buffers.
+pub const MIN_BUFFER_LENGTH: usize = 2;
+
+/// Sends the bytes (commands, parameters and colors of the pixels) to a display controller like the [`ILI9341`],
+/// one buffer at a time.
+///
+/// The buffers are owned by the transfer, so that an implementation can send them with a DMA channel: it can own 2
+/// buffers, return from [`Self::send`] right after having started the transfer of one, and let the driver fill the
+/// other one in the meantime (which is what [`Self::get_buffer`] returns).
+///
+/// [`ILI9341`]: `crate::hot_plate::drivers::ili9341::ILI9341`
+pub trait DisplayTransfer {
+	type Error: Debug;
+
+	/// Returns the buffer to fill with the next bytes to send, which must be at least [`MIN_BUFFER_LENGTH`] bytes long.
+	/// Its length is the maximum number of bytes a single call to [`Self::send`] can send.
+	///
+	/// If the buffer is still being sent by a previous transfer, this waits for it to finish.
+	fn get_buffer(&mut self) -> Result<&mut [u8], Self::Error>;
+
+	/// Starts sending the first `length` bytes of the buffer returned by the last call to [`Self::get_buffer`].
+	///
+	/// It can return before the bytes have been sent (check [`Self::flush`]).
+	fn send(&mut self, length: usize) -> Result<(), Self::Error>;
+
+	/// Blocks until all the bytes passed to [`Self::send`] have been sent.
+	fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A [`DisplayTransfer`] that sends each buffer of `N` bytes with a blocking write of an [`SpiDevice`] (so each buffer
+/// is sent in a single SPI transaction).
+///
+/// `N` must be at least [`MIN_BUFFER_LENGTH`].
+pub struct SpiTransfer<Spi: SpiDevice, const N: usize> {
+	spi: Spi,
+	buffer: [u8; N],
+}
+
+impl<Spi: SpiDevice, const N: usize> SpiTransfer<Spi, N> {
+	pub fn new(spi: Spi) -> Self {
+		Self { spi, buffer: [0; N] }
+	}
+}
+
+impl<Spi: SpiDevice, const N: usize> DisplayTransfer for SpiTransfer<Spi, N> {
+	type Error = Spi::Error;
+
+	fn get_buffer(&mut self) -> Result<&mut [u8], Self::Error> {
+		Ok(&mut self.buffer)
+	}
+
+	fn send(&mut self, length: usize) -> Result<(), Self::Error> {
+		self.spi.write(&self.buffer[..length])
+	}
+
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
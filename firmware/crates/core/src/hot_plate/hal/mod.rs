@@ -1,4 +1,6 @@
 pub mod adc;
+pub mod button;
+pub mod display_transfer;
 pub mod interrupt;
 pub mod pwm;
 pub mod system_time;
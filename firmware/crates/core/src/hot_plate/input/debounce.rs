@@ -0,0 +1,65 @@
+use core::time::Duration;
+
+/// Filters the bounces of the contacts of a [`Button`]: its state changes only after the samples stayed the same
+/// for the debounce time.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::input::debounce::Debouncer;
+/// # use std::time::Duration;
+/// #
+/// let debounce_time = Duration::from_millis(20);
+/// let mut debouncer = Debouncer::new(false);
+///
+/// // The contacts bounce for some milliseconds after the button is pressed
+/// assert_eq!(debouncer.update(true, Duration::from_millis(100), debounce_time), None);
+/// assert_eq!(debouncer.update(false, Duration::from_millis(105), debounce_time), None);
+/// assert_eq!(debouncer.update(true, Duration::from_millis(110), debounce_time), None);
+/// assert_eq!(debouncer.update(true, Duration::from_millis(125), debounce_time), None);
+/// assert!(!debouncer.get_state());
+///
+/// assert_eq!(debouncer.update(true, Duration::from_millis(130), debounce_time), Some(true));
+/// assert!(debouncer.get_state());
+/// ```
+///
+/// [`Button`]: `crate::hot_plate::hal::button::Button`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Debouncer {
+	state: bool,
+	last_sample: bool,
+	/// The time when the last sample changed.
+	last_change_time: Duration,
+}
+
+impl Debouncer {
+	/// Returns a [`Debouncer`] whose state is the provided one.
+	pub const fn new(state: bool) -> Self {
+		Self {
+			state,
+			last_sample: state,
+			last_change_time: Duration::ZERO,
+		}
+	}
+
+	/// Returns the debounced state (`true` if the button is pressed).
+	pub fn get_state(&self) -> bool {
+		self.state
+	}
+
+	/// Adds the `sample` read at the provided time (`now`), returning the new state if it changed (which happens when
+	/// the samples have been the same for at least the `debounce_time`).
+	pub fn update(&mut self, sample: bool, now: Duration, debounce_time: Duration) -> Option<bool> {
+		if sample != self.last_sample {
+			self.last_sample = sample;
+			self.last_change_time = now;
+		}
+
+		let is_stable = now.saturating_sub(self.last_change_time) >= debounce_time;
+		if sample != self.state && is_stable {
+			self.state = sample;
+			return Some(sample);
+		}
+
+		None
+	}
+}
@@ -0,0 +1,278 @@
+use core::time::Duration;
+
+use self::debounce::Debouncer;
+
+use super::hal::button::Button;
+
+pub mod debounce;
+
+/// Something the user did with a [`Button`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ButtonEvent {
+	/// The button has been pressed and released (and it hasn't been pressed again soon enough to make it a
+	/// [`Self::DoublePress`]).
+	ShortPress,
+	/// The button has been pressed and released twice in a short time.
+	DoublePress,
+	/// The button has been kept pressed for a long time (this is sent while it's still pressed).
+	LongPress,
+	/// The button is being kept pressed, so the action of a press should be repeated.
+	Repeat,
+}
+
+/// The parameters of a [`ButtonInput`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonConfig {
+	/// How long the reads of the button must stay the same for its state to change. Check [`Debouncer`].
+	pub debounce_in_seconds: f32,
+	/// If it's `Some`, a [`ButtonEvent::LongPress`] is sent when the button is kept pressed for this amount of seconds.
+	pub long_press_in_seconds: Option<f32>,
+	/// If it's `Some`, a [`ButtonEvent::DoublePress`] is sent when the button is pressed again before this amount of
+	/// seconds passes from its release.
+	///
+	/// This delays the [`ButtonEvent::ShortPress`] by the same amount (to be sure that it's not the first half of a
+	/// double press), so leave it `None` for buttons that don't need it.
+	pub double_press_interval_in_seconds: Option<f32>,
+	/// If it's `Some`, [`ButtonEvent::Repeat`]s are sent while the button is kept pressed.
+	pub repeat: Option<RepeatConfig>,
+}
+
+/// When the [`ButtonEvent::Repeat`]s are sent while a button is kept pressed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RepeatConfig {
+	/// How many seconds the button must be kept pressed before the first repeat.
+	pub delay_in_seconds: f32,
+	/// How many seconds pass between a repeat and the next one.
+	pub interval_in_seconds: f32,
+}
+
+/// Turns the reads of a [`Button`] into [`ButtonEvent`]s.
+///
+/// A press that produced a [`ButtonEvent::LongPress`] or a [`ButtonEvent::Repeat`] doesn't produce any other event
+/// when the button is released.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::{
+/// # 	hal::button::Button,
+/// # 	input::{ButtonConfig, ButtonEvent, ButtonInput, RepeatConfig},
+/// # };
+/// # use std::{cell::Cell, convert::Infallible, rc::Rc, time::Duration};
+/// #
+/// struct FakeButton(Rc<Cell<bool>>);
+///
+/// impl Button for FakeButton {
+/// 	type Error = Infallible;
+///
+/// 	fn is_pressed(&mut self) -> Result<bool, Self::Error> {
+/// 		Ok(self.0.get())
+/// 	}
+/// }
+///
+/// let is_pressed = Rc::new(Cell::new(false));
+/// let mut input = ButtonInput::new(
+/// 	FakeButton(Rc::clone(&is_pressed)),
+/// 	ButtonConfig {
+/// 		debounce_in_seconds: 0.02,
+/// 		long_press_in_seconds: Some(2.),
+/// 		double_press_interval_in_seconds: None,
+/// 		repeat: None,
+/// 	},
+/// );
+/// let mut now = Duration::ZERO;
+/// let mut wait = |input: &mut ButtonInput<FakeButton>, milliseconds| {
+/// 	let mut events = Vec::new();
+/// 	for _ in 0..milliseconds / 10 {
+/// 		now += Duration::from_millis(10);
+/// 		events.extend(input.update(now).unwrap());
+/// 	}
+/// 	events
+/// };
+///
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 200), []);
+/// is_pressed.set(false);
+/// assert_eq!(wait(&mut input, 100), [ButtonEvent::ShortPress]);
+///
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 3_000), [ButtonEvent::LongPress]);
+/// is_pressed.set(false);
+/// assert_eq!(wait(&mut input, 100), []);
+///
+/// input.set_config(ButtonConfig {
+/// 	long_press_in_seconds: None,
+/// 	double_press_interval_in_seconds: Some(0.3),
+/// 	repeat: Some(RepeatConfig { delay_in_seconds: 0.5, interval_in_seconds: 0.1 }),
+/// 	..*input.get_config()
+/// });
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 100), []);
+/// is_pressed.set(false);
+/// assert_eq!(wait(&mut input, 100), []);
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 100), []);
+/// is_pressed.set(false);
+/// assert_eq!(wait(&mut input, 100), [ButtonEvent::DoublePress]);
+///
+/// // A short press is sent only when it's too late for a double press
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 100), []);
+/// is_pressed.set(false);
+/// assert_eq!(wait(&mut input, 200), []);
+/// assert_eq!(wait(&mut input, 200), [ButtonEvent::ShortPress]);
+///
+/// is_pressed.set(true);
+/// assert_eq!(wait(&mut input, 1_000), [ButtonEvent::Repeat; 5]);
+/// ```
+pub struct ButtonInput<B: Button> {
+	button: B,
+	config: ButtonConfig,
+	debouncer: Debouncer,
+	press: PressState,
+}
+
+impl<B: Button> ButtonInput<B> {
+	/// Returns a [`ButtonInput`] that reads the provided `button`, which is considered released.
+	pub fn new(button: B, config: ButtonConfig) -> Self {
+		Self {
+			button,
+			config,
+			debouncer: Debouncer::new(false),
+			press: PressState::Released,
+		}
+	}
+
+	pub fn get_config(&self) -> &ButtonConfig {
+		&self.config
+	}
+
+	pub fn set_config(&mut self, config: ButtonConfig) {
+		self.config = config;
+	}
+
+	/// Returns `true` if the button is pressed (after having filtered its bounces).
+	pub fn is_pressed(&self) -> bool {
+		self.debouncer.get_state()
+	}
+
+	/// Reads the button at the provided time (`now`, which you can get from [`Clock::get_elapsed_time`]), returning
+	/// the event that happened to it (if any).
+	///
+	/// You should call it at least every few milliseconds (a period shorter than the debounce time is best).
+	///
+	/// [`Clock::get_elapsed_time`]: `crate::hot_plate::hal::system_time::Clock::get_elapsed_time`
+	pub fn update(&mut self, now: Duration) -> Result<Option<ButtonEvent>, B::Error> {
+		let sample = self.button.is_pressed()?;
+		let debounce_time = Duration::from_secs_f32(self.config.debounce_in_seconds);
+		let state_change = self.debouncer.update(sample, now, debounce_time);
+
+		let (press, event) = match (self.press, state_change) {
+			(PressState::Released, Some(true)) => (PressState::pressed(now, false), None),
+			(PressState::WaitingSecondPress { .. }, Some(true)) => (PressState::pressed(now, true), None),
+			(PressState::WaitingSecondPress { released_at }, _) => {
+				match has_elapsed(self.config.double_press_interval_in_seconds, now - released_at) {
+					true => (PressState::Released, Some(ButtonEvent::ShortPress)),
+					false => (self.press, None),
+				}
+			},
+			(
+				PressState::Pressed {
+					is_second_press,
+					is_long_press,
+					repeats_count,
+					..
+				},
+				Some(false),
+			) => match (is_long_press || repeats_count > 0, is_second_press) {
+				(true, _) => (PressState::Released, None),
+				(false, true) => (PressState::Released, Some(ButtonEvent::DoublePress)),
+				(false, false) => match self.config.double_press_interval_in_seconds {
+					Some(_) => (PressState::WaitingSecondPress { released_at: now }, None),
+					None => (PressState::Released, Some(ButtonEvent::ShortPress)),
+				},
+			},
+			(PressState::Pressed { .. }, _) => self.update_held_press(now),
+			(PressState::Released, _) => (self.press, None),
+		};
+		self.press = press;
+
+		Ok(event)
+	}
+
+	/// Returns the new state of a press that is still held, and the event it produced (if any).
+	fn update_held_press(&self, now: Duration) -> (PressState, Option<ButtonEvent>) {
+		let PressState::Pressed {
+			since,
+			is_second_press,
+			is_long_press,
+			repeats_count,
+		} = self.press
+		else {
+			return (self.press, None);
+		};
+		let held_time = now - since;
+
+		if !is_long_press && has_elapsed(self.config.long_press_in_seconds, held_time) {
+			return (
+				PressState::Pressed {
+					since,
+					is_second_press,
+					is_long_press: true,
+					repeats_count,
+				},
+				Some(ButtonEvent::LongPress),
+			);
+		}
+		if let Some(repeat) = self.config.repeat {
+			let next_repeat_in_seconds = repeat.delay_in_seconds + repeat.interval_in_seconds * repeats_count as f32;
+			if held_time.as_secs_f32() >= next_repeat_in_seconds {
+				return (
+					PressState::Pressed {
+						since,
+						is_second_press,
+						is_long_press,
+						repeats_count: repeats_count + 1,
+					},
+					Some(ButtonEvent::Repeat),
+				);
+			}
+		}
+
+		(self.press, None)
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PressState {
+	Released,
+	Pressed {
+		/// When the button has been pressed.
+		since: Duration,
+		/// `true` if the button has been released shortly before this press.
+		is_second_press: bool,
+		/// `true` if a [`ButtonEvent::LongPress`] has already been sent for this press.
+		is_long_press: bool,
+		/// The number of [`ButtonEvent::Repeat`]s sent for this press.
+		repeats_count: u32,
+	},
+	/// The button has been released after a short press, and it could be pressed again to make a double press.
+	WaitingSecondPress {
+		released_at: Duration,
+	},
+}
+
+impl PressState {
+	fn pressed(since: Duration, is_second_press: bool) -> Self {
+		Self::Pressed {
+			since,
+			is_second_press,
+			is_long_press: false,
+			repeats_count: 0,
+		}
+	}
+}
+
+/// Returns `true` if the `time` is at least the provided amount of seconds (`false` if it's `None`).
+fn has_elapsed(seconds: Option<f32>, time: Duration) -> bool {
+	seconds.is_some_and(|seconds| time.as_secs_f32() >= seconds)
+}
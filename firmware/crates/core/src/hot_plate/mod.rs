@@ -1,35 +1,78 @@
+use core::{sync::atomic::AtomicBool, time::Duration};
+
 use embedded_hal::{digital::OutputPin, spi::SpiDevice};
+use micromath::vector::U16x2;
+use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
 
-use crate::utils::math::Percentage;
+use crate::utils::{math::Percentage, measurement::temperature::Temperature};
 
 use self::{
 	config::Configuration,
 	drivers::{
 		cartridge_heater::CartridgeHeater,
 		fan::Fan,
-		ili9341::{SendError, ILI9341},
+		ili9341::{InitError, SendError, ILI9341},
+		mlx90614::Mlx90614,
 		thermistor::Thermistor,
+		xpt2046::Xpt2046,
+	},
+	hal::{
+		button::Button, display_transfer::DisplayTransfer, interrupt::InterruptPin, pwm::PwmPin, system_time::Clock,
 	},
-	hal::{pwm::PwmPin, system_time::Clock},
+	input::ButtonInput,
 	peripherals::Peripherals,
-	process::DefaultReflowProcess,
-	screen::Screen,
-	temperature::{safety::TemperatureSafety, TemperaturePidController},
+	process::{
+		AbortReason, BuiltinReflowProfile, CommandError, DefaultReflowStateMachine, Interpolation, ReflowEvent,
+		ReflowState,
+	},
+	screen::{
+		ui::{default::DefaultUI, message, HotPlateStatus, Menu, NavigationButton, TouchEvent, UiAction},
+		Screen,
+	},
+	temperature::{
+		autotune::{Autotune, AutotuneConfig, AutotuneError, TuningRule},
+		fusion::{SensorReadings, TemperatureSensors},
+		gain_schedule::GainSchedule,
+		safety::TemperatureSafety,
+		TemperaturePidController,
+	},
 };
 
 pub mod config;
 pub mod drivers;
 pub mod hal;
+pub mod input;
 pub mod peripherals;
 pub mod process;
 pub mod screen;
 pub mod temperature;
 
+/// The maximum number of [`ReflowEvent`]s the [`HotPlate`] keeps before starting to discard the oldest ones.
+pub const REFLOW_EVENTS_CAPACITY: usize = 16;
+
+/// Set by the interrupt of the touch panel when it's touched.
+static TOUCH_PEN_DOWN: AtomicBool = AtomicBool::new(false);
+
 pub struct HotPlate<P: Peripherals> {
-	screen: Screen<P::LcdDCXPin, P::LcdResetPin, P::LcdSpi>,
-	reflow_process: Option<DefaultReflowProcess>,
+	screen: Screen<P::LcdDCXPin, P::LcdResetPin, P::LcdTransfer>,
+	touch: Xpt2046<P::TouchSpi, P::TouchInterruptPin>,
+	/// The last point of the screen that has been touched, or `None` if the touch panel isn't being touched.
+	touch_position: Option<U16x2>,
+	/// The physical buttons that can be used instead of the touch panel (if they're mounted).
+	next_button: Option<ButtonInput<P::Button>>,
+	select_button: Option<ButtonInput<P::Button>>,
+	reflow_state_machine: DefaultReflowStateMachine,
+	selected_reflow_profile: BuiltinReflowProfile,
+	/// Events that the hot plate still has to react to.
+	pending_reflow_events: ConstGenericRingBuffer<ReflowEvent, REFLOW_EVENTS_CAPACITY>,
+	/// Events that the hot plate already reacted to, waiting to be [`polled`](Self::poll_reflow_event).
+	reflow_events: ConstGenericRingBuffer<ReflowEvent, REFLOW_EVENTS_CAPACITY>,
+	/// The last autotune that has been started, with the rule used to compute the gains at its end.
+	autotune: Option<(Autotune, TuningRule)>,
+	/// The parameters of the autotunes started from the user interface.
+	ui_autotune: (AutotuneConfig, TuningRule),
 
-	pid_controller: TemperaturePidController<P::HeaterPin, P::ADC, P::Thermistor1Pin>,
+	pid_controller: PeripheralsPidController<P>,
 	adc: P::ADC,
 
 	fan: Fan<P::FanPin>,
@@ -38,7 +81,13 @@ pub struct HotPlate<P: Peripherals> {
 }
 
 impl<P: Peripherals> HotPlate<P> {
-	pub fn new(mut peripherals: P, configuration: Configuration) -> Result<Self, CreationError<P::LcdResetPin>> {
+	pub fn new(mut peripherals: P, configuration: Configuration) -> Result<Self, PeripheralsCreationError<P>> {
+		let clock = Clock::new(
+			peripherals
+				.take_system_time()
+				.ok_or(CreationError::PeripheralMissing { name: "System time" })?,
+		);
+
 		Ok(Self {
 			screen: Screen::new(
 				ILI9341::new(
@@ -49,17 +98,46 @@ impl<P: Peripherals> HotPlate<P> {
 						.take_lcd_reset_pin()
 						.ok_or(CreationError::PeripheralMissing { name: "LCD reset pin" })?,
 					peripherals
-						.take_lcd_spi()
-						.ok_or(CreationError::PeripheralMissing { name: "LCD SPI" })?,
+						.take_lcd_transfer()
+						.ok_or(CreationError::PeripheralMissing { name: "LCD transfer" })?,
+					&clock,
+					configuration.ui.display_orientation,
 				)
 				.map_err(CreationError::ScreenCreation)?,
+				configuration.ui.use_line_buffer,
 			),
-			reflow_process: None,
-			clock: Clock::new(
+			touch: Xpt2046::new(
 				peripherals
-					.take_system_time()
-					.ok_or(CreationError::PeripheralMissing { name: "System time" })?,
-			),
+					.take_touch_spi()
+					.ok_or(CreationError::PeripheralMissing { name: "Touch SPI" })?,
+				peripherals
+					.take_touch_interrupt_pin()
+					.ok_or(CreationError::PeripheralMissing {
+						name: "Touch interrupt pin",
+					})?,
+				&TOUCH_PEN_DOWN,
+				configuration.ui.touch,
+			)
+			.map_err(CreationError::TouchCreation)?,
+			touch_position: None,
+			next_button: peripherals
+				.take_next_button()
+				.map(|button| ButtonInput::new(button, configuration.ui.next_button)),
+			select_button: peripherals
+				.take_select_button()
+				.map(|button| ButtonInput::new(button, configuration.ui.select_button)),
+			reflow_state_machine: {
+				let mut reflow_state_machine = DefaultReflowStateMachine::new();
+				reflow_state_machine.set_guaranteed_dwell(configuration.reflow.guaranteed_dwell);
+				reflow_state_machine.set_interpolation(configuration.reflow.interpolation);
+				reflow_state_machine
+			},
+			selected_reflow_profile: BuiltinReflowProfile::DEFAULT,
+			pending_reflow_events: ConstGenericRingBuffer::new(),
+			reflow_events: ConstGenericRingBuffer::new(),
+			autotune: None,
+			ui_autotune: (configuration.ui.autotune, configuration.ui.autotune_tuning_rule),
+			clock,
 			adc: peripherals
 				.take_adc()
 				.ok_or(CreationError::PeripheralMissing { name: "ADC" })?,
@@ -69,79 +147,570 @@ impl<P: Peripherals> HotPlate<P> {
 					.ok_or(CreationError::PeripheralMissing { name: "Fan pin" })?,
 				configuration.fan_min_duty_cycle_to_move,
 			),
-			pid_controller: TemperaturePidController::new(
-				Thermistor::new(
-					peripherals
-						.take_thermistor1_pin()
-						.ok_or(CreationError::PeripheralMissing {
-							name: "Thermistor 1 pin",
-						})?,
-					configuration.pid.thermistor.beta,
-					configuration.pid.thermistor.resistance_at_t0,
-					configuration.pid.thermistor.other_resistance,
-				),
-				CartridgeHeater::new(
-					peripherals
-						.take_heater_pin()
-						.ok_or(CreationError::PeripheralMissing { name: "Heater pin" })?,
-				),
-				configuration.pid.pid_gains,
-				TemperatureSafety::new(
-					configuration.pid.safety.allowed_temperature_range,
-					configuration.pid.safety.keep_target_temperature_config,
-					configuration.pid.safety.rise_to_target_temperature_config,
-					configuration.pid.safety.rise_to_target_temperature_samples_count,
-				),
-			),
+			pid_controller: {
+				let plate_thermistor = &configuration.pid.thermistor;
+				let board_thermistor = &configuration.pid.board_thermistor;
+				let mut pid_controller = TemperaturePidController::new(
+					TemperatureSensors {
+						plate_thermistor_1: Thermistor::new(
+							peripherals
+								.take_thermistor1_pin()
+								.ok_or(CreationError::PeripheralMissing {
+									name: "Thermistor 1 pin",
+								})?,
+							plate_thermistor.beta,
+							plate_thermistor.resistance_at_t0,
+							plate_thermistor.other_resistance,
+						),
+						plate_thermistor_2: Thermistor::new(
+							peripherals
+								.take_thermistor2_pin()
+								.ok_or(CreationError::PeripheralMissing {
+									name: "Thermistor 2 pin",
+								})?,
+							plate_thermistor.beta,
+							plate_thermistor.resistance_at_t0,
+							plate_thermistor.other_resistance,
+						),
+						board_thermistor: Thermistor::new(
+							peripherals
+								.take_board_thermistor_pin()
+								.ok_or(CreationError::PeripheralMissing {
+									name: "Board thermistor pin",
+								})?,
+							board_thermistor.beta,
+							board_thermistor.resistance_at_t0,
+							board_thermistor.other_resistance,
+						),
+						infrared_thermometer: peripherals
+							.take_infrared_thermometer_i2c()
+							.map(|i2c| Mlx90614::new(i2c, configuration.pid.infrared_thermometer_address)),
+					},
+					configuration.pid.sensor_fusion,
+					CartridgeHeater::new(
+						peripherals
+							.take_heater_pin()
+							.ok_or(CreationError::PeripheralMissing { name: "Heater pin" })?,
+					),
+					configuration.pid.pid_gains,
+					TemperatureSafety::new(
+						configuration.pid.safety.allowed_temperature_range,
+						configuration.pid.safety.keep_target_temperature_config,
+						configuration.pid.safety.rise_to_target_temperature_config,
+						configuration.pid.safety.rise_to_target_temperature_samples_count,
+					),
+				);
+				pid_controller.set_pid_control_config(configuration.pid.control);
+				pid_controller.set_gain_schedule(configuration.pid.gain_schedule);
+				pid_controller.set_feed_forward(configuration.pid.feed_forward);
+				pid_controller
+			},
 		})
 	}
 
-	pub fn tick(&mut self) -> Result<(), TickError<P::LcdDCXPin, P::LcdSpi, P::FanPin>> {
+	pub fn tick(&mut self) -> Result<(), PeripheralsTickError<P>> {
 		let delta_time = self.clock.get_delta_time();
 		self.clock.tick();
 
-		self.screen.tick().map_err(TickError::Screen)?;
+		// What the user asked is done right away, but an error reading the inputs mustn't stop the temperature control
+		let input_result = self.tick_input();
+		let temperature_result = self.tick_temperature(delta_time);
+		let screen_result = self.tick_screen();
+
+		temperature_result.and(input_result).and(screen_result)
+	}
+
+	/// Advances the reflow process and controls the heater (or the autotune), checking that the temperature is safe.
+	fn tick_temperature(&mut self, delta_time: Duration) -> Result<(), PeripheralsTickError<P>> {
+		let current_temperature = self.pid_controller.get_last_sample_of_current_temperature();
+		if let Some(event) = self.reflow_state_machine.tick(delta_time, current_temperature) {
+			self.pending_reflow_events.push(event);
+		}
+		self.handle_pending_reflow_events()?;
+
+		if self.is_autotune_in_progress() {
+			return self.tick_autotune(delta_time.as_secs_f32());
+		}
 
-		self.reflow_process = Some(DefaultReflowProcess::start_default());
-		if let Some(mut reflow_process) = self.reflow_process.take() {
-			if let Some(target_temperature) = reflow_process.tick(delta_time) {
+		let result = match self.reflow_state_machine.get_target_temperature() {
+			// While cooling the fan is at full speed, so the heater is kept off instead of fighting against it
+			Some(target_temperature) if self.get_reflow_state() != ReflowState::Cooling => {
 				self.pid_controller.set_target_temperature(target_temperature);
+				if let Some(feed_forward) = self.pid_controller.get_feed_forward() {
+					let look_ahead = Duration::from_secs_f32(feed_forward.look_ahead_in_seconds);
+					let slope = self.reflow_state_machine.get_target_temperature_slope(look_ahead);
+					self.pid_controller.set_target_temperature_slope(slope.unwrap_or(0.));
+				}
 
-				self.reflow_process = Some(reflow_process);
-			} else {
-				self.on_reflow_finished().map_err(TickError::SetFanSpeed)?;
+				self.pid_controller.tick(delta_time.as_secs_f32(), &mut self.adc)
+			},
+			// The temperature is still monitored while the heater is off
+			_ => self.pid_controller.tick_heater_off(&mut self.adc),
+		};
+		if let Err(error) = result {
+			// The temperature can't be controlled anymore, so stop the process (which turns off the heater)
+			let abort_event = self.reflow_state_machine.abort(AbortReason::TemperatureControl(error));
+			if let Ok(event) = abort_event {
+				self.pending_reflow_events.push(event);
 			}
+			self.handle_pending_reflow_events()?;
+
+			return Err(TickError::PidHeater(error));
 		}
 
-		self.pid_controller
-			.tick(delta_time.as_secs_f32(), &mut self.adc)
-			.map_err(TickError::PidHeater)?;
+		Ok(())
+	}
+
+	fn tick_autotune(&mut self, delta_time: f32) -> Result<(), PeripheralsTickError<P>> {
+		let Some((autotune, tuning_rule)) = self.autotune.as_mut() else {
+			return Ok(());
+		};
+
+		let was_finished = autotune.is_finished();
+		if let Err(error) = self.pid_controller.tick_autotune(autotune, delta_time, &mut self.adc) {
+			self.autotune = None;
+			self.pid_controller.turn_off_heater().map_err(TickError::PidHeater)?;
+			self.screen.show_error(message::autotune_error(error));
+
+			return Err(TickError::Autotune(error));
+		}
+
+		if let (false, Some(result)) = (was_finished, autotune.get_result()) {
+			let target_temperature = autotune.get_config().target_temperature;
+			let gains = result.get_gains(*tuning_rule);
+			self.pid_controller
+				.get_gain_schedule_mut()
+				.insert(target_temperature, gains)
+				.map_err(|_| TickError::Autotune(AutotuneError::GainScheduleFull))?;
+		}
 
 		Ok(())
 	}
 
-	fn on_reflow_finished(&mut self) -> Result<(), <P::FanPin as PwmPin>::Error> {
-		self.fan.set_speed(Percentage::FULL)?;
+	/// Reads the touch panel and the buttons, and passes what happened on them to the user interface (executing what the
+	/// user asked).
+	///
+	/// The buttons are read even if the touch panel can't be read (the first error is returned).
+	fn tick_input(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		let touch_result = self.tick_touch();
+
+		let now = self.clock.get_elapsed_time();
+		let mut buttons_result = Ok(());
+		for navigation_button in [NavigationButton::Next, NavigationButton::Select] {
+			let button = match navigation_button {
+				NavigationButton::Next => self.next_button.as_mut(),
+				NavigationButton::Select => self.select_button.as_mut(),
+			};
+			let event = match button.map(|button| button.update(now)).transpose() {
+				Ok(event) => event.flatten(),
+				Err(error) => {
+					buttons_result = buttons_result.and(Err(TickError::Button(error)));
+					continue;
+				},
+			};
+
+			if let Some(action) = event.and_then(|event| self.screen.handle_button(navigation_button, event)) {
+				self.on_ui_action(action);
+			}
+		}
+
+		touch_result.and(buttons_result)
+	}
+
+	fn tick_touch(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		let touch_position = self
+			.touch
+			.read_touch()
+			.map_err(TickError::Touch)?
+			.map(|touch| touch.position);
+		let touch_event = match (self.touch_position, touch_position) {
+			(None, Some(position)) => Some(TouchEvent::Press(position)),
+			(Some(previous_position), Some(position)) if previous_position != position => {
+				Some(TouchEvent::Move(position))
+			},
+			(Some(_), None) => Some(TouchEvent::Release),
+			_ => None,
+		};
+		self.touch_position = touch_position;
+
+		if let Some(action) = touch_event.and_then(|event| self.screen.handle_touch(event)) {
+			self.on_ui_action(action);
+		}
+
+		Ok(())
+	}
+
+	/// Draws on the screen what changed in the user interface.
+	fn tick_screen(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		let status = self.get_status();
+		self.screen.tick(&status).map_err(TickError::Screen)
+	}
+
+	fn on_ui_action(&mut self, action: UiAction) {
+		let result = match action {
+			UiAction::StartReflow => self.start_reflow(),
+			UiAction::PauseReflow => self.pause_reflow(),
+			UiAction::ResumeReflow => self.resume_reflow(),
+			UiAction::AbortReflow => self.abort_reflow(),
+			UiAction::SelectReflowProfile(profile) => {
+				self.select_reflow_profile(profile);
+				Ok(())
+			},
+			UiAction::SetInterpolation(interpolation) => {
+				self.set_reflow_interpolation(interpolation);
+				Ok(())
+			},
+			UiAction::StartAutotune { target_temperature } => {
+				let (config, tuning_rule) = self.ui_autotune;
+				self.start_autotune(
+					AutotuneConfig {
+						target_temperature,
+						..config
+					},
+					tuning_rule,
+				)
+			},
+			UiAction::EmergencyAbort => {
+				self.emergency_abort();
+				Ok(())
+			},
+		};
+
+		if let Err(error) = result {
+			self.screen.show_error(message::command_error(error));
+		}
+	}
+
+	/// Returns what the [`user interface`] shows about the hot plate.
+	///
+	/// [`user interface`]: `Self::get_ui`
+	pub fn get_status(&self) -> HotPlateStatus {
+		HotPlateStatus {
+			reflow_state: self.get_reflow_state(),
+			selected_reflow_profile: self.selected_reflow_profile,
+			interpolation: self.get_reflow_interpolation(),
+			current_temperature: self.get_current_temperature(),
+			target_temperature: self.get_target_temperature(),
+			reflow_time: self
+				.reflow_state_machine
+				.get_process()
+				.map(|process| process.get_current_time()),
+			is_autotune_in_progress: self.is_autotune_in_progress(),
+		}
+	}
+
+	/// Returns the user interface shown on the screen.
+	pub fn get_ui(&self) -> &DefaultUI {
+		self.screen.get_ui()
+	}
+
+	/// Returns the [`Menu`] shown on the screen.
+	pub fn get_current_menu(&self) -> Menu {
+		self.get_ui().get_current_menu()
+	}
+
+	/// Returns the [`BuiltinReflowProfile`] that will be followed by the reflow process.
+	pub fn get_selected_reflow_profile(&self) -> BuiltinReflowProfile {
+		self.selected_reflow_profile
+	}
+
+	/// Chooses the [`BuiltinReflowProfile`] that will be followed by the reflow process.
+	pub fn select_reflow_profile(&mut self, profile: BuiltinReflowProfile) {
+		self.selected_reflow_profile = profile;
+	}
+
+	/// Returns the [`Interpolation`] used by the reflow processes between the points of their profiles.
+	pub fn get_reflow_interpolation(&self) -> Interpolation {
+		self.reflow_state_machine.get_interpolation()
+	}
+
+	/// Sets the [`Interpolation`] used by the next reflow processes between the points of their profiles.
+	pub fn set_reflow_interpolation(&mut self, interpolation: Interpolation) {
+		self.reflow_state_machine.set_interpolation(interpolation);
+	}
+
+	/// Returns the current state of the reflow process.
+	pub fn get_reflow_state(&self) -> ReflowState {
+		self.reflow_state_machine.get_state()
+	}
+
+	/// Returns the [`Temperature`] the plate should be at right now according to the reflow process, or `None` if the
+	/// heater should be off.
+	///
+	/// While the process is [`cooling`] the heater is off even if there's a target temperature, so that it doesn't
+	/// fight against the fan.
+	///
+	/// [`cooling`]: `ReflowState::Cooling`
+	pub fn get_target_temperature(&self) -> Option<Temperature> {
+		self.reflow_state_machine.get_target_temperature()
+	}
+
+	/// Returns the [`Temperature`] of the plate read during the last tick, or `None` if it has never been read.
+	pub fn get_current_temperature(&self) -> Option<Temperature> {
+		self.pid_controller.get_last_sample_of_current_temperature()
+	}
+
+	/// Returns the temperatures read from the single sensors during the last tick (the
+	/// [`current temperature`] is obtained combining them).
+	///
+	/// [`current temperature`]: `Self::get_current_temperature`
+	pub fn get_sensor_readings(&self) -> &SensorReadings {
+		self.pid_controller.get_last_sensor_readings()
+	}
+
+	/// Starts a reflow process following the [`selected profile`].
+	///
+	/// [`selected profile`]: `Self::get_selected_reflow_profile`
+	pub fn start_reflow(&mut self) -> Result<(), CommandError> {
+		if self.is_autotune_in_progress() {
+			return Err(CommandError::AutotuneInProgress);
+		}
+
+		let event = self
+			.reflow_state_machine
+			.start(self.selected_reflow_profile.reflow_profile())?;
+		self.pending_reflow_events.push(event);
+
+		Ok(())
+	}
+
+	/// Pauses the reflow process. Check [`ReflowStateMachine::pause`].
+	///
+	/// [`ReflowStateMachine::pause`]: `process::ReflowStateMachine::pause`
+	pub fn pause_reflow(&mut self) -> Result<(), CommandError> {
+		let event = self.reflow_state_machine.pause()?;
+		self.pending_reflow_events.push(event);
+
+		Ok(())
+	}
+
+	/// Resumes the reflow process. Check [`ReflowStateMachine::resume`].
+	///
+	/// [`ReflowStateMachine::resume`]: `process::ReflowStateMachine::resume`
+	pub fn resume_reflow(&mut self) -> Result<(), CommandError> {
+		let event = self.reflow_state_machine.resume()?;
+		self.pending_reflow_events.push(event);
+
+		Ok(())
+	}
+
+	/// Aborts the reflow process, turning off the heater at the next [`tick`].
+	///
+	/// [`tick`]: `Self::tick`
+	pub fn abort_reflow(&mut self) -> Result<(), CommandError> {
+		let event = self.reflow_state_machine.abort(AbortReason::Requested)?;
+		self.pending_reflow_events.push(event);
+
+		Ok(())
+	}
+
+	/// Starts tuning the gains of the PID that controls the temperature of the plate. When the [`Autotune`] finishes,
+	/// the gains computed using the provided `tuning_rule` are added to the [`gain schedule`] of the PID at the
+	/// [`target temperature`] of the autotune (so you can run it at multiple temperatures to fill the schedule).
+	///
+	/// It's not allowed while a reflow process is [`active`].
+	///
+	/// [`gain schedule`]: `Self::get_gain_schedule`
+	/// [`target temperature`]: `AutotuneConfig::target_temperature`
+	/// [`active`]: `ReflowState::is_active`
+	pub fn start_autotune(&mut self, config: AutotuneConfig, tuning_rule: TuningRule) -> Result<(), CommandError> {
+		let reflow_state = self.get_reflow_state();
+		if reflow_state.is_active() {
+			return Err(CommandError::NotAllowed { state: reflow_state });
+		}
+		if self.is_autotune_in_progress() {
+			return Err(CommandError::AutotuneInProgress);
+		}
+
+		self.autotune = Some((Autotune::new(config), tuning_rule));
+
+		Ok(())
+	}
+
+	/// Stops the autotune in progress without changing the gains of the PID. The heater is turned off at the next
+	/// [`tick`].
+	///
+	/// [`tick`]: `Self::tick`
+	pub fn abort_autotune(&mut self) -> Result<(), CommandError> {
+		if !self.is_autotune_in_progress() {
+			return Err(CommandError::NoAutotuneInProgress);
+		}
+
+		self.autotune = None;
+
+		Ok(())
+	}
+
+	/// Stops the reflow process and the autotune if any of them is in progress, turning off the heater at the next
+	/// [`tick`]. Unlike [`abort_reflow`] and [`abort_autotune`] it doesn't fail if there's nothing to stop.
+	///
+	/// [`tick`]: `Self::tick`
+	/// [`abort_reflow`]: `Self::abort_reflow`
+	/// [`abort_autotune`]: `Self::abort_autotune`
+	pub fn emergency_abort(&mut self) {
+		// It fails only if there isn't an active process
+		if let Ok(event) = self.reflow_state_machine.abort(AbortReason::Requested) {
+			self.pending_reflow_events.push(event);
+		}
+		if self.is_autotune_in_progress() {
+			self.autotune = None;
+		}
+	}
+
+	/// Returns the last [`Autotune`] that has been started (so that you can check its progress or its result), or
+	/// `None` if there isn't any or it has been aborted.
+	pub fn get_autotune(&self) -> Option<&Autotune> {
+		self.autotune.as_ref().map(|(autotune, _)| autotune)
+	}
+
+	/// Returns the [`GainSchedule`] of the PID that controls the temperature of the plate, which is filled by the
+	/// [`autotunes`] that complete.
+	///
+	/// [`autotunes`]: `Self::start_autotune`
+	pub fn get_gain_schedule(&self) -> &GainSchedule {
+		self.pid_controller.get_gain_schedule()
+	}
+
+	fn is_autotune_in_progress(&self) -> bool {
+		self.get_autotune().is_some_and(|autotune| !autotune.is_finished())
+	}
+
+	/// Returns the oldest [`ReflowEvent`] the hot plate reacted to that hasn't been polled yet, or `None` if there
+	/// isn't any.
+	///
+	/// Only the last [`REFLOW_EVENTS_CAPACITY`] events are kept.
+	pub fn poll_reflow_event(&mut self) -> Option<ReflowEvent> {
+		self.reflow_events.dequeue()
+	}
+
+	fn handle_pending_reflow_events(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		while let Some(event) = self.pending_reflow_events.dequeue() {
+			self.on_reflow_event(event)?;
+			self.reflow_events.push(event);
+		}
+
+		Ok(())
+	}
+
+	fn on_reflow_event(&mut self, event: ReflowEvent) -> Result<(), PeripheralsTickError<P>> {
+		match event {
+			ReflowEvent::StateChanged {
+				from,
+				to: ReflowState::Preheating,
+			} if from != ReflowState::Paused => self.on_reflow_started(),
+			ReflowEvent::StateChanged {
+				to: ReflowState::Cooling,
+				..
+			} => self.on_cooling_started(),
+			ReflowEvent::Finished => self.on_reflow_finished(),
+			ReflowEvent::Aborted(_) => self.on_reflow_aborted(),
+			ReflowEvent::StateChanged { .. } => Ok(()),
+		}
+	}
+
+	fn on_reflow_started(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		self.fan.set_speed(Percentage::ZERO).map_err(TickError::SetFanSpeed)?;
+
+		Ok(())
+	}
+
+	fn on_cooling_started(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		self.fan.set_speed(Percentage::FULL).map_err(TickError::SetFanSpeed)?;
+
+		Ok(())
+	}
+
+	fn on_reflow_finished(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		self.fan.set_speed(Percentage::FULL).map_err(TickError::SetFanSpeed)?;
+
+		Ok(())
+	}
+
+	fn on_reflow_aborted(&mut self) -> Result<(), PeripheralsTickError<P>> {
+		self.pid_controller.turn_off_heater().map_err(TickError::PidHeater)?;
+		self.fan.set_speed(Percentage::FULL).map_err(TickError::SetFanSpeed)?;
 
 		Ok(())
 	}
 }
 
-#[derive(Debug)]
 /// An error that can occur when you instatiate a [`HotPlate`] struct.
-pub enum CreationError<ResetPin: OutputPin> {
+pub enum CreationError<
+	DCXPin: OutputPin,
+	ResetPin: OutputPin,
+	Transfer: DisplayTransfer,
+	TouchInterruptPin: InterruptPin,
+> {
 	/// A peripheral from the provided ones is missing (`name` is the name of the peripheral that's missing).
 	/// This means that `peripherals.take_...()` returned `None` instead of `Some`.
 	PeripheralMissing {
 		name: &'static str,
 	},
 
-	ScreenCreation(ResetPin::Error),
+	ScreenCreation(InitError<DCXPin, ResetPin, Transfer>),
+	TouchCreation(TouchInterruptPin::Error),
+}
+
+/// The [`CreationError`] returned by an [`HotPlate`] that uses the peripherals `P`.
+pub type PeripheralsCreationError<P> = CreationError<
+	<P as Peripherals>::LcdDCXPin,
+	<P as Peripherals>::LcdResetPin,
+	<P as Peripherals>::LcdTransfer,
+	<P as Peripherals>::TouchInterruptPin,
+>;
+
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer, TouchInterruptPin: InterruptPin>
+	core::fmt::Debug for CreationError<DCXPin, ResetPin, Transfer, TouchInterruptPin>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::PeripheralMissing { name } => f.debug_struct("PeripheralMissing").field("name", name).finish(),
+			Self::ScreenCreation(arg0) => f.debug_tuple("ScreenCreation").field(arg0).finish(),
+			Self::TouchCreation(arg0) => f.debug_tuple("TouchCreation").field(arg0).finish(),
+		}
+	}
 }
 
-/// An error that can occur when you tick a [`Printer3DComponents`] struct.
-pub enum TickError<DCXPin: OutputPin, Spi: SpiDevice, FanPin: PwmPin> {
-	Screen(SendError<DCXPin, Spi>),
+/// An error that can occur when you tick a [`HotPlate`] struct.
+pub enum TickError<DCXPin: OutputPin, Transfer: DisplayTransfer, TouchSpi: SpiDevice, B: Button, FanPin: PwmPin> {
+	Screen(SendError<DCXPin, Transfer>),
+	Touch(TouchSpi::Error),
+	Button(B::Error),
 	PidHeater(temperature::PidUpdateError),
+	Autotune(AutotuneError),
 	SetFanSpeed(FanPin::Error),
 }
+
+/// The [`TemperaturePidController`] of an [`HotPlate`] that uses the peripherals `P`.
+type PeripheralsPidController<P> = TemperaturePidController<
+	<P as Peripherals>::HeaterPin,
+	<P as Peripherals>::ADC,
+	<P as Peripherals>::Thermistor1Pin,
+	<P as Peripherals>::Thermistor2Pin,
+	<P as Peripherals>::BoardThermistorPin,
+	<P as Peripherals>::InfraredThermometerI2c,
+>;
+
+/// The [`TickError`] returned by an [`HotPlate`] that uses the peripherals `P`.
+pub type PeripheralsTickError<P> = TickError<
+	<P as Peripherals>::LcdDCXPin,
+	<P as Peripherals>::LcdTransfer,
+	<P as Peripherals>::TouchSpi,
+	<P as Peripherals>::Button,
+	<P as Peripherals>::FanPin,
+>;
+
+impl<DCXPin: OutputPin, Transfer: DisplayTransfer, TouchSpi: SpiDevice, B: Button, FanPin: PwmPin> core::fmt::Debug
+	for TickError<DCXPin, Transfer, TouchSpi, B, FanPin>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Screen(arg0) => f.debug_tuple("Screen").field(arg0).finish(),
+			Self::Touch(arg0) => f.debug_tuple("Touch").field(arg0).finish(),
+			Self::Button(arg0) => f.debug_tuple("Button").field(arg0).finish(),
+			Self::PidHeater(arg0) => f.debug_tuple("PidHeater").field(arg0).finish(),
+			Self::Autotune(arg0) => f.debug_tuple("Autotune").field(arg0).finish(),
+			Self::SetFanSpeed(arg0) => f.debug_tuple("SetFanSpeed").field(arg0).finish(),
+		}
+	}
+}
@@ -1,7 +1,10 @@
-use embedded_hal::{digital::OutputPin, spi::SpiDevice};
+use embedded_hal::{digital::OutputPin, i2c::I2c, spi::SpiDevice};
 
 use super::hal::{
 	adc::{Adc, AdcPin},
+	button::Button,
+	display_transfer::DisplayTransfer,
+	interrupt::InterruptPin,
 	pwm::PwmPin,
 	system_time::SystemTime,
 };
@@ -9,25 +12,41 @@ use super::hal::{
 pub trait Peripherals {
 	type LcdDCXPin: OutputPin;
 	type LcdResetPin: OutputPin;
-	type LcdSpi: SpiDevice;
+	type LcdTransfer: DisplayTransfer;
+	type TouchSpi: SpiDevice;
+	type TouchInterruptPin: InterruptPin;
+	type Button: Button;
 
 	type FanPin: PwmPin;
 
 	type HeaterPin: PwmPin;
 	type ADC: Adc;
 	type Thermistor1Pin: AdcPin<Self::ADC>;
+	type Thermistor2Pin: AdcPin<Self::ADC>;
+	type BoardThermistorPin: AdcPin<Self::ADC>;
+	type InfraredThermometerI2c: I2c;
 
 	type SystemTime: SystemTime;
 
 	fn take_lcd_dcx_pin(&mut self) -> Option<Self::LcdDCXPin>;
 	fn take_lcd_reset_pin(&mut self) -> Option<Self::LcdResetPin>;
-	fn take_lcd_spi(&mut self) -> Option<Self::LcdSpi>;
+	fn take_lcd_transfer(&mut self) -> Option<Self::LcdTransfer>;
+	fn take_touch_spi(&mut self) -> Option<Self::TouchSpi>;
+	fn take_touch_interrupt_pin(&mut self) -> Option<Self::TouchInterruptPin>;
+	/// Returns `None` also if the button isn't mounted, since the user interface can be used with the touch panel.
+	fn take_next_button(&mut self) -> Option<Self::Button>;
+	/// Returns `None` also if the button isn't mounted, since the user interface can be used with the touch panel.
+	fn take_select_button(&mut self) -> Option<Self::Button>;
 
 	fn take_fan_pin(&mut self) -> Option<Self::FanPin>;
 
 	fn take_heater_pin(&mut self) -> Option<Self::HeaterPin>;
 	fn take_adc(&mut self) -> Option<Self::ADC>;
 	fn take_thermistor1_pin(&mut self) -> Option<Self::Thermistor1Pin>;
+	fn take_thermistor2_pin(&mut self) -> Option<Self::Thermistor2Pin>;
+	fn take_board_thermistor_pin(&mut self) -> Option<Self::BoardThermistorPin>;
+	/// Returns `None` also if the infrared thermometer isn't mounted, since it's optional.
+	fn take_infrared_thermometer_i2c(&mut self) -> Option<Self::InfraredThermometerI2c>;
 
 	fn take_system_time(&mut self) -> Option<Self::SystemTime>;
 }
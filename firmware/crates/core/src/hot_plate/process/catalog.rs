@@ -0,0 +1,204 @@
+use enumset::EnumSet;
+
+use super::{
+	phased_reflow_profile::*,
+	temperature_reflow_profile::{ReflowProfile, TimeInSeconds},
+};
+use crate::utils::measurement::temperature::Temperature;
+
+/// The number of points of the [`ReflowProfile`] of every [`BuiltinReflowProfile`].
+pub const CATALOG_PROFILE_POINTS: usize = PHASED_PROFILE_POINTS;
+
+/// The reflow profiles that are always available in the hot plate, one for each common type of solder paste.
+///
+/// You can enumerate them using [`Self::all`] and show their names and metadata to the user.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::process::BuiltinReflowProfile;
+/// #
+/// for profile in BuiltinReflowProfile::all() {
+/// 	// Every profile with solder paste limits respects them
+/// 	if let (Some(phased_profile), Some(limits)) = (profile.phased_profile(), profile.solder_paste_limits()) {
+/// 		assert!(phased_profile.check_against(&limits).is_empty(), "{}", profile.name());
+/// 	}
+///
+/// 	let points = profile.reflow_profile().temperature_points;
+/// 	assert_eq!(points.last().unwrap().1, profile.duration_in_seconds());
+/// }
+///
+/// assert_eq!(BuiltinReflowProfile::LeadFreeSac305.name(), "SAC305");
+/// assert_eq!(BuiltinReflowProfile::LeadFreeSac305.peak_temperature().as_celsius().round(), 245.);
+/// ```
+#[derive(enumset::EnumSetType, Debug, Hash)]
+pub enum BuiltinReflowProfile {
+	/// Leaded `Sn63/Pb37` solder paste.
+	LeadedSn63Pb37,
+	/// Lead-free `Sn96.5/Ag3.0/Cu0.5` solder paste.
+	LeadFreeSac305,
+	/// Low temperature lead-free `Sn42/Bi58` solder paste.
+	LowTemperatureSn42Bi58,
+	/// Heats the board to `150°C` and keeps it there for 10 minutes, without ever melting the solder. It's useful to
+	/// preheat a board before reworking it with an hot air station.
+	ReworkPreheat,
+}
+
+impl BuiltinReflowProfile {
+	/// The profile selected when the hot plate starts.
+	pub const DEFAULT: Self = Self::LeadFreeSac305;
+
+	/// Returns a set with all the built-in profiles, which can be iterated in the order they are declared.
+	pub fn all() -> EnumSet<Self> {
+		EnumSet::all()
+	}
+
+	/// Returns a short name of the profile that can be shown to the user.
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::LeadedSn63Pb37 => "Sn63/Pb37",
+			Self::LeadFreeSac305 => "SAC305",
+			Self::LowTemperatureSn42Bi58 => "Sn42/Bi58",
+			Self::ReworkPreheat => "Rework preheat",
+		}
+	}
+
+	/// Returns a description of the profile that can be shown to the user.
+	pub const fn description(self) -> &'static str {
+		match self {
+			Self::LeadedSn63Pb37 => "Leaded solder paste, peak at 215°C",
+			Self::LeadFreeSac305 => "Lead-free solder paste, peak at 245°C",
+			Self::LowTemperatureSn42Bi58 => "Low temperature lead-free solder paste, peak at 165°C",
+			Self::ReworkPreheat => "Keeps the board at 150°C for 10 minutes",
+		}
+	}
+
+	/// Returns the [`PhasedReflowProfile`] this profile is made of, or `None` if it doesn't melt any solder paste (so
+	/// it has no reflow phase).
+	pub fn phased_profile(self) -> Option<PhasedReflowProfile> {
+		let start_temperature = Temperature::from_celsius(25.);
+		let end_temperature = Temperature::from_celsius(50.);
+
+		match self {
+			Self::LeadedSn63Pb37 => Some(PhasedReflowProfile {
+				start_temperature,
+				preheat: PreheatPhase {
+					target_temperature: Temperature::from_celsius(150.),
+					ramp_rate: 1.5,
+				},
+				soak: SoakPhase {
+					end_temperature: Temperature::from_celsius(165.),
+					duration_in_seconds: 90,
+				},
+				reflow: ReflowPhase {
+					ramp_rate: 1.5,
+					liquidus_temperature: Temperature::from_celsius(183.),
+					peak_temperature: Temperature::from_celsius(215.),
+					time_above_liquidus_in_seconds: 60,
+				},
+				cooling: CoolingPhase {
+					cool_down_rate: 2.,
+					end_temperature,
+				},
+			}),
+			Self::LeadFreeSac305 => Some(PhasedReflowProfile {
+				start_temperature,
+				preheat: PreheatPhase {
+					target_temperature: Temperature::from_celsius(150.),
+					ramp_rate: 1.5,
+				},
+				soak: SoakPhase {
+					end_temperature: Temperature::from_celsius(180.),
+					duration_in_seconds: 90,
+				},
+				reflow: ReflowPhase {
+					ramp_rate: 1.5,
+					liquidus_temperature: Temperature::from_celsius(217.),
+					peak_temperature: Temperature::from_celsius(245.),
+					time_above_liquidus_in_seconds: 75,
+				},
+				cooling: CoolingPhase {
+					cool_down_rate: 2.,
+					end_temperature,
+				},
+			}),
+			Self::LowTemperatureSn42Bi58 => Some(PhasedReflowProfile {
+				start_temperature,
+				preheat: PreheatPhase {
+					target_temperature: Temperature::from_celsius(90.),
+					ramp_rate: 1.,
+				},
+				soak: SoakPhase {
+					end_temperature: Temperature::from_celsius(120.),
+					duration_in_seconds: 90,
+				},
+				reflow: ReflowPhase {
+					ramp_rate: 1.,
+					liquidus_temperature: Temperature::from_celsius(138.),
+					peak_temperature: Temperature::from_celsius(165.),
+					time_above_liquidus_in_seconds: 60,
+				},
+				cooling: CoolingPhase {
+					cool_down_rate: 2.,
+					end_temperature,
+				},
+			}),
+			Self::ReworkPreheat => None,
+		}
+	}
+
+	/// Returns the limits of the solder paste this profile is made for, or `None` if it's not made for a specific
+	/// solder paste.
+	pub fn solder_paste_limits(self) -> Option<SolderPasteLimits> {
+		match self {
+			Self::LeadedSn63Pb37 => Some(SolderPasteLimits::SN63_PB37),
+			Self::LeadFreeSac305 => Some(SolderPasteLimits::SAC305),
+			Self::LowTemperatureSn42Bi58 => Some(SolderPasteLimits::SN42_BI58),
+			Self::ReworkPreheat => None,
+		}
+	}
+
+	/// Returns the [`ReflowProfile`] a [`ReflowProcess`] should follow to execute this profile.
+	///
+	/// [`ReflowProcess`]: `super::ReflowProcess`
+	pub fn reflow_profile(self) -> ReflowProfile<CATALOG_PROFILE_POINTS> {
+		match self.phased_profile() {
+			Some(phased_profile) => phased_profile
+				.to_reflow_profile()
+				.expect("The built-in profiles are consistent"),
+			None => ReflowProfile {
+				start_temperature: Temperature::from_celsius(25.),
+				temperature_points: [
+					(Temperature::from_celsius(120.), 95),
+					(Temperature::from_celsius(150.), 155),
+					(Temperature::from_celsius(150.), 755),
+					(Temperature::from_celsius(100.), 780),
+					(Temperature::from_celsius(50.), 805),
+				],
+			},
+		}
+	}
+
+	/// Returns the highest temperature reached during this profile.
+	pub fn peak_temperature(self) -> Temperature {
+		self.reflow_profile()
+			.temperature_points
+			.iter()
+			.map(|&(temperature, _)| temperature)
+			.fold(Temperature::from_kelvin(0.), |peak, temperature| {
+				if temperature > peak {
+					temperature
+				} else {
+					peak
+				}
+			})
+	}
+
+	/// Returns how many seconds this profile lasts.
+	pub fn duration_in_seconds(self) -> TimeInSeconds {
+		self.reflow_profile()
+			.temperature_points
+			.last()
+			.map(|&(_, time)| time)
+			.unwrap_or(0)
+	}
+}
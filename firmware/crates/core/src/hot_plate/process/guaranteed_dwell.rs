@@ -0,0 +1,99 @@
+use core::time::Duration;
+
+use crate::utils::measurement::temperature::Temperature;
+
+/// The parameters of [`GuaranteedDwell`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GuaranteedDwellConfig {
+	/// How many degrees the measured temperature can be below the target temperature before the process clock is
+	/// held.
+	pub tolerance: f32,
+	/// The maximum amount of seconds the process can be stretched (in total) before it is aborted.
+	pub max_stretch_in_seconds: f32,
+}
+
+/// Makes sure the plate really follows the profile, by holding the clock of the process while the measured
+/// temperature is more than [`GuaranteedDwellConfig::tolerance`] degrees below the target temperature.
+///
+/// This way if the plate lags behind the profile, the phases that follow (like the soak and the time above liquidus)
+/// are shifted in time instead of being shortened. The process can't be stretched forever though: after it has been
+/// held for [`GuaranteedDwellConfig::max_stretch_in_seconds`] seconds in total it should be aborted.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::process::*, utils::measurement::temperature::Temperature};
+/// # use core::time::Duration;
+/// #
+/// let mut guaranteed_dwell = GuaranteedDwell::new(GuaranteedDwellConfig {
+/// 	tolerance: 5.,
+/// 	max_stretch_in_seconds: 2.,
+/// });
+/// let target_temperature = Temperature::from_celsius(150.);
+///
+/// // The plate is close enough to the target, so the time passes normally
+/// let delta_time = guaranteed_dwell.get_process_delta_time(Duration::from_secs(1), Temperature::from_celsius(147.), target_temperature);
+/// assert_eq!(delta_time, Ok(Duration::from_secs(1)));
+///
+/// // The plate is lagging behind, so the clock of the process is held
+/// let delta_time = guaranteed_dwell.get_process_delta_time(Duration::from_secs(1), Temperature::from_celsius(140.), target_temperature);
+/// assert_eq!(delta_time, Ok(Duration::ZERO));
+/// assert_eq!(guaranteed_dwell.get_stretch(), Duration::from_secs(1));
+///
+/// // The plate has been lagging for too long
+/// let delta_time = guaranteed_dwell.get_process_delta_time(Duration::from_secs(2), Temperature::from_celsius(140.), target_temperature);
+/// assert_eq!(delta_time, Err(MaxStretchExceeded));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GuaranteedDwell {
+	config: GuaranteedDwellConfig,
+	stretch: Duration,
+}
+
+impl GuaranteedDwell {
+	pub const fn new(config: GuaranteedDwellConfig) -> Self {
+		Self {
+			config,
+			stretch: Duration::ZERO,
+		}
+	}
+
+	pub fn get_config(&self) -> GuaranteedDwellConfig {
+		self.config
+	}
+
+	/// Returns for how much time in total the process has been held since the creation of this struct or since the
+	/// last time you called [`Self::reset`].
+	pub fn get_stretch(&self) -> Duration {
+		self.stretch
+	}
+
+	/// Sets the [`stretch`](Self::get_stretch) back to `0`, so that it can be used for a new process.
+	pub fn reset(&mut self) {
+		self.stretch = Duration::ZERO;
+	}
+
+	/// Returns how much of the `delta_time` that passed in the real world should pass in the process, based on how far
+	/// the `current_temperature` is from the `target_temperature`.
+	///
+	/// Returns `Err(MaxStretchExceeded)` if the process has been held for more than
+	/// [`GuaranteedDwellConfig::max_stretch_in_seconds`] seconds in total.
+	pub fn get_process_delta_time(
+		&mut self, delta_time: Duration, current_temperature: Temperature, target_temperature: Temperature,
+	) -> Result<Duration, MaxStretchExceeded> {
+		let temperature_difference = (target_temperature - current_temperature).as_kelvin();
+		if temperature_difference <= self.config.tolerance {
+			return Ok(delta_time);
+		}
+
+		self.stretch += delta_time;
+		if self.stretch.as_secs_f32() > self.config.max_stretch_in_seconds {
+			Err(MaxStretchExceeded)
+		} else {
+			Ok(Duration::ZERO)
+		}
+	}
+}
+
+/// The process has been held for more than [`GuaranteedDwellConfig::max_stretch_in_seconds`] seconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaxStretchExceeded;
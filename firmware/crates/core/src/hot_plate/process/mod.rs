@@ -2,30 +2,44 @@ use core::time::Duration;
 
 use crate::utils::measurement::temperature::Temperature;
 
-use self::temperature_reflow_profile::{ReflowProfile, DEFAULT_PROFILE, DEFAULT_PROFILE_POINTS};
+mod catalog;
+mod guaranteed_dwell;
+mod phased_reflow_profile;
+mod state_machine;
+mod temperature_reflow_profile;
 
-use super::screen::drawable::Plot;
+pub use catalog::*;
+pub use guaranteed_dwell::*;
+pub use phased_reflow_profile::*;
+pub use state_machine::*;
+pub use temperature_reflow_profile::*;
 
-mod temperature_reflow_profile;
+pub type DefaultReflowProcess = ReflowProcess<DEFAULT_PROFILE_POINTS>;
+pub type DefaultReflowStateMachine = ReflowStateMachine<DEFAULT_PROFILE_POINTS>;
 
-pub type DefaultReflowProcess = ReflowProcess<DEFAULT_PROFILE_POINTS, 200>;
+// The default process and state machine follow both the `DEFAULT_PROFILE` and the profiles of the catalog
+const _: () = assert!(DEFAULT_PROFILE_POINTS == CATALOG_PROFILE_POINTS);
 
-pub struct ReflowProcess<const N: usize, const PLOT_N: usize> {
+pub struct ReflowProcess<const N: usize> {
 	temperature_profile: ReflowProfile<N>,
-	plot: Plot<PLOT_N>,
+	interpolation: Interpolation,
 	current_time: Duration,
 }
 
-impl<const N: usize, const PLOT_N: usize> ReflowProcess<N, PLOT_N> {
+impl<const N: usize> ReflowProcess<N> {
+	/// Starts a process that follows the provided profile using [`Interpolation::Linear`].
 	pub fn start(temperature_profile: ReflowProfile<N>) -> Self {
-		assert_ne!(temperature_profile.temperature_points.len(), 0);
+		Self::start_with_interpolation(temperature_profile, Interpolation::Linear)
+	}
 
-		let plot = temperature_profile.to_plot(1);
+	/// Starts a process that follows the provided profile using the provided [`Interpolation`] between its points.
+	pub fn start_with_interpolation(temperature_profile: ReflowProfile<N>, interpolation: Interpolation) -> Self {
+		assert_ne!(temperature_profile.temperature_points.len(), 0);
 
 		Self {
 			temperature_profile,
+			interpolation,
 			current_time: Duration::ZERO,
-			plot,
 		}
 	}
 
@@ -33,19 +47,43 @@ impl<const N: usize, const PLOT_N: usize> ReflowProcess<N, PLOT_N> {
 		DefaultReflowProcess::start(DEFAULT_PROFILE)
 	}
 
-	/// Returns the target temperature.
+	/// Starts a process that follows the provided [`BuiltinReflowProfile`].
+	pub fn start_builtin(profile: BuiltinReflowProfile) -> DefaultReflowProcess {
+		DefaultReflowProcess::start(profile.reflow_profile())
+	}
+
+	/// Returns the [`ReflowProfile`] this process is following.
+	pub fn get_profile(&self) -> &ReflowProfile<N> {
+		&self.temperature_profile
+	}
+
+	/// Returns the [`Interpolation`] used between the points of the profile.
+	pub fn get_interpolation(&self) -> Interpolation {
+		self.interpolation
+	}
+
+	/// Returns how much time passed since the start of the process.
+	pub fn get_current_time(&self) -> Duration {
+		self.current_time
+	}
+
+	/// Returns the average rate in degrees per second at which the target temperature is going to change in the next
+	/// `look_ahead`, or `None` if the process is over.
+	///
+	/// Check [`ReflowProfile::get_slope_at`].
+	pub fn get_target_temperature_slope(&self, look_ahead: Duration) -> Option<f32> {
+		self.temperature_profile
+			.get_slope_at(self.current_time, look_ahead, self.interpolation)
+	}
+
+	/// Makes `delta_time` pass in the process and returns the target temperature at the new current time, or `None`
+	/// if the process is over.
+	///
+	/// Check [`ReflowProfile::get_temperature_at`].
 	pub fn tick(&mut self, delta_time: Duration) -> Option<Temperature> {
 		self.current_time += delta_time;
 
-		let &(_, last_point_time) = self.temperature_profile.temperature_points.last().unwrap();
-		let current_point_index = crate::utils::math::map(
-			self.current_time.as_secs() as u32,
-			0..=last_point_time as u32,
-			0..=(PLOT_N as u32 - 1),
-		) as usize;
-
-		self.plot
-			.get(current_point_index)
-			.map(|celsius| Temperature::from_celsius(celsius as f32))
+		self.temperature_profile
+			.get_temperature_at(self.current_time, self.interpolation)
 	}
 }
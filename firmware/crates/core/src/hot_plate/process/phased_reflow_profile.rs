@@ -0,0 +1,345 @@
+use core::ops::RangeInclusive;
+
+use enumset::EnumSet;
+
+use super::temperature_reflow_profile::ReflowProfile;
+use crate::utils::measurement::temperature::Temperature;
+
+/// The number of points of the [`ReflowProfile`] returned by [`PhasedReflowProfile::to_reflow_profile`].
+pub const PHASED_PROFILE_POINTS: usize = 5;
+
+/// Temperature change rate in `°C/s`.
+pub type Rate = f32;
+
+/// A reflow profile described the way solder paste vendors do in their datasheets: as a sequence of phases
+/// ([`preheat`], [`soak`], [`reflow`] and [`cooling`]) instead of a list of points.
+///
+/// You can check if the profile respects the limits of a solder paste with [`Self::check_against`], and then convert it
+/// to a [`ReflowProfile`] using [`Self::to_reflow_profile`].
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::process::*, utils::measurement::temperature::Temperature};
+/// #
+/// let profile = PhasedReflowProfile {
+/// 	start_temperature: Temperature::from_celsius(25.),
+/// 	preheat: PreheatPhase { target_temperature: Temperature::from_celsius(150.), ramp_rate: 1.25 },
+/// 	soak: SoakPhase { end_temperature: Temperature::from_celsius(180.), duration_in_seconds: 90 },
+/// 	reflow: ReflowPhase {
+/// 		ramp_rate: 1.5,
+/// 		liquidus_temperature: Temperature::from_celsius(217.),
+/// 		peak_temperature: Temperature::from_celsius(245.),
+/// 		time_above_liquidus_in_seconds: 75,
+/// 	},
+/// 	cooling: CoolingPhase { cool_down_rate: 2., end_temperature: Temperature::from_celsius(50.) },
+/// };
+/// assert!(profile.check().is_empty());
+///
+/// let points = profile.to_reflow_profile().unwrap().temperature_points;
+/// assert_eq!(points[0], (Temperature::from_celsius(150.), 100));
+/// assert_eq!(points[1], (Temperature::from_celsius(180.), 190));
+/// assert_eq!(points[2].1, 233);
+/// assert_eq!(points[3].1, 276);
+/// assert_eq!(points[4].1, 373);
+///
+/// // The same profile heats too fast for a paste that can't stand more than 1°C/s
+/// let limits = SolderPasteLimits { max_ramp_up_rate: 1., ..SolderPasteLimits::SAC305 };
+/// let errors = profile.check_against(&limits);
+/// assert_eq!(errors.len(), 1);
+/// assert!(errors.contains(ReflowProfileError::RampUpTooFast));
+/// ```
+///
+/// [`preheat`]: `PreheatPhase`
+/// [`soak`]: `SoakPhase`
+/// [`reflow`]: `ReflowPhase`
+/// [`cooling`]: `CoolingPhase`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhasedReflowProfile {
+	/// The temperature of the plate when the profile starts.
+	pub start_temperature: Temperature,
+	pub preheat: PreheatPhase,
+	pub soak: SoakPhase,
+	pub reflow: ReflowPhase,
+	pub cooling: CoolingPhase,
+}
+
+/// The plate heats from [`PhasedReflowProfile::start_temperature`] to [`Self::target_temperature`] at a constant rate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreheatPhase {
+	pub target_temperature: Temperature,
+	pub ramp_rate: Rate,
+}
+
+/// The plate slowly heats from [`PreheatPhase::target_temperature`] to [`Self::end_temperature`], activating the flux
+/// and letting the whole board reach a uniform temperature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoakPhase {
+	pub end_temperature: Temperature,
+	pub duration_in_seconds: u16,
+}
+
+/// The plate heats from [`SoakPhase::end_temperature`] to [`Self::peak_temperature`] at a constant rate, and stays above
+/// [`Self::liquidus_temperature`] (so the solder is melted) for [`Self::time_above_liquidus_in_seconds`] seconds.
+///
+/// The time above liquidus includes the ramp from the liquidus temperature to the peak and the cooling from the peak
+/// back to the liquidus temperature, and the plate is kept at the peak temperature for the remaining time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReflowPhase {
+	pub ramp_rate: Rate,
+	pub liquidus_temperature: Temperature,
+	pub peak_temperature: Temperature,
+	pub time_above_liquidus_in_seconds: u16,
+}
+
+/// The plate cools from [`ReflowPhase::peak_temperature`] to [`Self::end_temperature`] at a constant rate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CoolingPhase {
+	/// The rate at which the temperature decreases (it's a positive value).
+	pub cool_down_rate: Rate,
+	pub end_temperature: Temperature,
+}
+
+/// A phase of a [`PhasedReflowProfile`].
+#[derive(enumset::EnumSetType, Debug, Hash)]
+pub enum ReflowProfilePhase {
+	Preheat,
+	Soak,
+	Reflow,
+	Cooling,
+}
+
+/// The limits a [`PhasedReflowProfile`] must respect to correctly reflow a specific solder paste, as written in
+/// its datasheet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolderPasteLimits {
+	pub max_ramp_up_rate: Rate,
+	pub max_cool_down_rate: Rate,
+	/// Both the [`PreheatPhase::target_temperature`] and the [`SoakPhase::end_temperature`] must be in this range.
+	pub soak_temperature_range: RangeInclusive<Temperature>,
+	pub soak_duration_in_seconds: RangeInclusive<u16>,
+	pub time_above_liquidus_in_seconds: RangeInclusive<u16>,
+	pub peak_temperature_range: RangeInclusive<Temperature>,
+}
+
+impl SolderPasteLimits {
+	/// Typical limits of a lead-free `Sn96.5/Ag3.0/Cu0.5` solder paste (liquidus at `217°C`).
+	pub const SAC305: Self = Self {
+		max_ramp_up_rate: 3.,
+		max_cool_down_rate: 6.,
+		soak_temperature_range: Temperature::from_kelvin(150. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(200. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+		soak_duration_in_seconds: 60..=120,
+		time_above_liquidus_in_seconds: 60..=150,
+		peak_temperature_range: Temperature::from_kelvin(235. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(250. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+	};
+
+	/// Typical limits of a leaded `Sn63/Pb37` solder paste (liquidus at `183°C`).
+	pub const SN63_PB37: Self = Self {
+		max_ramp_up_rate: 3.,
+		max_cool_down_rate: 6.,
+		soak_temperature_range: Temperature::from_kelvin(100. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(165. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+		soak_duration_in_seconds: 60..=120,
+		time_above_liquidus_in_seconds: 45..=90,
+		peak_temperature_range: Temperature::from_kelvin(205. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(225. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+	};
+
+	/// Typical limits of a low temperature `Sn42/Bi58` solder paste (liquidus at `138°C`).
+	pub const SN42_BI58: Self = Self {
+		max_ramp_up_rate: 2.5,
+		max_cool_down_rate: 6.,
+		soak_temperature_range: Temperature::from_kelvin(90. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(130. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+		soak_duration_in_seconds: 60..=120,
+		time_above_liquidus_in_seconds: 30..=90,
+		peak_temperature_range: Temperature::from_kelvin(160. + Temperature::ZERO_CELSIUS_IN_KELVIN)
+			..=Temperature::from_kelvin(175. + Temperature::ZERO_CELSIUS_IN_KELVIN),
+	};
+}
+
+/// A reason why a [`PhasedReflowProfile`] is invalid.
+#[derive(enumset::EnumSetType, Debug, Hash)]
+pub enum ReflowProfileError {
+	/// One of the ramp or cool down rates is not a positive number.
+	RateNotPositive,
+	/// The [`PreheatPhase::target_temperature`] is not above the [`PhasedReflowProfile::start_temperature`].
+	PreheatNotHeating,
+	/// The [`SoakPhase::end_temperature`] is below the [`PreheatPhase::target_temperature`].
+	SoakNotHeating,
+	/// The [`SoakPhase::end_temperature`] is not below the [`ReflowPhase::liquidus_temperature`].
+	SoakAboveLiquidus,
+	/// The [`ReflowPhase::peak_temperature`] is not above the [`ReflowPhase::liquidus_temperature`].
+	PeakNotAboveLiquidus,
+	/// The ramp from the liquidus temperature to the peak and the cooling back to the liquidus temperature take more
+	/// than the [`ReflowPhase::time_above_liquidus_in_seconds`].
+	TimeAboveLiquidusTooShortForRates,
+	/// The [`CoolingPhase::end_temperature`] is not below the [`ReflowPhase::liquidus_temperature`].
+	CoolingEndsAboveLiquidus,
+
+	/// A ramp rate is above [`SolderPasteLimits::max_ramp_up_rate`].
+	RampUpTooFast,
+	/// The cool down rate is above [`SolderPasteLimits::max_cool_down_rate`].
+	CoolDownTooFast,
+	/// The soak phase is outside [`SolderPasteLimits::soak_temperature_range`].
+	SoakTemperatureOutOfRange,
+	/// The soak phase lasts a time outside [`SolderPasteLimits::soak_duration_in_seconds`].
+	SoakDurationOutOfRange,
+	/// The time above liquidus is outside [`SolderPasteLimits::time_above_liquidus_in_seconds`].
+	TimeAboveLiquidusOutOfRange,
+	/// The peak temperature is outside [`SolderPasteLimits::peak_temperature_range`].
+	PeakTemperatureOutOfRange,
+}
+
+impl PhasedReflowProfile {
+	/// Returns a set of all the reasons why this profile is not physically consistent (for example the peak is below
+	/// the liquidus temperature). If the profile is consistent the set is empty.
+	pub fn check(&self) -> EnumSet<ReflowProfileError> {
+		let mut errors = EnumSet::empty();
+
+		if [
+			self.preheat.ramp_rate,
+			self.reflow.ramp_rate,
+			self.cooling.cool_down_rate,
+		]
+		.iter()
+		.any(|&rate| rate.is_nan() || rate <= 0.)
+		{
+			// All the other checks divide by the rates
+			errors.insert(ReflowProfileError::RateNotPositive);
+			return errors;
+		}
+
+		if self.preheat.target_temperature <= self.start_temperature {
+			errors.insert(ReflowProfileError::PreheatNotHeating);
+		}
+		if self.soak.end_temperature < self.preheat.target_temperature {
+			errors.insert(ReflowProfileError::SoakNotHeating);
+		}
+		if self.soak.end_temperature >= self.reflow.liquidus_temperature {
+			errors.insert(ReflowProfileError::SoakAboveLiquidus);
+		}
+		if self.reflow.peak_temperature <= self.reflow.liquidus_temperature {
+			errors.insert(ReflowProfileError::PeakNotAboveLiquidus);
+		} else if self.peak_hold_duration() < 0. {
+			errors.insert(ReflowProfileError::TimeAboveLiquidusTooShortForRates);
+		}
+		if self.cooling.end_temperature >= self.reflow.liquidus_temperature {
+			errors.insert(ReflowProfileError::CoolingEndsAboveLiquidus);
+		}
+
+		errors
+	}
+
+	/// Returns a set of all the reasons why this profile is not [`consistent`] or doesn't respect the provided
+	/// `limits`. If the profile is valid the set is empty.
+	///
+	/// [`consistent`]: `Self::check`
+	pub fn check_against(&self, limits: &SolderPasteLimits) -> EnumSet<ReflowProfileError> {
+		let mut errors = self.check();
+
+		if self.preheat.ramp_rate > limits.max_ramp_up_rate || self.reflow.ramp_rate > limits.max_ramp_up_rate {
+			errors.insert(ReflowProfileError::RampUpTooFast);
+		}
+		if self.cooling.cool_down_rate > limits.max_cool_down_rate {
+			errors.insert(ReflowProfileError::CoolDownTooFast);
+		}
+		if !limits.soak_temperature_range.contains(&self.preheat.target_temperature)
+			|| !limits.soak_temperature_range.contains(&self.soak.end_temperature)
+		{
+			errors.insert(ReflowProfileError::SoakTemperatureOutOfRange);
+		}
+		if !limits.soak_duration_in_seconds.contains(&self.soak.duration_in_seconds) {
+			errors.insert(ReflowProfileError::SoakDurationOutOfRange);
+		}
+		if !limits
+			.time_above_liquidus_in_seconds
+			.contains(&self.reflow.time_above_liquidus_in_seconds)
+		{
+			errors.insert(ReflowProfileError::TimeAboveLiquidusOutOfRange);
+		}
+		if !limits.peak_temperature_range.contains(&self.reflow.peak_temperature) {
+			errors.insert(ReflowProfileError::PeakTemperatureOutOfRange);
+		}
+
+		errors
+	}
+
+	/// Returns how many seconds the provided `phase` lasts.
+	///
+	/// The [`ReflowProfilePhase::Reflow`] phase includes the time the plate is kept at the peak temperature.
+	pub fn duration_of(&self, phase: ReflowProfilePhase) -> f32 {
+		match phase {
+			ReflowProfilePhase::Preheat => {
+				(self.preheat.target_temperature - self.start_temperature).as_kelvin() / self.preheat.ramp_rate
+			},
+			ReflowProfilePhase::Soak => self.soak.duration_in_seconds as f32,
+			ReflowProfilePhase::Reflow => {
+				(self.reflow.peak_temperature - self.soak.end_temperature).as_kelvin() / self.reflow.ramp_rate
+					+ self.peak_hold_duration()
+			},
+			ReflowProfilePhase::Cooling => {
+				(self.reflow.peak_temperature - self.cooling.end_temperature).as_kelvin() / self.cooling.cool_down_rate
+			},
+		}
+	}
+
+	/// Converts this profile to a [`ReflowProfile`] that can be used by a [`ReflowProcess`].
+	///
+	/// Returns `Ok(ReflowProfile)` if the profile is [`consistent`], otherwise returns `Err(errors)`.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::process::*;
+	/// # use core::time::Duration;
+	/// #
+	/// let phased_profile = BuiltinReflowProfile::LeadFreeSac305.phased_profile().unwrap();
+	/// let profile = phased_profile.to_reflow_profile().unwrap();
+	///
+	/// // The profile starts from the start temperature, and preheats at the ramp rate
+	/// let start_temperature = profile.get_temperature_at(Duration::ZERO, Interpolation::Linear).unwrap();
+	/// assert_eq!(start_temperature, phased_profile.start_temperature);
+	///
+	/// let slope = profile.get_slope_at(Duration::ZERO, Duration::from_secs(30), Interpolation::Linear).unwrap();
+	/// assert!((slope - phased_profile.preheat.ramp_rate).abs() < 0.01);
+	/// ```
+	///
+	/// [`ReflowProcess`]: `super::ReflowProcess`
+	/// [`consistent`]: `Self::check`
+	pub fn to_reflow_profile(&self) -> Result<ReflowProfile<PHASED_PROFILE_POINTS>, EnumSet<ReflowProfileError>> {
+		let errors = self.check();
+		if !errors.is_empty() {
+			return Err(errors);
+		}
+
+		let preheat_end = self.duration_of(ReflowProfilePhase::Preheat);
+		let soak_end = preheat_end + self.duration_of(ReflowProfilePhase::Soak);
+		let peak_start =
+			soak_end + (self.reflow.peak_temperature - self.soak.end_temperature).as_kelvin() / self.reflow.ramp_rate;
+		let peak_end = peak_start + self.peak_hold_duration();
+		let cooling_end = peak_end + self.duration_of(ReflowProfilePhase::Cooling);
+
+		let to_seconds = |time: f32| (time + 0.5) as u16;
+		Ok(ReflowProfile {
+			start_temperature: self.start_temperature,
+			temperature_points: [
+				(self.preheat.target_temperature, to_seconds(preheat_end)),
+				(self.soak.end_temperature, to_seconds(soak_end)),
+				(self.reflow.peak_temperature, to_seconds(peak_start)),
+				(self.reflow.peak_temperature, to_seconds(peak_end)),
+				(self.cooling.end_temperature, to_seconds(cooling_end)),
+			],
+		})
+	}
+
+	/// Returns how many seconds the plate must be kept at the peak temperature to respect the time above liquidus.
+	/// It's negative if the time above liquidus is too short for the ramp and cool down rates.
+	fn peak_hold_duration(&self) -> f32 {
+		let peak_above_liquidus = (self.reflow.peak_temperature - self.reflow.liquidus_temperature).as_kelvin();
+
+		self.reflow.time_above_liquidus_in_seconds as f32
+			- peak_above_liquidus / self.reflow.ramp_rate
+			- peak_above_liquidus / self.cooling.cool_down_rate
+	}
+}
@@ -0,0 +1,358 @@
+use core::time::Duration;
+
+use super::{GuaranteedDwell, GuaranteedDwellConfig, Interpolation, MaxStretchExceeded, ReflowProcess, ReflowProfile};
+use crate::{hot_plate::temperature::PidUpdateError, utils::measurement::temperature::Temperature};
+
+/// Keeps track of the state of a [`ReflowProcess`], and lets you control it through explicit commands.
+///
+/// The process goes from [`Idle`] to [`Preheating`] when you [`start`] it, and then through [`Running`] and [`Cooling`]
+/// while it [`ticks`], until it's [`Done`]:
+/// - [`Preheating`]: the plate is heating up to the first point of the profile.
+/// - [`Running`]: the plate is following the profile up to its peak.
+/// - [`Cooling`]: the plate is following the profile from its peak down to its last point.
+///
+/// While it's in one of those 3 states you can [`abort`] it, and while it's heating you can also [`pause`] it (and later
+/// [`resume`] it).
+///
+/// Each command and each [`tick`] returns the [`ReflowEvent`] caused by the transition it made (if it made any).
+///
+/// If you [`enable the guaranteed dwell`], the clock of the process is held while the plate is lagging behind the
+/// profile (check [`GuaranteedDwell`]).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::process::*;
+/// # use core::time::Duration;
+/// #
+/// let mut state_machine = DefaultReflowStateMachine::new();
+/// assert_eq!(state_machine.get_state(), ReflowState::Idle);
+/// assert_eq!(state_machine.get_target_temperature(), None);
+///
+/// let event = state_machine.start(BuiltinReflowProfile::LeadFreeSac305.reflow_profile()).unwrap();
+/// assert_eq!(event, ReflowEvent::StateChanged { from: ReflowState::Idle, to: ReflowState::Preheating });
+/// assert!(state_machine.get_target_temperature().is_some());
+///
+/// state_machine.pause().unwrap();
+/// assert_eq!(state_machine.tick(Duration::from_secs(1_000), None), None);
+/// assert_eq!(state_machine.get_state(), ReflowState::Paused);
+/// assert_eq!(state_machine.resume(), Ok(ReflowEvent::StateChanged { from: ReflowState::Paused, to: ReflowState::Preheating }));
+///
+/// assert_eq!(state_machine.resume(), Err(CommandError::NotAllowed { state: ReflowState::Preheating }));
+///
+/// assert_eq!(state_machine.abort(AbortReason::Requested), Ok(ReflowEvent::Aborted(AbortReason::Requested)));
+/// assert_eq!(state_machine.get_target_temperature(), None);
+/// ```
+///
+/// [`Idle`]: `ReflowState::Idle`
+/// [`Preheating`]: `ReflowState::Preheating`
+/// [`Running`]: `ReflowState::Running`
+/// [`Cooling`]: `ReflowState::Cooling`
+/// [`Done`]: `ReflowState::Done`
+/// [`start`]: `Self::start`
+/// [`ticks`]: `Self::tick`
+/// [`pause`]: `Self::pause`
+/// [`resume`]: `Self::resume`
+/// [`abort`]: `Self::abort`
+/// [`tick`]: `Self::tick`
+/// [`enable the guaranteed dwell`]: `Self::set_guaranteed_dwell`
+pub struct ReflowStateMachine<const N: usize> {
+	state: ReflowState,
+	/// The state the process was in before being paused.
+	state_before_pause: ReflowState,
+	process: Option<ReflowProcess<N>>,
+	target_temperature: Option<Temperature>,
+	guaranteed_dwell: Option<GuaranteedDwell>,
+	interpolation: Interpolation,
+}
+
+impl<const N: usize> ReflowStateMachine<N> {
+	/// Returns a [`ReflowStateMachine`] in the [`ReflowState::Idle`] state.
+	pub const fn new() -> Self {
+		Self {
+			state: ReflowState::Idle,
+			state_before_pause: ReflowState::Idle,
+			process: None,
+			target_temperature: None,
+			guaranteed_dwell: None,
+			interpolation: Interpolation::Linear,
+		}
+	}
+
+	/// Returns the current state of the process.
+	pub fn get_state(&self) -> ReflowState {
+		self.state
+	}
+
+	/// Returns the [`ReflowProcess`] that is being executed, or `None` if the state is not [`active`].
+	///
+	/// [`active`]: `ReflowState::is_active`
+	pub fn get_process(&self) -> Option<&ReflowProcess<N>> {
+		self.process.as_ref()
+	}
+
+	/// Returns the [`Temperature`] the plate should be at right now, or `None` if the heater should be off.
+	pub fn get_target_temperature(&self) -> Option<Temperature> {
+		self.target_temperature
+	}
+
+	/// Returns the average rate in degrees per second at which the [`target temperature`] is going to change in the
+	/// next `look_ahead` (which is `0` while the process is [`paused`]), or `None` if the heater should be off.
+	///
+	/// [`target temperature`]: `Self::get_target_temperature`
+	/// [`paused`]: `ReflowState::Paused`
+	pub fn get_target_temperature_slope(&self, look_ahead: Duration) -> Option<f32> {
+		self.target_temperature?;
+
+		match self.state {
+			ReflowState::Paused => Some(0.),
+			_ => self.process.as_ref()?.get_target_temperature_slope(look_ahead),
+		}
+	}
+
+	/// Returns the [`GuaranteedDwell`] applied to the processes, or `None` if it's disabled.
+	pub fn get_guaranteed_dwell(&self) -> Option<&GuaranteedDwell> {
+		self.guaranteed_dwell.as_ref()
+	}
+
+	/// Enables the [`GuaranteedDwell`] with the provided `config` for the next processes (and the current one), or
+	/// disables it if `config` is `None`.
+	pub fn set_guaranteed_dwell(&mut self, config: Option<GuaranteedDwellConfig>) {
+		self.guaranteed_dwell = config.map(GuaranteedDwell::new);
+	}
+
+	/// Returns the [`Interpolation`] used by the processes between the points of their profiles.
+	pub fn get_interpolation(&self) -> Interpolation {
+		self.interpolation
+	}
+
+	/// Sets the [`Interpolation`] used by the next processes between the points of their profiles.
+	pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+		self.interpolation = interpolation;
+	}
+
+	/// Starts a new process that follows the provided `profile`.
+	///
+	/// It's allowed only if no process is [`active`].
+	///
+	/// [`active`]: `ReflowState::is_active`
+	pub fn start(&mut self, profile: ReflowProfile<N>) -> Result<ReflowEvent, CommandError> {
+		if self.state.is_active() {
+			return Err(CommandError::NotAllowed { state: self.state });
+		}
+
+		if let Some(guaranteed_dwell) = self.guaranteed_dwell.as_mut() {
+			guaranteed_dwell.reset();
+		}
+
+		let mut process = ReflowProcess::start_with_interpolation(profile, self.interpolation);
+		self.target_temperature = process.tick(Duration::ZERO);
+		self.process = Some(process);
+
+		Ok(self.change_state(ReflowState::Preheating))
+	}
+
+	/// Pauses the process: its clock stops and the plate is kept at the current target temperature until you
+	/// [`resume`] it.
+	///
+	/// It's allowed only in the [`Preheating`] and [`Running`] states: while [`Cooling`] the heater is off, so the
+	/// plate can't be kept at a temperature.
+	///
+	/// [`resume`]: `Self::resume`
+	/// [`Preheating`]: `ReflowState::Preheating`
+	/// [`Running`]: `ReflowState::Running`
+	/// [`Cooling`]: `ReflowState::Cooling`
+	pub fn pause(&mut self) -> Result<ReflowEvent, CommandError> {
+		match self.state {
+			ReflowState::Preheating | ReflowState::Running => {
+				self.state_before_pause = self.state;
+
+				Ok(self.change_state(ReflowState::Paused))
+			},
+			state => Err(CommandError::NotAllowed { state }),
+		}
+	}
+
+	/// Resumes a process you previously [`paused`], bringing it back to the state it was in.
+	///
+	/// [`paused`]: `Self::pause`
+	pub fn resume(&mut self) -> Result<ReflowEvent, CommandError> {
+		match self.state {
+			ReflowState::Paused => Ok(self.change_state(self.state_before_pause)),
+			state => Err(CommandError::NotAllowed { state }),
+		}
+	}
+
+	/// Stops the process for the provided `reason`. After this the heater should be turned off.
+	///
+	/// It's allowed only if a process is [`active`].
+	///
+	/// [`active`]: `ReflowState::is_active`
+	pub fn abort(&mut self, reason: AbortReason) -> Result<ReflowEvent, CommandError> {
+		if !self.state.is_active() {
+			return Err(CommandError::NotAllowed { state: self.state });
+		}
+
+		self.process = None;
+		self.target_temperature = None;
+
+		Ok(self.change_state(ReflowState::Aborted(reason)))
+	}
+
+	/// Makes `delta_time` pass in the process (unless it's [`paused`]), updating the target temperature and the state.
+	///
+	/// If the [`guaranteed dwell`] is enabled, the `current_temperature` of the plate is used to decide if the clock of
+	/// the process should be held (you can pass `None` if it's unknown, and the clock won't be held). The clock is never
+	/// held while [`cooling`]. The process is
+	/// [`aborted`] with [`AbortReason::MaxStretchExceeded`] if it has been held for too long.
+	///
+	/// [`paused`]: `ReflowState::Paused`
+	/// [`guaranteed dwell`]: `Self::set_guaranteed_dwell`
+	/// [`aborted`]: `ReflowState::Aborted`
+	/// [`cooling`]: `ReflowState::Cooling`
+	pub fn tick(&mut self, delta_time: Duration, current_temperature: Option<Temperature>) -> Option<ReflowEvent> {
+		if self.state == ReflowState::Paused || self.process.is_none() {
+			return None;
+		}
+
+		// While cooling the plate is allowed to be colder than the profile
+		let guaranteed_dwell = self
+			.guaranteed_dwell
+			.as_mut()
+			.filter(|_| self.state != ReflowState::Cooling);
+		let process_delta_time = match (guaranteed_dwell, current_temperature, self.target_temperature) {
+			(Some(guaranteed_dwell), Some(current_temperature), Some(target_temperature)) => {
+				match guaranteed_dwell.get_process_delta_time(delta_time, current_temperature, target_temperature) {
+					Ok(process_delta_time) => process_delta_time,
+					Err(MaxStretchExceeded) => return self.abort(AbortReason::MaxStretchExceeded).ok(),
+				}
+			},
+			_ => delta_time,
+		};
+
+		let process = self.process.as_mut()?;
+		self.target_temperature = process.tick(process_delta_time);
+
+		let new_state = match self.target_temperature {
+			Some(_) => Self::state_at(process.get_profile(), process.get_current_time()),
+			None => {
+				self.process = None;
+				ReflowState::Done
+			},
+		};
+
+		(new_state != self.state).then(|| self.change_state(new_state))
+	}
+
+	/// Returns in which of the [`Preheating`], [`Running`] and [`Cooling`] states the process should be at the
+	/// provided `time` of the `profile`.
+	///
+	/// [`Preheating`]: `ReflowState::Preheating`
+	/// [`Running`]: `ReflowState::Running`
+	/// [`Cooling`]: `ReflowState::Cooling`
+	fn state_at(profile: &ReflowProfile<N>, time: Duration) -> ReflowState {
+		let time = time.as_secs_f32();
+
+		let &(_, preheat_end) = profile.temperature_points.first().unwrap();
+		// The last point at the peak temperature
+		let (_, cooling_start) =
+			profile
+				.temperature_points
+				.iter()
+				.fold(profile.temperature_points[0], |peak, &point| {
+					if point.0 >= peak.0 {
+						point
+					} else {
+						peak
+					}
+				});
+		let is_last_point_at_peak = profile.temperature_points.last().unwrap().1 == cooling_start;
+
+		if time < preheat_end as f32 {
+			ReflowState::Preheating
+		} else if time >= cooling_start as f32 && !is_last_point_at_peak {
+			ReflowState::Cooling
+		} else {
+			ReflowState::Running
+		}
+	}
+
+	fn change_state(&mut self, new_state: ReflowState) -> ReflowEvent {
+		let old_state = self.state;
+		self.state = new_state;
+
+		match new_state {
+			ReflowState::Done => ReflowEvent::Finished,
+			ReflowState::Aborted(reason) => ReflowEvent::Aborted(reason),
+			_ => ReflowEvent::StateChanged {
+				from: old_state,
+				to: new_state,
+			},
+		}
+	}
+}
+
+impl<const N: usize> Default for ReflowStateMachine<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// A state of a [`ReflowStateMachine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReflowState {
+	/// No process has ever been started.
+	Idle,
+	Preheating,
+	Running,
+	Paused,
+	Cooling,
+	/// The last process has reached the end of its profile.
+	Done,
+	/// The last process has been stopped before reaching the end of its profile.
+	Aborted(AbortReason),
+}
+
+impl ReflowState {
+	/// Returns `true` if in this state there's a process that is being executed (even if it's paused).
+	pub fn is_active(&self) -> bool {
+		matches!(self, Self::Preheating | Self::Running | Self::Paused | Self::Cooling)
+	}
+}
+
+/// Something that happened to a [`ReflowStateMachine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReflowEvent {
+	/// The process moved `from` a state `to` another one (which is neither [`ReflowState::Done`] nor
+	/// [`ReflowState::Aborted`]).
+	StateChanged { from: ReflowState, to: ReflowState },
+	/// The process reached the end of its profile.
+	Finished,
+	/// The process has been stopped for the provided reason.
+	Aborted(AbortReason),
+}
+
+/// The reason why a process has been [`aborted`].
+///
+/// [`aborted`]: `ReflowStateMachine::abort`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AbortReason {
+	/// Someone asked to stop the process.
+	Requested,
+	/// The PID controller of the plate failed, so the temperature can't be controlled anymore.
+	TemperatureControl(PidUpdateError),
+	/// The plate lagged behind the profile for too long, so the [`GuaranteedDwell`] couldn't be honored.
+	MaxStretchExceeded,
+}
+
+/// An error that can occur when you send a command to a [`ReflowStateMachine`] (or to the [`HotPlate`]).
+///
+/// [`HotPlate`]: `crate::hot_plate::HotPlate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CommandError {
+	/// The command can't be executed in the current `state`.
+	NotAllowed { state: ReflowState },
+	/// The command can't be executed while the PID of the hot plate is being tuned.
+	AutotuneInProgress,
+	/// The command requires the PID of the hot plate to be being tuned, but it's not.
+	NoAutotuneInProgress,
+}
@@ -1,10 +1,13 @@
+use core::time::Duration;
+
 use crate::{
 	hot_plate::screen::drawable::{Plot, Thickness},
-	utils::measurement::temperature::Temperature,
+	utils::{math, measurement::temperature::Temperature},
 };
 
 pub const DEFAULT_PROFILE_POINTS: usize = 5;
 pub const DEFAULT_PROFILE: ReflowProfile<DEFAULT_PROFILE_POINTS> = ReflowProfile {
+	start_temperature: Temperature::from_kelvin(25. + Temperature::ZERO_CELSIUS_IN_KELVIN),
 	temperature_points: [
 		(Temperature::from_kelvin(150. + Temperature::ZERO_CELSIUS_IN_KELVIN), 60),
 		(
@@ -23,40 +26,187 @@ pub const DEFAULT_PROFILE: ReflowProfile<DEFAULT_PROFILE_POINTS> = ReflowProfile
 	],
 };
 
-type TimeInSeconds = u16;
+pub type TimeInSeconds = u16;
 
+/// A reflow profile described by a [`start temperature`] and a list of `(temperature, time)` points: the target
+/// temperature is interpolated between each couple of consecutive points, starting from the start temperature at time
+/// `0`.
+///
+/// Check [`PhasedReflowProfile`] to describe a profile in terms of phases instead.
+///
+/// [`start temperature`]: `Self::start_temperature`
+/// [`PhasedReflowProfile`]: `super::PhasedReflowProfile`
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ReflowProfile<const N: usize> {
+	/// The target temperature at time `0`, before reaching the first point (usually the temperature of the room).
+	pub start_temperature: Temperature,
 	pub temperature_points: [(Temperature, TimeInSeconds); N],
 }
 
 impl<const N: usize> ReflowProfile<N> {
+	/// Returns the target temperature at the provided `time` since the start of the profile, computed using the
+	/// provided `interpolation` between the points of the profile, or `None` if `time` is after the last point.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::{hot_plate::process::*, utils::measurement::temperature::Temperature};
+	/// # use core::time::Duration;
+	/// #
+	/// let profile = ReflowProfile {
+	/// 	start_temperature: Temperature::from_celsius(0.),
+	/// 	temperature_points: [
+	/// 		(Temperature::from_celsius(100.), 10),
+	/// 		(Temperature::from_celsius(200.), 20),
+	/// 	],
+	/// };
+	///
+	/// let temperature = profile.get_temperature_at(Duration::from_millis(12_500), Interpolation::Linear).unwrap();
+	/// assert_eq!(temperature.as_celsius().round(), 125.);
+	///
+	/// let temperature = profile.get_temperature_at(Duration::from_secs(15), Interpolation::Smooth).unwrap();
+	/// assert_eq!(temperature.as_celsius().round(), 150.);
+	///
+	/// assert_eq!(profile.get_temperature_at(Duration::from_millis(20_001), Interpolation::Linear), None);
+	/// ```
+	pub fn get_temperature_at(&self, time: Duration, interpolation: Interpolation) -> Option<Temperature> {
+		let time = time.as_secs_f32();
+
+		let next_point_index = (1..=N).find(|&index| time <= self.get_point(index).1)?;
+		let (start_temperature, start_time) = self.get_point(next_point_index - 1);
+		let (end_temperature, end_time) = self.get_point(next_point_index);
+		if end_time <= start_time {
+			return Some(Temperature::from_celsius(end_temperature));
+		}
+
+		let progress = math::map(time, start_time..=end_time, 0_f32..=1_f32);
+		let celsius = match interpolation {
+			Interpolation::Linear => math::lerp(progress, start_temperature..=end_temperature),
+			Interpolation::Smooth => {
+				// Cubic Hermite spline
+				let duration = end_time - start_time;
+				let progress_squared = progress * progress;
+				let progress_cubed = progress_squared * progress;
+
+				(2. * progress_cubed - 3. * progress_squared + 1.) * start_temperature
+					+ (progress_cubed - 2. * progress_squared + progress)
+						* duration * self.get_smooth_tangent(next_point_index - 1)
+					+ (-2. * progress_cubed + 3. * progress_squared) * end_temperature
+					+ (progress_cubed - progress_squared) * duration * self.get_smooth_tangent(next_point_index)
+			},
+		};
+
+		Some(Temperature::from_celsius(celsius))
+	}
+
+	/// Returns the average rate in degrees per second at which the target temperature changes from the provided `time`
+	/// to `look_ahead` later (or to the end of the profile, if it comes first), or `None` if `time` is after the last
+	/// point.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::{hot_plate::process::*, utils::measurement::temperature::Temperature};
+	/// # use core::time::Duration;
+	/// #
+	/// let profile = ReflowProfile {
+	/// 	start_temperature: Temperature::from_celsius(0.),
+	/// 	temperature_points: [
+	/// 		(Temperature::from_celsius(100.), 10),
+	/// 		(Temperature::from_celsius(100.), 20),
+	/// 	],
+	/// };
+	///
+	/// let slope = profile.get_slope_at(Duration::from_secs(5), Duration::from_secs(2), Interpolation::Linear);
+	/// assert_eq!(slope.map(f32::round), Some(10.));
+	///
+	/// let slope = profile.get_slope_at(Duration::from_secs(9), Duration::from_secs(2), Interpolation::Linear);
+	/// assert_eq!(slope.map(f32::round), Some(5.));
+	///
+	/// assert_eq!(profile.get_slope_at(Duration::from_secs(20), Duration::from_secs(2), Interpolation::Linear), Some(0.));
+	/// ```
+	pub fn get_slope_at(&self, time: Duration, look_ahead: Duration, interpolation: Interpolation) -> Option<f32> {
+		let current_temperature = self.get_temperature_at(time, interpolation)?;
+
+		let end_time = Duration::from_secs(self.temperature_points.last().map(|&(_, time)| time).unwrap_or(0) as u64);
+		let future_time = (time + look_ahead).min(end_time);
+		if future_time <= time {
+			return Some(0.);
+		}
+		let future_temperature = self.get_temperature_at(future_time, interpolation)?;
+
+		Some((future_temperature - current_temperature).as_kelvin() / (future_time - time).as_secs_f32())
+	}
+
 	pub fn to_plot<const P: usize>(&self, thickness: Thickness) -> Plot<P> {
 		let mut points = [0; P];
 		if let Some(&(_, last_point_time)) = self.temperature_points.last() {
 			let step_size = last_point_time as f32 / P as f32;
 
-			let mut current_point_index = 0;
-			let mut point_before = (Temperature::from_celsius(0.), 0);
-			for i in 0..P {
-				let time = i as f32 * step_size;
-				if time as u16 > self.temperature_points[current_point_index].1 {
-					point_before = self.temperature_points[current_point_index];
-					current_point_index += 1;
-				}
-
-				let point = crate::utils::math::lerp(
-					crate::utils::math::map(
-						time,
-						point_before.1 as f32..=self.temperature_points[current_point_index].1 as f32,
-						0_f32..=1_f32,
-					),
-					point_before.0.as_celsius()..=self.temperature_points[current_point_index].0.as_celsius(),
-				) as u16;
-
-				points[i] = point;
+			for (i, point) in points.iter_mut().enumerate() {
+				let time = Duration::from_secs_f32(i as f32 * step_size);
+				*point = self
+					.get_temperature_at(time, Interpolation::Linear)
+					.map(|temperature| temperature.as_celsius() as u16)
+					.unwrap_or(0);
 			}
 		}
 
 		Plot { points, thickness }
 	}
+
+	/// Returns the `(celsius, seconds)` of the point at the provided `index`, where the point at index `0` is the
+	/// [`start`](Self::start_temperature) of the profile and the others are the [`Self::temperature_points`].
+	fn get_point(&self, index: usize) -> (f32, f32) {
+		match index {
+			0 => (self.start_temperature.as_celsius(), 0.),
+			_ => {
+				let (temperature, time) = self.temperature_points[index - 1];
+				(temperature.as_celsius(), time as f32)
+			},
+		}
+	}
+
+	/// Returns the slope (in degrees per second) of the segment that goes from the point at `index` to the next one, or
+	/// `None` if there isn't a next point.
+	fn get_segment_slope(&self, index: usize) -> Option<f32> {
+		if index >= N {
+			return None;
+		}
+
+		let (start_temperature, start_time) = self.get_point(index);
+		let (end_temperature, end_time) = self.get_point(index + 1);
+		Some(match end_time > start_time {
+			true => (end_temperature - start_temperature) / (end_time - start_time),
+			false => 0.,
+		})
+	}
+
+	/// Returns the slope of the [`Interpolation::Smooth`] curve at the point at `index`.
+	///
+	/// It's the harmonic mean of the slopes of the 2 segments around the point, and it's `0` if the point is a local
+	/// maximum or minimum: this way the curve never overshoots the points (which is what makes it a
+	/// [`monotone cubic interpolation`]).
+	///
+	/// [`monotone cubic interpolation`]: <https://en.wikipedia.org/wiki/Monotone_cubic_interpolation>
+	fn get_smooth_tangent(&self, index: usize) -> f32 {
+		let slope_before = index.checked_sub(1).and_then(|index| self.get_segment_slope(index));
+		let slope_after = self.get_segment_slope(index);
+
+		match (slope_before, slope_after) {
+			(Some(before), Some(after)) if before * after > 0. => 2. * before * after / (before + after),
+			(Some(_), Some(_)) => 0.,
+			(Some(slope), None) | (None, Some(slope)) => slope,
+			(None, None) => 0.,
+		}
+	}
+}
+
+/// How the target temperature is computed between 2 consecutive points of a [`ReflowProfile`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+	/// The temperature changes at a constant rate between the 2 points.
+	#[default]
+	Linear,
+	/// The temperature follows a cubic curve that passes through all the points without overshooting them, so that the
+	/// rate of change doesn't jump at each point (which is easier to follow for the plate).
+	Smooth,
 }
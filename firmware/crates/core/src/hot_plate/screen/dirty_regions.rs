@@ -0,0 +1,99 @@
+use super::ui::widget::Rect;
+
+/// The maximum number of separate rectangles a [`DirtyRegions`] can store.
+pub const MAX_DIRTY_REGIONS: usize = 8;
+
+/// The areas of the screen that changed since the last time they have been drawn, stored as at most
+/// [`MAX_DIRTY_REGIONS`] rectangles that don't touch each other.
+///
+/// A rectangle that overlaps or touches the ones already stored is merged with them. If there's no space left for a new
+/// rectangle, it's merged with the one that grows the least.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::{dirty_regions::DirtyRegions, ui::widget::Rect};
+/// #
+/// let mut regions = DirtyRegions::new();
+/// regions.add(Rect::new(0, 0, 100, 20));
+/// regions.add(Rect::new(200, 100, 1, 50));
+/// // The columns next to each other are merged
+/// regions.add(Rect::new(201, 100, 1, 50));
+/// assert_eq!(regions.iter().collect::<Vec<_>>(), [Rect::new(0, 0, 100, 20), Rect::new(200, 100, 2, 50)]);
+///
+/// // The empty rectangles are ignored
+/// regions.add(Rect::new(50, 50, 0, 10));
+/// assert_eq!(regions.iter().count(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DirtyRegions {
+	regions: [Rect; MAX_DIRTY_REGIONS],
+	count: usize,
+}
+
+impl DirtyRegions {
+	/// Returns a [`DirtyRegions`] without any rectangle.
+	pub const fn new() -> Self {
+		Self {
+			regions: [Rect::new(0, 0, 0, 0); MAX_DIRTY_REGIONS],
+			count: 0,
+		}
+	}
+
+	/// Adds the provided `rect` to the dirty regions.
+	pub fn add(&mut self, mut rect: Rect) {
+		if rect.is_empty() {
+			return;
+		}
+
+		// The merged rectangle can touch other rectangles that the original one didn't touch, which must be merged too
+		while let Some(index) = self.regions[..self.count]
+			.iter()
+			.position(|region| are_touching(region, &rect))
+		{
+			rect = rect.union(&self.regions[index]);
+			self.remove(index);
+		}
+
+		if self.count < MAX_DIRTY_REGIONS {
+			self.regions[self.count] = rect;
+			self.count += 1;
+		} else if let Some(index) = self
+			.iter()
+			.enumerate()
+			.min_by_key(|(_, region)| region.union(&rect).area() - region.area())
+			.map(|(index, _)| index)
+		{
+			let merged = rect.union(&self.regions[index]);
+			self.remove(index);
+			self.add(merged);
+		}
+	}
+
+	/// Returns `true` if nothing changed on the screen.
+	pub fn is_empty(&self) -> bool {
+		self.count == 0
+	}
+
+	/// Removes all the rectangles.
+	pub fn clear(&mut self) {
+		self.count = 0;
+	}
+
+	/// Returns an iterator over the rectangles (which don't overlap each other).
+	pub fn iter(&self) -> impl Iterator<Item = Rect> + '_ {
+		self.regions[..self.count].iter().copied()
+	}
+
+	fn remove(&mut self, index: usize) {
+		self.regions.copy_within(index + 1..self.count, index);
+		self.count -= 1;
+	}
+}
+
+/// Returns `true` if the 2 rectangles overlap or share (part of) a side.
+fn are_touching(a: &Rect, b: &Rect) -> bool {
+	let a_end = a.position + a.size;
+	let b_end = b.position + b.size;
+
+	a.position.x <= b_end.x && b.position.x <= a_end.x && a.position.y <= b_end.y && b.position.y <= a_end.y
+}
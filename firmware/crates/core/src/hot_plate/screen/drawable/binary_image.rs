@@ -28,24 +28,33 @@ impl<const CHUNKS: usize> Drawable for BinaryImage<CHUNKS> {
 	}
 
 	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let get_color = |x: u16, y: u16| {
+			let index_of_pixel = y as u32 * self.width as u32 + x as u32;
+			let index_of_chunk = index_of_pixel / Chunk::BITS;
+			let index_in_chunk = index_of_pixel - index_of_chunk * Chunk::BITS;
+
+			match self.chunks_data[index_of_chunk as usize] & 1 << index_in_chunk != 0 {
+				true => self.fill_color,
+				false => self.background_color,
+			}
+		};
+
+		// Each run of pixels of the same color in a row is drawn at once
 		for y in 0..self.height() {
-			for x in 0..self.width {
-				let index_of_pixel = y as u32 * self.width as u32 + x as u32;
-				let index_of_chunk = index_of_pixel / Chunk::BITS;
-				let index_in_chunk = index_of_pixel - index_of_chunk * Chunk::BITS;
-
-				let bit = self.chunks_data[index_of_chunk as usize] & 1 << index_in_chunk;
-				let color = match bit {
-					0 => self.background_color,
-					1 => self.fill_color,
-					_ => panic!(),
-				};
+			let mut start = 0;
+			while start < self.width {
+				let color = get_color(start, y);
+				let length = (start + 1..self.width)
+					.position(|x| get_color(x, y) != color)
+					.map_or(self.width - start, |length| length as u16 + 1);
 
 				(draw_fn)(Pixels {
-					offset_position: Vector2d { x, y },
+					offset_position: Vector2d { x: start, y },
+					repetitions_count: length,
 					color,
 					..Default::default()
 				});
+				start += length;
 			}
 		}
 	}
@@ -0,0 +1,201 @@
+use micromath::vector::U16x2;
+
+use crate::utils::measurement::color::ColorRGB565;
+
+use super::{Drawable, Pixels};
+
+/// A color image compressed with a codec similar to [QOI](https://qoiformat.org), usually generated from a PNG with the
+/// `asset-generator` tool (check the [`images`](super::images) built into the firmware).
+///
+/// The image is decoded while it's drawn, without storing its pixels anywhere, and each run of pixels of the same
+/// color in a row is drawn at once.
+///
+/// # Format
+/// The pixels are encoded row by row, from the top left one. Each pixel is encoded with one of the following
+/// operations, starting from the previous pixel (which is black before the first one) and from an array of the
+/// [`INDEX_LENGTH`] colors seen most recently (which are black at the start):
+/// - `0b00iiiiii`: the color at the index `i` of the array;
+/// - `0b01rrggbb`: the previous color with each channel changed by `-2` to `1` (stored with a bias of `2`);
+/// - `0b10gggggg 0brrrrbbbb`: the previous color with the green channel changed by `-32` to `31` (stored with a bias of
+///   `32`), and the red and blue channels changed by half of it plus `-8` to `7` (stored with a bias of `8`);
+/// - `0b11nnnnnn`: the previous color repeated `n + 1` times, from `1` to [`MAX_RUN`] times;
+/// - `0xFF` followed by the 2 bytes of a color (check [`ColorRGB565::as_bytes`]).
+///
+/// The channels wrap around when they are changed. Every color that isn't repeated is put in the array at the index
+/// returned by [`get_index_of`].
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{CompressedImage, Drawable},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// // A red row and a black row
+/// let image = CompressedImage {
+/// 	size: U16x2 { x: 4, y: 2 },
+/// 	data: &[0xFF, 0xF8, 0x00, 0b11_000010, 0b00_000000, 0b11_000010],
+/// };
+/// assert_eq!(
+/// 	image.runs().collect::<Vec<_>>(),
+/// 	[(ColorRGB565::RED, 1), (ColorRGB565::RED, 3), (ColorRGB565::BLACK, 1), (ColorRGB565::BLACK, 3)]
+/// );
+///
+/// // The runs of the same color are drawn at once, without crossing the rows
+/// let mut drawn = Vec::new();
+/// image.draw(&mut |pixels| drawn.push((pixels.offset_position.y, pixels.repetitions_count, pixels.color)));
+/// assert_eq!(drawn, [(0, 4, ColorRGB565::RED), (1, 4, ColorRGB565::BLACK)]);
+/// ```
+///
+/// [`INDEX_LENGTH`]: `Self::INDEX_LENGTH`
+/// [`MAX_RUN`]: `Self::MAX_RUN`
+/// [`get_index_of`]: `Self::get_index_of`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompressedImage {
+	pub size: U16x2,
+	pub data: &'static [u8],
+}
+
+impl CompressedImage {
+	/// How many of the colors seen most recently are kept while the image is decoded.
+	pub const INDEX_LENGTH: usize = 64;
+	/// The maximum number of times a color can be repeated by a single operation.
+	pub const MAX_RUN: u16 = 63;
+
+	const OP_INDEX: u8 = 0b00;
+	const OP_DIFF: u8 = 0b01;
+	const OP_LUMA: u8 = 0b10;
+	const OP_LITERAL: u8 = 0xFF;
+
+	/// Returns the index of the array of the colors seen most recently where the provided `color` is put.
+	pub const fn get_index_of(color: ColorRGB565) -> usize {
+		(color.get_red() as usize * 3 + color.get_green() as usize * 5 + color.get_blue() as usize * 7)
+			% Self::INDEX_LENGTH
+	}
+
+	/// Returns an iterator over the colors of the image and how many times each one is repeated, row by row.
+	///
+	/// The pixels of a run can be in different rows, and consecutive runs can have the same color.
+	pub fn runs(&self) -> Runs {
+		Runs {
+			data: self.data,
+			remaining_pixels: self.size.x as u32 * self.size.y as u32,
+			previous: ColorRGB565::BLACK,
+			index: [ColorRGB565::BLACK; Self::INDEX_LENGTH],
+		}
+	}
+}
+
+impl Drawable for CompressedImage {
+	fn size(&self) -> U16x2 {
+		self.size
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		if self.size.x == 0 {
+			return;
+		}
+
+		// The pixels of the current row with the same color that haven't been drawn yet
+		let mut start = U16x2 { x: 0, y: 0 };
+		let mut length = 0;
+		let mut color = ColorRGB565::BLACK;
+		let mut flush = |start: U16x2, length: u16, color: ColorRGB565| {
+			if length > 0 {
+				(draw_fn)(Pixels {
+					offset_position: start,
+					repetitions_count: length,
+					color,
+					..Default::default()
+				})
+			}
+		};
+
+		for (run_color, mut count) in self.runs() {
+			while count > 0 {
+				if run_color != color {
+					flush(start, length, color);
+					start.x += length;
+					length = 0;
+					color = run_color;
+				}
+
+				let added = count.min(self.size.x - start.x - length);
+				length += added;
+				count -= added;
+				if start.x + length == self.size.x {
+					flush(start, length, color);
+					start = U16x2 { x: 0, y: start.y + 1 };
+					length = 0;
+				}
+			}
+		}
+		flush(start, length, color);
+	}
+}
+
+/// An iterator over the runs of pixels of the same color of a [`CompressedImage`] (check [`CompressedImage::runs`]).
+pub struct Runs {
+	data: &'static [u8],
+	remaining_pixels: u32,
+	previous: ColorRGB565,
+	index: [ColorRGB565; CompressedImage::INDEX_LENGTH],
+}
+
+impl Runs {
+	fn next_byte(&mut self) -> Option<u8> {
+		let (&byte, data) = self.data.split_first()?;
+		self.data = data;
+
+		Some(byte)
+	}
+}
+
+impl Iterator for Runs {
+	type Item = (ColorRGB565, u16);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining_pixels == 0 {
+			return None;
+		}
+
+		let op = self.next_byte()?;
+		let previous = self.previous;
+		let change = |red: u8, green: u8, blue: u8| {
+			ColorRGB565::new(
+				previous.get_red().wrapping_add(red),
+				previous.get_green().wrapping_add(green),
+				previous.get_blue().wrapping_add(blue),
+			)
+		};
+
+		let color = match (op, op >> 6) {
+			(CompressedImage::OP_LITERAL, _) => ColorRGB565::from_bytes([self.next_byte()?, self.next_byte()?]),
+			(_, CompressedImage::OP_INDEX) => self.index[op as usize],
+			(_, CompressedImage::OP_DIFF) => change(
+				((op >> 4) & 0b11).wrapping_sub(2),
+				((op >> 2) & 0b11).wrapping_sub(2),
+				(op & 0b11).wrapping_sub(2),
+			),
+			(_, CompressedImage::OP_LUMA) => {
+				let second = self.next_byte()?;
+				let green = (op & 0x3F) as i8 - 32;
+				let red = (second >> 4) as i8 - 8 + (green >> 1);
+				let blue = (second & 0x0F) as i8 - 8 + (green >> 1);
+				change(red as u8, green as u8, blue as u8)
+			},
+			_ => {
+				let count = ((op & 0x3F) as u16 + 1).min(self.remaining_pixels.min(u16::MAX as u32) as u16);
+				self.remaining_pixels -= count as u32;
+				return Some((previous, count));
+			},
+		};
+
+		self.index[CompressedImage::get_index_of(color)] = color;
+		self.previous = color;
+		self.remaining_pixels -= 1;
+
+		Some((color, 1))
+	}
+}
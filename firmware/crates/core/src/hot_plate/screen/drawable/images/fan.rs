@@ -0,0 +1,60 @@
+//! The fan.png image.
+//!
+//! 32x32 pixels compressed in 689 bytes (2048 bytes uncompressed).
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use micromath::vector::U16x2;
+
+use super::super::CompressedImage;
+
+#[rustfmt::skip]
+pub static FAN: CompressedImage = CompressedImage {
+	size: U16x2 { x: 32, y: 32 },
+	data: &[
+		0xCB, 0xA4, 0x88, 0xAB, 0x88, 0xA7, 0x99, 0xA3, 0x88, 0xC0, 0x1C, 0x11, 0x28, 0x00, 0xD3, 0xA5,
+		0x99, 0x35, 0xB0, 0x88, 0xA8, 0x88, 0xC6, 0x15, 0x35, 0x37, 0x00, 0xCE, 0xA3, 0x88, 0xBA, 0x88,
+		0xB3, 0x99, 0x25, 0x94, 0x88, 0x1C, 0x95, 0x88, 0x28, 0x00, 0xC0, 0x28, 0x29, 0x1C, 0x2D, 0x25,
+		0x20, 0x1D, 0x19, 0x00, 0xCB, 0xAC, 0x88, 0x80, 0x88, 0x25, 0x1D, 0x8A, 0x88, 0x00, 0xC1, 0x6F,
+		0xAD, 0x6A, 0xA9, 0x7B, 0x9D, 0x97, 0x98, 0x97, 0x0C, 0x00, 0x01, 0x1D, 0x25, 0x9B, 0x99, 0x80,
+		0x88, 0x00, 0xC9, 0xB5, 0x88, 0x20, 0x15, 0x81, 0x99, 0x00, 0xC2, 0xAD, 0x6B, 0xBD, 0x2D, 0xA5,
+		0x79, 0xC2, 0x65, 0x91, 0xC7, 0x3D, 0x00, 0x24, 0x15, 0x20, 0x0D, 0x00, 0xC7, 0x0D, 0x25, 0x93,
+		0x99, 0xBD, 0x99, 0x00, 0xC2, 0x11, 0x2B, 0xC7, 0x09, 0x85, 0xE4, 0x0F, 0x28, 0x25, 0x0D, 0x00,
+		0xC5, 0x38, 0x20, 0x28, 0x0F, 0x00, 0xC2, 0xAA, 0x6A, 0x2B, 0xC9, 0x09, 0x00, 0x0F, 0x28, 0x20,
+		0x38, 0x00, 0xC3, 0x19, 0x89, 0x99, 0x15, 0x0F, 0x00, 0xC3, 0x83, 0x1E, 0x2B, 0xC9, 0x82, 0xF3,
+		0x00, 0xC0, 0x0F, 0x15, 0x38, 0x19, 0x00, 0xC2, 0x1D, 0x25, 0x24, 0x00, 0xC3, 0xA4, 0x79, 0x2B,
+		0xC8, 0x92, 0xB6, 0x89, 0xE5, 0x00, 0xC2, 0x24, 0x25, 0x1D, 0x00, 0xC1, 0x37, 0x20, 0x1D, 0x00,
+		0xC4, 0xA8, 0x69, 0x2B, 0xC5, 0x9D, 0xA8, 0x8B, 0xD6, 0x8E, 0xB4, 0x00, 0xC5, 0x1D, 0x20, 0x37,
+		0x00, 0xC0, 0x35, 0x25, 0x01, 0x00, 0xC4, 0xA4, 0x79, 0x2B, 0xC4, 0x8E, 0xC5, 0x85, 0xF5, 0x00,
+		0xC7, 0x01, 0x25, 0x35, 0x00, 0xC0, 0x15, 0x2D, 0x00, 0xC6, 0x83, 0x1E, 0x2B, 0xC2, 0x85, 0xF4,
+		0x00, 0xCA, 0x2D, 0x15, 0x00, 0xA4, 0x88, 0x25, 0x1C, 0x00, 0xC6, 0xA6, 0x79, 0x85, 0x0E, 0x2B,
+		0xA1, 0xB7, 0x96, 0xD3, 0x94, 0xB7, 0x8F, 0x88, 0x97, 0x99, 0xC9, 0x1C, 0x25, 0x28, 0xAB, 0x88,
+		0x25, 0x29, 0x00, 0xA6, 0x79, 0x1B, 0x50, 0xC4, 0x2C, 0x82, 0x59, 0xB0, 0xC6, 0xC2, 0x80, 0x88,
+		0x00, 0xC8, 0x29, 0x25, 0x11, 0x1C, 0x25, 0x28, 0xAD, 0x5B, 0x2B, 0x0E, 0x88, 0xD4, 0x00, 0xC3,
+		0x15, 0x8D, 0x99, 0xC4, 0xB7, 0x89, 0xAD, 0x3C, 0xAD, 0x6B, 0xC0, 0x0E, 0x8B, 0xD5, 0x00, 0xC2,
+		0x28, 0x25, 0x85, 0x99, 0x35, 0x25, 0x00, 0x38, 0x2B, 0xC1, 0x09, 0x82, 0xE3, 0x00, 0xC1, 0xBA,
+		0x88, 0xBC, 0x88, 0xC4, 0x9A, 0x3C, 0x2B, 0xC2, 0x9D, 0xA8, 0x81, 0xF4, 0x00, 0xC2, 0x25, 0x35,
+		0xC0, 0x25, 0x00, 0x38, 0x2B, 0xC2, 0x9A, 0xA7, 0x81, 0xF4, 0x00, 0xC0, 0x04, 0x1C, 0xC4, 0x9C,
+		0x2B, 0x2B, 0xC3, 0x3F, 0xFF, 0x08, 0x83, 0x00, 0xC1, 0x25, 0x35, 0x9D, 0x99, 0x25, 0x28, 0x11,
+		0x2B, 0xC4, 0x09, 0x3D, 0x15, 0x8D, 0x99, 0xC4, 0x8C, 0x3C, 0x2B, 0xC4, 0x8A, 0xD4, 0x00, 0xC0,
+		0x28, 0x25, 0x85, 0x99, 0x99, 0x88, 0x25, 0x29, 0x3D, 0x2B, 0xC6, 0x0E, 0x9F, 0xD5, 0xAB, 0xD5,
+		0xC2, 0x80, 0x88, 0x9A, 0x4A, 0x2B, 0xC4, 0x2E, 0x00, 0xC0, 0x29, 0x25, 0x11, 0x28, 0x25, 0x1C,
+		0x8B, 0x89, 0x1F, 0x2B, 0xC7, 0x32, 0xFF, 0x73, 0xAF, 0x04, 0x15, 0x00, 0xA8, 0x69, 0x2B, 0xC5,
+		0x00, 0xC0, 0x1C, 0x25, 0x28, 0x00, 0x89, 0x88, 0x9C, 0x88, 0x00, 0x09, 0x2B, 0xC8, 0x11, 0x00,
+		0xC2, 0x2B, 0xC5, 0x00, 0xC0, 0x2D, 0x15, 0x00, 0xC0, 0x35, 0x25, 0x01, 0x3D, 0x2B, 0xC7, 0x9B,
+		0xA8, 0x00, 0xC2, 0x1B, 0x2B, 0xC4, 0x0E, 0x00, 0x01, 0x25, 0x35, 0x00, 0xC0, 0xA5, 0x99, 0x20,
+		0x1D, 0x00, 0x02, 0x2B, 0xC6, 0x82, 0xF3, 0x00, 0xC2, 0x11, 0x2B, 0xC4, 0x8F, 0xD6, 0x00, 0x1D,
+		0x20, 0x37, 0x00, 0xC1, 0x1D, 0x25, 0xB9, 0x99, 0x00, 0xAD, 0x6B, 0x1A, 0x2B, 0xC2, 0x0E, 0x31,
+		0x00, 0xC3, 0xAE, 0x5A, 0x2B, 0xC3, 0x3F, 0x2C, 0x24, 0x25, 0x1D, 0x00, 0xC2, 0x19, 0x89, 0x99,
+		0x15, 0x0F, 0x00, 0xC0, 0xA9, 0x7A, 0x31, 0xA4, 0x79, 0x0C, 0x94, 0xA6, 0x00, 0xC4, 0x1D, 0x2B,
+		0xC2, 0x1F, 0x20, 0x0F, 0x15, 0x38, 0x19, 0x00, 0xC3, 0xAC, 0x88, 0x84, 0x88, 0x94, 0x88, 0x0F,
+		0x00, 0xCA, 0xB7, 0x4D, 0x2B, 0xC1, 0x3F, 0xFF, 0x19, 0x26, 0x0F, 0x28, 0xAC, 0x88, 0xBC, 0x88,
+		0x00, 0xC5, 0x0D, 0x25, 0x28, 0x0F, 0x00, 0xC9, 0xB2, 0x4B, 0x2B, 0xC0, 0x09, 0x2C, 0x0F, 0x28,
+		0x25, 0x0D, 0x00, 0xC7, 0x0D, 0x20, 0x15, 0x24, 0x00, 0xC8, 0x0C, 0xB3, 0x5B, 0x98, 0x97, 0x00,
+		0x24, 0x15, 0x20, 0x0D, 0x00, 0xC9, 0x38, 0x80, 0x88, 0x25, 0x8C, 0x88, 0x01, 0x00, 0xC8, 0x01,
+		0x1D, 0x25, 0x38, 0x80, 0x88, 0x00, 0xCB, 0x19, 0x1D, 0x20, 0x25, 0x2D, 0x1C, 0x29, 0x99, 0x99,
+		0x00, 0xC0, 0x28, 0x29, 0x1C, 0x2D, 0x25, 0x20, 0x1D, 0x19, 0x00, 0xCE, 0x37, 0x35, 0x15, 0x25,
+		0xC6, 0x15, 0x35, 0x37, 0x00, 0xD3, 0x28, 0xAB, 0x88, 0x1C, 0x35, 0xC0, 0x1C, 0x11, 0x28, 0x00,
+		0xCA,
+	],
+};
@@ -0,0 +1,43 @@
+//! The heater.png image.
+//!
+//! 32x32 pixels compressed in 420 bytes (2048 bytes uncompressed).
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use micromath::vector::U16x2;
+
+use super::super::CompressedImage;
+
+#[rustfmt::skip]
+pub static HEATER: CompressedImage = CompressedImage {
+	size: U16x2 { x: 32, y: 32 },
+	data: &[
+		0xFE, 0xE9, 0xA3, 0xA7, 0xFF, 0xF4, 0x40, 0x7E, 0xFF, 0x71, 0xE0, 0x00, 0xC1, 0x18, 0x04, 0x0C,
+		0x35, 0x00, 0xC1, 0x18, 0x04, 0x0C, 0x35, 0x00, 0xCC, 0xA7, 0xB5, 0x0C, 0xC0, 0xFF, 0x92, 0x80,
+		0x00, 0xC1, 0x35, 0x0C, 0xC0, 0x1A, 0x00, 0xC1, 0x35, 0x0C, 0xC0, 0x1A, 0x00, 0xCC, 0xAF, 0xF1,
+		0x0C, 0xC0, 0xFF, 0x59, 0xA0, 0x00, 0xC1, 0x35, 0x0C, 0xC0, 0x22, 0x00, 0xC1, 0x35, 0x0C, 0xC0,
+		0x22, 0x00, 0xCB, 0x7E, 0xFF, 0xE3, 0xE0, 0x0C, 0x9B, 0x7B, 0x08, 0x00, 0xC0, 0x08, 0x2F, 0x0C,
+		0x27, 0x08, 0x00, 0xC0, 0x08, 0x2F, 0x0C, 0x27, 0x08, 0x00, 0xCB, 0xFF, 0xA2, 0xC0, 0x0C, 0x04,
+		0xFF, 0x39, 0x00, 0x00, 0xC1, 0x2A, 0x0C, 0x04, 0x3D, 0x00, 0xC1, 0x2A, 0x0C, 0x04, 0x3D, 0x00,
+		0xCB, 0xAD, 0xE2, 0x0C, 0xC0, 0x25, 0x00, 0xC1, 0x25, 0x0C, 0xC0, 0x25, 0x00, 0xC1, 0x25, 0x0C,
+		0xC0, 0x25, 0x00, 0xCB, 0x3D, 0x04, 0x0C, 0x2A, 0x00, 0xC1, 0x3D, 0x04, 0x0C, 0x2A, 0x00, 0xC1,
+		0x3D, 0x04, 0x0C, 0x2A, 0x00, 0xCB, 0x08, 0x27, 0x0C, 0x2F, 0x08, 0x00, 0xC0, 0x08, 0x27, 0x0C,
+		0x2F, 0x08, 0x00, 0xC0, 0x08, 0x27, 0x0C, 0x2F, 0x08, 0x00, 0xCB, 0x22, 0x0C, 0xC0, 0x35, 0x00,
+		0xC1, 0x22, 0x0C, 0xC0, 0x35, 0x00, 0xC1, 0x22, 0x0C, 0xC0, 0x35, 0x00, 0xCC, 0x1A, 0x0C, 0xC0,
+		0xFF, 0x30, 0xE0, 0x00, 0xC1, 0x1A, 0x0C, 0xC0, 0x35, 0x00, 0xC1, 0x1A, 0x0C, 0xC0, 0x35, 0x00,
+		0xCC, 0xB0, 0xF0, 0x0C, 0xC0, 0xFF, 0x49, 0x40, 0x00, 0xC1, 0x3D, 0x0C, 0xC0, 0x0D, 0x00, 0xC1,
+		0x3D, 0x0C, 0xC0, 0x0D, 0x00, 0xCC, 0xA6, 0xA5, 0x0C, 0xC0, 0x94, 0x4E, 0x00, 0xC1, 0x2D, 0x0C,
+		0xC0, 0x32, 0x00, 0xC1, 0x2D, 0x0C, 0xC0, 0x32, 0x00, 0xCD, 0xFF, 0x8A, 0x60, 0x0C, 0xC0, 0xFF,
+		0x41, 0x20, 0x00, 0xC1, 0x12, 0x0C, 0xC0, 0x05, 0x00, 0xC1, 0x12, 0x0C, 0xC0, 0x05, 0x00, 0xCC,
+		0x08, 0xFF, 0xC3, 0x60, 0x0C, 0x9D, 0x7A, 0x18, 0x00, 0xC0, 0x08, 0x0F, 0x0C, 0x34, 0x18, 0x00,
+		0xC0, 0x08, 0x0F, 0x0C, 0x34, 0x18, 0x00, 0xCC, 0x18, 0x34, 0x0C, 0x0F, 0x18, 0x00, 0xC0, 0x18,
+		0x34, 0x0C, 0x0F, 0x18, 0x00, 0xC0, 0x18, 0x34, 0x0C, 0x0F, 0x18, 0x00, 0xCC, 0x05, 0x0C, 0xC0,
+		0x97, 0x5D, 0x00, 0xC1, 0x05, 0x0C, 0xC0, 0x07, 0x00, 0xC1, 0x05, 0x0C, 0xC0, 0x07, 0x00, 0xCD,
+		0x07, 0x0C, 0x07, 0x00, 0xC2, 0x07, 0x0C, 0x07, 0x00, 0xC2, 0x07, 0x0C, 0x07, 0x00, 0xFE, 0xFE,
+		0xC8, 0xAA, 0x88, 0xB8, 0x88, 0xA3, 0x88, 0xD6, 0x14, 0x24, 0x00, 0xC2, 0x14, 0x2D, 0x9C, 0xC8,
+		0xFF, 0xBA, 0xEA, 0xD4, 0x11, 0x2D, 0x14, 0x00, 0xC2, 0x2D, 0xC0, 0xFF, 0xD9, 0xA4, 0x9D, 0xB8,
+		0xD4, 0x2E, 0x2D, 0xC0, 0x00, 0xC2, 0x2D, 0xC0, 0x2E, 0x14, 0xD4, 0x2E, 0x2D, 0xC0, 0x00, 0xC2,
+		0x82, 0x88, 0x2D, 0x11, 0x3E, 0xD4, 0x11, 0x2D, 0x14, 0x00, 0xC2, 0x24, 0x14, 0x2D, 0xD6, 0x14,
+		0x24, 0x00, 0xFE, 0xC1,
+	],
+};
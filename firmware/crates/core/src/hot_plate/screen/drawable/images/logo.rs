@@ -0,0 +1,167 @@
+//! The logo.png image.
+//!
+//! 197x40 pixels compressed in 2414 bytes (15760 bytes uncompressed).
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use micromath::vector::U16x2;
+
+use super::super::CompressedImage;
+
+#[rustfmt::skip]
+pub static LOGO: CompressedImage = CompressedImage {
+	size: U16x2 { x: 197, y: 40 },
+	data: &[
+		0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xF4,
+		0xB0, 0xF0, 0xA7, 0xB5, 0x9D, 0x7A, 0x00, 0xC3, 0x1A, 0x32, 0x9B, 0x6B, 0x00, 0xC2, 0x7E, 0xFF,
+		0xA2, 0xC0, 0x32, 0x98, 0x5C, 0x00, 0xFE, 0xFE, 0xF2, 0xFF, 0xFC, 0x60, 0xC1, 0xFF, 0x28, 0xC0,
+		0x00, 0xC1, 0xA5, 0xA6, 0x0C, 0xC1, 0x00, 0xC2, 0x2D, 0x0C, 0xC0, 0x9D, 0x7A, 0x00, 0xFE, 0xFE,
+		0xF1, 0xA2, 0x97, 0x0C, 0xC1, 0xFF, 0x18, 0x80, 0x00, 0xC1, 0xA7, 0xB5, 0x0C, 0xC0, 0x56, 0x00,
+		0xC2, 0xAB, 0xC3, 0x0C, 0xC0, 0x9A, 0x6B, 0x00, 0xFE, 0xFE, 0xF1, 0xAD, 0xD2, 0x0C, 0xC0, 0x99,
+		0x6C, 0x00, 0xC2, 0xFF, 0x82, 0x20, 0x0C, 0xC0, 0x32, 0x00, 0xC2, 0xFF, 0x9A, 0xA0, 0x0C, 0xC0,
+		0x0A, 0x00, 0xFE, 0xFE, 0xF0, 0x08, 0xFF, 0xDB, 0xC0, 0x0C, 0xC0, 0xFF, 0x41, 0x20, 0x00, 0xC1,
+		0xA3, 0xA7, 0xFF, 0xEC, 0x20, 0x0C, 0x04, 0x25, 0x00, 0xC1, 0x35, 0x04, 0x0C, 0x34, 0x18, 0x00,
+		0xFE, 0xE5, 0x2D, 0x05, 0xC2, 0x10, 0x00, 0xFE, 0xC3, 0x22, 0x0C, 0xC0, 0x0A, 0x00, 0xC2, 0xFF,
+		0xBB, 0x40, 0x0C, 0xC0, 0xFF, 0x69, 0xC0, 0x00, 0xC1, 0x08, 0x1F, 0x0C, 0xC0, 0x12, 0x00, 0xCF,
+		0xAB, 0x88, 0xAF, 0x99, 0xC2, 0x9B, 0x99, 0x00, 0xC5, 0xAA, 0x88, 0x04, 0xC2, 0x9C, 0x88, 0x00,
+		0xFE, 0xC2, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xFE, 0xC2, 0xAD, 0xE2, 0x0C, 0xC0,
+		0x96, 0x4D, 0x08, 0x00, 0xC1, 0x0A, 0x0C, 0xC0, 0x2A, 0x00, 0xC2, 0x2A, 0x0C, 0xC0, 0xFF, 0x82,
+		0x20, 0x00, 0xD0, 0xBB, 0x88, 0x84, 0x88, 0xC2, 0x96, 0x88, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2,
+		0x97, 0x99, 0x00, 0xD6, 0xA8, 0x88, 0x82, 0x77, 0xC2, 0x93, 0x99, 0x00, 0xE3, 0x2A, 0x0C, 0xC2,
+		0xFF, 0x41, 0x00, 0x98, 0x4C, 0xD4, 0xA4, 0xA6, 0xFF, 0xA2, 0xE0, 0xC2, 0x99, 0x6C, 0x00, 0xE4,
+		0xA8, 0xB4, 0xFF, 0xF4, 0x40, 0x0C, 0x9C, 0x7A, 0x18, 0x00, 0xC1, 0xAC, 0xC2, 0x0C, 0xC0, 0x99,
+		0x6C, 0x08, 0x00, 0xC1, 0xB0, 0xF0, 0x0C, 0xC0, 0x95, 0x5E, 0x00, 0xD1, 0x09, 0x31, 0xC2, 0x0D,
+		0x00, 0xC5, 0x35, 0x31, 0xC2, 0x1C, 0x00, 0xD6, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xE3,
+		0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xD4, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xE3,
+		0x08, 0x2F, 0x0C, 0xC0, 0xFF, 0x39, 0x00, 0x00, 0xC1, 0xA4, 0x96, 0x3C, 0x0C, 0x04, 0xFF, 0x20,
+		0xA0, 0x00, 0xC1, 0x35, 0x04, 0x0C, 0x34, 0x18, 0x00, 0xD1, 0x09, 0x31, 0xC2, 0x0D, 0x00, 0xC5,
+		0xB9, 0x88, 0x31, 0xC2, 0x1C, 0x00, 0xD6, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xE3, 0x2A,
+		0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xD4, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xE3, 0x05,
+		0x0C, 0xC0, 0x32, 0x00, 0xC2, 0xFF, 0x92, 0x80, 0x0C, 0xC0, 0xFF, 0x8A, 0x60, 0x00, 0xC2, 0x3F,
+		0x0C, 0xC0, 0x2D, 0x00, 0xD2, 0x09, 0x31, 0xC2, 0x0D, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2, 0x1C,
+		0x00, 0xD6, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xE3, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00,
+		0x98, 0x4C, 0xD4, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xE3, 0x17, 0x0C, 0xC0, 0xFF, 0x51, 0x80,
+		0x00, 0xC2, 0x3C, 0x0C, 0xC0, 0x3D, 0x00, 0xC1, 0x18, 0x0C, 0xC1, 0x18, 0x00, 0xD2, 0x09, 0x31,
+		0xC2, 0x0D, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2, 0x1C, 0x00, 0xC5, 0x6E, 0xB0, 0x88, 0xAE, 0x88,
+		0xA8, 0x88, 0xA3, 0x99, 0x55, 0x99, 0x99, 0x93, 0x88, 0x8E, 0x99, 0x00, 0xC4, 0xAC, 0x88, 0xA8,
+		0x88, 0xA8, 0x88, 0x83, 0x88, 0xC2, 0x93, 0x99, 0x08, 0xC2, 0x9B, 0x88, 0x00, 0xCA, 0xA7, 0xB5,
+		0xA4, 0xA6, 0xC2, 0x97, 0x5D, 0x00, 0xA3, 0xA7, 0xAC, 0xC2, 0x22, 0xA2, 0x87, 0x12, 0x96, 0x4D,
+		0x00, 0xC5, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC2, 0x1D, 0xA6, 0xB5, 0xA6, 0xA5,
+		0x12, 0x2A, 0x7E, 0x2A, 0x46, 0x3A, 0x3D, 0x00, 0xC4, 0x35, 0x15, 0x3A, 0x0C, 0xC2, 0x98, 0x6C,
+		0x15, 0xC2, 0x3D, 0x00, 0xC5, 0xA8, 0xC4, 0xA9, 0xB4, 0x22, 0x32, 0x2A, 0x0A, 0x97, 0x6D, 0x97,
+		0x4D, 0xD0, 0xFF, 0xE4, 0x00, 0x0C, 0xC0, 0x35, 0x00, 0xC2, 0x0C, 0xC1, 0xFF, 0x28, 0xC0, 0x00,
+		0xC1, 0x2D, 0x0C, 0xC1, 0x00, 0xD3, 0x09, 0x31, 0xC2, 0x96, 0x88, 0x00, 0xC5, 0xB9, 0x88, 0x31,
+		0xC2, 0x1C, 0x00, 0xC4, 0xB7, 0x88, 0x81, 0x88, 0x31, 0xC5, 0x9D, 0x99, 0x85, 0x88, 0x81, 0x99,
+		0x00, 0xC2, 0x88, 0x88, 0x31, 0xC9, 0x90, 0x88, 0x00, 0xCA, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00,
+		0xA5, 0xA6, 0x3C, 0x0C, 0xC3, 0x96, 0x5D, 0xFF, 0x18, 0x60, 0x9D, 0x7A, 0xC3, 0x2A, 0x0C, 0xC2,
+		0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC1, 0xAF, 0xE1, 0x0C, 0xC8, 0x27, 0xFF, 0x49, 0x40, 0x00, 0xC2,
+		0x2A, 0x0C, 0xC9, 0x07, 0x00, 0xC3, 0x15, 0x27, 0x0C, 0xC5, 0x27, 0xFF, 0x49, 0x60, 0x00, 0xCE,
+		0x07, 0x0C, 0xC0, 0xFF, 0x69, 0xC0, 0x00, 0xC2, 0x27, 0x0C, 0xC0, 0x12, 0x00, 0xC1, 0x7E, 0x3C,
+		0x0C, 0xC0, 0xFF, 0x28, 0xC0, 0x00, 0xD2, 0x09, 0x31, 0xC2, 0x96, 0x88, 0x00, 0xC5, 0x35, 0x31,
+		0xC2, 0x1C, 0x00, 0xC3, 0x85, 0x88, 0x31, 0xC9, 0x92, 0x88, 0x23, 0x00, 0xC1, 0x10, 0x31, 0xC9,
+		0x11, 0x00, 0xCA, 0x2A, 0x0C, 0xC2, 0x2A, 0x0C, 0xC6, 0x99, 0x7C, 0xFF, 0x08, 0x40, 0x00, 0xC2,
+		0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC1, 0x3A, 0x0C, 0xC9, 0x66, 0x15, 0x00, 0xC1,
+		0x2A, 0x0C, 0xC9, 0x97, 0x5D, 0x00, 0xC2, 0xFF, 0x8A, 0x60, 0x0C, 0xC9, 0x3A, 0x00, 0xCD, 0xAC,
+		0xC2, 0x0C, 0xC0, 0x9A, 0x6B, 0x00, 0xC2, 0xAE, 0xE1, 0x0C, 0xC0, 0x95, 0x5E, 0x00, 0xC2, 0xFF,
+		0x92, 0x80, 0x0C, 0xC0, 0x1A, 0x00, 0xD2, 0x09, 0x31, 0xC2, 0x9C, 0x88, 0x10, 0xC5, 0x25, 0x31,
+		0xC2, 0x1C, 0x00, 0xC2, 0xBC, 0x88, 0x31, 0xC3, 0x51, 0x98, 0x88, 0x09, 0x31, 0xC3, 0x8C, 0x88,
+		0x00, 0xC1, 0x10, 0x31, 0xC9, 0x11, 0x00, 0xCA, 0x2A, 0x0C, 0xC4, 0x04, 0x9D, 0x8A, 0x04, 0x0C,
+		0xC3, 0x1A, 0x00, 0xC2, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC1, 0xB0, 0xE0, 0x0C,
+		0x66, 0x17, 0x9B, 0x7B, 0x9E, 0x69, 0xC0, 0xA3, 0xB7, 0xA8, 0xB4, 0x0C, 0xC2, 0x34, 0x08, 0x00,
+		0xC0, 0x2A, 0x0C, 0xC9, 0x97, 0x5D, 0x00, 0xC1, 0x2D, 0x0C, 0xC3, 0x07, 0x1F, 0x3A, 0xAA, 0xC3,
+		0x0C, 0xC2, 0xFF, 0x49, 0x40, 0x00, 0xCD, 0x3A, 0x0C, 0xC0, 0xFF, 0x71, 0xE0, 0x00, 0xC1, 0x08,
+		0x17, 0x0C, 0xC0, 0xFF, 0x51, 0x80, 0x00, 0xC1, 0xA2, 0x97, 0x2F, 0x0C, 0x04, 0xFF, 0x30, 0xE0,
+		0x00, 0xD1, 0xBB, 0x88, 0x31, 0xCF, 0x1C, 0x00, 0xC1, 0xA4, 0x88, 0x98, 0x77, 0x31, 0xC2, 0x89,
+		0x99, 0x23, 0x00, 0x6E, 0x09, 0x31, 0xC3, 0xB1, 0x99, 0x00, 0xC0, 0xA7, 0x99, 0x38, 0xAA, 0x88,
+		0x31, 0xC2, 0x11, 0x38, 0xC2, 0x9D, 0x88, 0x00, 0xCA, 0x2A, 0x0C, 0xC3, 0x22, 0xFF, 0x10, 0x40,
+		0x00, 0x10, 0x22, 0x0C, 0xC2, 0x04, 0xFF, 0x18, 0x60, 0x00, 0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41,
+		0x00, 0x98, 0x4C, 0xC1, 0xAB, 0xD3, 0xA3, 0x97, 0x10, 0x00, 0xC3, 0x08, 0x3F, 0x0C, 0xC2, 0x15,
+		0x00, 0xC0, 0xA4, 0xA6, 0xA2, 0x97, 0xA6, 0xA5, 0x0C, 0xC2, 0x97, 0x5D, 0x30, 0xC2, 0x46, 0x00,
+		0xC0, 0x10, 0x3C, 0x0C, 0xC1, 0x04, 0x0D, 0x00, 0xC1, 0x3D, 0x04, 0x0C, 0xC1, 0x9A, 0x6B, 0x00,
+		0xCD, 0x18, 0x2F, 0x0C, 0x04, 0x3D, 0x00, 0xC1, 0x25, 0x34, 0x0C, 0x34, 0x25, 0x00, 0xC1, 0x3D,
+		0x04, 0x0C, 0x27, 0x18, 0x00, 0xD0, 0x09, 0x31, 0xCF, 0x97, 0x99, 0x00, 0xC1, 0xB9, 0x88, 0x31,
+		0xC2, 0x1C, 0x05, 0x00, 0xC2, 0x88, 0x88, 0x31, 0xC2, 0x10, 0x00, 0xC2, 0x38, 0x31, 0xC2, 0x8D,
+		0x99, 0x00, 0xCF, 0xFF, 0xA2, 0xC0, 0x0C, 0xC2, 0x17, 0x00, 0xC3, 0x1F, 0x0C, 0xC2, 0x2D, 0x00,
+		0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xCA, 0x0D, 0x0C, 0xC2, 0x12, 0x00, 0xC2,
+		0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xC5, 0xAD, 0xE2, 0x0C, 0xC2, 0xFF, 0x92, 0x80, 0x00, 0xC3,
+		0xFF, 0x92, 0xA0, 0x0C, 0xC2, 0x3D, 0x00, 0xCD, 0xA6, 0xA5, 0x04, 0x0C, 0x34, 0x18, 0x00, 0xC1,
+		0x0D, 0x0C, 0xC0, 0x9A, 0x6B, 0xFF, 0x10, 0x40, 0x00, 0xC1, 0x25, 0x0C, 0xC0, 0x3F, 0x08, 0x00,
+		0xCF, 0x09, 0x31, 0xCF, 0x1C, 0x00, 0xC1, 0x88, 0x88, 0x31, 0xC2, 0x81, 0x99, 0x80, 0x88, 0xC3,
+		0xB2, 0x88, 0x31, 0xC2, 0x98, 0x88, 0x00, 0xC2, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xCF,
+		0x2A, 0x0C, 0xC2, 0xFF, 0x72, 0x00, 0x00, 0xC3, 0xB1, 0xF0, 0x0C, 0xC2, 0x93, 0x3F, 0x00, 0xC1,
+		0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC3, 0x35, 0x02, 0x2A, 0x07, 0x7E, 0x17, 0xC0,
+		0x1F, 0x0C, 0xC2, 0x2A, 0x00, 0xC2, 0x35, 0x0C, 0xC2, 0x95, 0x5E, 0x00, 0xC5, 0x27, 0x0C, 0xC2,
+		0x12, 0x15, 0xC3, 0x22, 0x0C, 0xC2, 0x02, 0x00, 0xCE, 0xAD, 0xD2, 0x0C, 0xC0, 0x9D, 0x7A, 0xFF,
+		0x18, 0x80, 0x00, 0xC1, 0xFF, 0x82, 0x20, 0x0C, 0xC0, 0x2F, 0xFF, 0x10, 0x40, 0x00, 0xC1, 0x2A,
+		0x0C, 0xC0, 0x17, 0x00, 0xCF, 0x09, 0x31, 0xC2, 0x99, 0x99, 0xBE, 0x88, 0xC5, 0xB0, 0x88, 0x31,
+		0xC2, 0x97, 0x99, 0x00, 0xC1, 0x90, 0x77, 0x31, 0xC2, 0xB7, 0x99, 0x00, 0xC3, 0x0B, 0x31, 0xC2,
+		0x66, 0x00, 0xC2, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xCF, 0x2A, 0x0C, 0xC2, 0x0D, 0x00,
+		0xC3, 0x15, 0x0C, 0xC2, 0x96, 0x5D, 0x00, 0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C,
+		0xC1, 0xA5, 0xA6, 0x0F, 0x0C, 0xCA, 0x95, 0x4E, 0x00, 0xC2, 0x35, 0x0C, 0xC2, 0x3A, 0x00, 0xC5,
+		0x07, 0x0C, 0xCD, 0x92, 0x3F, 0x00, 0xCF, 0x17, 0x0C, 0xC0, 0xFF, 0x51, 0x80, 0x00, 0xC1, 0x08,
+		0x2F, 0x0C, 0xC0, 0x2D, 0x00, 0xC1, 0x10, 0x0C, 0xC1, 0x1D, 0x00, 0xCE, 0x09, 0x31, 0xC2, 0x96,
+		0x88, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2, 0x97, 0x99, 0x00, 0xC1, 0x92, 0x77, 0x31, 0xC2, 0xB5,
+		0x99, 0x00, 0xC3, 0xA5, 0x88, 0x31, 0xC3, 0xA2, 0x99, 0x00, 0xC1, 0xAC, 0x88, 0x31, 0xC2, 0x8D,
+		0x99, 0x00, 0xCF, 0xFF, 0xA2, 0xC0, 0x0C, 0xC2, 0xFF, 0x41, 0x20, 0x00, 0xC3, 0xAA, 0xC3, 0x0C,
+		0xC2, 0x98, 0x5C, 0x00, 0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0x10, 0x2F,
+		0x0C, 0xCB, 0x3A, 0x00, 0xC2, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xC5, 0x0F, 0x0C, 0xCD, 0x2A,
+		0x00, 0xCF, 0x05, 0xA8, 0xC4, 0xC0, 0x10, 0x00, 0xC2, 0xAB, 0xC3, 0x05, 0x56, 0x00, 0xC3, 0xAD,
+		0xE2, 0x05, 0x9D, 0x7A, 0x00, 0xCF, 0x09, 0x31, 0xC2, 0x96, 0x88, 0x00, 0xC5, 0xB9, 0x88, 0x31,
+		0xC2, 0x1C, 0x00, 0xC1, 0x8E, 0x88, 0x31, 0xC2, 0xB9, 0x99, 0x00, 0xC3, 0xA9, 0x88, 0x31, 0xC2,
+		0x51, 0x00, 0xC2, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xCF, 0x2A, 0xAD, 0xD2, 0xC2, 0xFF,
+		0x51, 0x60, 0x00, 0xC3, 0xAC, 0xD2, 0x0C, 0xC2, 0x3A, 0x00, 0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41,
+		0x00, 0x98, 0x4C, 0xC0, 0x3D, 0x0C, 0xC2, 0x2F, 0xFF, 0x61, 0xC0, 0x98, 0x5C, 0x42, 0xC0, 0x15,
+		0x0C, 0xC2, 0x3A, 0x00, 0xC2, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xC5, 0x02, 0x0C, 0xC2, 0x97,
+		0x6D, 0x3A, 0xC8, 0x97, 0x5D, 0x00, 0xF3, 0x09, 0x31, 0xC2, 0x0D, 0x00, 0xC5, 0xB9, 0x88, 0x31,
+		0xC2, 0x1C, 0x00, 0xC1, 0x85, 0x88, 0x31, 0xC2, 0x2D, 0x00, 0xC3, 0xB6, 0x88, 0x31, 0xC2, 0x95,
+		0x88, 0x00, 0xC2, 0xAC, 0x88, 0x31, 0xC2, 0x8D, 0x99, 0x00, 0xCF, 0xFF, 0xA2, 0xC0, 0x0C, 0xC2,
+		0xFF, 0x8A, 0x60, 0x00, 0xC3, 0xFF, 0x8A, 0x80, 0x0C, 0xC2, 0xFF, 0x92, 0x80, 0x00, 0xC1, 0x2A,
+		0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0x3A, 0x0C, 0xC2, 0xFF, 0x49, 0x60, 0x00, 0xC2,
+		0xAC, 0xD2, 0x0C, 0xC2, 0x3A, 0x00, 0xC2, 0xA7, 0xB5, 0x0C, 0xC2, 0x3A, 0x00, 0xC5, 0x1A, 0x0C,
+		0xC2, 0xFF, 0x59, 0xA0, 0x00, 0xFE, 0x09, 0x31, 0xC2, 0x0D, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2,
+		0x97, 0x99, 0x00, 0xC1, 0xB3, 0x88, 0x31, 0xC2, 0x9C, 0x88, 0xAB, 0x99, 0x00, 0xC1, 0x6E, 0x90,
+		0x88, 0x31, 0xC2, 0x83, 0x99, 0x00, 0xC2, 0xA9, 0x99, 0x31, 0xC2, 0x0D, 0x00, 0xCF, 0x2A, 0x0C,
+		0xC2, 0x34, 0xFF, 0x10, 0x60, 0x00, 0xC1, 0x18, 0xFF, 0xEC, 0x00, 0x0C, 0xC2, 0xFF, 0x51, 0x60,
+		0x00, 0xC1, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0x0F, 0x0C, 0xC2, 0xFF, 0x30,
+		0xE0, 0x00, 0xC2, 0x02, 0x0C, 0xC2, 0x3A, 0x00, 0xC2, 0xA5, 0xB6, 0x0C, 0xC2, 0x9A, 0x6B, 0x00,
+		0xC5, 0xAA, 0xC3, 0x0C, 0xC2, 0x0F, 0x00, 0xC6, 0x10, 0xAD, 0xE2, 0x00, 0xF4, 0xBB, 0x88, 0x31,
+		0xC2, 0x96, 0x88, 0x00, 0xC5, 0xB9, 0x88, 0x31, 0xC2, 0x1C, 0x00, 0xC1, 0x05, 0x1C, 0x31, 0xC2,
+		0x0D, 0x39, 0x95, 0x99, 0xA7, 0x88, 0x38, 0x31, 0xC2, 0x55, 0x10, 0x00, 0xC2, 0xA2, 0x88, 0x2C,
+		0x31, 0xC2, 0x8F, 0x88, 0x93, 0x99, 0x51, 0xC0, 0x8B, 0x99, 0x00, 0xCA, 0x2A, 0x0C, 0xC3, 0x1F,
+		0x15, 0x9B, 0x7B, 0x15, 0x1F, 0x0C, 0xC2, 0x34, 0xFF, 0x08, 0x20, 0x00, 0xC1, 0x2A, 0x0C, 0xC2,
+		0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0x32, 0x0C, 0xC2, 0x3A, 0xFF, 0x10, 0x40, 0x00, 0x10, 0xFF,
+		0x92, 0xA0, 0x0C, 0xC3, 0x3A, 0x00, 0xC2, 0x08, 0xFF, 0xFC, 0x40, 0x0C, 0xC2, 0x3F, 0x99, 0x6C,
+		0x56, 0xC0, 0x28, 0x00, 0xC1, 0xFF, 0xD3, 0xA0, 0x0C, 0xC2, 0x3A, 0xFF, 0x28, 0xC0, 0x00, 0xC1,
+		0x18, 0x15, 0x2A, 0xAB, 0xD3, 0x34, 0x00, 0xF4, 0x09, 0x84, 0x88, 0xC2, 0x0D, 0x00, 0xC5, 0x35,
+		0x31, 0xC2, 0x1C, 0x00, 0xC2, 0xB0, 0x88, 0x22, 0x31, 0xCA, 0xBF, 0x99, 0x00, 0xC4, 0x8F, 0x88,
+		0x31, 0xC6, 0xB5, 0x99, 0x00, 0xCA, 0x2A, 0x0C, 0xC2, 0x04, 0x0C, 0xC7, 0xFF, 0x61, 0xC0, 0x00,
+		0xC2, 0xFF, 0xA2, 0xC0, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0x1D, 0x0C, 0xC3, 0x04,
+		0x9D, 0x7A, 0x04, 0x0C, 0x04, 0x0C, 0xC2, 0x3A, 0x00, 0xC3, 0xFF, 0xBB, 0x40, 0x0C, 0xC6, 0x15,
+		0x00, 0xC1, 0xA8, 0xB4, 0x04, 0x0C, 0xC3, 0x37, 0x42, 0x37, 0x0C, 0xC2, 0x34, 0x00, 0xC8, 0xA3,
+		0x88, 0xB9, 0x99, 0xA9, 0x88, 0xDA, 0x18, 0x19, 0x00, 0xCA, 0x09, 0x31, 0xC2, 0x0D, 0x00, 0xC5,
+		0x35, 0x31, 0xC2, 0x1C, 0x00, 0xC3, 0x08, 0x88, 0x88, 0x31, 0xC8, 0x05, 0x00, 0xC5, 0xAE, 0x88,
+		0x8F, 0x88, 0x31, 0xC5, 0x08, 0x00, 0xCA, 0x2A, 0xAD, 0xD2, 0xC2, 0xFF, 0x71, 0xE0, 0x37, 0x0C,
+		0xC5, 0x92, 0x3F, 0x00, 0xC3, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC1, 0xFF, 0xBB,
+		0x20, 0x0C, 0xC5, 0x37, 0xFF, 0x69, 0xE0, 0x0C, 0xC2, 0x3A, 0x00, 0xC3, 0x3D, 0x04, 0x0C, 0xC5,
+		0x15, 0x00, 0xC2, 0xAA, 0xC3, 0x37, 0x0C, 0xC9, 0x34, 0x00, 0xC8, 0xB7, 0x88, 0x2D, 0xC0, 0x9C,
+		0xC8, 0x9B, 0xD8, 0xD6, 0x11, 0x2D, 0xC0, 0x21, 0x00, 0xCA, 0x09, 0x31, 0xC2, 0x96, 0x88, 0x00,
+		0xC5, 0xB9, 0x88, 0x31, 0xC2, 0x1C, 0x00, 0xC4, 0xA8, 0x88, 0x01, 0x18, 0x31, 0xC3, 0x66, 0x90,
+		0x77, 0x81, 0x99, 0x00, 0xC7, 0xAB, 0x88, 0x01, 0xB0, 0x88, 0x1D, 0x31, 0xC2, 0x08, 0x00, 0xCA,
+		0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x28, 0x07, 0x0C, 0xC2, 0x42, 0xFF, 0x72, 0x00, 0xFF, 0x00,
+		0x00, 0xC4, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC1, 0x7E, 0xFF, 0x92, 0xA0, 0x04,
+		0x0C, 0xC2, 0x97, 0x6D, 0x28, 0x3D, 0x0C, 0xC2, 0x95, 0x5E, 0x00, 0xC4, 0xA6, 0xA5, 0x22, 0x27,
+		0x3F, 0x0C, 0xC2, 0x15, 0x00, 0xC3, 0xA3, 0xA7, 0xB0, 0xE0, 0x37, 0x0C, 0xC4, 0x66, 0x9A, 0x5B,
+		0x12, 0x94, 0x3E, 0x00, 0xC8, 0xBE, 0x88, 0xA7, 0x88, 0x9A, 0xD8, 0xFF, 0xE1, 0x63, 0x65, 0xD6,
+		0x20, 0x00, 0x2D, 0x2C, 0x82, 0x88, 0xE5, 0x7F, 0xAC, 0x77, 0xA8, 0x99, 0xA3, 0x88, 0x55, 0x99,
+		0x99, 0x94, 0x88, 0x00, 0xDE, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xC0, 0xA5, 0xA6,
+		0xA7, 0xB5, 0x7E, 0x9C, 0x7A, 0x08, 0x00, 0xD0, 0xA3, 0x97, 0xA7, 0xC5, 0xA3, 0xA7, 0x1A, 0x99,
+		0x6C, 0x00, 0xDD, 0xA6, 0xA5, 0xA5, 0xB6, 0xA2, 0x97, 0x22, 0x15, 0x35, 0x9B, 0x6B, 0x00, 0xCB,
+		0xBF, 0x88, 0xA6, 0x88, 0xFF, 0xBB, 0x0B, 0x14, 0xD8, 0x0A, 0x2D, 0x31, 0x00, 0xFE, 0xCD, 0x2A,
+		0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xFE, 0xD0, 0xBC, 0x88, 0x2D, 0x9C, 0xC8, 0xFF, 0xD9,
+		0xA4, 0x14, 0xD6, 0x2E, 0x11, 0x2D, 0x18, 0x00, 0xFE, 0xCD, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00,
+		0x98, 0x4C, 0xFE, 0xD0, 0xB2, 0x88, 0x2D, 0xDC, 0x34, 0x00, 0xFE, 0xCD, 0x2A, 0x0C, 0xC2, 0xFF,
+		0x41, 0x00, 0x98, 0x4C, 0xFE, 0xD0, 0x6E, 0x34, 0x18, 0x31, 0xD8, 0x18, 0x34, 0x05, 0x00, 0xFE,
+		0xCD, 0x2A, 0x0C, 0xC2, 0xFF, 0x41, 0x00, 0x98, 0x4C, 0xFE, 0xFE, 0xFE, 0xC0, 0x12, 0xA9, 0xD4,
+		0xC2, 0x35, 0x00, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xE4,
+	],
+};
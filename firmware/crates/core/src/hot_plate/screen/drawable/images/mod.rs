@@ -0,0 +1,26 @@
+//! The [`CompressedImage`](super::CompressedImage)s built into the firmware, generated from the PNG images in the
+//! `crates/asset-generator/images` folder with the `asset-generator` tool.
+//!
+//! They are regenerated from the `firmware` folder with:
+//! ```sh
+//! cargo run -p asset-generator --bin generate_image -- crates/asset-generator/images/logo.png LOGO > <this folder>/logo.rs
+//! cargo run -p asset-generator --bin generate_image -- crates/asset-generator/images/heater.png HEATER \
+//! 	> <this folder>/heater.rs
+//! cargo run -p asset-generator --bin generate_image -- crates/asset-generator/images/fan.png FAN > <this folder>/fan.rs
+//! cargo run -p asset-generator --bin generate_image -- crates/asset-generator/images/warning.png WARNING \
+//! 	> <this folder>/warning.rs
+//! ```
+
+mod fan;
+mod heater;
+mod logo;
+mod warning;
+
+/// A fan of 32x32 pixels, shown while the plate is cooling down.
+pub use fan::FAN;
+/// A heating plate of 32x32 pixels, shown while the plate is heated.
+pub use heater::HEATER;
+/// The logo of the hot plate, 197x40 pixels, shown in the home menu (which is the first one shown at boot).
+pub use logo::LOGO;
+/// A warning sign of 32x32 pixels, shown with the errors.
+pub use warning::WARNING;
@@ -0,0 +1,37 @@
+//! The warning.png image.
+//!
+//! 32x32 pixels compressed in 329 bytes (2048 bytes uncompressed).
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use micromath::vector::U16x2;
+
+use super::super::CompressedImage;
+
+#[rustfmt::skip]
+pub static WARNING: CompressedImage = CompressedImage {
+	size: U16x2 { x: 32, y: 32 },
+	data: &[
+		0xFE, 0xCE, 0xA7, 0x95, 0xFF, 0xE5, 0xA0, 0xC0, 0x2F, 0x00, 0xDA, 0xFF, 0xB4, 0x60, 0xAF, 0xA1,
+		0xC0, 0x31, 0x00, 0xD9, 0xAD, 0xA2, 0x17, 0xC2, 0x19, 0x00, 0xD8, 0xFF, 0xC4, 0xE0, 0x17, 0xC2,
+		0x0B, 0x00, 0xD7, 0xB1, 0xA0, 0x17, 0xC4, 0x33, 0x00, 0xD5, 0x7E, 0xFF, 0xD5, 0x40, 0x17, 0xC4,
+		0x20, 0x08, 0x00, 0xD4, 0xFF, 0x6A, 0xA0, 0x17, 0xC6, 0x10, 0x00, 0xD3, 0x08, 0x35, 0x17, 0xC1,
+		0x35, 0xC0, 0x17, 0xC1, 0x35, 0x08, 0x00, 0xD2, 0xFF, 0x83, 0x20, 0x17, 0xC1, 0xFF, 0xA4, 0x00,
+		0x00, 0xC0, 0x1C, 0x17, 0xC1, 0x2D, 0x00, 0xD1, 0xA3, 0x97, 0xFF, 0xEE, 0x00, 0x17, 0xC1, 0x33,
+		0x00, 0xC0, 0x33, 0x17, 0xC1, 0x07, 0x15, 0x00, 0xD0, 0xFF, 0x93, 0xA0, 0x17, 0xC2, 0x33, 0x00,
+		0xC0, 0x33, 0x17, 0xC2, 0x07, 0x00, 0xCF, 0xA5, 0x96, 0xFF, 0xF6, 0x20, 0x17, 0xC2, 0x33, 0x00,
+		0xC0, 0x33, 0x17, 0xC2, 0x0F, 0x22, 0x00, 0xCE, 0xFF, 0xAC, 0x40, 0x17, 0xC3, 0x33, 0x00, 0xC0,
+		0x33, 0x17, 0xC3, 0x29, 0x00, 0xCD, 0xA8, 0x94, 0x17, 0xC4, 0x33, 0x00, 0xC0, 0x33, 0x17, 0xC4,
+		0x37, 0x00, 0xCC, 0xFF, 0xBC, 0xC0, 0x17, 0xC4, 0x33, 0x00, 0xC0, 0x33, 0x17, 0xC4, 0x03, 0x00,
+		0xCB, 0x19, 0x17, 0xC5, 0x33, 0x00, 0xC0, 0x33, 0x17, 0xC5, 0x19, 0x00, 0xCA, 0xFF, 0xCD, 0x20,
+		0x17, 0xC5, 0x33, 0x00, 0xC0, 0x33, 0x17, 0xC5, 0x18, 0x00, 0xC9, 0x33, 0x17, 0xC6, 0xFF, 0x62,
+		0x80, 0x00, 0xC0, 0x08, 0x17, 0xC6, 0x33, 0x00, 0xC7, 0x7E, 0xFF, 0xDD, 0x60, 0x17, 0xC6, 0x9C,
+		0x7A, 0xFF, 0x62, 0x80, 0xC0, 0x3A, 0x17, 0xC6, 0x28, 0xFF, 0x08, 0x20, 0x00, 0xC6, 0x10, 0x17,
+		0xD4, 0x10, 0x00, 0xC5, 0x15, 0x3A, 0x17, 0xC7, 0x20, 0xFF, 0x49, 0xC0, 0xC0, 0x20, 0x17, 0xC7,
+		0x3A, 0x15, 0x00, 0xC4, 0x2D, 0x17, 0xC8, 0x21, 0x00, 0xC0, 0x21, 0x17, 0xC8, 0x2D, 0x00, 0xC3,
+		0x22, 0xFF, 0xEE, 0x00, 0x17, 0xC8, 0x21, 0x00, 0xC0, 0x21, 0x17, 0xC8, 0x07, 0x22, 0x00, 0xC2,
+		0xFF, 0x93, 0xA0, 0x17, 0xC9, 0x20, 0x21, 0xC0, 0x20, 0x17, 0xC9, 0x07, 0x00, 0xC1, 0x2F, 0x0F,
+		0x17, 0xD8, 0x0F, 0x2F, 0x00, 0xC0, 0x29, 0x17, 0xDA, 0x29, 0x00, 0xC0, 0x17, 0xDC, 0x00, 0xC0,
+		0xFF, 0x83, 0x60, 0x17, 0xDA, 0x37, 0x00, 0xFE, 0xC0,
+	],
+};
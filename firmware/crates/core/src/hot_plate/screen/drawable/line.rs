@@ -28,8 +28,8 @@ impl Drawable for HorizontalLine {
 }
 
 pub struct VerticalLine {
-	length: u16,
-	thickness: Thickness,
+	pub length: u16,
+	pub thickness: Thickness,
 }
 
 impl Drawable for VerticalLine {
@@ -3,17 +3,22 @@ use micromath::vector::U16x2;
 use crate::utils::measurement::color::ColorRGB565;
 
 mod binary_image;
+mod compressed_image;
+pub mod images;
 mod line;
 mod plot;
 mod point;
+mod shapes;
 pub mod special;
 mod text;
 mod triangle;
 
 pub use binary_image::*;
+pub use compressed_image::*;
 pub use line::*;
 pub use plot::*;
 pub use point::*;
+pub use shapes::*;
 pub use text::*;
 pub use triangle::*;
 
@@ -0,0 +1,97 @@
+use micromath::{vector::U16x2, F32Ext};
+
+use crate::{
+	hot_plate::screen::drawable::{Axis, Drawable, Pixels, Thickness},
+	utils::measurement::color::ColorRGB565,
+};
+
+use super::{draw_run, get_circle_row_inset};
+
+/// A part of the outline of a circle that fits in a square whose side is the provided `diameter`.
+///
+/// The arc starts at `start_angle` and goes on clockwise for `sweep_angle`, both in degrees (`0°` is at the right of
+/// the center of the circle, `90°` below it).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Arc, Drawable},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// #
+/// // The bottom half of a ring
+/// let arc = Arc {
+/// 	diameter: 40,
+/// 	thickness: 4,
+/// 	start_angle: 0.,
+/// 	sweep_angle: 180.,
+/// 	color: ColorRGB565::GREEN,
+/// };
+/// let mut is_bottom_half = true;
+/// arc.draw(&mut |pixels| is_bottom_half &= pixels.offset_position.y >= 20);
+/// assert!(is_bottom_half);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Arc {
+	pub diameter: u16,
+	pub thickness: Thickness,
+	pub start_angle: f32,
+	pub sweep_angle: f32,
+	pub color: ColorRGB565,
+}
+
+impl Arc {
+	/// Returns `true` if the pixel at the provided `x` and `y` is in the part of the circle covered by the arc.
+	fn contains(&self, x: u16, y: u16) -> bool {
+		let radius = self.diameter as f32 / 2.;
+		let angle = F32Ext::atan2(y as f32 + 0.5 - radius, x as f32 + 0.5 - radius).to_degrees();
+		let angle_from_start = angle - self.start_angle;
+
+		angle_from_start - 360. * F32Ext::floor(angle_from_start / 360.) <= self.sweep_angle
+	}
+}
+
+impl Drawable for Arc {
+	fn size(&self) -> U16x2 {
+		U16x2 {
+			x: self.diameter,
+			y: self.diameter,
+		}
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let thickness = self.thickness.min(self.diameter / 2);
+		let inner_diameter = self.diameter - 2 * thickness;
+
+		for y in 0..self.diameter {
+			let outer_inset = get_circle_row_inset(self.diameter, y);
+			// The pixels of the ring on the left and on the right of the inner circle (which is missing in the rows
+			// of the outline above and below it)
+			let spans = match (thickness..self.diameter - thickness).contains(&y) {
+				true => {
+					let inner_inset = thickness + get_circle_row_inset(inner_diameter, y - thickness);
+					[
+						outer_inset..inner_inset,
+						self.diameter - inner_inset..self.diameter - outer_inset,
+					]
+				},
+				false => [outer_inset..self.diameter - outer_inset, 0..0],
+			};
+
+			// The pixels next to each other covered by the arc are joined in runs
+			for span in spans {
+				let mut run_start = None;
+				for x in span.start..=span.end {
+					match (run_start, x < span.end && self.contains(x, y)) {
+						(None, true) => run_start = Some(x),
+						(Some(start), false) => {
+							draw_run(U16x2 { x: start, y }, x - start, Axis::Horizontal, self.color, draw_fn);
+							run_start = None;
+						},
+						_ => {},
+					}
+				}
+			}
+		}
+	}
+}
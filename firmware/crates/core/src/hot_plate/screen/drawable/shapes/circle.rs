@@ -0,0 +1,57 @@
+use micromath::vector::U16x2;
+
+use crate::hot_plate::screen::drawable::{Drawable, Pixels};
+
+use super::{draw_row, get_circle_row_inset, ShapeStyle};
+
+/// A circle that fits in a square whose side is the provided `diameter`, drawn row by row.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Circle, Drawable, ShapeStyle},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// #
+/// let circle = Circle {
+/// 	diameter: 5,
+/// 	style: ShapeStyle::filled(ColorRGB565::RED),
+/// };
+/// let mut rows = Vec::new();
+/// circle.draw(&mut |pixels| rows.push((pixels.offset_position.x, pixels.repetitions_count)));
+/// assert_eq!(rows, [(1, 3), (0, 5), (0, 5), (0, 5), (1, 3)]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Circle {
+	pub diameter: u16,
+	pub style: ShapeStyle,
+}
+
+impl Drawable for Circle {
+	fn size(&self) -> U16x2 {
+		U16x2 {
+			x: self.diameter,
+			y: self.diameter,
+		}
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let thickness = self.style.get_stroke_thickness().min(self.diameter / 2);
+		let inner_diameter = self.diameter - 2 * thickness;
+
+		for y in 0..self.diameter {
+			let outer_inset = get_circle_row_inset(self.diameter, y);
+			let inner = (thickness..self.diameter - thickness).contains(&y).then(|| {
+				let inset = thickness + get_circle_row_inset(inner_diameter, y - thickness);
+				(inset, self.diameter - inset)
+			});
+			draw_row(
+				y,
+				(outer_inset, self.diameter - outer_inset),
+				inner,
+				&self.style,
+				draw_fn,
+			);
+		}
+	}
+}
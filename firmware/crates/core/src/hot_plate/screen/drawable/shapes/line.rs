@@ -0,0 +1,172 @@
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::drawable::{Axis, Drawable, Pixels, Thickness},
+	utils::measurement::color::ColorRGB565,
+};
+
+use super::draw_run;
+
+/// A straight line from `start` to `end` (both included) with any angle, drawn with the Bresenham's algorithm.
+///
+/// The pixels of the line that are next to each other along its main direction (horizontal if the line is more wide
+/// than tall, vertical otherwise) are drawn as a single run. A line more than 1 pixel thick grows below (or on the
+/// right of) the points.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Drawable, Line},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let line = Line {
+/// 	start: U16x2 { x: 0, y: 0 },
+/// 	end: U16x2 { x: 9, y: 2 },
+/// 	thickness: 1,
+/// 	color: ColorRGB565::WHITE,
+/// };
+/// let mut runs = Vec::new();
+/// line.draw(&mut |pixels| runs.push((pixels.offset_position.x, pixels.offset_position.y, pixels.repetitions_count)));
+/// assert_eq!(runs, [(0, 0, 3), (3, 1, 4), (7, 2, 3)]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Line {
+	pub start: U16x2,
+	pub end: U16x2,
+	pub thickness: Thickness,
+	pub color: ColorRGB565,
+}
+
+impl Line {
+	/// Returns `true` if the line is taller than wide, so it's drawn in vertical runs.
+	fn is_steep(&self) -> bool {
+		self.start.y.abs_diff(self.end.y) > self.start.x.abs_diff(self.end.x)
+	}
+}
+
+impl Drawable for Line {
+	fn size(&self) -> U16x2 {
+		let (extra_x, extra_y) = match self.is_steep() {
+			true => (self.thickness, 1),
+			false => (1, self.thickness),
+		};
+
+		U16x2 {
+			x: self.start.x.max(self.end.x) + extra_x,
+			y: self.start.y.max(self.end.y) + extra_y,
+		}
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		// The line is drawn along its major axis (where it's longer), from the lowest coordinate on that axis
+		let is_steep = self.is_steep();
+		let to_major_minor = |point: U16x2| match is_steep {
+			true => (point.y, point.x),
+			false => (point.x, point.y),
+		};
+		let (mut start, mut end) = (to_major_minor(self.start), to_major_minor(self.end));
+		if start.0 > end.0 {
+			(start, end) = (end, start);
+		}
+
+		let major_delta = (end.0 - start.0) as i32;
+		let minor_delta = end.1.abs_diff(start.1) as i32;
+		let minor_step = match end.1 >= start.1 {
+			true => 1,
+			false => -1,
+		};
+
+		let mut error = 2 * minor_delta - major_delta;
+		let mut minor = start.1;
+		let mut run_start = start.0;
+		for major in start.0..=end.0 {
+			// A run ends when the next pixel moves on the minor axis
+			let is_moving = error > 0;
+			if is_moving || major == end.0 {
+				let length = major - run_start + 1;
+				for offset in 0..self.thickness {
+					let (position, direction) = match is_steep {
+						true => (
+							U16x2 {
+								x: minor + offset,
+								y: run_start,
+							},
+							Axis::Vertical,
+						),
+						false => (
+							U16x2 {
+								x: run_start,
+								y: minor + offset,
+							},
+							Axis::Horizontal,
+						),
+					};
+					draw_run(position, length, direction, self.color, draw_fn);
+				}
+				run_start = major + 1;
+			}
+
+			if is_moving {
+				minor = minor.saturating_add_signed(minor_step);
+				error -= 2 * major_delta;
+			}
+			error += 2 * minor_delta;
+		}
+	}
+}
+
+/// Lines that join the provided `points` one after the other (check [`Line`]).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Drawable, Polyline},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// // A check mark
+/// let polyline = Polyline {
+/// 	points: [U16x2 { x: 0, y: 10 }, U16x2 { x: 8, y: 18 }, U16x2 { x: 24, y: 2 }],
+/// 	thickness: 3,
+/// 	color: ColorRGB565::GREEN,
+/// };
+/// assert_eq!(polyline.size(), U16x2 { x: 24 + 1, y: 18 + 3 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polyline<const N: usize> {
+	pub points: [U16x2; N],
+	pub thickness: Thickness,
+	pub color: ColorRGB565,
+}
+
+impl<const N: usize> Polyline<N> {
+	fn lines(&self) -> impl Iterator<Item = Line> + '_ {
+		self.points.windows(2).map(|points| Line {
+			start: points[0],
+			end: points[1],
+			thickness: self.thickness,
+			color: self.color,
+		})
+	}
+}
+
+impl<const N: usize> Drawable for Polyline<N> {
+	fn size(&self) -> U16x2 {
+		self.lines().fold(U16x2 { x: 0, y: 0 }, |size, line| {
+			let line_size = line.size();
+			U16x2 {
+				x: size.x.max(line_size.x),
+				y: size.y.max(line_size.y),
+			}
+		})
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		for line in self.lines() {
+			line.draw(draw_fn);
+		}
+	}
+}
@@ -0,0 +1,132 @@
+use micromath::vector::U16x2;
+
+use crate::utils::measurement::color::ColorRGB565;
+
+use super::{Axis, Pixels, Thickness};
+
+mod arc;
+mod circle;
+mod line;
+mod progress_bar;
+mod rectangle;
+
+pub use arc::*;
+pub use circle::*;
+pub use line::*;
+pub use progress_bar::*;
+pub use rectangle::*;
+
+/// How a shape is drawn: its outline is drawn with the `stroke_color` and is `stroke_thickness` pixels thick (inside
+/// the bounds of the shape), while the rest of the shape is filled with the `fill_color`.
+///
+/// The parts whose color is `None` aren't drawn, so what is below them stays visible.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShapeStyle {
+	pub fill_color: Option<ColorRGB565>,
+	pub stroke_color: Option<ColorRGB565>,
+	pub stroke_thickness: Thickness,
+}
+
+impl ShapeStyle {
+	/// Returns a [`ShapeStyle`] that fills the whole shape with the provided `color`.
+	pub const fn filled(color: ColorRGB565) -> Self {
+		Self {
+			fill_color: Some(color),
+			stroke_color: None,
+			stroke_thickness: 0,
+		}
+	}
+
+	/// Returns a [`ShapeStyle`] that draws only the outline of the shape, `thickness` pixels thick with the provided
+	/// `color`.
+	pub const fn outlined(color: ColorRGB565, thickness: Thickness) -> Self {
+		Self {
+			fill_color: None,
+			stroke_color: Some(color),
+			stroke_thickness: thickness,
+		}
+	}
+
+	/// Returns the same style that also fills the inside of the outline with the provided `color`.
+	pub const fn with_fill(self, color: ColorRGB565) -> Self {
+		Self {
+			fill_color: Some(color),
+			..self
+		}
+	}
+
+	/// Returns how thick the outline is (which is `0` if it isn't drawn).
+	const fn get_stroke_thickness(&self) -> Thickness {
+		match self.stroke_color {
+			Some(_) => self.stroke_thickness,
+			None => 0,
+		}
+	}
+}
+
+/// Draws a run of `length` pixels of the provided `color` starting from the `position`, if it isn't empty.
+fn draw_run(position: U16x2, length: u16, direction: Axis, color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels)) {
+	if length > 0 {
+		(draw_fn)(Pixels {
+			offset_position: position,
+			repetitions_count: length,
+			repetitions_direction: direction,
+			color,
+		});
+	}
+}
+
+/// Fills a rectangle with runs along its longest side, so that it's drawn with as few runs as possible.
+fn fill_rect(position: U16x2, size: U16x2, color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels)) {
+	if size.x >= size.y {
+		for y in 0..size.y {
+			draw_run(position + U16x2 { x: 0, y }, size.x, Axis::Horizontal, color, draw_fn);
+		}
+	} else {
+		for x in 0..size.x {
+			draw_run(position + U16x2 { x, y: 0 }, size.y, Axis::Vertical, color, draw_fn);
+		}
+	}
+}
+
+/// Draws the row `y` of a shape that covers the pixels from `outer.0` to `outer.1` (excluded), filling the ones from
+/// `inner.0` to `inner.1` and drawing the others with the stroke (or everything with the stroke if `inner` is `None`).
+fn draw_row(
+	y: u16, outer: (u16, u16), inner: Option<(u16, u16)>, style: &ShapeStyle, draw_fn: &mut impl FnMut(Pixels),
+) {
+	let mut draw_span = |(start, end): (u16, u16), color: Option<ColorRGB565>| {
+		if let Some(color) = color {
+			draw_run(
+				U16x2 { x: start, y },
+				end.saturating_sub(start),
+				Axis::Horizontal,
+				color,
+				draw_fn,
+			);
+		}
+	};
+
+	match inner.filter(|(start, end)| start < end) {
+		Some(inner) => {
+			draw_span((outer.0, inner.0), style.stroke_color);
+			draw_span(inner, style.fill_color);
+			draw_span((inner.1, outer.1), style.stroke_color);
+		},
+		None => draw_span(outer, style.stroke_color.or(style.fill_color)),
+	}
+}
+
+/// Returns how many pixels the row `y` of a circle of the provided `diameter` is far from the left (and the right) of
+/// the square around the circle.
+///
+/// A pixel is inside the circle if its center is.
+fn get_circle_row_inset(diameter: u16, y: u16) -> u16 {
+	let (diameter, y) = (diameter as u32, y as u32);
+	// The distances are in halves of a pixel, so that the centers of the pixels have integer coordinates
+	let distance_from_center = (2 * y + 1).abs_diff(diameter);
+	let half_width = (diameter * diameter)
+		.saturating_sub(distance_from_center * distance_from_center)
+		.isqrt();
+
+	(diameter.saturating_sub(half_width + 1).div_ceil(2)) as u16
+}
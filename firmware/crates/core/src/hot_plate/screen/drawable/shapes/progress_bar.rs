@@ -0,0 +1,91 @@
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::drawable::{Drawable, Pixels, Thickness},
+	utils::{math::Percentage, measurement::color::ColorRGB565},
+};
+
+use super::{fill_rect, Rectangle, ShapeStyle};
+
+/// A horizontal bar of the provided `size` inside a border, filled from the left with the `bar_color` for the
+/// `progress` of its width, and with the `background_color` for the rest.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Drawable, ProgressBar},
+/// # 	utils::{math::Percentage, measurement::color::ColorRGB565},
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let progress_bar = ProgressBar {
+/// 	size: U16x2 { x: 104, y: 12 },
+/// 	progress: Percentage::from_0_to_100(25.).unwrap(),
+/// 	bar_color: ColorRGB565::GREEN,
+/// 	background_color: ColorRGB565::BLACK,
+/// 	border_color: ColorRGB565::GRAY,
+/// 	border_thickness: 2,
+/// };
+/// let mut green_pixels = 0;
+/// progress_bar.draw(&mut |pixels| {
+/// 	if pixels.color == ColorRGB565::GREEN {
+/// 		green_pixels += pixels.repetitions_count;
+/// 	}
+/// });
+/// // A quarter of the 100x8 pixels inside the border
+/// assert_eq!(green_pixels, 25 * 8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressBar {
+	pub size: U16x2,
+	pub progress: Percentage,
+	pub bar_color: ColorRGB565,
+	pub background_color: ColorRGB565,
+	pub border_color: ColorRGB565,
+	pub border_thickness: Thickness,
+}
+
+impl Drawable for ProgressBar {
+	fn size(&self) -> U16x2 {
+		self.size
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let thickness = self.border_thickness.min(self.size.x / 2).min(self.size.y / 2);
+		Rectangle {
+			size: self.size,
+			style: ShapeStyle::outlined(self.border_color, thickness),
+		}
+		.draw(draw_fn);
+
+		let inner_size = U16x2 {
+			x: self.size.x - 2 * thickness,
+			y: self.size.y - 2 * thickness,
+		};
+		let bar_width = ((inner_size.x as f32 * self.progress.into_0_to_1() + 0.5) as u16).min(inner_size.x);
+		fill_rect(
+			U16x2 {
+				x: thickness,
+				y: thickness,
+			},
+			U16x2 {
+				x: bar_width,
+				y: inner_size.y,
+			},
+			self.bar_color,
+			draw_fn,
+		);
+		fill_rect(
+			U16x2 {
+				x: thickness + bar_width,
+				y: thickness,
+			},
+			U16x2 {
+				x: inner_size.x - bar_width,
+				y: inner_size.y,
+			},
+			self.background_color,
+			draw_fn,
+		);
+	}
+}
@@ -0,0 +1,232 @@
+use micromath::vector::U16x2;
+
+use crate::hot_plate::screen::drawable::{Drawable, Pixels};
+
+use super::{draw_row, fill_rect, get_circle_row_inset, ShapeStyle};
+
+/// A rectangle of the provided `size`.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Drawable, Rectangle, ShapeStyle},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let rectangle = Rectangle {
+/// 	size: U16x2 { x: 100, y: 40 },
+/// 	style: ShapeStyle::outlined(ColorRGB565::GRAY, 2).with_fill(ColorRGB565::BLACK),
+/// };
+/// let mut drawn_pixels = 0;
+/// let mut runs = 0;
+/// rectangle.draw(&mut |pixels| {
+/// 	drawn_pixels += pixels.repetitions_count as u32;
+/// 	runs += 1;
+/// });
+/// assert_eq!(drawn_pixels, 100 * 40);
+/// // The pixels are drawn in long runs: 2 rows on the top and bottom sides, 2 columns on the left and right sides and
+/// // 36 rows inside
+/// assert_eq!(runs, 2 * 2 + 2 * 2 + 36);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rectangle {
+	pub size: U16x2,
+	pub style: ShapeStyle,
+}
+
+impl Drawable for Rectangle {
+	fn size(&self) -> U16x2 {
+		self.size
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let size = self.size;
+		let thickness = self.style.get_stroke_thickness().min(size.x / 2).min(size.y / 2);
+		let inner_size = U16x2 {
+			x: size.x - 2 * thickness,
+			y: size.y - 2 * thickness,
+		};
+
+		if let Some(color) = self.style.stroke_color {
+			let horizontal_side = U16x2 {
+				x: size.x,
+				y: thickness,
+			};
+			let vertical_side = U16x2 {
+				x: thickness,
+				y: inner_size.y,
+			};
+			fill_rect(U16x2 { x: 0, y: 0 }, horizontal_side, color, draw_fn);
+			fill_rect(
+				U16x2 {
+					x: 0,
+					y: size.y - thickness,
+				},
+				horizontal_side,
+				color,
+				draw_fn,
+			);
+			fill_rect(U16x2 { x: 0, y: thickness }, vertical_side, color, draw_fn);
+			fill_rect(
+				U16x2 {
+					x: size.x - thickness,
+					y: thickness,
+				},
+				vertical_side,
+				color,
+				draw_fn,
+			);
+		}
+		if let Some(color) = self.style.fill_color {
+			fill_rect(
+				U16x2 {
+					x: thickness,
+					y: thickness,
+				},
+				inner_size,
+				color,
+				draw_fn,
+			);
+		}
+	}
+}
+
+/// A rectangle of the provided `size` whose corners are quarters of a circle with the provided `corner_radius` (which
+/// is at most half of the shortest side).
+///
+/// Only the rows of the corners are drawn one by one, while the part between them is drawn in long runs like a
+/// [`Rectangle`].
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::{Drawable, RoundedRectangle, ShapeStyle},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let rectangle = RoundedRectangle {
+/// 	size: U16x2 { x: 20, y: 10 },
+/// 	corner_radius: 4,
+/// 	style: ShapeStyle::filled(ColorRGB565::BLUE),
+/// };
+/// let mut first_row = None;
+/// rectangle.draw(&mut |pixels| {
+/// 	if pixels.offset_position.y == 0 {
+/// 		first_row = Some((pixels.offset_position.x, pixels.repetitions_count));
+/// 	}
+/// });
+/// // The corners are cut
+/// assert_eq!(first_row, Some((2, 16)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RoundedRectangle {
+	pub size: U16x2,
+	pub corner_radius: u16,
+	pub style: ShapeStyle,
+}
+
+impl RoundedRectangle {
+	/// Returns the first pixel and the end of the row `y` of a rounded rectangle of the provided `size` and
+	/// `corner_radius`.
+	fn get_row_span(size: U16x2, corner_radius: u16, y: u16) -> (u16, u16) {
+		let inset = if y < corner_radius {
+			get_circle_row_inset(2 * corner_radius, y)
+		} else if y >= size.y - corner_radius {
+			get_circle_row_inset(2 * corner_radius, y + 2 * corner_radius - size.y)
+		} else {
+			0
+		};
+
+		(inset, size.x - inset)
+	}
+}
+
+impl Drawable for RoundedRectangle {
+	fn size(&self) -> U16x2 {
+		self.size
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let size = self.size;
+		let corner_radius = self.corner_radius.min(size.x / 2).min(size.y / 2);
+		let thickness = self.style.get_stroke_thickness().min(size.x / 2).min(size.y / 2);
+		let inner_size = U16x2 {
+			x: size.x - 2 * thickness,
+			y: size.y - 2 * thickness,
+		};
+		let inner_corner_radius = corner_radius.saturating_sub(thickness);
+
+		for y in (0..corner_radius).chain(size.y - corner_radius..size.y) {
+			let inner = (thickness..size.y - thickness).contains(&y).then(|| {
+				let (start, end) = Self::get_row_span(inner_size, inner_corner_radius, y - thickness);
+				(start + thickness, end + thickness)
+			});
+			draw_row(
+				y,
+				Self::get_row_span(size, corner_radius, y),
+				inner,
+				&self.style,
+				draw_fn,
+			);
+		}
+
+		// The straight part between the corners, whose top and bottom rows are part of the outline if it's thicker than
+		// the radius of the corners
+		let straight_start = corner_radius.max(thickness);
+		let straight_height = size.y - 2 * straight_start;
+		if let Some(color) = self.style.stroke_color {
+			let horizontal_side = U16x2 {
+				x: size.x,
+				y: straight_start - corner_radius,
+			};
+			let vertical_side = U16x2 {
+				x: thickness,
+				y: straight_height,
+			};
+			fill_rect(U16x2 { x: 0, y: corner_radius }, horizontal_side, color, draw_fn);
+			fill_rect(
+				U16x2 {
+					x: 0,
+					y: size.y - straight_start,
+				},
+				horizontal_side,
+				color,
+				draw_fn,
+			);
+			fill_rect(
+				U16x2 {
+					x: 0,
+					y: straight_start,
+				},
+				vertical_side,
+				color,
+				draw_fn,
+			);
+			fill_rect(
+				U16x2 {
+					x: size.x - thickness,
+					y: straight_start,
+				},
+				vertical_side,
+				color,
+				draw_fn,
+			);
+		}
+		if let Some(color) = self.style.fill_color {
+			fill_rect(
+				U16x2 {
+					x: thickness,
+					y: straight_start,
+				},
+				U16x2 {
+					x: inner_size.x,
+					y: straight_height,
+				},
+				color,
+				draw_fn,
+			);
+		}
+	}
+}
@@ -6,8 +6,8 @@ use crate::{
 };
 
 pub struct Colored<D: Drawable> {
-	draw: D,
-	color: ColorRGB565,
+	pub draw: D,
+	pub color: ColorRGB565,
 }
 
 impl<D: Drawable> Drawable for Colored<D> {
@@ -1,10 +1,10 @@
 use micromath::vector::U16x2;
 
-use crate::hot_plate::screen::drawable::{Drawable, Pixels};
+use crate::hot_plate::screen::drawable::{Axis, Drawable, Pixels};
 
 pub struct Scale<D: Drawable> {
-	draw: D,
-	scale_factor: u16,
+	pub draw: D,
+	pub scale_factor: u16,
 }
 
 impl<D: Drawable> Drawable for Scale<D> {
@@ -15,12 +15,15 @@ impl<D: Drawable> Drawable for Scale<D> {
 	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
 		self.draw.draw(&mut |mut pixels| {
 			pixels.offset_position *= self.scale_factor;
-			for y in 0..self.scale_factor {
-				for x in 0..self.scale_factor {
-					let mut copied_pixels = pixels.clone();
-					copied_pixels.offset_position += U16x2 { x, y };
-					(draw_fn)(copied_pixels)
-				}
+			pixels.repetitions_count *= self.scale_factor;
+			// Each run of pixels becomes `scale_factor` times longer, and it's repeated `scale_factor` times side by side
+			for i in 0..self.scale_factor {
+				let mut copied_pixels = pixels.clone();
+				copied_pixels.offset_position += match pixels.repetitions_direction {
+					Axis::Horizontal => U16x2 { x: 0, y: i },
+					Axis::Vertical => U16x2 { x: i, y: 0 },
+				};
+				(draw_fn)(copied_pixels)
 			}
 		});
 	}
@@ -0,0 +1,88 @@
+use core::fmt::{self, Write};
+
+/// A text of at most `N` bytes stored on the stack, which can be written using [`write!`] (so numbers, temperatures
+/// and durations can be formatted without an allocator).
+///
+/// The text that doesn't fit is discarded (without splitting a character), and writing it returns an error so that
+/// the rest of the formatted text is discarded too.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::drawable::TextBuffer,
+/// # 	utils::measurement::temperature::Temperature,
+/// # };
+/// # use core::fmt::Write;
+/// #
+/// let mut text = TextBuffer::<16>::new("Now ");
+/// write!(text, "{:.1}", Temperature::from_celsius(238.43)).unwrap();
+/// assert_eq!(text.as_str(), "Now 238.4°C");
+///
+/// // `°` is made of 2 bytes, and only 1 is left
+/// let text = TextBuffer::<4>::from_fmt(format_args!("{}", Temperature::from_celsius(100.)));
+/// assert_eq!(text.as_str(), "100");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TextBuffer<const N: usize> {
+	bytes: [u8; N],
+	length: usize,
+}
+
+impl<const N: usize> TextBuffer<N> {
+	/// Returns a [`TextBuffer`] with the provided `text`.
+	pub fn new(text: &str) -> Self {
+		let mut self_ = Self {
+			bytes: [0; N],
+			length: 0,
+		};
+		let _ = self_.write_str(text);
+
+		self_
+	}
+
+	/// Returns a [`TextBuffer`] with the formatted `arguments` (check [`format_args!`]).
+	pub fn from_fmt(arguments: fmt::Arguments) -> Self {
+		let mut self_ = Self::new("");
+		let _ = self_.write_fmt(arguments);
+
+		self_
+	}
+
+	pub fn as_str(&self) -> &str {
+		// Only whole characters are written, so the bytes are always valid UTF-8
+		core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+	}
+
+	/// Removes all the text.
+	pub fn clear(&mut self) {
+		self.length = 0;
+	}
+}
+
+impl<const N: usize> Default for TextBuffer<N> {
+	fn default() -> Self {
+		Self::new("")
+	}
+}
+
+impl<const N: usize> Write for TextBuffer<N> {
+	fn write_str(&mut self, text: &str) -> fmt::Result {
+		for char in text.chars() {
+			let end = self.length + char.len_utf8();
+			if end > N {
+				return Err(fmt::Error);
+			}
+
+			char.encode_utf8(&mut self.bytes[self.length..end]);
+			self.length = end;
+		}
+
+		Ok(())
+	}
+}
+
+impl<const N: usize> fmt::Debug for TextBuffer<N> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_str(), f)
+	}
+}
@@ -0,0 +1,154 @@
+use micromath::vector::U16x2;
+
+use crate::{hot_plate::screen::drawable::Pixels, utils::measurement::color::ColorRGB565};
+
+use super::{super::Char, draw_cell, Font};
+
+/// How many bits of a [`BitmapFont`]'s atlas store how much of a pixel is covered by a glyph.
+///
+/// With more than 1 bit per pixel the glyphs are anti-aliased: the pixels on their edges are drawn with a blend of the
+/// color of the text and the one of the background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BitsPerPixel {
+	One = 1,
+	Two = 2,
+	Four = 4,
+}
+
+impl BitsPerPixel {
+	/// Returns the coverage of a pixel completely covered by a glyph (the one of an empty pixel is `0`).
+	pub const fn get_max_coverage(self) -> u8 {
+		(1 << self as u8) - 1
+	}
+}
+
+/// Where the pixels of a character of a [`BitmapFont`] are in its atlas, and where they are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Glyph {
+	pub char: Char,
+	/// The index of the byte of the atlas where the pixels of the glyph start.
+	pub offset: u32,
+	/// The width in pixels of the image of the glyph.
+	pub width: u8,
+	/// The height in pixels of the image of the glyph.
+	pub height: u8,
+	/// The horizontal distance from the start of the character to the left of the image of the glyph.
+	pub left: u8,
+	/// The vertical distance from the top of the line to the top of the image of the glyph.
+	pub top: u8,
+	/// The horizontal distance between the start of this character and the start of the next one.
+	pub advance: u8,
+}
+
+/// A proportional [`Font`] whose glyphs are images stored in an atlas, usually generated from a TTF or a BDF font with
+/// the `asset-generator` tool (check the [`fonts`](super::fonts) built into the firmware).
+///
+/// The atlas contains the images of the glyphs one after the other, each starting at the beginning of a byte. The
+/// image of a glyph contains the coverage of its pixels row by row, each one stored in [`bits_per_pixel`] bits
+/// starting from the most significant bits of a byte.
+///
+/// Like for [`SmallFont`](super::SmallFont), each byte of a string is a character: the ones the font doesn't have are
+/// drawn as the [`replacement`] character if they are printable ASCII characters, and they are skipped otherwise. So a
+/// font can have the degree sign as the character `0xB0` (the last byte of `°`).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::drawable::{fonts, Drawable, Font, Text};
+/// #
+/// let font = &fonts::SANS_16;
+/// // The font is proportional
+/// assert!(font.get_width_of_char(b'i') < font.get_width_of_char(b'W'));
+///
+/// let text = Text::<8, _>::from_str("25.0°C", font);
+/// assert_eq!(text.size().y, font.get_line_height());
+/// ```
+///
+/// [`bits_per_pixel`]: `Self::bits_per_pixel`
+/// [`replacement`]: `Self::replacement`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BitmapFont {
+	pub line_height: u16,
+	/// The vertical distance from the top of the line to the baseline.
+	pub baseline: u16,
+	pub bits_per_pixel: BitsPerPixel,
+	/// The glyphs of the characters of the font, sorted by character.
+	pub glyphs: &'static [Glyph],
+	pub atlas: &'static [u8],
+	/// The character drawn in place of the printable ASCII characters the font doesn't have.
+	pub replacement: Char,
+}
+
+impl BitmapFont {
+	/// Returns the glyph of the provided `char`, or `None` if the font doesn't have it.
+	pub fn get_glyph(&self, char: Char) -> Option<&Glyph> {
+		self.glyphs
+			.binary_search_by_key(&char, |glyph| glyph.char)
+			.ok()
+			.map(|index| &self.glyphs[index])
+	}
+
+	/// Returns the glyph drawn for the provided `char` (which is the one of the [`replacement`] character for the
+	/// printable ASCII characters the font doesn't have), or `None` if the character isn't drawn.
+	///
+	/// [`replacement`]: `Self::replacement`
+	fn get_drawn_glyph(&self, char: Char) -> Option<&Glyph> {
+		self.get_glyph(char).or_else(|| match char.is_ascii_graphic() {
+			true => self.get_glyph(self.replacement),
+			false => None,
+		})
+	}
+
+	/// Returns how much the pixel at the provided `x` and `y` of the image of the `glyph` is covered by it, from `0` to
+	/// [`BitsPerPixel::get_max_coverage`].
+	pub fn get_coverage(&self, glyph: &Glyph, x: u8, y: u8) -> u8 {
+		let bits_per_pixel = self.bits_per_pixel as usize;
+		let bit = (y as usize * glyph.width as usize + x as usize) * bits_per_pixel;
+		let byte = self.atlas[glyph.offset as usize + bit / 8];
+
+		(byte >> (8 - bits_per_pixel - bit % 8)) & self.bits_per_pixel.get_max_coverage()
+	}
+}
+
+impl Font for BitmapFont {
+	fn get_line_height(&self) -> u16 {
+		self.line_height
+	}
+
+	fn get_width_of_char(&self, char: Char) -> u16 {
+		self.get_drawn_glyph(char)
+			.map(|glyph| glyph.advance as u16)
+			.unwrap_or(0)
+	}
+
+	fn draw_char(
+		&self, char: Char, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels),
+	) {
+		let Some(glyph) = self.get_drawn_glyph(char) else {
+			return;
+		};
+
+		let max_coverage = self.bits_per_pixel.get_max_coverage();
+		let mut colors = [background_color; 1 << BitsPerPixel::Four as u8];
+		for (coverage, blended_color) in colors.iter_mut().enumerate().take(max_coverage as usize + 1) {
+			let opacity = coverage as u16 * u8::MAX as u16 / max_coverage as u16;
+			*blended_color = background_color.blend(color, opacity as u8);
+		}
+
+		let size = U16x2 {
+			x: glyph.advance as u16,
+			y: self.line_height,
+		};
+		draw_cell(
+			size,
+			|x, y| {
+				let x = x.wrapping_sub(glyph.left as u16);
+				let y = y.wrapping_sub(glyph.top as u16);
+				match x < glyph.width as u16 && y < glyph.height as u16 {
+					true => colors[self.get_coverage(glyph, x as u8, y as u8) as usize],
+					false => background_color,
+				}
+			},
+			draw_fn,
+		);
+	}
+}
@@ -0,0 +1,25 @@
+//! The [`BitmapFont`](super::BitmapFont)s built into the firmware, generated from the DejaVu fonts (which can be
+//! freely redistributed, check <https://dejavu-fonts.github.io/License.html>) with the `asset-generator` tool.
+//!
+//! They are regenerated from the `firmware` folder with:
+//! ```sh
+//! cargo run -p asset-generator --bin generate_font -- DejaVuSans.ttf 12 2 SANS_12 > <this folder>/sans_12.rs
+//! cargo run -p asset-generator --bin generate_font -- DejaVuSans.ttf 16 4 SANS_16 > <this folder>/sans_16.rs
+//! cargo run -p asset-generator --bin generate_font -- DejaVuSans-Bold.ttf 20 4 SANS_BOLD_20 > <this folder>/sans_bold_20.rs
+//! cargo run -p asset-generator --bin generate_font -- DejaVuSans-Bold.ttf 36 4 SANS_BOLD_DIGITS_36 "0123456789.-°C " \
+//! 	> <this folder>/sans_bold_digits_36.rs
+//! ```
+
+mod sans_12;
+mod sans_16;
+mod sans_bold_20;
+mod sans_bold_digits_36;
+
+/// DejaVu Sans with a line height of 14 pixels, with printable ASCII characters and the degree sign.
+pub use sans_12::SANS_12;
+/// DejaVu Sans with a line height of 19 pixels, with printable ASCII characters and the degree sign.
+pub use sans_16::SANS_16;
+/// DejaVu Sans Bold with a line height of 24 pixels, with printable ASCII characters and the degree sign.
+pub use sans_bold_20::SANS_BOLD_20;
+/// DejaVu Sans Bold with a line height of 42 pixels, with only the characters of a temperature (`0123456789.-°C `).
+pub use sans_bold_digits_36::SANS_BOLD_DIGITS_36;
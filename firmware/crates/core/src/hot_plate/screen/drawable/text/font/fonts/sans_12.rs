@@ -0,0 +1,195 @@
+//! The DejaVuSans.ttf font at 12 pixels, with 2 bits per pixel.
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use super::super::{BitmapFont, BitsPerPixel, Glyph};
+
+#[rustfmt::skip]
+pub static SANS_12: BitmapFont = BitmapFont {
+	line_height: 14,
+	baseline: 12,
+	bits_per_pixel: BitsPerPixel::Two,
+	glyphs: &[
+		Glyph { char: b' ', offset: 0, width: 0, height: 0, left: 0, top: 0, advance: 4 },
+		Glyph { char: b'!', offset: 0, width: 2, height: 9, left: 1, top: 3, advance: 5 },
+		Glyph { char: b'\"', offset: 5, width: 4, height: 4, left: 1, top: 3, advance: 6 },
+		Glyph { char: b'#', offset: 9, width: 10, height: 9, left: 0, top: 3, advance: 10 },
+		Glyph { char: b'$', offset: 32, width: 6, height: 12, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'%', offset: 50, width: 11, height: 10, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'&', offset: 78, width: 9, height: 10, left: 0, top: 3, advance: 9 },
+		Glyph { char: b'\'', offset: 101, width: 2, height: 4, left: 1, top: 3, advance: 3 },
+		Glyph { char: b'(', offset: 103, width: 3, height: 12, left: 1, top: 2, advance: 5 },
+		Glyph { char: b')', offset: 112, width: 3, height: 12, left: 1, top: 2, advance: 5 },
+		Glyph { char: b'*', offset: 121, width: 6, height: 6, left: 0, top: 3, advance: 6 },
+		Glyph { char: b'+', offset: 130, width: 8, height: 8, left: 1, top: 4, advance: 10 },
+		Glyph { char: b',', offset: 146, width: 3, height: 4, left: 0, top: 10, advance: 4 },
+		Glyph { char: b'-', offset: 149, width: 4, height: 2, left: 0, top: 8, advance: 4 },
+		Glyph { char: b'.', offset: 151, width: 2, height: 2, left: 1, top: 10, advance: 4 },
+		Glyph { char: b'/', offset: 152, width: 4, height: 11, left: 0, top: 3, advance: 4 },
+		Glyph { char: b'0', offset: 163, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'1', offset: 181, width: 6, height: 9, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'2', offset: 195, width: 7, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'3', offset: 211, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'4', offset: 229, width: 7, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'5', offset: 245, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'6', offset: 263, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'7', offset: 281, width: 7, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'8', offset: 297, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'9', offset: 315, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b':', offset: 333, width: 2, height: 7, left: 1, top: 5, advance: 4 },
+		Glyph { char: b';', offset: 337, width: 3, height: 9, left: 0, top: 5, advance: 4 },
+		Glyph { char: b'<', offset: 344, width: 8, height: 7, left: 1, top: 5, advance: 10 },
+		Glyph { char: b'=', offset: 358, width: 8, height: 4, left: 1, top: 6, advance: 10 },
+		Glyph { char: b'>', offset: 366, width: 8, height: 7, left: 1, top: 5, advance: 10 },
+		Glyph { char: b'?', offset: 380, width: 6, height: 9, left: 0, top: 3, advance: 6 },
+		Glyph { char: b'@', offset: 394, width: 12, height: 11, left: 0, top: 3, advance: 12 },
+		Glyph { char: b'A', offset: 427, width: 8, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'B', offset: 445, width: 7, height: 9, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'C', offset: 461, width: 8, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'D', offset: 481, width: 8, height: 9, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'E', offset: 499, width: 6, height: 9, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'F', offset: 513, width: 6, height: 9, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'G', offset: 527, width: 9, height: 10, left: 0, top: 3, advance: 9 },
+		Glyph { char: b'H', offset: 550, width: 7, height: 9, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'I', offset: 566, width: 2, height: 9, left: 1, top: 3, advance: 4 },
+		Glyph { char: b'J', offset: 571, width: 3, height: 11, left: 0, top: 3, advance: 4 },
+		Glyph { char: b'K', offset: 580, width: 7, height: 9, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'L', offset: 596, width: 6, height: 9, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'M', offset: 610, width: 9, height: 9, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'N', offset: 631, width: 7, height: 9, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'O', offset: 647, width: 9, height: 10, left: 0, top: 3, advance: 9 },
+		Glyph { char: b'P', offset: 670, width: 6, height: 9, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'Q', offset: 684, width: 9, height: 11, left: 0, top: 3, advance: 9 },
+		Glyph { char: b'R', offset: 709, width: 7, height: 9, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'S', offset: 725, width: 7, height: 10, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'T', offset: 743, width: 7, height: 9, left: 0, top: 3, advance: 7 },
+		Glyph { char: b'U', offset: 759, width: 7, height: 10, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'V', offset: 777, width: 8, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'W', offset: 795, width: 12, height: 9, left: 0, top: 3, advance: 12 },
+		Glyph { char: b'X', offset: 822, width: 8, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'Y', offset: 840, width: 7, height: 9, left: 0, top: 3, advance: 7 },
+		Glyph { char: b'Z', offset: 856, width: 8, height: 9, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'[', offset: 874, width: 3, height: 12, left: 1, top: 2, advance: 5 },
+		Glyph { char: b'\\', offset: 883, width: 4, height: 11, left: 0, top: 3, advance: 4 },
+		Glyph { char: b']', offset: 894, width: 3, height: 12, left: 1, top: 2, advance: 5 },
+		Glyph { char: b'^', offset: 903, width: 8, height: 4, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'_', offset: 911, width: 6, height: 1, left: 0, top: 13, advance: 6 },
+		Glyph { char: b'`', offset: 913, width: 3, height: 3, left: 1, top: 2, advance: 6 },
+		Glyph { char: b'a', offset: 916, width: 7, height: 8, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'b', offset: 930, width: 6, height: 11, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'c', offset: 947, width: 6, height: 8, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'd', offset: 959, width: 7, height: 11, left: 0, top: 2, advance: 8 },
+		Glyph { char: b'e', offset: 979, width: 7, height: 8, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'f', offset: 993, width: 4, height: 10, left: 0, top: 2, advance: 4 },
+		Glyph { char: b'g', offset: 1003, width: 7, height: 9, left: 0, top: 5, advance: 8 },
+		Glyph { char: b'h', offset: 1019, width: 6, height: 10, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'i', offset: 1034, width: 2, height: 10, left: 1, top: 2, advance: 3 },
+		Glyph { char: b'j', offset: 1039, width: 3, height: 12, left: 0, top: 2, advance: 3 },
+		Glyph { char: b'k', offset: 1048, width: 6, height: 10, left: 1, top: 2, advance: 7 },
+		Glyph { char: b'l', offset: 1063, width: 2, height: 10, left: 1, top: 2, advance: 3 },
+		Glyph { char: b'm', offset: 1068, width: 10, height: 7, left: 1, top: 5, advance: 12 },
+		Glyph { char: b'n', offset: 1086, width: 6, height: 7, left: 1, top: 5, advance: 8 },
+		Glyph { char: b'o', offset: 1097, width: 7, height: 8, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'p', offset: 1111, width: 6, height: 9, left: 1, top: 5, advance: 8 },
+		Glyph { char: b'q', offset: 1125, width: 7, height: 9, left: 0, top: 5, advance: 8 },
+		Glyph { char: b'r', offset: 1141, width: 4, height: 7, left: 1, top: 5, advance: 5 },
+		Glyph { char: b's', offset: 1148, width: 6, height: 8, left: 0, top: 5, advance: 6 },
+		Glyph { char: b't', offset: 1160, width: 5, height: 9, left: 0, top: 3, advance: 5 },
+		Glyph { char: b'u', offset: 1172, width: 6, height: 8, left: 1, top: 5, advance: 8 },
+		Glyph { char: b'v', offset: 1184, width: 7, height: 7, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'w', offset: 1197, width: 10, height: 7, left: 0, top: 5, advance: 10 },
+		Glyph { char: b'x', offset: 1215, width: 7, height: 7, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'y', offset: 1228, width: 7, height: 9, left: 0, top: 5, advance: 7 },
+		Glyph { char: b'z', offset: 1244, width: 6, height: 7, left: 0, top: 5, advance: 6 },
+		Glyph { char: b'{', offset: 1255, width: 6, height: 12, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'|', offset: 1273, width: 2, height: 12, left: 1, top: 2, advance: 4 },
+		Glyph { char: b'}', offset: 1279, width: 6, height: 12, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'~', offset: 1297, width: 8, height: 3, left: 1, top: 7, advance: 10 },
+		Glyph { char: 0xB0, offset: 1303, width: 4, height: 4, left: 1, top: 3, advance: 6 },
+	],
+	atlas: &[
+		0x27, 0x77, 0x33, 0x01, 0x70, 0x85, 0xC9, 0xC9, 0x45, 0x00, 0x42, 0x00, 0x0C, 0x60, 0x05, 0x9A,
+		0x41, 0xBA, 0xE8, 0x02, 0x4C, 0x01, 0x75, 0x90, 0x2B, 0xBA, 0x40, 0x52, 0x00, 0x09, 0x30, 0x00,
+		0x00, 0x00, 0x80, 0x2E, 0x8A, 0x94, 0xC8, 0x0B, 0x90, 0x1A, 0xD0, 0x8A, 0x48, 0x9B, 0xF8, 0x08,
+		0x00, 0x40, 0x1A, 0x01, 0x81, 0x86, 0x08, 0x06, 0x18, 0x50, 0x08, 0x62, 0x00, 0x1A, 0x25, 0x90,
+		0x01, 0x8D, 0x90, 0x08, 0x61, 0x80, 0x51, 0x86, 0x02, 0x02, 0xA4, 0x00, 0x00, 0x00, 0x06, 0xE0,
+		0x03, 0x44, 0x01, 0xC0, 0x00, 0x38, 0x00, 0x2A, 0x81, 0x4C, 0x28, 0x97, 0x03, 0xB0, 0xD0, 0x78,
+		0x1F, 0xB7, 0x40, 0x00, 0x00, 0x8C, 0xC4, 0x00, 0x93, 0x18, 0x93, 0x4D, 0x34, 0x91, 0x82, 0x05,
+		0x02, 0x46, 0x0D, 0x24, 0xA2, 0x8A, 0x24, 0xC6, 0x20, 0x05, 0x05, 0x55, 0x1A, 0x42, 0xA8, 0x45,
+		0x10, 0x50, 0x01, 0x40, 0x01, 0x80, 0x01, 0x80, 0x56, 0x95, 0xAB, 0xEA, 0x01, 0x80, 0x01, 0x80,
+		0x01, 0x80, 0x14, 0xA2, 0x04, 0x6A, 0x14, 0x5A, 0x02, 0x06, 0x09, 0x0C, 0x18, 0x24, 0x30, 0x60,
+		0x90, 0x90, 0x00, 0x0B, 0x90, 0xA1, 0xD3, 0x42, 0x8C, 0x06, 0x70, 0x19, 0xC0, 0x63, 0x42, 0x8A,
+		0x0D, 0x0F, 0xE0, 0x00, 0x00, 0x69, 0x06, 0xA0, 0x0A, 0x00, 0xA0, 0x0A, 0x00, 0xA0, 0x0A, 0x00,
+		0xA0, 0xBF, 0xE0, 0x2B, 0x90, 0x91, 0xC0, 0x03, 0x40, 0x0D, 0x00, 0xA0, 0x0A, 0x00, 0xA0, 0x0A,
+		0x00, 0x3F, 0xF4, 0x2B, 0x90, 0x51, 0xD0, 0x02, 0x40, 0x5C, 0x06, 0xE0, 0x00, 0x90, 0x02, 0x80,
+		0x0D, 0x3F, 0xE0, 0x00, 0x00, 0x00, 0xA0, 0x0B, 0x80, 0x66, 0x03, 0x18, 0x24, 0x61, 0x81, 0x87,
+		0xFF, 0xC0, 0x18, 0x00, 0x60, 0x2A, 0xA0, 0xA5, 0x42, 0x40, 0x0A, 0xA4, 0x15, 0xB0, 0x00, 0x90,
+		0x02, 0x80, 0x1D, 0x3F, 0xE0, 0x00, 0x00, 0x06, 0xE4, 0x74, 0x42, 0x40, 0x0D, 0xA4, 0x39, 0x74,
+		0xD0, 0x63, 0x41, 0xCA, 0x0A, 0x0B, 0xE0, 0x00, 0x00, 0x2A, 0xA4, 0x55, 0xD0, 0x07, 0x00, 0x28,
+		0x00, 0xC0, 0x06, 0x00, 0x34, 0x01, 0xC0, 0x0A, 0x00, 0x0B, 0xE0, 0xA0, 0xD3, 0x42, 0x8A, 0x1D,
+		0x0F, 0xE0, 0xD0, 0xA3, 0x01, 0x8D, 0x0A, 0x1E, 0xE0, 0x00, 0x00, 0x0B, 0x90, 0xE1, 0xC7, 0x02,
+		0x9C, 0x0A, 0x34, 0x38, 0x7F, 0xA0, 0x02, 0x80, 0x1C, 0x2F, 0xD0, 0x00, 0x00, 0x0A, 0x50, 0x05,
+		0xA0, 0x00, 0xA1, 0x40, 0x00, 0x52, 0x88, 0x10, 0x00, 0x0A, 0x01, 0xB9, 0x6E, 0x40, 0xB8, 0x00,
+		0x1B, 0x90, 0x00, 0x6E, 0x00, 0x01, 0x55, 0x55, 0x6A, 0xA9, 0x00, 0x00, 0xBF, 0xFE, 0xA0, 0x00,
+		0x2E, 0x40, 0x01, 0xB9, 0x00, 0x1E, 0x06, 0xE4, 0xB9, 0x00, 0x40, 0x00, 0x2F, 0x81, 0x1D, 0x00,
+		0x90, 0x1C, 0x07, 0x00, 0x90, 0x05, 0x00, 0x50, 0x09, 0x00, 0x00, 0x55, 0x00, 0x02, 0x96, 0x90,
+		0x09, 0x00, 0x24, 0x24, 0x69, 0x48, 0x21, 0xD7, 0x48, 0x61, 0x81, 0x48, 0x21, 0x82, 0x4C, 0x30,
+		0xD7, 0xA4, 0x28, 0x15, 0x40, 0x0A, 0x00, 0x40, 0x01, 0xAB, 0x40, 0x02, 0x80, 0x03, 0xD0, 0x06,
+		0x60, 0x09, 0x30, 0x1C, 0x24, 0x29, 0x68, 0x3A, 0xAC, 0x70, 0x0A, 0xA0, 0x07, 0xAA, 0x82, 0x96,
+		0x89, 0x07, 0x24, 0x68, 0xBB, 0xD2, 0x41, 0xC9, 0x03, 0x64, 0x1D, 0xBF, 0xD0, 0x06, 0xF9, 0x1D,
+		0x06, 0x34, 0x00, 0x70, 0x00, 0x70, 0x00, 0x70, 0x00, 0x34, 0x00, 0x2C, 0x01, 0x0B, 0xFD, 0x00,
+		0x00, 0xAA, 0x90, 0xA5, 0x74, 0x90, 0x1C, 0x90, 0x0D, 0x90, 0x0A, 0x90, 0x09, 0x90, 0x0D, 0x90,
+		0x68, 0xBF, 0x90, 0xAA, 0xAA, 0x55, 0x90, 0x09, 0x00, 0xBF, 0xD9, 0x00, 0x90, 0x09, 0x00, 0xBF,
+		0xE0, 0xAA, 0x8A, 0x54, 0x90, 0x09, 0x50, 0xBA, 0x89, 0x00, 0x90, 0x09, 0x00, 0x90, 0x00, 0x06,
+		0xF9, 0x07, 0x41, 0x83, 0x40, 0x01, 0xC0, 0x00, 0x70, 0x1A, 0x5C, 0x05, 0xD3, 0x40, 0x34, 0xB0,
+		0x0D, 0x07, 0xEE, 0x00, 0x00, 0x00, 0x90, 0x1A, 0x40, 0x79, 0x01, 0xE4, 0x07, 0xBF, 0xFE, 0x40,
+		0x79, 0x01, 0xE4, 0x07, 0x90, 0x1C, 0x99, 0x99, 0x99, 0x99, 0x90, 0x24, 0x92, 0x49, 0x24, 0x92,
+		0x49, 0x24, 0xDA, 0x00, 0x90, 0x26, 0x43, 0x49, 0x74, 0x2B, 0x40, 0xB8, 0x02, 0x78, 0x09, 0x38,
+		0x24, 0x38, 0x90, 0x38, 0x90, 0x09, 0x00, 0x90, 0x09, 0x00, 0x90, 0x09, 0x00, 0x90, 0x09, 0x00,
+		0xBF, 0xE0, 0xA0, 0x0A, 0x2D, 0x03, 0xDA, 0x81, 0xB6, 0x70, 0x9D, 0x96, 0x63, 0x64, 0xE4, 0xD9,
+		0x2C, 0x36, 0x41, 0x0D, 0x90, 0x03, 0x40, 0xA0, 0x1A, 0xD0, 0x6A, 0xC1, 0xA6, 0x46, 0x97, 0x1A,
+		0x4A, 0x69, 0x0D, 0xA4, 0x2E, 0x90, 0x38, 0x06, 0xF8, 0x07, 0x46, 0x83, 0x40, 0x35, 0xC0, 0x0A,
+		0x70, 0x01, 0x9C, 0x00, 0xA3, 0x40, 0x24, 0xB0, 0x2C, 0x0B, 0xB9, 0x00, 0x00, 0x00, 0xAA, 0x4A,
+		0x5D, 0x90, 0x69, 0x0A, 0xA6, 0xDA, 0x50, 0x90, 0x09, 0x00, 0x90, 0x00, 0x06, 0xF8, 0x07, 0x46,
+		0x83, 0x40, 0x35, 0xC0, 0x0A, 0x70, 0x01, 0x9C, 0x00, 0xA3, 0x40, 0x24, 0xB0, 0x2C, 0x0B, 0xB8,
+		0x00, 0x07, 0x00, 0x00, 0x50, 0xAA, 0x42, 0x97, 0x89, 0x06, 0x24, 0x28, 0xAA, 0xD2, 0x97, 0x09,
+		0x0A, 0x24, 0x0D, 0x90, 0x28, 0x1B, 0xE4, 0xE0, 0x57, 0x00, 0x0E, 0x40, 0x1B, 0xE0, 0x01, 0xA0,
+		0x01, 0xC4, 0x0A, 0x2E, 0xE4, 0x00, 0x00, 0xAA, 0xA9, 0x5D, 0x50, 0x34, 0x00, 0xD0, 0x03, 0x40,
+		0x0D, 0x00, 0x34, 0x00, 0xD0, 0x03, 0x40, 0x90, 0x1B, 0x40, 0x6D, 0x01, 0xB4, 0x06, 0xD0, 0x1B,
+		0x40, 0x6D, 0x02, 0xA8, 0x0D, 0x2E, 0xE0, 0x00, 0x00, 0x90, 0x06, 0x60, 0x0A, 0x34, 0x0D, 0x28,
+		0x1C, 0x1C, 0x24, 0x0D, 0x30, 0x0A, 0x60, 0x03, 0xD0, 0x02, 0xC0, 0x60, 0x28, 0x09, 0x70, 0x3C,
+		0x0C, 0x30, 0x68, 0x1C, 0x24, 0x99, 0x28, 0x28, 0x96, 0x24, 0x1C, 0xC3, 0x30, 0x0D, 0x82, 0x70,
+		0x0B, 0x42, 0xE0, 0x0B, 0x41, 0xD0, 0x24, 0x09, 0x1C, 0x18, 0x0A, 0x34, 0x03, 0xD0, 0x02, 0xC0,
+		0x07, 0xA0, 0x0D, 0x34, 0x28, 0x18, 0x70, 0x0A, 0x90, 0x09, 0xC0, 0xA2, 0x86, 0x02, 0xB4, 0x07,
+		0x40, 0x0D, 0x00, 0x34, 0x00, 0xD0, 0x03, 0x40, 0x6A, 0xA9, 0x15, 0x5D, 0x00, 0x34, 0x00, 0xA0,
+		0x02, 0x80, 0x07, 0x00, 0x1D, 0x00, 0x34, 0x00, 0x7F, 0xFE, 0x03, 0x9C, 0x30, 0xC3, 0x0C, 0x30,
+		0xC3, 0x0D, 0x29, 0x80, 0x90, 0x60, 0x30, 0x24, 0x18, 0x0C, 0x09, 0x06, 0x03, 0x00, 0x02, 0xE1,
+		0x86, 0x18, 0x61, 0x86, 0x18, 0x66, 0x99, 0x02, 0x80, 0x0A, 0xA0, 0x28, 0x28, 0x50, 0x05, 0x00,
+		0x00, 0x50, 0x81, 0x40, 0x1A, 0x80, 0x56, 0xC0, 0x03, 0x4B, 0xAD, 0x70, 0x35, 0xC1, 0xD2, 0xEB,
+		0x40, 0x00, 0x00, 0x0D, 0x00, 0xD0, 0x0D, 0xA4, 0xE5, 0xDD, 0x06, 0xD0, 0x7D, 0x07, 0xE0, 0xAE,
+		0xB8, 0x00, 0x00, 0x06, 0x92, 0x95, 0x30, 0x06, 0x00, 0x70, 0x03, 0x40, 0x1E, 0xA0, 0x00, 0x00,
+		0x00, 0x00, 0xA0, 0x02, 0x82, 0x9A, 0x29, 0x79, 0xC0, 0xA6, 0x02, 0x9C, 0x0A, 0x34, 0x38, 0x7A,
+		0xA0, 0x00, 0x00, 0x06, 0x90, 0xA5, 0xD3, 0x01, 0x9F, 0xFE, 0x70, 0x00, 0xD0, 0x01, 0xEB, 0x40,
+		0x00, 0x00, 0x1E, 0x24, 0x7A, 0x69, 0x24, 0x24, 0x24, 0x24, 0x24, 0x0A, 0x54, 0xA5, 0xE7, 0x02,
+		0x98, 0x0A, 0x70, 0x28, 0xD1, 0xE1, 0xFA, 0x80, 0x09, 0x19, 0xB0, 0x00, 0x0D, 0x00, 0xD0, 0x0D,
+		0xA4, 0xE5, 0xDD, 0x0A, 0xD0, 0x6D, 0x06, 0xD0, 0x6D, 0x06, 0x0D, 0x44, 0xDD, 0xDD, 0xDD, 0x00,
+		0xD1, 0x04, 0x34, 0xD3, 0x4D, 0x34, 0xD3, 0x6C, 0x00, 0x0D, 0x00, 0xD0, 0x0D, 0x05, 0xD2, 0x8D,
+		0x90, 0xF8, 0x0D, 0xD0, 0xD3, 0x4D, 0x0D, 0x0D, 0xDD, 0xDD, 0xDD, 0xDD, 0x9A, 0x46, 0x4E, 0x5E,
+		0x5D, 0xD0, 0xA0, 0x6D, 0x09, 0x06, 0xD0, 0x90, 0x6D, 0x09, 0x06, 0xD0, 0x90, 0x60, 0x9A, 0x4E,
+		0x5D, 0xD0, 0xAD, 0x06, 0xD0, 0x6D, 0x06, 0xD0, 0x60, 0x06, 0x90, 0xA5, 0xC7, 0x02, 0x98, 0x06,
+		0x70, 0x28, 0xD0, 0xD1, 0xEE, 0x00, 0x00, 0x9A, 0x4E, 0x5D, 0xD0, 0x6D, 0x07, 0xD0, 0x7E, 0x0A,
+		0xEB, 0x8D, 0x00, 0xD0, 0x00, 0x0A, 0x54, 0xA5, 0xE7, 0x02, 0x98, 0x0A, 0x70, 0x28, 0xD0, 0xE1,
+		0xEA, 0x80, 0x0A, 0x00, 0x28, 0x9A, 0xE5, 0xD0, 0xD0, 0xD0, 0xD0, 0xD0, 0x1A, 0x83, 0x55, 0x70,
+		0x02, 0xE4, 0x01, 0xA0, 0x0A, 0x7A, 0xC0, 0x00, 0x10, 0x0D, 0x07, 0xA5, 0xD4, 0x34, 0x0D, 0x03,
+		0x40, 0x90, 0x1F, 0x40, 0x80, 0x5C, 0x0A, 0xC0, 0xAC, 0x0A, 0xC0, 0xAD, 0x0E, 0x7A, 0xA0, 0x00,
+		0x50, 0x15, 0xC0, 0x93, 0x43, 0x06, 0x28, 0x0C, 0xD0, 0x2B, 0x00, 0x74, 0x00, 0x50, 0x50, 0x47,
+		0x0E, 0x0C, 0x31, 0xB1, 0x82, 0x66, 0x68, 0x1A, 0x1A, 0x41, 0xF0, 0xF0, 0x0E, 0x0A, 0x00, 0x20,
+		0x14, 0xA1, 0x80, 0xEC, 0x01, 0xD0, 0x0A, 0x80, 0xA2, 0x87, 0x03, 0x40, 0x50, 0x15, 0xC0, 0x92,
+		0x47, 0x06, 0x28, 0x0D, 0xC0, 0x2E, 0x00, 0x34, 0x01, 0xC0, 0x19, 0x00, 0x6A, 0x91, 0x5A, 0x01,
+		0x80, 0x70, 0x1D, 0x03, 0x40, 0x7F, 0xE0, 0x00, 0x00, 0x78, 0x09, 0x00, 0x90, 0x09, 0x00, 0xD0,
+		0x78, 0x00, 0xD0, 0x09, 0x00, 0x90, 0x0A, 0x00, 0x6C, 0x06, 0x66, 0x66, 0x66, 0x66, 0x66, 0x00,
+		0x06, 0x80, 0x0D, 0x00, 0x90, 0x09, 0x00, 0xA0, 0x07, 0xC0, 0x90, 0x09, 0x00, 0x90, 0x0D, 0x07,
+		0x80, 0x19, 0x01, 0xA6, 0xFD, 0x00, 0x00, 0x69, 0x82, 0x82, 0x68,
+	],
+	replacement: b'?',
+};
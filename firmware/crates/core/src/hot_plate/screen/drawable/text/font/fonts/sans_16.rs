@@ -0,0 +1,393 @@
+//! The DejaVuSans.ttf font at 16 pixels, with 4 bits per pixel.
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use super::super::{BitmapFont, BitsPerPixel, Glyph};
+
+#[rustfmt::skip]
+pub static SANS_16: BitmapFont = BitmapFont {
+	line_height: 19,
+	baseline: 15,
+	bits_per_pixel: BitsPerPixel::Four,
+	glyphs: &[
+		Glyph { char: b' ', offset: 0, width: 0, height: 0, left: 0, top: 0, advance: 5 },
+		Glyph { char: b'!', offset: 0, width: 2, height: 12, left: 2, top: 3, advance: 6 },
+		Glyph { char: b'\"', offset: 12, width: 5, height: 5, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'#', offset: 25, width: 12, height: 12, left: 1, top: 3, advance: 13 },
+		Glyph { char: b'$', offset: 97, width: 8, height: 16, left: 1, top: 2, advance: 10 },
+		Glyph { char: b'%', offset: 161, width: 15, height: 13, left: 0, top: 3, advance: 15 },
+		Glyph { char: b'&', offset: 259, width: 11, height: 13, left: 1, top: 3, advance: 12 },
+		Glyph { char: b'\'', offset: 331, width: 2, height: 5, left: 1, top: 3, advance: 4 },
+		Glyph { char: b'(', offset: 336, width: 4, height: 16, left: 1, top: 2, advance: 6 },
+		Glyph { char: b')', offset: 368, width: 4, height: 16, left: 1, top: 2, advance: 6 },
+		Glyph { char: b'*', offset: 400, width: 8, height: 8, left: 0, top: 3, advance: 8 },
+		Glyph { char: b'+', offset: 432, width: 11, height: 11, left: 1, top: 4, advance: 13 },
+		Glyph { char: b',', offset: 493, width: 3, height: 4, left: 1, top: 13, advance: 5 },
+		Glyph { char: b'-', offset: 499, width: 5, height: 3, left: 0, top: 9, advance: 6 },
+		Glyph { char: b'.', offset: 507, width: 3, height: 2, left: 1, top: 13, advance: 5 },
+		Glyph { char: b'/', offset: 510, width: 5, height: 14, left: 0, top: 3, advance: 5 },
+		Glyph { char: b'0', offset: 545, width: 9, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'1', offset: 604, width: 8, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'2', offset: 652, width: 8, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'3', offset: 700, width: 8, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'4', offset: 752, width: 10, height: 12, left: 0, top: 3, advance: 10 },
+		Glyph { char: b'5', offset: 812, width: 8, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'6', offset: 864, width: 9, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'7', offset: 923, width: 8, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'8', offset: 971, width: 9, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'9', offset: 1030, width: 9, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b':', offset: 1089, width: 3, height: 9, left: 1, top: 6, advance: 5 },
+		Glyph { char: b';', offset: 1103, width: 3, height: 11, left: 1, top: 6, advance: 5 },
+		Glyph { char: b'<', offset: 1120, width: 11, height: 10, left: 1, top: 5, advance: 13 },
+		Glyph { char: b'=', offset: 1175, width: 11, height: 6, left: 1, top: 7, advance: 13 },
+		Glyph { char: b'>', offset: 1208, width: 11, height: 10, left: 1, top: 5, advance: 13 },
+		Glyph { char: b'?', offset: 1263, width: 7, height: 12, left: 1, top: 3, advance: 8 },
+		Glyph { char: b'@', offset: 1305, width: 14, height: 15, left: 1, top: 3, advance: 16 },
+		Glyph { char: b'A', offset: 1410, width: 11, height: 12, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'B', offset: 1476, width: 9, height: 12, left: 1, top: 3, advance: 11 },
+		Glyph { char: b'C', offset: 1530, width: 11, height: 13, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'D', offset: 1602, width: 11, height: 12, left: 1, top: 3, advance: 12 },
+		Glyph { char: b'E', offset: 1668, width: 9, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'F', offset: 1722, width: 8, height: 12, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'G', offset: 1770, width: 12, height: 13, left: 0, top: 3, advance: 12 },
+		Glyph { char: b'H', offset: 1848, width: 10, height: 12, left: 1, top: 3, advance: 12 },
+		Glyph { char: b'I', offset: 1908, width: 3, height: 12, left: 1, top: 3, advance: 5 },
+		Glyph { char: b'J', offset: 1926, width: 4, height: 16, left: 0, top: 3, advance: 5 },
+		Glyph { char: b'K', offset: 1958, width: 9, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'L', offset: 2012, width: 8, height: 12, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'M', offset: 2060, width: 12, height: 12, left: 1, top: 3, advance: 14 },
+		Glyph { char: b'N', offset: 2132, width: 10, height: 12, left: 1, top: 3, advance: 12 },
+		Glyph { char: b'O', offset: 2192, width: 12, height: 13, left: 0, top: 3, advance: 13 },
+		Glyph { char: b'P', offset: 2270, width: 9, height: 12, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'Q', offset: 2324, width: 12, height: 15, left: 0, top: 3, advance: 13 },
+		Glyph { char: b'R', offset: 2414, width: 10, height: 12, left: 1, top: 3, advance: 11 },
+		Glyph { char: b'S', offset: 2474, width: 9, height: 13, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'T', offset: 2533, width: 10, height: 12, left: 0, top: 3, advance: 10 },
+		Glyph { char: b'U', offset: 2593, width: 10, height: 13, left: 1, top: 3, advance: 12 },
+		Glyph { char: b'V', offset: 2658, width: 11, height: 12, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'W', offset: 2724, width: 16, height: 12, left: 0, top: 3, advance: 16 },
+		Glyph { char: b'X', offset: 2820, width: 11, height: 12, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'Y', offset: 2886, width: 10, height: 12, left: 0, top: 3, advance: 10 },
+		Glyph { char: b'Z', offset: 2946, width: 11, height: 12, left: 0, top: 3, advance: 11 },
+		Glyph { char: b'[', offset: 3012, width: 4, height: 16, left: 1, top: 2, advance: 6 },
+		Glyph { char: b'\\', offset: 3044, width: 5, height: 14, left: 0, top: 3, advance: 5 },
+		Glyph { char: b']', offset: 3079, width: 4, height: 16, left: 1, top: 2, advance: 6 },
+		Glyph { char: b'^', offset: 3111, width: 11, height: 5, left: 1, top: 3, advance: 13 },
+		Glyph { char: b'_', offset: 3139, width: 8, height: 2, left: 0, top: 17, advance: 8 },
+		Glyph { char: b'`', offset: 3147, width: 4, height: 4, left: 1, top: 2, advance: 8 },
+		Glyph { char: b'a', offset: 3155, width: 9, height: 10, left: 0, top: 6, advance: 10 },
+		Glyph { char: b'b', offset: 3200, width: 9, height: 14, left: 1, top: 2, advance: 10 },
+		Glyph { char: b'c', offset: 3263, width: 8, height: 10, left: 0, top: 6, advance: 9 },
+		Glyph { char: b'd', offset: 3303, width: 9, height: 14, left: 0, top: 2, advance: 10 },
+		Glyph { char: b'e', offset: 3366, width: 9, height: 10, left: 0, top: 6, advance: 10 },
+		Glyph { char: b'f', offset: 3411, width: 6, height: 13, left: 0, top: 2, advance: 6 },
+		Glyph { char: b'g', offset: 3450, width: 9, height: 13, left: 0, top: 6, advance: 10 },
+		Glyph { char: b'h', offset: 3509, width: 8, height: 13, left: 1, top: 2, advance: 10 },
+		Glyph { char: b'i', offset: 3561, width: 2, height: 13, left: 1, top: 2, advance: 4 },
+		Glyph { char: b'j', offset: 3574, width: 3, height: 17, left: 0, top: 2, advance: 4 },
+		Glyph { char: b'k', offset: 3600, width: 8, height: 13, left: 1, top: 2, advance: 9 },
+		Glyph { char: b'l', offset: 3652, width: 2, height: 13, left: 1, top: 2, advance: 4 },
+		Glyph { char: b'm', offset: 3665, width: 14, height: 9, left: 1, top: 6, advance: 16 },
+		Glyph { char: b'n', offset: 3728, width: 8, height: 9, left: 1, top: 6, advance: 10 },
+		Glyph { char: b'o', offset: 3764, width: 9, height: 10, left: 0, top: 6, advance: 10 },
+		Glyph { char: b'p', offset: 3809, width: 9, height: 13, left: 1, top: 6, advance: 10 },
+		Glyph { char: b'q', offset: 3868, width: 9, height: 13, left: 0, top: 6, advance: 10 },
+		Glyph { char: b'r', offset: 3927, width: 6, height: 9, left: 1, top: 6, advance: 7 },
+		Glyph { char: b's', offset: 3954, width: 8, height: 10, left: 0, top: 6, advance: 8 },
+		Glyph { char: b't', offset: 3994, width: 6, height: 12, left: 0, top: 3, advance: 6 },
+		Glyph { char: b'u', offset: 4030, width: 8, height: 10, left: 1, top: 6, advance: 10 },
+		Glyph { char: b'v', offset: 4070, width: 9, height: 9, left: 0, top: 6, advance: 9 },
+		Glyph { char: b'w', offset: 4111, width: 13, height: 9, left: 0, top: 6, advance: 13 },
+		Glyph { char: b'x', offset: 4170, width: 9, height: 9, left: 0, top: 6, advance: 9 },
+		Glyph { char: b'y', offset: 4211, width: 9, height: 13, left: 0, top: 6, advance: 9 },
+		Glyph { char: b'z', offset: 4270, width: 8, height: 9, left: 0, top: 6, advance: 8 },
+		Glyph { char: b'{', offset: 4306, width: 7, height: 16, left: 2, top: 2, advance: 10 },
+		Glyph { char: b'|', offset: 4362, width: 2, height: 17, left: 2, top: 2, advance: 5 },
+		Glyph { char: b'}', offset: 4379, width: 7, height: 16, left: 2, top: 2, advance: 10 },
+		Glyph { char: b'~', offset: 4435, width: 11, height: 4, left: 1, top: 8, advance: 13 },
+		Glyph { char: 0xB0, offset: 4457, width: 6, height: 6, left: 1, top: 3, advance: 8 },
+	],
+	atlas: &[
+		0x6A, 0x9F, 0x9F, 0x9F, 0x9F, 0x9F, 0x8E, 0x7D, 0x23, 0x00, 0x9F, 0x9F, 0x59, 0x05, 0x87, 0xD0,
+		0x8C, 0x7D, 0x08, 0xC7, 0xD0, 0x8C, 0x59, 0x05, 0x80, 0x00, 0x00, 0x27, 0x00, 0x64, 0x00, 0x00,
+		0x00, 0x7B, 0x00, 0xE5, 0x00, 0x00, 0x00, 0xB8, 0x03, 0xF1, 0x00, 0x03, 0x44, 0xE7, 0x48, 0xD4,
+		0x41, 0x0D, 0xEF, 0xFE, 0xEF, 0xFE, 0xE3, 0x00, 0x07, 0xC0, 0x0E, 0x50, 0x00, 0x00, 0x0B, 0x80,
+		0x3F, 0x10, 0x00, 0x56, 0x6F, 0x96, 0xAE, 0x66, 0x20, 0x9B, 0xDF, 0xBB, 0xED, 0xBB, 0x30, 0x00,
+		0x7B, 0x00, 0xE5, 0x00, 0x00, 0x00, 0xB8, 0x03, 0xF1, 0x00, 0x00, 0x00, 0xE4, 0x07, 0xC0, 0x00,
+		0x00, 0x00, 0x01, 0x10, 0x00, 0x00, 0x06, 0x60, 0x00, 0x00, 0x28, 0x93, 0x10, 0x1A, 0xFD, 0xDE,
+		0xF2, 0x7E, 0x36, 0x60, 0x41, 0xAC, 0x06, 0x60, 0x00, 0x7F, 0x67, 0x60, 0x00, 0x09, 0xFF, 0xD9,
+		0x30, 0x00, 0x18, 0xBC, 0xF5, 0x00, 0x06, 0x60, 0xCC, 0x00, 0x06, 0x60, 0xAC, 0x98, 0x47, 0x87,
+		0xF7, 0x4A, 0xDF, 0xFC, 0x60, 0x00, 0x06, 0x60, 0x00, 0x00, 0x06, 0x60, 0x00, 0x00, 0x02, 0x20,
+		0x00, 0x01, 0xAD, 0xB2, 0x00, 0x00, 0x88, 0x00, 0x00, 0xBB, 0x29, 0xD0, 0x00, 0x3E, 0x10, 0x00,
+		0x1F, 0x30, 0x1F, 0x30, 0x0C, 0x70, 0x00, 0x01, 0xF2, 0x00, 0xF4, 0x06, 0xC0, 0x00, 0x00, 0x0E,
+		0x50, 0x3F, 0x21, 0xE4, 0x00, 0x00, 0x00, 0x7E, 0x8D, 0x90, 0x9A, 0x00, 0x10, 0x00, 0x00, 0x47,
+		0x50, 0x3E, 0x13, 0xDE, 0xE5, 0x00, 0x00, 0x00, 0x0C, 0x60, 0xC9, 0x05, 0xE1, 0x00, 0x00, 0x06,
+		0xC0, 0x0F, 0x30, 0x0F, 0x40, 0x00, 0x01, 0xE3, 0x01, 0xF3, 0x00, 0xE4, 0x00, 0x00, 0x99, 0x00,
+		0x0C, 0x80, 0x4F, 0x10, 0x00, 0x4E, 0x10, 0x00, 0x3E, 0xCE, 0x60, 0x00, 0x02, 0x20, 0x00, 0x00,
+		0x13, 0x10, 0x00, 0x00, 0x5B, 0xDC, 0x81, 0x00, 0x00, 0x4F, 0xB6, 0x7C, 0x20, 0x00, 0x09, 0xE0,
+		0x00, 0x00, 0x00, 0x00, 0x9E, 0x10, 0x00, 0x00, 0x00, 0x02, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x9E,
+		0xF9, 0x00, 0x00, 0x42, 0x7F, 0x35, 0xF9, 0x00, 0x2F, 0x5D, 0xA0, 0x06, 0xF9, 0x05, 0xF1, 0xF8,
+		0x00, 0x06, 0xF8, 0xC9, 0x0C, 0xC0, 0x00, 0x06, 0xFE, 0x10, 0x5F, 0xA3, 0x13, 0xAF, 0xF8, 0x00,
+		0x5D, 0xFF, 0xFB, 0x37, 0xF7, 0x00, 0x02, 0x31, 0x00, 0x00, 0x00, 0x59, 0x7D, 0x7D, 0x7D, 0x59,
+		0x00, 0x12, 0x00, 0xA9, 0x03, 0xF2, 0x0A, 0xB0, 0x1F, 0x60, 0x5F, 0x20, 0x8F, 0x00, 0x9E, 0x00,
+		0x9E, 0x00, 0x8F, 0x00, 0x5F, 0x20, 0x1F, 0x60, 0x0A, 0xB0, 0x03, 0xF2, 0x00, 0xA9, 0x00, 0x12,
+		0x11, 0x00, 0x6D, 0x10, 0x0D, 0x70, 0x07, 0xD0, 0x02, 0xF4, 0x00, 0xE8, 0x00, 0xBB, 0x00, 0xAD,
+		0x00, 0xAD, 0x00, 0xBB, 0x00, 0xE8, 0x02, 0xF4, 0x07, 0xD0, 0x0D, 0x70, 0x6D, 0x00, 0x11, 0x00,
+		0x00, 0x06, 0x60, 0x00, 0x23, 0x07, 0x70, 0x32, 0x3C, 0x98, 0x89, 0xC3, 0x00, 0x5E, 0xE5, 0x00,
+		0x04, 0xCC, 0xCC, 0x40, 0x4B, 0x27, 0x72, 0xB4, 0x00, 0x07, 0x70, 0x00, 0x00, 0x03, 0x30, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE5, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x50, 0x00,
+		0x00, 0x00, 0x00, 0xE5, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x50, 0x00, 0x03, 0xAA, 0xAA, 0xFC, 0xAA,
+		0xA7, 0x3A, 0xAA, 0xAF, 0xCA, 0xAA, 0x70, 0x00, 0x00, 0xE5, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x50,
+		0x00, 0x00, 0x00, 0x00, 0xE5, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x50, 0x00, 0x00, 0x2F, 0x83, 0xF6,
+		0x6E, 0x18, 0x60, 0x00, 0x00, 0x03, 0xFF, 0xFF, 0x14, 0x44, 0x40, 0x4F, 0x54, 0xF5, 0x00, 0x00,
+		0xA0, 0x00, 0x4F, 0x00, 0x09, 0xB0, 0x00, 0xE6, 0x00, 0x3F, 0x20, 0x08, 0xC0, 0x00, 0xD7, 0x00,
+		0x2F, 0x30, 0x07, 0xD0, 0x00, 0xC8, 0x00, 0x1F, 0x40, 0x06, 0xE0, 0x00, 0xA9, 0x00, 0x07, 0x30,
+		0x00, 0x01, 0x8C, 0xD9, 0x10, 0x00, 0xBE, 0x77, 0xDD, 0x10, 0x5F, 0x50, 0x02, 0xF8, 0x0A, 0xE0,
+		0x00, 0x0B, 0xC0, 0xDB, 0x00, 0x00, 0x8F, 0x0E, 0xA0, 0x00, 0x07, 0xF1, 0xEA, 0x00, 0x00, 0x7F,
+		0x2D, 0xB0, 0x00, 0x08, 0xF1, 0xBD, 0x00, 0x00, 0xAE, 0x07, 0xF2, 0x00, 0x1E, 0xA0, 0x1E, 0xC2,
+		0x1A, 0xF3, 0x00, 0x3D, 0xFF, 0xE5, 0x00, 0x00, 0x03, 0x30, 0x00, 0x00, 0x04, 0x7A, 0xA1, 0x00,
+		0x4F, 0xFE, 0xF2, 0x00, 0x13, 0x07, 0xF2, 0x00, 0x00, 0x07, 0xF2, 0x00, 0x00, 0x07, 0xF2, 0x00,
+		0x00, 0x07, 0xF2, 0x00, 0x00, 0x07, 0xF2, 0x00, 0x00, 0x07, 0xF2, 0x00, 0x00, 0x07, 0xF2, 0x00,
+		0x00, 0x07, 0xF2, 0x00, 0x05, 0x59, 0xF6, 0x53, 0x0F, 0xFF, 0xFF, 0xFB, 0x38, 0xBD, 0xC8, 0x10,
+		0xBD, 0x97, 0x9F, 0xC0, 0x30, 0x00, 0x06, 0xF5, 0x00, 0x00, 0x01, 0xF7, 0x00, 0x00, 0x04, 0xF5,
+		0x00, 0x00, 0x1D, 0xD0, 0x00, 0x00, 0xBE, 0x20, 0x00, 0x0A, 0xE3, 0x00, 0x00, 0xAE, 0x40, 0x00,
+		0x0A, 0xE4, 0x00, 0x00, 0x9F, 0x95, 0x55, 0x53, 0xCF, 0xFF, 0xFF, 0xF9, 0x39, 0xCD, 0xC9, 0x20,
+		0x6B, 0x87, 0x8E, 0xE2, 0x00, 0x00, 0x02, 0xF8, 0x00, 0x00, 0x00, 0xF9, 0x00, 0x00, 0x07, 0xF4,
+		0x00, 0xAD, 0xEE, 0x40, 0x00, 0x56, 0x8D, 0xD2, 0x00, 0x00, 0x01, 0xEA, 0x00, 0x00, 0x00, 0xAD,
+		0x00, 0x00, 0x00, 0xDC, 0x85, 0x22, 0x4A, 0xF6, 0x9F, 0xFF, 0xFD, 0x60, 0x00, 0x33, 0x20, 0x00,
+		0x00, 0x00, 0x06, 0xA6, 0x00, 0x00, 0x00, 0x2E, 0xF9, 0x00, 0x00, 0x00, 0xC8, 0xE9, 0x00, 0x00,
+		0x07, 0xD1, 0xE9, 0x00, 0x00, 0x2E, 0x40, 0xE9, 0x00, 0x00, 0xB9, 0x00, 0xE9, 0x00, 0x06, 0xE1,
+		0x00, 0xE9, 0x00, 0x1E, 0x61, 0x11, 0xEA, 0x10, 0x3F, 0xFF, 0xFF, 0xFF, 0xF4, 0x14, 0x44, 0x44,
+		0xEB, 0x41, 0x00, 0x00, 0x00, 0xE9, 0x00, 0x00, 0x00, 0x00, 0xE9, 0x00, 0x3A, 0xAA, 0xAA, 0x90,
+		0x4F, 0xBA, 0xAA, 0x90, 0x4F, 0x30, 0x00, 0x00, 0x4F, 0x30, 0x00, 0x00, 0x4F, 0xAA, 0x95, 0x00,
+		0x4E, 0xAA, 0xCF, 0xB0, 0x10, 0x00, 0x07, 0xF6, 0x00, 0x00, 0x00, 0xEB, 0x00, 0x00, 0x00, 0xCB,
+		0x00, 0x00, 0x02, 0xF9, 0x85, 0x22, 0x5D, 0xF3, 0xAF, 0xFF, 0xFD, 0x40, 0x01, 0x33, 0x20, 0x00,
+		0x00, 0x3A, 0xDD, 0xA3, 0x00, 0x5F, 0xC8, 0x7A, 0x60, 0x1E, 0xA0, 0x00, 0x00, 0x07, 0xF2, 0x00,
+		0x00, 0x00, 0xBD, 0x29, 0xA8, 0x20, 0x0D, 0xDE, 0xA9, 0xDF, 0x40, 0xDF, 0x70, 0x01, 0xDD, 0x0C,
+		0xF1, 0x00, 0x07, 0xF2, 0xAF, 0x00, 0x00, 0x7F, 0x26, 0xF3, 0x00, 0x0A, 0xF0, 0x1D, 0xC3, 0x16,
+		0xF9, 0x00, 0x2C, 0xFF, 0xF9, 0x00, 0x00, 0x02, 0x31, 0x00, 0x00, 0x7A, 0xAA, 0xAA, 0xA8, 0x7A,
+		0xAA, 0xAB, 0xF9, 0x00, 0x00, 0x06, 0xF4, 0x00, 0x00, 0x0C, 0xD0, 0x00, 0x00, 0x3F, 0x70, 0x00,
+		0x00, 0x8F, 0x10, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x05, 0xF5, 0x00, 0x00, 0x0B, 0xE0, 0x00, 0x00,
+		0x2F, 0x80, 0x00, 0x00, 0x7F, 0x20, 0x00, 0x00, 0xDC, 0x00, 0x00, 0x03, 0xAD, 0xDA, 0x40, 0x02,
+		0xED, 0x76, 0xCF, 0x50, 0x8F, 0x20, 0x00, 0xEB, 0x09, 0xE0, 0x00, 0x0C, 0xC0, 0x4F, 0x50, 0x03,
+		0xF7, 0x00, 0x5E, 0xDC, 0xE7, 0x00, 0x1C, 0xD8, 0x8C, 0xD3, 0x0A, 0xE1, 0x00, 0x0C, 0xC0, 0xDB,
+		0x00, 0x00, 0x8F, 0x1C, 0xC0, 0x00, 0x09, 0xF0, 0x7F, 0x81, 0x16, 0xFA, 0x00, 0x8F, 0xFF, 0xFA,
+		0x10, 0x00, 0x13, 0x31, 0x00, 0x00, 0x03, 0xAD, 0xC8, 0x10, 0x03, 0xEC, 0x67, 0xEC, 0x00, 0xBE,
+		0x10, 0x03, 0xF6, 0x0E, 0xA0, 0x00, 0x0D, 0xB0, 0xE9, 0x00, 0x00, 0xCE, 0x0C, 0xD0, 0x00, 0x2F,
+		0xF0, 0x5F, 0xA4, 0x4C, 0xEF, 0x10, 0x5D, 0xFE, 0x99, 0xE0, 0x00, 0x00, 0x00, 0xCB, 0x00, 0x00,
+		0x00, 0x4F, 0x60, 0x26, 0x22, 0x6E, 0xC0, 0x03, 0xEF, 0xFF, 0xA1, 0x00, 0x00, 0x33, 0x10, 0x00,
+		0x00, 0x04, 0x22, 0xF8, 0x1B, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xF8, 0x2F, 0x80, 0x04,
+		0x22, 0xF8, 0x1B, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xF8, 0x3F, 0x66, 0xE1, 0x86, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x27, 0xDB, 0x00, 0x00, 0x16, 0xCF, 0xE8,
+		0x30, 0x05, 0xAF, 0xE9, 0x40, 0x00, 0x3E, 0xFA, 0x50, 0x00, 0x00, 0x03, 0xEF, 0xB5, 0x10, 0x00,
+		0x00, 0x00, 0x4A, 0xEE, 0xA4, 0x00, 0x00, 0x00, 0x01, 0x6B, 0xFE, 0x93, 0x00, 0x00, 0x00, 0x02,
+		0x7C, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x44, 0x44, 0x44, 0x44, 0x35, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFB, 0x01, 0x11, 0x11, 0x11, 0x11, 0x00, 0x11, 0x11, 0x11, 0x11, 0x11, 0x5F, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xB1, 0x44, 0x44, 0x44, 0x44, 0x43, 0x11, 0x00, 0x00, 0x00, 0x00, 0x05, 0xEA, 0x40,
+		0x00, 0x00, 0x00, 0x16, 0xCF, 0xE8, 0x30, 0x00, 0x00, 0x00, 0x27, 0xDF, 0xC7, 0x20, 0x00, 0x00,
+		0x00, 0x38, 0xDF, 0x80, 0x00, 0x00, 0x03, 0x9E, 0xF8, 0x00, 0x02, 0x8D, 0xFC, 0x71, 0x01, 0x7C,
+		0xFD, 0x82, 0x00, 0x00, 0x5E, 0x94, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x39,
+		0xCD, 0xA2, 0x0D, 0xB7, 0x7E, 0xE1, 0x30, 0x00, 0x5F, 0x50, 0x00, 0x05, 0xF4, 0x00, 0x02, 0xEB,
+		0x00, 0x02, 0xEC, 0x10, 0x00, 0xAD, 0x10, 0x00, 0x0D, 0x90, 0x00, 0x00, 0xC8, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0xE9, 0x00, 0x00, 0x0E, 0xA0, 0x00, 0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00,
+		0x00, 0x04, 0xBF, 0xED, 0xFD, 0x70, 0x00, 0x00, 0x8E, 0x72, 0x00, 0x04, 0xCC, 0x10, 0x08, 0xD2,
+		0x00, 0x00, 0x00, 0x0A, 0xB0, 0x2E, 0x20, 0x06, 0xA9, 0x37, 0x30, 0xD5, 0x99, 0x00, 0x9E, 0x87,
+		0xDE, 0x50, 0x7A, 0xC4, 0x02, 0xF4, 0x00, 0x3F, 0x50, 0x4D, 0xE3, 0x04, 0xF0, 0x00, 0x0E, 0x50,
+		0x4D, 0xE3, 0x03, 0xF1, 0x00, 0x0F, 0x50, 0x89, 0xB6, 0x00, 0xE8, 0x00, 0x7F, 0x54, 0xE3, 0x6C,
+		0x00, 0x4E, 0xDD, 0xCD, 0xED, 0x40, 0x1D, 0x70, 0x01, 0x43, 0x04, 0x30, 0x00, 0x03, 0xE8, 0x00,
+		0x00, 0x00, 0x61, 0x00, 0x00, 0x3C, 0xD8, 0x65, 0x8D, 0xD3, 0x00, 0x00, 0x00, 0x59, 0xBB, 0x95,
+		0x00, 0x00, 0x00, 0x00, 0x5A, 0x50, 0x00, 0x00, 0x00, 0x0D, 0xFC, 0x00, 0x00, 0x00, 0x04, 0xF8,
+		0xF3, 0x00, 0x00, 0x00, 0x9D, 0x0E, 0x90, 0x00, 0x00, 0x1E, 0x80, 0x8E, 0x00, 0x00, 0x06, 0xF2,
+		0x03, 0xF5, 0x00, 0x00, 0xCC, 0x00, 0x0C, 0xB0, 0x00, 0x2F, 0x95, 0x55, 0xAF, 0x20, 0x08, 0xFF,
+		0xFF, 0xFF, 0xF7, 0x00, 0xEA, 0x00, 0x00, 0x0B, 0xD0, 0x5F, 0x50, 0x00, 0x00, 0x5F, 0x4A, 0xE0,
+		0x00, 0x00, 0x01, 0xE9, 0x4A, 0xAA, 0xA9, 0x50, 0x06, 0xFA, 0x9A, 0xCF, 0xB0, 0x6F, 0x20, 0x00,
+		0x7F, 0x46, 0xF2, 0x00, 0x04, 0xF5, 0x6F, 0x20, 0x00, 0x9F, 0x26, 0xFD, 0xDD, 0xEE, 0x40, 0x6F,
+		0x86, 0x68, 0xDC, 0x16, 0xF2, 0x00, 0x01, 0xF9, 0x6F, 0x20, 0x00, 0x0D, 0xC6, 0xF2, 0x00, 0x01,
+		0xEB, 0x6F, 0x64, 0x46, 0xCF, 0x56, 0xFF, 0xFF, 0xEB, 0x50, 0x00, 0x02, 0x8C, 0xDC, 0x93, 0x00,
+		0x05, 0xEE, 0x87, 0x7B, 0xF4, 0x03, 0xFB, 0x10, 0x00, 0x04, 0x30, 0xAF, 0x20, 0x00, 0x00, 0x00,
+		0x0E, 0xB0, 0x00, 0x00, 0x00, 0x01, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x90, 0x00, 0x00, 0x00,
+		0x00, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x5F, 0x70, 0x00, 0x00,
+		0x12, 0x00, 0xAF, 0x93, 0x12, 0x6D, 0x50, 0x00, 0x7E, 0xFF, 0xFE, 0x81, 0x00, 0x00, 0x02, 0x32,
+		0x00, 0x00, 0x4A, 0xAA, 0xA8, 0x61, 0x00, 0x06, 0xFA, 0x9A, 0xBF, 0xE7, 0x00, 0x6F, 0x20, 0x00,
+		0x1A, 0xF6, 0x06, 0xF2, 0x00, 0x00, 0x0D, 0xE0, 0x6F, 0x20, 0x00, 0x00, 0x7F, 0x36, 0xF2, 0x00,
+		0x00, 0x05, 0xF5, 0x6F, 0x20, 0x00, 0x00, 0x5F, 0x56, 0xF2, 0x00, 0x00, 0x06, 0xF4, 0x6F, 0x20,
+		0x00, 0x00, 0xAF, 0x16, 0xF2, 0x00, 0x00, 0x5F, 0x90, 0x6F, 0x64, 0x56, 0xBF, 0xC1, 0x06, 0xFF,
+		0xFF, 0xDB, 0x50, 0x00, 0x4A, 0xAA, 0xAA, 0xA9, 0x06, 0xFB, 0xAA, 0xAA, 0x90, 0x6F, 0x20, 0x00,
+		0x00, 0x06, 0xF2, 0x00, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x06, 0xFE, 0xDD, 0xDD, 0x90, 0x6F,
+		0x87, 0x77, 0x75, 0x06, 0xF2, 0x00, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x06, 0xF2, 0x00, 0x00,
+		0x00, 0x6F, 0x65, 0x55, 0x55, 0x06, 0xFF, 0xFF, 0xFF, 0xF1, 0x4A, 0xAA, 0xAA, 0xA3, 0x6F, 0xBA,
+		0xAA, 0xA3, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0xED,
+		0xDD, 0xA0, 0x6F, 0x86, 0x66, 0x50, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20,
+		0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x00, 0x02, 0x8C, 0xDD, 0xA6, 0x10,
+		0x00, 0x5E, 0xE9, 0x77, 0xAF, 0xB0, 0x03, 0xFB, 0x10, 0x00, 0x01, 0x70, 0x0A, 0xF2, 0x00, 0x00,
+		0x00, 0x00, 0x0E, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x90, 0x00, 0x01, 0x11, 0x10, 0x1F, 0x90,
+		0x00, 0x1F, 0xFF, 0xF1, 0x0F, 0xA0, 0x00, 0x03, 0x39, 0xF1, 0x0C, 0xE0, 0x00, 0x00, 0x07, 0xF1,
+		0x05, 0xF7, 0x00, 0x00, 0x07, 0xF1, 0x00, 0xAF, 0x93, 0x12, 0x4B, 0xF1, 0x00, 0x07, 0xDF, 0xFF,
+		0xFB, 0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x4A, 0x10, 0x00, 0x01, 0xA5, 0x6F, 0x20, 0x00,
+		0x02, 0xF7, 0x6F, 0x20, 0x00, 0x02, 0xF7, 0x6F, 0x20, 0x00, 0x02, 0xF7, 0x6F, 0x20, 0x00, 0x02,
+		0xF7, 0x6F, 0xED, 0xDD, 0xDD, 0xF7, 0x6F, 0x87, 0x77, 0x78, 0xF7, 0x6F, 0x20, 0x00, 0x02, 0xF7,
+		0x6F, 0x20, 0x00, 0x02, 0xF7, 0x6F, 0x20, 0x00, 0x02, 0xF7, 0x6F, 0x20, 0x00, 0x02, 0xF7, 0x6F,
+		0x20, 0x00, 0x02, 0xF7, 0x4A, 0x16, 0xF2, 0x6F, 0x26, 0xF2, 0x6F, 0x26, 0xF2, 0x6F, 0x26, 0xF2,
+		0x6F, 0x26, 0xF2, 0x6F, 0x26, 0xF2, 0x04, 0xA1, 0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2,
+		0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2, 0x06, 0xF2, 0x07, 0xF2, 0x08, 0xF1,
+		0x4E, 0xB0, 0xFD, 0x20, 0x20, 0x00, 0x4A, 0x10, 0x00, 0x19, 0x96, 0xF2, 0x00, 0x1C, 0xE4, 0x6F,
+		0x20, 0x1C, 0xE3, 0x06, 0xF2, 0x2D, 0xD2, 0x00, 0x6F, 0x5D, 0xD2, 0x00, 0x06, 0xFE, 0xD1, 0x00,
+		0x00, 0x6F, 0xCF, 0x50, 0x00, 0x06, 0xF3, 0xBF, 0x50, 0x00, 0x6F, 0x20, 0xBF, 0x50, 0x06, 0xF2,
+		0x00, 0xBF, 0x50, 0x6F, 0x20, 0x00, 0xBF, 0x56, 0xF2, 0x00, 0x00, 0xBF, 0x4A, 0x10, 0x00, 0x00,
+		0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00,
+		0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00,
+		0x6F, 0x20, 0x00, 0x00, 0x6F, 0x65, 0x55, 0x54, 0x6F, 0xFF, 0xFF, 0xFC, 0x4A, 0xA1, 0x00, 0x00,
+		0x2A, 0xA2, 0x6F, 0xF5, 0x00, 0x00, 0x8F, 0xF4, 0x6F, 0xBB, 0x00, 0x00, 0xEB, 0xF4, 0x6F, 0x5F,
+		0x20, 0x05, 0xF6, 0xF4, 0x6F, 0x2D, 0x70, 0x0A, 0xA4, 0xF4, 0x6F, 0x17, 0xD0, 0x1F, 0x44, 0xF4,
+		0x6F, 0x12, 0xF3, 0x6E, 0x04, 0xF4, 0x6F, 0x10, 0xB9, 0xC8, 0x04, 0xF4, 0x6F, 0x10, 0x5F, 0xF3,
+		0x04, 0xF4, 0x6F, 0x10, 0x1B, 0xA0, 0x04, 0xF4, 0x6F, 0x10, 0x00, 0x00, 0x04, 0xF4, 0x6F, 0x10,
+		0x00, 0x00, 0x04, 0xF4, 0x4A, 0x90, 0x00, 0x01, 0xA4, 0x6F, 0xF5, 0x00, 0x02, 0xF6, 0x6F, 0xDC,
+		0x00, 0x02, 0xF6, 0x6F, 0x5F, 0x60, 0x02, 0xF6, 0x6F, 0x1B, 0xD0, 0x02, 0xF6, 0x6F, 0x13, 0xF6,
+		0x02, 0xF6, 0x6F, 0x10, 0xAE, 0x12, 0xF6, 0x6F, 0x10, 0x2F, 0x72, 0xF6, 0x6F, 0x10, 0x09, 0xE3,
+		0xF6, 0x6F, 0x10, 0x01, 0xEA, 0xF6, 0x6F, 0x10, 0x00, 0x8F, 0xF6, 0x6F, 0x10, 0x00, 0x1E, 0xF6,
+		0x00, 0x03, 0x9C, 0xDB, 0x71, 0x00, 0x00, 0x5F, 0xD8, 0x79, 0xFD, 0x10, 0x03, 0xFB, 0x10, 0x00,
+		0x3E, 0xB0, 0x0A, 0xF2, 0x00, 0x00, 0x08, 0xF4, 0x0E, 0xB0, 0x00, 0x00, 0x02, 0xF8, 0x1F, 0x90,
+		0x00, 0x00, 0x00, 0xFA, 0x1F, 0x90, 0x00, 0x00, 0x00, 0xFA, 0x0F, 0xA0, 0x00, 0x00, 0x01, 0xF9,
+		0x0C, 0xE0, 0x00, 0x00, 0x05, 0xF5, 0x05, 0xF7, 0x00, 0x00, 0x1D, 0xE1, 0x00, 0xAF, 0x82, 0x14,
+		0xCF, 0x40, 0x00, 0x07, 0xEF, 0xFF, 0xC4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x4A, 0xAA,
+		0xA8, 0x20, 0x06, 0xFA, 0x9A, 0xEF, 0x50, 0x6F, 0x20, 0x01, 0xDD, 0x06, 0xF2, 0x00, 0x09, 0xF1,
+		0x6F, 0x20, 0x00, 0xAF, 0x16, 0xF2, 0x00, 0x5F, 0xB0, 0x6F, 0xFF, 0xFF, 0xC2, 0x06, 0xF6, 0x54,
+		0x20, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x06, 0xF2, 0x00, 0x00, 0x00, 0x6F, 0x20, 0x00, 0x00, 0x06,
+		0xF2, 0x00, 0x00, 0x00, 0x00, 0x03, 0x9C, 0xDB, 0x71, 0x00, 0x00, 0x5F, 0xD8, 0x79, 0xFD, 0x10,
+		0x03, 0xFB, 0x10, 0x00, 0x3E, 0xB0, 0x0A, 0xF2, 0x00, 0x00, 0x08, 0xF4, 0x0E, 0xB0, 0x00, 0x00,
+		0x02, 0xF8, 0x1F, 0x90, 0x00, 0x00, 0x00, 0xFA, 0x1F, 0x90, 0x00, 0x00, 0x00, 0xFA, 0x0F, 0xA0,
+		0x00, 0x00, 0x01, 0xF9, 0x0C, 0xE0, 0x00, 0x00, 0x05, 0xF5, 0x05, 0xF7, 0x00, 0x00, 0x1D, 0xE1,
+		0x00, 0xAF, 0x82, 0x14, 0xCF, 0x40, 0x00, 0x07, 0xEF, 0xFF, 0xD3, 0x00, 0x00, 0x00, 0x03, 0x3B,
+		0xE3, 0x00, 0x00, 0x00, 0x00, 0x01, 0xCE, 0x20, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x4A, 0xAA,
+		0xA8, 0x30, 0x00, 0x6F, 0xA9, 0xAE, 0xF6, 0x00, 0x6F, 0x20, 0x01, 0xDE, 0x00, 0x6F, 0x20, 0x00,
+		0x9F, 0x10, 0x6F, 0x20, 0x00, 0xBF, 0x00, 0x6F, 0x53, 0x48, 0xF9, 0x00, 0x6F, 0xFF, 0xFF, 0x90,
+		0x00, 0x6F, 0x31, 0x29, 0xF4, 0x00, 0x6F, 0x20, 0x00, 0xCD, 0x00, 0x6F, 0x20, 0x00, 0x4F, 0x60,
+		0x6F, 0x20, 0x00, 0x0C, 0xD0, 0x6F, 0x20, 0x00, 0x04, 0xF6, 0x03, 0xAC, 0xDC, 0x93, 0x04, 0xFC,
+		0x76, 0x8C, 0x80, 0xCD, 0x00, 0x00, 0x01, 0x0E, 0xA0, 0x00, 0x00, 0x00, 0xCE, 0x40, 0x00, 0x00,
+		0x03, 0xEF, 0xEB, 0x72, 0x00, 0x01, 0x6A, 0xDF, 0xF6, 0x00, 0x00, 0x00, 0x3D, 0xE1, 0x00, 0x00,
+		0x00, 0x7F, 0x40, 0x00, 0x00, 0x07, 0xF3, 0xC8, 0x31, 0x26, 0xEC, 0x09, 0xEF, 0xFF, 0xFB, 0x20,
+		0x00, 0x23, 0x31, 0x00, 0x00, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0xAA, 0xAA, 0xFD, 0xAA, 0xA8, 0x00,
+		0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00,
+		0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA,
+		0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00,
+		0x00, 0x6A, 0x00, 0x00, 0x03, 0xA3, 0x9F, 0x00, 0x00, 0x04, 0xF5, 0x9F, 0x00, 0x00, 0x04, 0xF5,
+		0x9F, 0x00, 0x00, 0x04, 0xF5, 0x9F, 0x00, 0x00, 0x04, 0xF5, 0x9F, 0x00, 0x00, 0x04, 0xF5, 0x9F,
+		0x00, 0x00, 0x04, 0xF5, 0x9F, 0x00, 0x00, 0x04, 0xF5, 0x8F, 0x10, 0x00, 0x05, 0xF4, 0x5F, 0x50,
+		0x00, 0x09, 0xF1, 0x0D, 0xE5, 0x12, 0x7F, 0x90, 0x02, 0xBF, 0xFF, 0xF9, 0x00, 0x00, 0x01, 0x33,
+		0x10, 0x00, 0x79, 0x00, 0x00, 0x00, 0x09, 0x76, 0xF3, 0x00, 0x00, 0x04, 0xF6, 0x1F, 0x90, 0x00,
+		0x00, 0xAE, 0x10, 0xAE, 0x00, 0x00, 0x1E, 0x90, 0x04, 0xF5, 0x00, 0x06, 0xF3, 0x00, 0x0D, 0xB0,
+		0x00, 0xCD, 0x00, 0x00, 0x8F, 0x20, 0x2F, 0x70, 0x00, 0x02, 0xF7, 0x08, 0xF1, 0x00, 0x00, 0x0B,
+		0xD0, 0xDB, 0x00, 0x00, 0x00, 0x6F, 0x8F, 0x50, 0x00, 0x00, 0x01, 0xEF, 0xE0, 0x00, 0x00, 0x00,
+		0x09, 0xF8, 0x00, 0x00, 0x4A, 0x20, 0x00, 0x1A, 0x90, 0x00, 0x04, 0xA2, 0x3F, 0x60, 0x00, 0x4F,
+		0xF1, 0x00, 0x09, 0xF0, 0x0E, 0xA0, 0x00, 0x8C, 0xE5, 0x00, 0x0D, 0xB0, 0x0A, 0xE0, 0x00, 0xB8,
+		0xA9, 0x00, 0x1F, 0x80, 0x06, 0xF2, 0x01, 0xF4, 0x7D, 0x00, 0x5F, 0x40, 0x03, 0xF6, 0x04, 0xF1,
+		0x3F, 0x10, 0x9F, 0x00, 0x00, 0xEA, 0x08, 0xC0, 0x0E, 0x50, 0xDB, 0x00, 0x00, 0xAE, 0x0B, 0x80,
+		0x0B, 0x91, 0xF7, 0x00, 0x00, 0x6F, 0x3F, 0x40, 0x07, 0xC5, 0xF4, 0x00, 0x00, 0x3F, 0xAF, 0x10,
+		0x03, 0xFA, 0xF0, 0x00, 0x00, 0x0E, 0xFC, 0x00, 0x00, 0xEF, 0xB0, 0x00, 0x00, 0x0A, 0xF8, 0x00,
+		0x00, 0xBF, 0x70, 0x00, 0x08, 0x90, 0x00, 0x00, 0x79, 0x00, 0x3F, 0x70, 0x00, 0x4F, 0x60, 0x00,
+		0x8F, 0x30, 0x1D, 0xB0, 0x00, 0x00, 0xDC, 0x09, 0xE2, 0x00, 0x00, 0x03, 0xFB, 0xF6, 0x00, 0x00,
+		0x00, 0x09, 0xFB, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xC0, 0x00, 0x00, 0x00, 0x8F, 0x6F, 0x70, 0x00,
+		0x00, 0x3F, 0x80, 0x8F, 0x30, 0x00, 0x0C, 0xC0, 0x01, 0xDC, 0x00, 0x08, 0xF3, 0x00, 0x04, 0xF7,
+		0x03, 0xF8, 0x00, 0x00, 0x08, 0xF2, 0x89, 0x00, 0x00, 0x01, 0xA6, 0x4F, 0x70, 0x00, 0x0A, 0xE1,
+		0x09, 0xF2, 0x00, 0x5F, 0x50, 0x01, 0xDC, 0x01, 0xEA, 0x00, 0x00, 0x3F, 0x7A, 0xE1, 0x00, 0x00,
+		0x08, 0xFF, 0x50, 0x00, 0x00, 0x00, 0xEB, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00,
+		0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA, 0x00, 0x00, 0x00, 0x00, 0xEA,
+		0x00, 0x00, 0x1A, 0xAA, 0xAA, 0xAA, 0xAA, 0x11, 0xAA, 0xAA, 0xAA, 0xAF, 0xE1, 0x00, 0x00, 0x00,
+		0x09, 0xF4, 0x00, 0x00, 0x00, 0x06, 0xF7, 0x00, 0x00, 0x00, 0x03, 0xFA, 0x00, 0x00, 0x00, 0x02,
+		0xEC, 0x10, 0x00, 0x00, 0x00, 0xCE, 0x20, 0x00, 0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x00, 0x6F,
+		0x70, 0x00, 0x00, 0x00, 0x4F, 0x90, 0x00, 0x00, 0x00, 0x2E, 0xE5, 0x55, 0x55, 0x55, 0x14, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xF4, 0x12, 0x22, 0x9F, 0xEA, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00,
+		0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00, 0x9C, 0x00,
+		0x9F, 0xFA, 0x12, 0x21, 0x94, 0x00, 0x0A, 0xA0, 0x00, 0x5E, 0x10, 0x01, 0xF5, 0x00, 0x0B, 0x90,
+		0x00, 0x6E, 0x00, 0x01, 0xF3, 0x00, 0x0C, 0x80, 0x00, 0x7D, 0x00, 0x03, 0xF2, 0x00, 0x0D, 0x70,
+		0x00, 0x8C, 0x00, 0x04, 0xF0, 0x00, 0x07, 0x12, 0x22, 0x6E, 0xFD, 0x00, 0x9D, 0x00, 0x9D, 0x00,
+		0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00, 0x9D, 0x00,
+		0x9D, 0x00, 0x9D, 0x7F, 0xFD, 0x12, 0x21, 0x00, 0x00, 0x4A, 0x80, 0x00, 0x00, 0x00, 0x4E, 0xDF,
+		0x90, 0x00, 0x00, 0x3E, 0xA0, 0x5F, 0x90, 0x00, 0x3E, 0x90, 0x00, 0x4E, 0x80, 0x19, 0x70, 0x00,
+		0x00, 0x3A, 0x40, 0x55, 0x55, 0x55, 0x55, 0xCC, 0xCC, 0xCC, 0xCC, 0x4C, 0x20, 0x08, 0xC0, 0x00,
+		0xA9, 0x00, 0x02, 0x02, 0xAD, 0xED, 0x91, 0x00, 0x59, 0x54, 0x6E, 0xB0, 0x00, 0x00, 0x00, 0x4F,
+		0x20, 0x02, 0x68, 0x89, 0xF5, 0x05, 0xFD, 0xA9, 0xAF, 0x50, 0xDA, 0x00, 0x02, 0xF5, 0x0F, 0x60,
+		0x00, 0x6F, 0x50, 0xDC, 0x20, 0x4E, 0xF5, 0x03, 0xEF, 0xFD, 0x5F, 0x50, 0x00, 0x33, 0x00, 0x00,
+		0x12, 0x00, 0x00, 0x00, 0x08, 0xD0, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x00, 0x08, 0xD0, 0x00,
+		0x00, 0x00, 0x8D, 0x3B, 0xED, 0x60, 0x08, 0xED, 0x64, 0x9F, 0x60, 0x8F, 0x50, 0x00, 0xAE, 0x08,
+		0xF0, 0x00, 0x05, 0xF3, 0x8E, 0x00, 0x00, 0x3F, 0x48, 0xE0, 0x00, 0x04, 0xF3, 0x8F, 0x40, 0x00,
+		0x8E, 0x18, 0xFC, 0x20, 0x5F, 0x90, 0x8D, 0x6F, 0xFF, 0xA0, 0x00, 0x00, 0x13, 0x20, 0x00, 0x00,
+		0x2A, 0xEE, 0xC7, 0x03, 0xEC, 0x64, 0x69, 0x0B, 0xD1, 0x00, 0x00, 0x0F, 0x80, 0x00, 0x00, 0x2F,
+		0x60, 0x00, 0x00, 0x1F, 0x70, 0x00, 0x00, 0x0C, 0xC0, 0x00, 0x00, 0x05, 0xF9, 0x20, 0x26, 0x00,
+		0x6E, 0xFF, 0xFA, 0x00, 0x00, 0x33, 0x10, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0xBB,
+		0x00, 0x00, 0x00, 0x0B, 0xB0, 0x00, 0x00, 0x00, 0xBB, 0x00, 0x4C, 0xEC, 0x5B, 0xB0, 0x4F, 0xA4,
+		0x6D, 0xEB, 0x0B, 0xC0, 0x00, 0x3F, 0xB0, 0xF7, 0x00, 0x00, 0xDB, 0x1F, 0x60, 0x00, 0x0B, 0xB1,
+		0xF7, 0x00, 0x00, 0xCB, 0x0D, 0xB0, 0x00, 0x1F, 0xB0, 0x6F, 0x70, 0x2B, 0xFB, 0x00, 0x8F, 0xFF,
+		0x8B, 0xB0, 0x00, 0x13, 0x10, 0x00, 0x00, 0x2A, 0xEE, 0xB3, 0x00, 0x2E, 0xB5, 0x4A, 0xF3, 0x0B,
+		0xC0, 0x00, 0x0C, 0xB0, 0xF9, 0x22, 0x22, 0x9E, 0x2F, 0xFF, 0xFF, 0xFF, 0xE1, 0xF7, 0x00, 0x00,
+		0x00, 0x0C, 0xC0, 0x00, 0x00, 0x00, 0x5F, 0x92, 0x01, 0x56, 0x00, 0x5D, 0xFF, 0xFE, 0x70, 0x00,
+		0x02, 0x32, 0x00, 0x00, 0x01, 0x22, 0x00, 0x7F, 0xFE, 0x02, 0xF7, 0x11, 0x04, 0xF3, 0x00, 0x7C,
+		0xFC, 0xB6, 0x38, 0xF7, 0x53, 0x04, 0xF3, 0x00, 0x04, 0xF3, 0x00, 0x04, 0xF3, 0x00, 0x04, 0xF3,
+		0x00, 0x04, 0xF3, 0x00, 0x04, 0xF3, 0x00, 0x04, 0xF3, 0x00, 0x00, 0x5C, 0xEC, 0x58, 0x80, 0x4F,
+		0xA4, 0x5D, 0xEB, 0x0C, 0xC0, 0x00, 0x3F, 0xB1, 0xF7, 0x00, 0x00, 0xDB, 0x1F, 0x60, 0x00, 0x0B,
+		0xB1, 0xF7, 0x00, 0x00, 0xCB, 0x0C, 0xC0, 0x00, 0x2F, 0xB0, 0x5F, 0x94, 0x5D, 0xEB, 0x00, 0x5D,
+		0xFD, 0x5B, 0xA0, 0x00, 0x00, 0x00, 0xE8, 0x00, 0x40, 0x01, 0x9F, 0x30, 0x1F, 0xED, 0xFE, 0x60,
+		0x00, 0x14, 0x54, 0x10, 0x00, 0x12, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00,
+		0x00, 0x8D, 0x00, 0x00, 0x00, 0x8D, 0x2B, 0xED, 0x70, 0x8E, 0xD7, 0x59, 0xF5, 0x8F, 0x40, 0x00,
+		0xDA, 0x8E, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00,
+		0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x12, 0x7E, 0x59, 0x00, 0x6B, 0x7E, 0x7E,
+		0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x01, 0x20, 0x7E, 0x05, 0x90, 0x00, 0x06, 0xB0, 0x7E, 0x07,
+		0xE0, 0x7E, 0x07, 0xE0, 0x7E, 0x07, 0xE0, 0x7E, 0x07, 0xE0, 0x8E, 0x0B, 0xCE, 0xF5, 0x52, 0x00,
+		0x12, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x00,
+		0x8D, 0x00, 0x03, 0xB7, 0x8D, 0x00, 0x4E, 0x90, 0x8D, 0x06, 0xF7, 0x00, 0x8E, 0x8F, 0x50, 0x00,
+		0x8F, 0xFA, 0x00, 0x00, 0x8D, 0x5F, 0x90, 0x00, 0x8D, 0x04, 0xF9, 0x00, 0x8D, 0x00, 0x4E, 0xA0,
+		0x8D, 0x00, 0x03, 0xEA, 0x12, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E,
+		0x7E, 0x6A, 0x3B, 0xED, 0x50, 0x6D, 0xEB, 0x20, 0x8E, 0xD7, 0x5B, 0xF8, 0xC5, 0x6E, 0xC0, 0x8F,
+		0x40, 0x01, 0xFD, 0x10, 0x06, 0xF2, 0x8E, 0x00, 0x00, 0xD9, 0x00, 0x03, 0xF3, 0x8D, 0x00, 0x00,
+		0xD8, 0x00, 0x03, 0xF3, 0x8D, 0x00, 0x00, 0xD8, 0x00, 0x03, 0xF3, 0x8D, 0x00, 0x00, 0xD8, 0x00,
+		0x03, 0xF3, 0x8D, 0x00, 0x00, 0xD8, 0x00, 0x03, 0xF3, 0x8D, 0x00, 0x00, 0xD8, 0x00, 0x03, 0xF3,
+		0x6A, 0x2B, 0xED, 0x70, 0x8E, 0xD7, 0x59, 0xF5, 0x8F, 0x40, 0x00, 0xDA, 0x8E, 0x00, 0x00, 0xAC,
+		0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC, 0x8D, 0x00, 0x00, 0xAC,
+		0x8D, 0x00, 0x00, 0xAC, 0x00, 0x3B, 0xEE, 0xA2, 0x00, 0x3F, 0xB5, 0x5D, 0xE1, 0x0B, 0xD0, 0x00,
+		0x2F, 0x80, 0xF8, 0x00, 0x00, 0xBC, 0x1F, 0x60, 0x00, 0x09, 0xD1, 0xF7, 0x00, 0x00, 0xAD, 0x0D,
+		0xB0, 0x00, 0x1E, 0xA0, 0x6F, 0x71, 0x1A, 0xF3, 0x00, 0x7E, 0xFF, 0xE5, 0x00, 0x00, 0x13, 0x30,
+		0x00, 0x6A, 0x3B, 0xED, 0x60, 0x08, 0xED, 0x64, 0x9F, 0x60, 0x8F, 0x50, 0x00, 0xAE, 0x08, 0xF0,
+		0x00, 0x05, 0xF3, 0x8E, 0x00, 0x00, 0x3F, 0x48, 0xE0, 0x00, 0x04, 0xF3, 0x8F, 0x40, 0x00, 0x8E,
+		0x18, 0xFC, 0x20, 0x5F, 0x90, 0x8D, 0x6F, 0xFF, 0xA0, 0x08, 0xD0, 0x13, 0x20, 0x00, 0x8D, 0x00,
+		0x00, 0x00, 0x08, 0xD0, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4C, 0xEC, 0x58,
+		0x80, 0x4F, 0xA4, 0x6D, 0xEB, 0x0B, 0xC0, 0x00, 0x3F, 0xB0, 0xF7, 0x00, 0x00, 0xDB, 0x1F, 0x60,
+		0x00, 0x0B, 0xB1, 0xF7, 0x00, 0x00, 0xCB, 0x0D, 0xB0, 0x00, 0x1F, 0xB0, 0x6F, 0x70, 0x2B, 0xFB,
+		0x00, 0x8F, 0xFF, 0x8B, 0xB0, 0x00, 0x13, 0x10, 0xBB, 0x00, 0x00, 0x00, 0x0B, 0xB0, 0x00, 0x00,
+		0x00, 0xBB, 0x00, 0x00, 0x00, 0x04, 0x30, 0x6A, 0x3B, 0xE8, 0x8E, 0xD7, 0x54, 0x8F, 0x50, 0x00,
+		0x8F, 0x00, 0x00, 0x8E, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x8D, 0x00, 0x00, 0x8D,
+		0x00, 0x00, 0x02, 0xAD, 0xED, 0xA1, 0x0C, 0xC5, 0x45, 0x91, 0x1F, 0x50, 0x00, 0x00, 0x0D, 0xC5,
+		0x10, 0x00, 0x03, 0xCF, 0xFC, 0x50, 0x00, 0x01, 0x5B, 0xF4, 0x00, 0x00, 0x00, 0xF8, 0x28, 0x30,
+		0x05, 0xF5, 0x1D, 0xFF, 0xFF, 0x80, 0x00, 0x13, 0x31, 0x00, 0x02, 0x30, 0x00, 0x08, 0xE0, 0x00,
+		0x08, 0xE0, 0x00, 0x6D, 0xFB, 0xBA, 0x3A, 0xE5, 0x55, 0x08, 0xE0, 0x00, 0x08, 0xE0, 0x00, 0x08,
+		0xE0, 0x00, 0x08, 0xE0, 0x00, 0x07, 0xE0, 0x00, 0x05, 0xF6, 0x33, 0x00, 0x9E, 0xFD, 0x79, 0x00,
+		0x00, 0x88, 0xAC, 0x00, 0x00, 0xBA, 0xAC, 0x00, 0x00, 0xBA, 0xAC, 0x00, 0x00, 0xBA, 0xAC, 0x00,
+		0x00, 0xBA, 0xAC, 0x00, 0x00, 0xCA, 0x8E, 0x00, 0x01, 0xEA, 0x4F, 0x71, 0x2B, 0xEA, 0x09, 0xFF,
+		0xF7, 0xBA, 0x00, 0x13, 0x10, 0x00, 0x4B, 0x20, 0x00, 0x08, 0xA1, 0xF7, 0x00, 0x01, 0xE8, 0x0A,
+		0xD0, 0x00, 0x6F, 0x20, 0x5F, 0x30, 0x0B, 0xC0, 0x00, 0xE9, 0x02, 0xF6, 0x00, 0x08, 0xE0, 0x7E,
+		0x10, 0x00, 0x3F, 0x5D, 0xA0, 0x00, 0x00, 0xCD, 0xF4, 0x00, 0x00, 0x06, 0xFD, 0x00, 0x00, 0x3B,
+		0x20, 0x05, 0xB5, 0x00, 0x1B, 0x40, 0xF7, 0x00, 0x9F, 0xB0, 0x05, 0xF1, 0x0B, 0xB0, 0x0D, 0xAE,
+		0x00, 0x9C, 0x00, 0x7E, 0x02, 0xF2, 0xF4, 0x0D, 0x80, 0x03, 0xF3, 0x6D, 0x0B, 0x82, 0xF5, 0x00,
+		0x0E, 0x7A, 0x90, 0x7C, 0x6F, 0x10, 0x00, 0xAB, 0xE5, 0x03, 0xFB, 0xC0, 0x00, 0x06, 0xFF, 0x10,
+		0x0E, 0xF8, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0xBF, 0x40, 0x00, 0x1A, 0x80, 0x00, 0x2B, 0x60, 0x5F,
+		0x60, 0x0C, 0xC0, 0x00, 0x9E, 0x29, 0xE2, 0x00, 0x00, 0xCE, 0xF5, 0x00, 0x00, 0x05, 0xFC, 0x00,
+		0x00, 0x01, 0xDD, 0xF5, 0x00, 0x00, 0xAE, 0x19, 0xE2, 0x00, 0x6F, 0x40, 0x1C, 0xC0, 0x3F, 0x80,
+		0x00, 0x2E, 0x90, 0x4B, 0x20, 0x00, 0x08, 0x91, 0xE7, 0x00, 0x01, 0xE7, 0x09, 0xD0, 0x00, 0x6F,
+		0x20, 0x3F, 0x40, 0x0C, 0xA0, 0x00, 0xCA, 0x03, 0xF4, 0x00, 0x05, 0xF2, 0x9D, 0x00, 0x00, 0x0E,
+		0x8E, 0x70, 0x00, 0x00, 0x8F, 0xF1, 0x00, 0x00, 0x02, 0xFA, 0x00, 0x00, 0x00, 0x4F, 0x40, 0x00,
+		0x00, 0x0B, 0xD0, 0x00, 0x00, 0xAE, 0xF4, 0x00, 0x00, 0x04, 0x52, 0x00, 0x00, 0x00, 0x1B, 0xBB,
+		0xBB, 0xB8, 0x16, 0x66, 0x68, 0xF9, 0x00, 0x00, 0x1C, 0xD1, 0x00, 0x00, 0xBE, 0x20, 0x00, 0x09,
+		0xF3, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x05, 0xF7, 0x00, 0x00, 0x2E, 0xB2, 0x22, 0x22, 0x5F, 0xFF,
+		0xFF, 0xFB, 0x00, 0x00, 0x12, 0x00, 0x01, 0xCF, 0xF3, 0x00, 0x7F, 0x30, 0x00, 0x08, 0xD0, 0x00,
+		0x00, 0x9D, 0x00, 0x00, 0x09, 0xD0, 0x00, 0x00, 0xAC, 0x00, 0x05, 0x8F, 0x70, 0x00, 0xCE, 0xD2,
+		0x00, 0x00, 0x0D, 0xB0, 0x00, 0x00, 0x9D, 0x00, 0x00, 0x09, 0xD0, 0x00, 0x00, 0x9D, 0x00, 0x00,
+		0x08, 0xE0, 0x00, 0x00, 0x4F, 0xB8, 0x10, 0x00, 0x48, 0x92, 0x31, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5,
+		0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xF5, 0xB4, 0x21, 0x00, 0x00, 0x0F, 0xFD,
+		0x30, 0x00, 0x01, 0xD9, 0x00, 0x00, 0x0B, 0xB0, 0x00, 0x00, 0xAB, 0x00, 0x00, 0x0A, 0xB0, 0x00,
+		0x00, 0x9D, 0x00, 0x00, 0x04, 0xF9, 0x51, 0x00, 0x1B, 0xEC, 0x20, 0x08, 0xE1, 0x00, 0x00, 0xAC,
+		0x00, 0x00, 0x0A, 0xB0, 0x00, 0x00, 0xAB, 0x00, 0x00, 0x0C, 0xA0, 0x00, 0x8A, 0xF6, 0x00, 0x09,
+		0x85, 0x00, 0x00, 0x00, 0x13, 0x10, 0x00, 0x00, 0x11, 0x8E, 0xFF, 0xC6, 0x33, 0x9A, 0x5B, 0x42,
+		0x49, 0xEF, 0xFB, 0x31, 0x00, 0x00, 0x00, 0x21, 0x00, 0x05, 0xCC, 0x50, 0x3E, 0x44, 0xE3, 0x78,
+		0x00, 0x87, 0x5C, 0x11, 0xC4, 0x09, 0xEE, 0x80, 0x00, 0x10, 0x00,
+	],
+	replacement: b'?',
+};
@@ -0,0 +1,603 @@
+//! The DejaVuSans-Bold.ttf font at 20 pixels, with 4 bits per pixel.
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use super::super::{BitmapFont, BitsPerPixel, Glyph};
+
+#[rustfmt::skip]
+pub static SANS_BOLD_20: BitmapFont = BitmapFont {
+	line_height: 24,
+	baseline: 19,
+	bits_per_pixel: BitsPerPixel::Four,
+	glyphs: &[
+		Glyph { char: b' ', offset: 0, width: 0, height: 0, left: 0, top: 0, advance: 7 },
+		Glyph { char: b'!', offset: 0, width: 5, height: 15, left: 2, top: 4, advance: 9 },
+		Glyph { char: b'\"', offset: 38, width: 8, height: 6, left: 1, top: 4, advance: 10 },
+		Glyph { char: b'#', offset: 62, width: 15, height: 15, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'$', offset: 175, width: 12, height: 19, left: 1, top: 3, advance: 14 },
+		Glyph { char: b'%', offset: 289, width: 20, height: 16, left: 0, top: 4, advance: 20 },
+		Glyph { char: b'&', offset: 449, width: 16, height: 16, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'\'', offset: 577, width: 4, height: 6, left: 1, top: 4, advance: 6 },
+		Glyph { char: b'(', offset: 589, width: 7, height: 19, left: 1, top: 3, advance: 9 },
+		Glyph { char: b')', offset: 656, width: 7, height: 19, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'*', offset: 723, width: 10, height: 10, left: 0, top: 4, advance: 10 },
+		Glyph { char: b'+', offset: 773, width: 13, height: 13, left: 2, top: 6, advance: 17 },
+		Glyph { char: b',', offset: 858, width: 5, height: 7, left: 1, top: 15, advance: 8 },
+		Glyph { char: b'-', offset: 876, width: 7, height: 4, left: 1, top: 11, advance: 8 },
+		Glyph { char: b'.', offset: 890, width: 4, height: 4, left: 2, top: 15, advance: 8 },
+		Glyph { char: b'/', offset: 898, width: 7, height: 17, left: 0, top: 4, advance: 7 },
+		Glyph { char: b'0', offset: 958, width: 13, height: 16, left: 0, top: 4, advance: 14 },
+		Glyph { char: b'1', offset: 1062, width: 11, height: 15, left: 2, top: 4, advance: 14 },
+		Glyph { char: b'2', offset: 1145, width: 12, height: 15, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'3', offset: 1235, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'4', offset: 1331, width: 13, height: 15, left: 0, top: 4, advance: 14 },
+		Glyph { char: b'5', offset: 1429, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'6', offset: 1525, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'7', offset: 1621, width: 12, height: 15, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'8', offset: 1711, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'9', offset: 1807, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b':', offset: 1903, width: 4, height: 11, left: 2, top: 8, advance: 8 },
+		Glyph { char: b';', offset: 1925, width: 5, height: 14, left: 1, top: 8, advance: 8 },
+		Glyph { char: b'<', offset: 1960, width: 13, height: 12, left: 2, top: 7, advance: 17 },
+		Glyph { char: b'=', offset: 2038, width: 13, height: 8, left: 2, top: 9, advance: 17 },
+		Glyph { char: b'>', offset: 2090, width: 13, height: 12, left: 2, top: 7, advance: 17 },
+		Glyph { char: b'?', offset: 2168, width: 10, height: 15, left: 1, top: 4, advance: 12 },
+		Glyph { char: b'@', offset: 2243, width: 18, height: 19, left: 1, top: 4, advance: 20 },
+		Glyph { char: b'A', offset: 2414, width: 15, height: 15, left: 0, top: 4, advance: 15 },
+		Glyph { char: b'B', offset: 2527, width: 13, height: 15, left: 1, top: 4, advance: 15 },
+		Glyph { char: b'C', offset: 2625, width: 13, height: 16, left: 1, top: 4, advance: 15 },
+		Glyph { char: b'D', offset: 2729, width: 15, height: 15, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'E', offset: 2842, width: 12, height: 15, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'F', offset: 2932, width: 11, height: 15, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'G', offset: 3015, width: 14, height: 16, left: 1, top: 4, advance: 16 },
+		Glyph { char: b'H', offset: 3127, width: 14, height: 15, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'I', offset: 3232, width: 5, height: 15, left: 1, top: 4, advance: 7 },
+		Glyph { char: b'J', offset: 3270, width: 6, height: 19, left: 0, top: 4, advance: 7 },
+		Glyph { char: b'K', offset: 3327, width: 14, height: 15, left: 1, top: 4, advance: 15 },
+		Glyph { char: b'L', offset: 3432, width: 12, height: 15, left: 1, top: 4, advance: 13 },
+		Glyph { char: b'M', offset: 3522, width: 18, height: 15, left: 1, top: 4, advance: 20 },
+		Glyph { char: b'N', offset: 3657, width: 14, height: 15, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'O', offset: 3762, width: 15, height: 16, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'P', offset: 3882, width: 13, height: 15, left: 1, top: 4, advance: 15 },
+		Glyph { char: b'Q', offset: 3980, width: 15, height: 18, left: 1, top: 4, advance: 17 },
+		Glyph { char: b'R', offset: 4115, width: 14, height: 15, left: 1, top: 4, advance: 15 },
+		Glyph { char: b'S', offset: 4220, width: 12, height: 16, left: 1, top: 4, advance: 14 },
+		Glyph { char: b'T', offset: 4316, width: 14, height: 15, left: 0, top: 4, advance: 14 },
+		Glyph { char: b'U', offset: 4421, width: 14, height: 16, left: 1, top: 4, advance: 16 },
+		Glyph { char: b'V', offset: 4533, width: 15, height: 15, left: 0, top: 4, advance: 15 },
+		Glyph { char: b'W', offset: 4646, width: 22, height: 15, left: 0, top: 4, advance: 22 },
+		Glyph { char: b'X', offset: 4811, width: 15, height: 15, left: 0, top: 4, advance: 15 },
+		Glyph { char: b'Y', offset: 4924, width: 14, height: 15, left: 0, top: 4, advance: 14 },
+		Glyph { char: b'Z', offset: 5029, width: 14, height: 15, left: 0, top: 4, advance: 15 },
+		Glyph { char: b'[', offset: 5134, width: 7, height: 19, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'\\', offset: 5201, width: 7, height: 17, left: 0, top: 4, advance: 7 },
+		Glyph { char: b']', offset: 5261, width: 7, height: 19, left: 1, top: 3, advance: 9 },
+		Glyph { char: b'^', offset: 5328, width: 13, height: 6, left: 2, top: 4, advance: 17 },
+		Glyph { char: b'_', offset: 5367, width: 10, height: 3, left: 0, top: 21, advance: 10 },
+		Glyph { char: b'`', offset: 5382, width: 6, height: 4, left: 1, top: 3, advance: 10 },
+		Glyph { char: b'a', offset: 5394, width: 12, height: 13, left: 0, top: 7, advance: 13 },
+		Glyph { char: b'b', offset: 5472, width: 13, height: 17, left: 1, top: 3, advance: 14 },
+		Glyph { char: b'c', offset: 5583, width: 11, height: 13, left: 0, top: 7, advance: 12 },
+		Glyph { char: b'd', offset: 5655, width: 13, height: 17, left: 0, top: 3, advance: 14 },
+		Glyph { char: b'e', offset: 5766, width: 13, height: 13, left: 0, top: 7, advance: 14 },
+		Glyph { char: b'f', offset: 5851, width: 9, height: 16, left: 0, top: 3, advance: 9 },
+		Glyph { char: b'g', offset: 5923, width: 13, height: 17, left: 0, top: 7, advance: 14 },
+		Glyph { char: b'h', offset: 6034, width: 12, height: 16, left: 1, top: 3, advance: 14 },
+		Glyph { char: b'i', offset: 6130, width: 5, height: 16, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'j', offset: 6170, width: 6, height: 21, left: 0, top: 3, advance: 7 },
+		Glyph { char: b'k', offset: 6233, width: 12, height: 16, left: 1, top: 3, advance: 13 },
+		Glyph { char: b'l', offset: 6329, width: 5, height: 16, left: 1, top: 3, advance: 7 },
+		Glyph { char: b'm', offset: 6369, width: 19, height: 12, left: 1, top: 7, advance: 21 },
+		Glyph { char: b'n', offset: 6483, width: 12, height: 12, left: 1, top: 7, advance: 14 },
+		Glyph { char: b'o', offset: 6555, width: 13, height: 13, left: 0, top: 7, advance: 14 },
+		Glyph { char: b'p', offset: 6640, width: 13, height: 17, left: 1, top: 7, advance: 14 },
+		Glyph { char: b'q', offset: 6751, width: 13, height: 17, left: 0, top: 7, advance: 14 },
+		Glyph { char: b'r', offset: 6862, width: 9, height: 12, left: 1, top: 7, advance: 10 },
+		Glyph { char: b's', offset: 6916, width: 10, height: 13, left: 1, top: 7, advance: 12 },
+		Glyph { char: b't', offset: 6981, width: 10, height: 15, left: 0, top: 4, advance: 10 },
+		Glyph { char: b'u', offset: 7056, width: 12, height: 12, left: 1, top: 8, advance: 14 },
+		Glyph { char: b'v', offset: 7128, width: 13, height: 11, left: 0, top: 8, advance: 13 },
+		Glyph { char: b'w', offset: 7200, width: 18, height: 11, left: 0, top: 8, advance: 18 },
+		Glyph { char: b'x', offset: 7299, width: 13, height: 11, left: 0, top: 8, advance: 13 },
+		Glyph { char: b'y', offset: 7371, width: 13, height: 16, left: 0, top: 8, advance: 13 },
+		Glyph { char: b'z', offset: 7475, width: 11, height: 11, left: 0, top: 8, advance: 12 },
+		Glyph { char: b'{', offset: 7536, width: 10, height: 20, left: 2, top: 3, advance: 14 },
+		Glyph { char: b'|', offset: 7636, width: 3, height: 21, left: 2, top: 3, advance: 7 },
+		Glyph { char: b'}', offset: 7668, width: 10, height: 20, left: 2, top: 3, advance: 14 },
+		Glyph { char: b'~', offset: 7768, width: 13, height: 5, left: 2, top: 10, advance: 17 },
+		Glyph { char: 0xB0, offset: 7801, width: 8, height: 7, left: 1, top: 4, advance: 10 },
+	],
+	atlas: &[
+		0x29, 0x99, 0x33, 0xFF, 0xF5, 0x3F, 0xFF, 0x53, 0xFF, 0xF5, 0x3F, 0xFF, 0x53, 0xFF, 0xF5, 0x2F,
+		0xFF, 0x40, 0xFF, 0xF2, 0x0D, 0xFF, 0x00, 0xCF, 0xD0, 0x01, 0x11, 0x01, 0x77, 0x72, 0x3F, 0xFF,
+		0x53, 0xFF, 0xF5, 0x3F, 0xFF, 0x50, 0x19, 0x92, 0x07, 0x94, 0x1F, 0xF3, 0x0C, 0xF8, 0x1F, 0xF3,
+		0x0C, 0xF8, 0x1F, 0xF3, 0x0C, 0xF8, 0x1F, 0xF3, 0x0C, 0xF8, 0x1D, 0xD3, 0x0A, 0xD6, 0x00, 0x00,
+		0x02, 0x55, 0x00, 0x35, 0x30, 0x00, 0x00, 0x00, 0x7F, 0xA0, 0x0B, 0xF6, 0x00, 0x00, 0x00, 0x0B,
+		0xF7, 0x00, 0xEF, 0x30, 0x00, 0x00, 0x00, 0xFF, 0x30, 0x4F, 0xE0, 0x00, 0x05, 0x99, 0xAF, 0xF9,
+		0x9B, 0xFD, 0x99, 0x40, 0x8F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF6, 0x04, 0x77, 0xEF, 0xA7, 0x8F,
+		0xF8, 0x77, 0x30, 0x00, 0x1F, 0xF2, 0x04, 0xFE, 0x00, 0x00, 0x00, 0x04, 0xFE, 0x00, 0x8F, 0xA0,
+		0x00, 0x08, 0xDD, 0xEF, 0xED, 0xDE, 0xFE, 0xDD, 0x30, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF4,
+		0x02, 0x44, 0xFF, 0x64, 0x7F, 0xE4, 0x44, 0x10, 0x00, 0x3F, 0xE0, 0x07, 0xFA, 0x00, 0x00, 0x00,
+		0x07, 0xFA, 0x00, 0xBF, 0x70, 0x00, 0x00, 0x00, 0xBF, 0x70, 0x0E, 0xF3, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xB0, 0x00,
+		0x00, 0x00, 0x5A, 0xDF, 0xFE, 0xB9, 0x40, 0x09, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x3F, 0xFF, 0x7D,
+		0xC5, 0x7B, 0xA0, 0x6F, 0xFB, 0x0C, 0xB0, 0x00, 0x10, 0x6F, 0xFE, 0x5D, 0xB0, 0x00, 0x00, 0x2F,
+		0xFF, 0xFF, 0xFC, 0x83, 0x00, 0x05, 0xEF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x15, 0x9E, 0xFF, 0xFF,
+		0xF4, 0x00, 0x00, 0x0C, 0xC3, 0xDF, 0xF8, 0x21, 0x00, 0x0C, 0xB0, 0x9F, 0xF8, 0x6F, 0xA7, 0x4D,
+		0xC6, 0xEF, 0xF5, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xB0, 0x16, 0xAD, 0xEF, 0xFE, 0xB6, 0x00, 0x00,
+		0x00, 0x0C, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xB0, 0x00,
+		0x00, 0x00, 0x5B, 0xCB, 0x60, 0x00, 0x00, 0x0A, 0xD5, 0x00, 0x00, 0x08, 0xFF, 0xDF, 0xF9, 0x00,
+		0x00, 0x5F, 0xD0, 0x00, 0x00, 0x1F, 0xF7, 0x06, 0xFF, 0x20, 0x01, 0xDF, 0x40, 0x00, 0x00, 0x5F,
+		0xF2, 0x01, 0xFF, 0x60, 0x08, 0xFA, 0x00, 0x00, 0x00, 0x5F, 0xF2, 0x01, 0xFF, 0x60, 0x2F, 0xE1,
+		0x00, 0x00, 0x00, 0x2F, 0xF5, 0x04, 0xFF, 0x40, 0xBF, 0x70, 0x00, 0x00, 0x00, 0x0B, 0xFE, 0x9D,
+		0xFC, 0x05, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x01, 0x9E, 0xFF, 0xA1, 0x1D, 0xF4, 0x03, 0x8A, 0x83,
+		0x00, 0x00, 0x00, 0x10, 0x00, 0x8F, 0x90, 0x6F, 0xFF, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x02, 0xFE,
+		0x11, 0xFF, 0x91, 0x9F, 0xF1, 0x00, 0x00, 0x00, 0x0B, 0xF6, 0x05, 0xFF, 0x20, 0x2F, 0xF5, 0x00,
+		0x00, 0x00, 0x5F, 0xC0, 0x06, 0xFF, 0x10, 0x1F, 0xF6, 0x00, 0x00, 0x01, 0xDF, 0x30, 0x04, 0xFF,
+		0x30, 0x4F, 0xF4, 0x00, 0x00, 0x08, 0xF9, 0x00, 0x00, 0xDF, 0xC7, 0xCF, 0xD0, 0x00, 0x00, 0x2F,
+		0xE1, 0x00, 0x00, 0x2C, 0xFF, 0xFC, 0x20, 0x00, 0x00, 0x24, 0x30, 0x00, 0x00, 0x00, 0x34, 0x20,
+		0x00, 0x00, 0x00, 0x6A, 0xCC, 0xB9, 0x40, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFF, 0xC0, 0x00,
+		0x00, 0x00, 0x6F, 0xFF, 0xB8, 0xAD, 0xC0, 0x00, 0x00, 0x00, 0x9F, 0xFE, 0x00, 0x00, 0x30, 0x00,
+		0x00, 0x00, 0x7F, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0xFF, 0xD1, 0x00, 0x00, 0x00,
+		0x00, 0x01, 0xBF, 0xFF, 0xFC, 0x10, 0x02, 0xBB, 0xA0, 0x0B, 0xFF, 0xEF, 0xFF, 0xB0, 0x04, 0xFF,
+		0xC0, 0x6F, 0xFE, 0x27, 0xFF, 0xFA, 0x08, 0xFF, 0x80, 0xBF, 0xFA, 0x00, 0x8F, 0xFF, 0xAE, 0xFF,
+		0x30, 0xCF, 0xFA, 0x00, 0x0A, 0xFF, 0xFF, 0xFB, 0x00, 0xAF, 0xFE, 0x20, 0x00, 0xBF, 0xFF, 0xE2,
+		0x00, 0x5F, 0xFF, 0xD6, 0x46, 0xDF, 0xFF, 0xF5, 0x00, 0x09, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0x40, 0x00, 0x6D, 0xFF, 0xFF, 0xD7, 0x2D, 0xFF, 0xE3, 0x00, 0x00, 0x24, 0x42, 0x00, 0x00, 0x00,
+		0x00, 0x19, 0x92, 0x1F, 0xF3, 0x1F, 0xF3, 0x1F, 0xF3, 0x1F, 0xF3, 0x1D, 0xD3, 0x00, 0x01, 0x33,
+		0x10, 0x00, 0xBF, 0xF3, 0x00, 0x5F, 0xFB, 0x00, 0x0C, 0xFF, 0x40, 0x03, 0xFF, 0xE0, 0x00, 0x9F,
+		0xF9, 0x00, 0x0D, 0xFF, 0x60, 0x01, 0xFF, 0xF3, 0x00, 0x3F, 0xFF, 0x10, 0x04, 0xFF, 0xF1, 0x00,
+		0x4F, 0xFF, 0x10, 0x02, 0xFF, 0xF2, 0x00, 0x0E, 0xFF, 0x50, 0x00, 0xBF, 0xF8, 0x00, 0x06, 0xFF,
+		0xC0, 0x00, 0x1E, 0xFF, 0x20, 0x00, 0x8F, 0xF8, 0x00, 0x01, 0xEF, 0xE1, 0x00, 0x05, 0xAA, 0x40,
+		0x13, 0x31, 0x00, 0x01, 0xEF, 0xD0, 0x00, 0x09, 0xFF, 0x70, 0x00, 0x2F, 0xFE, 0x00, 0x00, 0xCF,
+		0xF5, 0x00, 0x07, 0xFF, 0xB0, 0x00, 0x4F, 0xFF, 0x10, 0x01, 0xFF, 0xF3, 0x00, 0x0E, 0xFF, 0x50,
+		0x00, 0xEF, 0xF6, 0x00, 0x0E, 0xFF, 0x60, 0x00, 0xFF, 0xF4, 0x00, 0x2F, 0xFF, 0x20, 0x06, 0xFF,
+		0xD0, 0x00, 0xAF, 0xF8, 0x00, 0x1E, 0xFF, 0x20, 0x06, 0xFF, 0xA0, 0x00, 0xCF, 0xF3, 0x00, 0x2A,
+		0xA7, 0x00, 0x00, 0x00, 0x00, 0x8D, 0x10, 0x00, 0x01, 0x00, 0x9F, 0x10, 0x01, 0x4E, 0x81, 0x9F,
+		0x14, 0xCB, 0x2A, 0xFE, 0xDF, 0xBF, 0xD6, 0x00, 0x3C, 0xFF, 0xF7, 0x10, 0x02, 0x9F, 0xFF, 0xFD,
+		0x50, 0x5E, 0xF9, 0xAF, 0x6D, 0xFB, 0x2A, 0x20, 0x9F, 0x10, 0x67, 0x00, 0x00, 0x9F, 0x10, 0x00,
+		0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x00, 0x06, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x80,
+		0x00, 0x00, 0x00, 0x00, 0x0C, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x80, 0x00, 0x00, 0x00,
+		0x00, 0x0C, 0xF8, 0x00, 0x00, 0x06, 0x66, 0x66, 0xDF, 0xB6, 0x66, 0x64, 0xDF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xAC, 0xDD, 0xDD, 0xFF, 0xED, 0xDD, 0xD8, 0x00, 0x00, 0x0C, 0xF8, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0xCF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF,
+		0x80, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF8, 0x00, 0x00, 0x00, 0x0B, 0xCC, 0x70, 0xEF, 0xF8, 0x0E,
+		0xFF, 0x81, 0xFF, 0xF5, 0x5F, 0xFA, 0x09, 0xFE, 0x10, 0xAD, 0x50, 0x00, 0x23, 0x33, 0x33, 0x1E,
+		0xFF, 0xFF, 0xF3, 0xEF, 0xFF, 0xFF, 0x39, 0xAA, 0xAA, 0xA2, 0xBC, 0xC7, 0xEF, 0xF8, 0xEF, 0xF8,
+		0xEF, 0xF8, 0x00, 0x00, 0x08, 0x90, 0x00, 0x03, 0xFE, 0x00, 0x00, 0x8F, 0xA0, 0x00, 0x0C, 0xF5,
+		0x00, 0x02, 0xFF, 0x10, 0x00, 0x7F, 0xB0, 0x00, 0x0B, 0xF6, 0x00, 0x01, 0xFF, 0x10, 0x00, 0x6F,
+		0xC0, 0x00, 0x0B, 0xF7, 0x00, 0x01, 0xFF, 0x20, 0x00, 0x5F, 0xD0, 0x00, 0x0A, 0xF8, 0x00, 0x00,
+		0xEF, 0x30, 0x00, 0x4F, 0xD0, 0x00, 0x09, 0xF9, 0x00, 0x00, 0xBD, 0x40, 0x00, 0x00, 0x00, 0x00,
+		0x6A, 0xCC, 0xA5, 0x00, 0x00, 0x01, 0xCF, 0xFF, 0xFF, 0xFB, 0x10, 0x00, 0xBF, 0xFF, 0xCC, 0xFF,
+		0xFA, 0x00, 0x4F, 0xFF, 0x70, 0x08, 0xFF, 0xF3, 0x0A, 0xFF, 0xF1, 0x00, 0x2F, 0xFF, 0x80, 0xDF,
+		0xFD, 0x00, 0x00, 0xEF, 0xFC, 0x0F, 0xFF, 0xB0, 0x00, 0x0D, 0xFF, 0xE0, 0xFF, 0xFB, 0x00, 0x00,
+		0xCF, 0xFE, 0x0F, 0xFF, 0xB0, 0x00, 0x0C, 0xFF, 0xE0, 0xEF, 0xFC, 0x00, 0x00, 0xDF, 0xFD, 0x0B,
+		0xFF, 0xE0, 0x00, 0x0F, 0xFF, 0xA0, 0x7F, 0xFF, 0x30, 0x05, 0xFF, 0xF6, 0x01, 0xEF, 0xFD, 0x56,
+		0xDF, 0xFD, 0x10, 0x05, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x04, 0xCF, 0xFF, 0xFC, 0x30, 0x00,
+		0x00, 0x00, 0x24, 0x42, 0x00, 0x00, 0x02, 0x58, 0x99, 0x92, 0x00, 0x0B, 0xFF, 0xFF, 0xFF, 0x30,
+		0x00, 0xBF, 0xFF, 0xFF, 0xF3, 0x00, 0x07, 0x63, 0x6F, 0xFF, 0x30, 0x00, 0x00, 0x05, 0xFF, 0xF3,
+		0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x5F, 0xFF,
+		0x30, 0x00, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x05, 0xFF,
+		0xF3, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x69, 0x9B, 0xFF, 0xFA, 0x99, 0x5A, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xF8, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0x04, 0x7A, 0xCC, 0xC9, 0x50, 0x00, 0x6F,
+		0xFF, 0xFF, 0xFF, 0xFB, 0x10, 0x6F, 0xFE, 0xBC, 0xFF, 0xFF, 0x90, 0x6B, 0x40, 0x00, 0x5F, 0xFF,
+		0xE0, 0x10, 0x00, 0x00, 0x0C, 0xFF, 0xF1, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xE0, 0x00, 0x00, 0x00,
+		0x3F, 0xFF, 0x90, 0x00, 0x00, 0x03, 0xEF, 0xFD, 0x10, 0x00, 0x00, 0x4E, 0xFF, 0xD2, 0x00, 0x00,
+		0x05, 0xFF, 0xFC, 0x10, 0x00, 0x00, 0x7F, 0xFF, 0xA1, 0x00, 0x00, 0x09, 0xFF, 0xF9, 0x00, 0x00,
+		0x00, 0x6F, 0xFF, 0xFB, 0xBB, 0xBB, 0xB2, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0x6F, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xF3, 0x05, 0x9B, 0xCC, 0xCA, 0x61, 0x00, 0x1F, 0xFF, 0xFF, 0xFF, 0xFD, 0x20, 0x1F,
+		0xFC, 0xBC, 0xFF, 0xFF, 0xB0, 0x05, 0x10, 0x00, 0x3F, 0xFF, 0xE0, 0x00, 0x00, 0x00, 0x0C, 0xFF,
+		0xE0, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0x90, 0x00, 0x2B, 0xBC, 0xFF, 0xFA, 0x10, 0x00, 0x2F, 0xFF,
+		0xFF, 0xD6, 0x00, 0x00, 0x2B, 0xBC, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x2D, 0xFF, 0xF2, 0x00,
+		0x00, 0x00, 0x07, 0xFF, 0xF4, 0x20, 0x00, 0x00, 0x0A, 0xFF, 0xF4, 0xAD, 0x85, 0x45, 0xAF, 0xFF,
+		0xE1, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0x50, 0x6D, 0xFF, 0xFF, 0xFF, 0xB4, 0x00, 0x00, 0x13, 0x44,
+		0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49, 0x99, 0x80, 0x00, 0x00, 0x00, 0x1E, 0xFF, 0xFE, 0x00,
+		0x00, 0x00, 0x0A, 0xFF, 0xFF, 0xE0, 0x00, 0x00, 0x05, 0xFF, 0xEF, 0xFE, 0x00, 0x00, 0x01, 0xEF,
+		0xAA, 0xFF, 0xE0, 0x00, 0x00, 0xAF, 0xE1, 0xAF, 0xFE, 0x00, 0x00, 0x5F, 0xF5, 0x0A, 0xFF, 0xE0,
+		0x00, 0x1E, 0xFA, 0x00, 0xAF, 0xFE, 0x00, 0x0A, 0xFE, 0x10, 0x0A, 0xFF, 0xE0, 0x01, 0xFF, 0xA6,
+		0x66, 0xCF, 0xFE, 0x66, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0x05, 0x55, 0x55, 0x5B, 0xFF, 0xE5, 0x50, 0x00, 0x00, 0x00, 0xAF, 0xFE, 0x00, 0x00, 0x00,
+		0x00, 0x0A, 0xFF, 0xE0, 0x00, 0x08, 0x99, 0x99, 0x99, 0x99, 0x40, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF,
+		0x70, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF, 0x70, 0x0D, 0xFF, 0x43, 0x33, 0x33, 0x10, 0x0D, 0xFF, 0x20,
+		0x00, 0x00, 0x00, 0x0D, 0xFF, 0xAC, 0xBA, 0x50, 0x00, 0x0D, 0xFF, 0xFF, 0xFF, 0xFC, 0x20, 0x0D,
+		0xFE, 0xCC, 0xFF, 0xFF, 0xC0, 0x06, 0x20, 0x00, 0x2D, 0xFF, 0xF4, 0x00, 0x00, 0x00, 0x05, 0xFF,
+		0xF7, 0x00, 0x00, 0x00, 0x04, 0xFF, 0xF8, 0x42, 0x00, 0x00, 0x09, 0xFF, 0xF6, 0x7F, 0xB7, 0x55,
+		0xAF, 0xFF, 0xE1, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0x50, 0x3A, 0xEF, 0xFF, 0xFF, 0xB4, 0x00, 0x00,
+		0x02, 0x44, 0x31, 0x00, 0x00, 0x00, 0x01, 0x6A, 0xCC, 0xB8, 0x40, 0x00, 0x3D, 0xFF, 0xFF, 0xFF,
+		0xD0, 0x03, 0xEF, 0xFF, 0xBA, 0xBE, 0xD0, 0x0C, 0xFF, 0xD3, 0x00, 0x00, 0x40, 0x3F, 0xFF, 0x40,
+		0x00, 0x00, 0x00, 0x8F, 0xFE, 0x27, 0x99, 0x61, 0x00, 0xAF, 0xFE, 0xEF, 0xFF, 0xFE, 0x40, 0xBF,
+		0xFF, 0xFE, 0xDF, 0xFF, 0xE2, 0xBF, 0xFF, 0xB0, 0x04, 0xFF, 0xF9, 0xAF, 0xFF, 0x50, 0x00, 0xDF,
+		0xFC, 0x7F, 0xFF, 0x40, 0x00, 0xCF, 0xFC, 0x3F, 0xFF, 0x60, 0x00, 0xEF, 0xFA, 0x0B, 0xFF, 0xD3,
+		0x28, 0xFF, 0xF5, 0x02, 0xEF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x2B, 0xFF, 0xFF, 0xE7, 0x00, 0x00,
+		0x00, 0x14, 0x42, 0x00, 0x00, 0x69, 0x99, 0x99, 0x99, 0x99, 0x93, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xF5, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF4, 0x23, 0x33, 0x33, 0x3D, 0xFF, 0xD0, 0x00, 0x00, 0x00,
+		0x4F, 0xFF, 0x60, 0x00, 0x00, 0x00, 0xAF, 0xFE, 0x10, 0x00, 0x00, 0x02, 0xFF, 0xF8, 0x00, 0x00,
+		0x00, 0x09, 0xFF, 0xF1, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x8F, 0xFF, 0x20,
+		0x00, 0x00, 0x01, 0xEF, 0xFA, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xF4, 0x00, 0x00, 0x00, 0x0D, 0xFF,
+		0xC0, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x50, 0x00, 0x00, 0x00, 0xCF, 0xFD, 0x00, 0x00, 0x00, 0x00,
+		0x38, 0xBC, 0xCB, 0x82, 0x00, 0x07, 0xFF, 0xFF, 0xFF, 0xFF, 0x60, 0x3F, 0xFF, 0xE9, 0x9E, 0xFF,
+		0xF1, 0x6F, 0xFF, 0x50, 0x06, 0xFF, 0xF5, 0x6F, 0xFF, 0x20, 0x04, 0xFF, 0xF5, 0x2F, 0xFF, 0x80,
+		0x09, 0xFF, 0xE1, 0x04, 0xEF, 0xFE, 0xEF, 0xFD, 0x30, 0x01, 0x8F, 0xFF, 0xFF, 0xE7, 0x00, 0x1D,
+		0xFF, 0xD8, 0x8E, 0xFF, 0xC0, 0x8F, 0xFE, 0x10, 0x02, 0xFF, 0xF6, 0xBF, 0xFC, 0x00, 0x00, 0xEF,
+		0xFA, 0xBF, 0xFD, 0x00, 0x00, 0xEF, 0xF9, 0x8F, 0xFF, 0x92, 0x2A, 0xFF, 0xF6, 0x1D, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xC0, 0x02, 0xAE, 0xFF, 0xFF, 0xE9, 0x10, 0x00, 0x00, 0x34, 0x43, 0x00, 0x00, 0x00,
+		0x27, 0xBC, 0xC9, 0x30, 0x00, 0x05, 0xEF, 0xFF, 0xFF, 0xF7, 0x00, 0x3F, 0xFF, 0xD8, 0xBF, 0xFF,
+		0x50, 0xAF, 0xFE, 0x10, 0x0B, 0xFF, 0xD0, 0xDF, 0xFB, 0x00, 0x06, 0xFF, 0xF4, 0xEF, 0xFA, 0x00,
+		0x06, 0xFF, 0xF7, 0xCF, 0xFD, 0x00, 0x09, 0xFF, 0xF9, 0x7F, 0xFF, 0xB6, 0x8F, 0xFF, 0xF9, 0x1C,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x01, 0x8E, 0xFF, 0xD7, 0xFF, 0xF7, 0x00, 0x00, 0x11, 0x03, 0xFF,
+		0xF4, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xD0, 0x0C, 0x74, 0x35, 0xBF, 0xFF, 0x50, 0x0F, 0xFF, 0xFF,
+		0xFF, 0xF8, 0x00, 0x0C, 0xFF, 0xFF, 0xFC, 0x50, 0x00, 0x00, 0x13, 0x44, 0x20, 0x00, 0x00, 0xBE,
+		0xEB, 0xBF, 0xFB, 0xBF, 0xFB, 0xAD, 0xDA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9C, 0xC9, 0xBF,
+		0xFB, 0xBF, 0xFB, 0xBF, 0xFB, 0x0B, 0xEE, 0xB0, 0xBF, 0xFB, 0x0B, 0xFF, 0xB0, 0xAD, 0xDA, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9C, 0xC9, 0x0B, 0xFF, 0xB0, 0xBF, 0xFB, 0x0D, 0xFF,
+		0x82, 0xFF, 0xD1, 0x6F, 0xF4, 0x08, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x80, 0x00,
+		0x00, 0x00, 0x17, 0xCF, 0xFA, 0x00, 0x00, 0x15, 0xBF, 0xFF, 0xFD, 0x60, 0x04, 0xAE, 0xFF, 0xFE,
+		0x94, 0x00, 0x8E, 0xFF, 0xFE, 0x94, 0x00, 0x00, 0x0D, 0xFF, 0xC5, 0x00, 0x00, 0x00, 0x00, 0xDF,
+		0xFF, 0xC7, 0x20, 0x00, 0x00, 0x01, 0x6B, 0xFF, 0xFF, 0xC7, 0x20, 0x00, 0x00, 0x02, 0x7C, 0xFF,
+		0xFF, 0xC6, 0x10, 0x00, 0x00, 0x03, 0x8D, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x04, 0x9E, 0xA0,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6D, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFA, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x2D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xDF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xA2, 0x22, 0x22, 0x22, 0x22, 0x22, 0x21, 0xA7, 0x10, 0x00, 0x00, 0x00, 0x00,
+		0x0D, 0xFF, 0xB5, 0x10, 0x00, 0x00, 0x00, 0x9E, 0xFF, 0xFE, 0xA4, 0x00, 0x00, 0x00, 0x05, 0xAE,
+		0xFF, 0xFE, 0x83, 0x00, 0x00, 0x00, 0x15, 0xAF, 0xFF, 0xFD, 0x50, 0x00, 0x00, 0x00, 0x16, 0xEF,
+		0xFA, 0x00, 0x00, 0x00, 0x38, 0xDF, 0xFF, 0x90, 0x00, 0x38, 0xDF, 0xFF, 0xEA, 0x40, 0x28, 0xDF,
+		0xFF, 0xFB, 0x61, 0x00, 0x0D, 0xFF, 0xFC, 0x72, 0x00, 0x00, 0x00, 0xDD, 0x83, 0x00, 0x00, 0x00,
+		0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x9C, 0xCC, 0x93, 0x00, 0x9F, 0xFF, 0xFF,
+		0xFF, 0x60, 0x9F, 0xEC, 0xCF, 0xFF, 0xF1, 0x86, 0x10, 0x07, 0xFF, 0xF4, 0x00, 0x00, 0x04, 0xFF,
+		0xF4, 0x00, 0x00, 0x1C, 0xFF, 0xE1, 0x00, 0x01, 0xBF, 0xFF, 0x50, 0x00, 0x0B, 0xFF, 0xF6, 0x00,
+		0x00, 0x6F, 0xFF, 0x60, 0x00, 0x00, 0x9F, 0xFE, 0x00, 0x00, 0x00, 0x11, 0x11, 0x00, 0x00, 0x00,
+		0x47, 0x77, 0x00, 0x00, 0x00, 0x9F, 0xFE, 0x00, 0x00, 0x00, 0x9F, 0xFE, 0x00, 0x00, 0x00, 0x9F,
+		0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xAE,
+		0xFF, 0xEC, 0x71, 0x00, 0x00, 0x00, 0x02, 0xBF, 0xFE, 0xCC, 0xDF, 0xFE, 0x60, 0x00, 0x00, 0x3E,
+		0xFC, 0x40, 0x00, 0x02, 0x8F, 0xF7, 0x00, 0x01, 0xEF, 0x70, 0x00, 0x00, 0x00, 0x03, 0xEF, 0x50,
+		0x09, 0xF8, 0x00, 0x17, 0x97, 0x16, 0x71, 0x3F, 0xD0, 0x1F, 0xD0, 0x01, 0xDF, 0xFF, 0xDE, 0xF2,
+		0x0A, 0xF4, 0x6F, 0x80, 0x0A, 0xFD, 0x44, 0xDF, 0xF2, 0x06, 0xF7, 0x9F, 0x40, 0x0E, 0xF5, 0x00,
+		0x4F, 0xF2, 0x04, 0xF9, 0xAF, 0x20, 0x1F, 0xF2, 0x00, 0x1F, 0xF2, 0x05, 0xF8, 0xAF, 0x30, 0x1F,
+		0xF3, 0x00, 0x2F, 0xF2, 0x09, 0xF5, 0x8F, 0x60, 0x0D, 0xF9, 0x00, 0x8F, 0xF2, 0x4F, 0xD0, 0x4F,
+		0xB0, 0x05, 0xFF, 0xCC, 0xFF, 0xFC, 0xFE, 0x30, 0x0D, 0xF4, 0x00, 0x6E, 0xFE, 0x7D, 0xFE, 0xA2,
+		0x00, 0x05, 0xFD, 0x20, 0x00, 0x21, 0x02, 0x20, 0x00, 0x00, 0x00, 0x8F, 0xE5, 0x00, 0x00, 0x00,
+		0x4C, 0x20, 0x00, 0x00, 0x08, 0xFF, 0xD8, 0x66, 0x8C, 0xFF, 0x70, 0x00, 0x00, 0x00, 0x3B, 0xFF,
+		0xFF, 0xFF, 0xC5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x77, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x05, 0x99, 0x99, 0x10, 0x00, 0x00, 0x00, 0x00, 0xDF, 0xFF, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x4F,
+		0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xFF, 0xFF, 0x20, 0x00, 0x00, 0x01, 0xEF, 0xF9,
+		0xFF, 0xF7, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0x3A, 0xFF, 0xD0, 0x00, 0x00, 0x0B, 0xFF, 0xC0, 0x5F,
+		0xFF, 0x30, 0x00, 0x02, 0xFF, 0xF7, 0x01, 0xEF, 0xF9, 0x00, 0x00, 0x7F, 0xFF, 0x20, 0x0A, 0xFF,
+		0xE0, 0x00, 0x0D, 0xFF, 0xE5, 0x55, 0x9F, 0xFF, 0x50, 0x04, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB,
+		0x00, 0x9F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1, 0x1E, 0xFF, 0xC5, 0x55, 0x55, 0x7F, 0xFF, 0x75,
+		0xFF, 0xF6, 0x00, 0x00, 0x00, 0xEF, 0xFC, 0xBF, 0xFF, 0x10, 0x00, 0x00, 0x09, 0xFF, 0xF0, 0x19,
+		0x99, 0x99, 0x98, 0x73, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x30, 0x2F, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFD, 0x02, 0xFF, 0xF9, 0x00, 0x2D, 0xFF, 0xF2, 0x2F, 0xFF, 0x90, 0x00, 0x8F, 0xFF, 0x32,
+		0xFF, 0xF9, 0x00, 0x1C, 0xFF, 0xE1, 0x2F, 0xFF, 0xFE, 0xEF, 0xFF, 0xF6, 0x02, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFC, 0x30, 0x2F, 0xFF, 0xD9, 0x9A, 0xEF, 0xFE, 0x32, 0xFF, 0xF9, 0x00, 0x03, 0xFF, 0xFA,
+		0x2F, 0xFF, 0x90, 0x00, 0x0E, 0xFF, 0xC2, 0xFF, 0xF9, 0x00, 0x03, 0xFF, 0xFC, 0x2F, 0xFF, 0xC8,
+		0x89, 0xEF, 0xFF, 0x82, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xC1, 0x2F, 0xFF, 0xFF, 0xFF, 0xEB, 0x70,
+		0x00, 0x00, 0x00, 0x49, 0xBC, 0xCB, 0x72, 0x00, 0x03, 0xCF, 0xFF, 0xFF, 0xFF, 0xF5, 0x03, 0xEF,
+		0xFF, 0xFE, 0xEF, 0xFF, 0x61, 0xDF, 0xFF, 0xB3, 0x00, 0x14, 0xB6, 0x6F, 0xFF, 0xC0, 0x00, 0x00,
+		0x00, 0x1B, 0xFF, 0xF4, 0x00, 0x00, 0x00, 0x00, 0xEF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xFF,
+		0xE0, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xF2, 0x00, 0x00,
+		0x00, 0x00, 0x9F, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x03, 0xFF, 0xFF, 0x50, 0x00, 0x00, 0x44, 0x08,
+		0xFF, 0xFF, 0xB8, 0x79, 0xDF, 0x60, 0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0xF6, 0x00, 0x04, 0xBF, 0xFF,
+		0xFF, 0xE9, 0x20, 0x00, 0x00, 0x13, 0x44, 0x20, 0x00, 0x19, 0x99, 0x99, 0x87, 0x52, 0x00, 0x00,
+		0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFC, 0x50, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0x02,
+		0xFF, 0xFB, 0x44, 0x7C, 0xFF, 0xFF, 0x50, 0x2F, 0xFF, 0x90, 0x00, 0x07, 0xFF, 0xFE, 0x02, 0xFF,
+		0xF9, 0x00, 0x00, 0x0C, 0xFF, 0xF4, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x7F, 0xFF, 0x72, 0xFF, 0xF9,
+		0x00, 0x00, 0x05, 0xFF, 0xF8, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x6F, 0xFF, 0x82, 0xFF, 0xF9, 0x00,
+		0x00, 0x09, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x02, 0xFF, 0xFF, 0x22, 0xFF, 0xF9, 0x00, 0x15,
+		0xEF, 0xFF, 0x90, 0x2F, 0xFF, 0xED, 0xDF, 0xFF, 0xFF, 0xD1, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xB1, 0x00, 0x2F, 0xFF, 0xFF, 0xED, 0xC9, 0x40, 0x00, 0x00, 0x19, 0x99, 0x99, 0x99, 0x99, 0x90,
+		0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0x2F, 0xFF, 0xB4, 0x44,
+		0x44, 0x40, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0x90, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0x90, 0x2F, 0xFF, 0xEC, 0xCC, 0xCC, 0x70,
+		0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00,
+		0x00, 0x00, 0x2F, 0xFF, 0xED, 0xDD, 0xDD, 0xD2, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0x2F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xF3, 0x19, 0x99, 0x99, 0x99, 0x99, 0x92, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x2F,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0xFF, 0xFB, 0x44, 0x44, 0x44, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x02,
+		0xFF, 0xF9, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0x92, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9,
+		0x2F, 0xFF, 0xEC, 0xCC, 0xCC, 0x72, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00,
+		0x02, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x02, 0xFF, 0xF9, 0x00, 0x00,
+		0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0xBC, 0xCB, 0x96, 0x20, 0x00, 0x2C,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xF5, 0x03, 0xEF, 0xFF, 0xFF, 0xEE, 0xFF, 0xF6, 0x1D, 0xFF, 0xFC, 0x40,
+		0x00, 0x26, 0xC6, 0x6F, 0xFF, 0xC0, 0x00, 0x00, 0x00, 0x01, 0xBF, 0xFF, 0x40, 0x00, 0x00, 0x00,
+		0x00, 0xEF, 0xFF, 0x00, 0x00, 0x11, 0x11, 0x11, 0xFF, 0xFE, 0x00, 0x00, 0xEF, 0xFF, 0xFE, 0xFF,
+		0xFE, 0x00, 0x00, 0xEF, 0xFF, 0xFE, 0xDF, 0xFF, 0x20, 0x00, 0x66, 0xCF, 0xFE, 0x9F, 0xFF, 0x80,
+		0x00, 0x00, 0x9F, 0xFE, 0x3F, 0xFF, 0xF5, 0x00, 0x00, 0x9F, 0xFE, 0x08, 0xFF, 0xFF, 0xC8, 0x78,
+		0xDF, 0xFE, 0x00, 0x8F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x04, 0xBF, 0xFF, 0xFF, 0xFC, 0x82,
+		0x00, 0x00, 0x01, 0x34, 0x43, 0x10, 0x00, 0x19, 0x99, 0x50, 0x00, 0x00, 0x79, 0x98, 0x2F, 0xFF,
+		0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00,
+		0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF,
+		0xFE, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x2F,
+		0xFF, 0xEC, 0xCC, 0xCC, 0xFF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90,
+		0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00,
+		0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xFE,
+		0x19, 0x99, 0x52, 0xFF, 0xF9, 0x2F, 0xFF, 0x92, 0xFF, 0xF9, 0x2F, 0xFF, 0x92, 0xFF, 0xF9, 0x2F,
+		0xFF, 0x92, 0xFF, 0xF9, 0x2F, 0xFF, 0x92, 0xFF, 0xF9, 0x2F, 0xFF, 0x92, 0xFF, 0xF9, 0x2F, 0xFF,
+		0x92, 0xFF, 0xF9, 0x2F, 0xFF, 0x90, 0x01, 0x99, 0x95, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02,
+		0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF,
+		0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x02, 0xFF, 0xF9, 0x03, 0xFF, 0xF9,
+		0x04, 0xFF, 0xF8, 0x1C, 0xFF, 0xF5, 0xFF, 0xFF, 0xE1, 0xFF, 0xFE, 0x40, 0xFD, 0x92, 0x00, 0x19,
+		0x99, 0x50, 0x00, 0x02, 0x99, 0x98, 0x2F, 0xFF, 0x90, 0x00, 0x3E, 0xFF, 0xF5, 0x2F, 0xFF, 0x90,
+		0x03, 0xEF, 0xFF, 0x40, 0x2F, 0xFF, 0x90, 0x3E, 0xFF, 0xF4, 0x00, 0x2F, 0xFF, 0x93, 0xEF, 0xFE,
+		0x40, 0x00, 0x2F, 0xFF, 0xCE, 0xFF, 0xE4, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFE, 0x40, 0x00, 0x00,
+		0x2F, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x2F, 0xFF,
+		0xCE, 0xFF, 0xF8, 0x00, 0x00, 0x2F, 0xFF, 0x93, 0xEF, 0xFF, 0x80, 0x00, 0x2F, 0xFF, 0x90, 0x3E,
+		0xFF, 0xF9, 0x00, 0x2F, 0xFF, 0x90, 0x03, 0xEF, 0xFF, 0x90, 0x2F, 0xFF, 0x90, 0x00, 0x3E, 0xFF,
+		0xF9, 0x2F, 0xFF, 0x90, 0x00, 0x03, 0xEF, 0xFF, 0x19, 0x99, 0x50, 0x00, 0x00, 0x00, 0x2F, 0xFF,
+		0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00,
+		0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00,
+		0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF,
+		0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00,
+		0x2F, 0xFF, 0xED, 0xDD, 0xDD, 0xD2, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0x2F, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xF3, 0x19, 0x99, 0x96, 0x00, 0x00, 0x00, 0x79, 0x99, 0x90, 0x2F, 0xFF, 0xFF, 0x10, 0x00,
+		0x03, 0xFF, 0xFF, 0xF1, 0x2F, 0xFF, 0xFF, 0x80, 0x00, 0x09, 0xFF, 0xFF, 0xF1, 0x2F, 0xFF, 0xFF,
+		0xE0, 0x00, 0x1E, 0xFF, 0xFF, 0xF1, 0x2F, 0xFF, 0xEF, 0xF5, 0x00, 0x7F, 0xFE, 0xFF, 0xF1, 0x2F,
+		0xFF, 0x8F, 0xFC, 0x00, 0xDF, 0xE8, 0xFF, 0xF1, 0x2F, 0xFF, 0x6A, 0xFF, 0x35, 0xFF, 0x97, 0xFF,
+		0xF1, 0x2F, 0xFF, 0x64, 0xFF, 0xAB, 0xFF, 0x27, 0xFF, 0xF1, 0x2F, 0xFF, 0x60, 0xCF, 0xFF, 0xFB,
+		0x07, 0xFF, 0xF1, 0x2F, 0xFF, 0x60, 0x6F, 0xFF, 0xF4, 0x07, 0xFF, 0xF1, 0x2F, 0xFF, 0x60, 0x1E,
+		0xFF, 0xD0, 0x07, 0xFF, 0xF1, 0x2F, 0xFF, 0x60, 0x08, 0xFF, 0x70, 0x07, 0xFF, 0xF1, 0x2F, 0xFF,
+		0x60, 0x01, 0x33, 0x10, 0x07, 0xFF, 0xF1, 0x2F, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xF1,
+		0x2F, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xF1, 0x19, 0x99, 0x92, 0x00, 0x00, 0x69, 0x98,
+		0x2F, 0xFF, 0xF9, 0x00, 0x00, 0xAF, 0xFE, 0x2F, 0xFF, 0xFF, 0x20, 0x00, 0xAF, 0xFE, 0x2F, 0xFF,
+		0xFF, 0xA0, 0x00, 0xAF, 0xFE, 0x2F, 0xFF, 0xFF, 0xF3, 0x00, 0xAF, 0xFE, 0x2F, 0xFF, 0xBF, 0xFB,
+		0x00, 0xAF, 0xFE, 0x2F, 0xFF, 0x6C, 0xFF, 0x40, 0xAF, 0xFE, 0x2F, 0xFF, 0x64, 0xFF, 0xC0, 0xAF,
+		0xFE, 0x2F, 0xFF, 0x60, 0xBF, 0xF5, 0xAF, 0xFE, 0x2F, 0xFF, 0x60, 0x3F, 0xFD, 0xAF, 0xFE, 0x2F,
+		0xFF, 0x60, 0x0A, 0xFF, 0xEF, 0xFE, 0x2F, 0xFF, 0x60, 0x02, 0xFF, 0xFF, 0xFE, 0x2F, 0xFF, 0x60,
+		0x00, 0x9F, 0xFF, 0xFE, 0x2F, 0xFF, 0x60, 0x00, 0x2F, 0xFF, 0xFE, 0x2F, 0xFF, 0x60, 0x00, 0x08,
+		0xFF, 0xFE, 0x00, 0x01, 0x6A, 0xCD, 0xCA, 0x61, 0x00, 0x00, 0x04, 0xDF, 0xFF, 0xFF, 0xFF, 0xD4,
+		0x00, 0x05, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xF5, 0x01, 0xEF, 0xFF, 0x81, 0x01, 0x8F, 0xFF, 0xE1,
+		0x7F, 0xFF, 0x90, 0x00, 0x00, 0x9F, 0xFF, 0x7B, 0xFF, 0xF3, 0x00, 0x00, 0x03, 0xFF, 0xFB, 0xEF,
+		0xFF, 0x00, 0x00, 0x00, 0x0E, 0xFF, 0xEF, 0xFF, 0xE0, 0x00, 0x00, 0x00, 0xDF, 0xFF, 0xFF, 0xFE,
+		0x00, 0x00, 0x00, 0x0E, 0xFF, 0xED, 0xFF, 0xF1, 0x00, 0x00, 0x01, 0xFF, 0xFD, 0x9F, 0xFF, 0x60,
+		0x00, 0x00, 0x6F, 0xFF, 0x93, 0xFF, 0xFE, 0x20, 0x00, 0x2E, 0xFF, 0xF3, 0x0A, 0xFF, 0xFE, 0x97,
+		0x9E, 0xFF, 0xFA, 0x00, 0x0B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB, 0x00, 0x00, 0x06, 0xCF, 0xFF, 0xFF,
+		0xC6, 0x00, 0x00, 0x00, 0x00, 0x13, 0x43, 0x10, 0x00, 0x00, 0x19, 0x99, 0x99, 0x98, 0x74, 0x00,
+		0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x30, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x22, 0xFF, 0xFA,
+		0x22, 0x4C, 0xFF, 0xF8, 0x2F, 0xFF, 0x90, 0x00, 0x2F, 0xFF, 0xC2, 0xFF, 0xF9, 0x00, 0x01, 0xFF,
+		0xFC, 0x2F, 0xFF, 0x90, 0x00, 0x8F, 0xFF, 0xA2, 0xFF, 0xFE, 0xCC, 0xDF, 0xFF, 0xF4, 0x2F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xF8, 0x02, 0xFF, 0xFF, 0xEE, 0xEC, 0x93, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00,
+		0x00, 0x02, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x02, 0xFF,
+		0xF9, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x6A, 0xCD,
+		0xCA, 0x61, 0x00, 0x00, 0x04, 0xDF, 0xFF, 0xFF, 0xFF, 0xE5, 0x00, 0x05, 0xFF, 0xFF, 0xFE, 0xFF,
+		0xFF, 0xF5, 0x01, 0xEF, 0xFF, 0x81, 0x01, 0x8F, 0xFF, 0xE1, 0x7F, 0xFF, 0x90, 0x00, 0x00, 0x9F,
+		0xFF, 0x7B, 0xFF, 0xF3, 0x00, 0x00, 0x03, 0xFF, 0xFB, 0xEF, 0xFF, 0x00, 0x00, 0x00, 0x0E, 0xFF,
+		0xEF, 0xFF, 0xE0, 0x00, 0x00, 0x00, 0xDF, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x00, 0x0E, 0xFF, 0xED,
+		0xFF, 0xF1, 0x00, 0x00, 0x01, 0xFF, 0xFC, 0x9F, 0xFF, 0x60, 0x00, 0x00, 0x6F, 0xFF, 0x93, 0xFF,
+		0xFD, 0x20, 0x00, 0x2E, 0xFF, 0xF3, 0x0A, 0xFF, 0xFE, 0x97, 0x9E, 0xFF, 0xF8, 0x00, 0x0A, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x05, 0xCF, 0xFF, 0xFF, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x13,
+		0x4C, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2D, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x2D, 0xEE, 0x60, 0x19, 0x99, 0x99, 0x98, 0x63, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xC1,
+		0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB, 0x00, 0x2F, 0xFF, 0xA2, 0x26, 0xFF, 0xFF, 0x10, 0x2F,
+		0xFF, 0x90, 0x00, 0xAF, 0xFF, 0x20, 0x2F, 0xFF, 0x90, 0x00, 0xAF, 0xFF, 0x10, 0x2F, 0xFF, 0xA2,
+		0x26, 0xFF, 0xF9, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x00, 0x2F, 0xFF, 0xFF, 0xFF, 0xFD,
+		0x30, 0x00, 0x2F, 0xFF, 0xC7, 0x9F, 0xFF, 0xE3, 0x00, 0x2F, 0xFF, 0x90, 0x05, 0xFF, 0xFB, 0x00,
+		0x2F, 0xFF, 0x90, 0x00, 0xBF, 0xFF, 0x40, 0x2F, 0xFF, 0x90, 0x00, 0x3F, 0xFF, 0xC0, 0x2F, 0xFF,
+		0x90, 0x00, 0x0B, 0xFF, 0xF4, 0x2F, 0xFF, 0x90, 0x00, 0x04, 0xFF, 0xFB, 0x00, 0x28, 0xBC, 0xCB,
+		0xA7, 0x30, 0x05, 0xEF, 0xFF, 0xFF, 0xFF, 0xF0, 0x2E, 0xFF, 0xFE, 0xDE, 0xFF, 0xF0, 0x6F, 0xFF,
+		0x40, 0x00, 0x26, 0xB0, 0x8F, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0x93, 0x00, 0x00, 0x00,
+		0x3F, 0xFF, 0xFF, 0xFC, 0x83, 0x00, 0x07, 0xFF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x39, 0xDF, 0xFF,
+		0xFF, 0xF7, 0x00, 0x00, 0x02, 0x6B, 0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFE, 0x57, 0x10,
+		0x00, 0x00, 0xCF, 0xFD, 0x7F, 0xFC, 0x87, 0x7C, 0xFF, 0xF9, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xE2,
+		0x28, 0xCF, 0xFF, 0xFF, 0xFA, 0x20, 0x00, 0x01, 0x34, 0x43, 0x10, 0x00, 0x89, 0x99, 0x99, 0x99,
+		0x99, 0x99, 0x95, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF8, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xF8, 0x44, 0x44, 0x5F, 0xFF, 0xC4, 0x44, 0x42, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00,
+		0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F,
+		0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0,
+		0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00,
+		0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00,
+		0x1F, 0xFF, 0xB0, 0x00, 0x00, 0x19, 0x99, 0x50, 0x00, 0x03, 0x99, 0x94, 0x2F, 0xFF, 0x90, 0x00,
+		0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x05, 0xFF,
+		0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x05, 0xFF, 0xF6, 0x2F,
+		0xFF, 0x90, 0x00, 0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90,
+		0x00, 0x05, 0xFF, 0xF6, 0x2F, 0xFF, 0x90, 0x00, 0x06, 0xFF, 0xF6, 0x1F, 0xFF, 0xB0, 0x00, 0x07,
+		0xFF, 0xF5, 0x0D, 0xFF, 0xE2, 0x00, 0x0C, 0xFF, 0xF2, 0x07, 0xFF, 0xFD, 0x88, 0xCF, 0xFF, 0xB0,
+		0x01, 0xCF, 0xFF, 0xFF, 0xFF, 0xFE, 0x20, 0x00, 0x18, 0xEF, 0xFF, 0xFF, 0xA2, 0x00, 0x00, 0x00,
+		0x02, 0x44, 0x30, 0x00, 0x00, 0x79, 0x98, 0x00, 0x00, 0x00, 0x04, 0x99, 0x98, 0xFF, 0xF4, 0x00,
+		0x00, 0x00, 0xCF, 0xFE, 0x2F, 0xFF, 0x90, 0x00, 0x00, 0x2F, 0xFF, 0x90, 0xBF, 0xFE, 0x10, 0x00,
+		0x08, 0xFF, 0xF4, 0x06, 0xFF, 0xF5, 0x00, 0x00, 0xDF, 0xFD, 0x00, 0x1E, 0xFF, 0xB0, 0x00, 0x3F,
+		0xFF, 0x70, 0x00, 0xAF, 0xFF, 0x10, 0x09, 0xFF, 0xF2, 0x00, 0x04, 0xFF, 0xF6, 0x00, 0xEF, 0xFB,
+		0x00, 0x00, 0x0D, 0xFF, 0xC0, 0x4F, 0xFF, 0x60, 0x00, 0x00, 0x8F, 0xFF, 0x2A, 0xFF, 0xE1, 0x00,
+		0x00, 0x03, 0xFF, 0xF8, 0xEF, 0xFA, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x00,
+		0x00, 0x6F, 0xFF, 0xFF, 0xD0, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0x00,
+		0x0A, 0xFF, 0xFF, 0x20, 0x00, 0x00, 0x39, 0x99, 0x20, 0x00, 0x07, 0x99, 0x80, 0x00, 0x02, 0x99,
+		0x93, 0x2F, 0xFF, 0x70, 0x00, 0x1F, 0xFF, 0xF1, 0x00, 0x06, 0xFF, 0xF3, 0x0E, 0xFF, 0xA0, 0x00,
+		0x4F, 0xFF, 0xF5, 0x00, 0x0A, 0xFF, 0xE0, 0x0A, 0xFF, 0xE0, 0x00, 0x8F, 0xFF, 0xF9, 0x00, 0x0D,
+		0xFF, 0xB0, 0x07, 0xFF, 0xF3, 0x00, 0xBF, 0xDD, 0xFC, 0x00, 0x2F, 0xFF, 0x70, 0x03, 0xFF, 0xF6,
+		0x00, 0xEF, 0xA9, 0xFF, 0x10, 0x5F, 0xFF, 0x40, 0x00, 0xEF, 0xFA, 0x03, 0xFF, 0x66, 0xFF, 0x40,
+		0x9F, 0xFF, 0x00, 0x00, 0xBF, 0xFD, 0x07, 0xFF, 0x32, 0xFF, 0x80, 0xCF, 0xFC, 0x00, 0x00, 0x7F,
+		0xFF, 0x2A, 0xFE, 0x00, 0xDF, 0xB1, 0xFF, 0xF8, 0x00, 0x00, 0x4F, 0xFF, 0x5E, 0xFA, 0x00, 0xAF,
+		0xE5, 0xFF, 0xF4, 0x00, 0x00, 0x0F, 0xFF, 0xBF, 0xF7, 0x00, 0x6F, 0xFB, 0xFF, 0xF1, 0x00, 0x00,
+		0x0C, 0xFF, 0xFF, 0xF3, 0x00, 0x3F, 0xFF, 0xFF, 0xC0, 0x00, 0x00, 0x08, 0xFF, 0xFF, 0xE0, 0x00,
+		0x0E, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x04, 0xFF, 0xFF, 0xB0, 0x00, 0x0A, 0xFF, 0xFF, 0x50, 0x00,
+		0x00, 0x01, 0xFF, 0xFF, 0x80, 0x00, 0x07, 0xFF, 0xFF, 0x20, 0x00, 0x29, 0x99, 0x60, 0x00, 0x00,
+		0x39, 0x99, 0x50, 0xAF, 0xFF, 0x40, 0x00, 0x0C, 0xFF, 0xE2, 0x01, 0xEF, 0xFD, 0x10, 0x08, 0xFF,
+		0xF6, 0x00, 0x05, 0xFF, 0xF9, 0x03, 0xFF, 0xFB, 0x00, 0x00, 0x0A, 0xFF, 0xF5, 0xDF, 0xFE, 0x20,
+		0x00, 0x00, 0x1D, 0xFF, 0xFF, 0xFF, 0x50, 0x00, 0x00, 0x00, 0x4F, 0xFF, 0xFF, 0xA0, 0x00, 0x00,
+		0x00, 0x00, 0xBF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00,
+		0x1D, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x00, 0x08, 0xFF, 0xF7, 0xEF, 0xFD, 0x10, 0x00, 0x04, 0xFF,
+		0xFA, 0x04, 0xFF, 0xFA, 0x00, 0x01, 0xDF, 0xFE, 0x10, 0x09, 0xFF, 0xF5, 0x00, 0x9F, 0xFF, 0x50,
+		0x00, 0x1D, 0xFF, 0xE1, 0x4F, 0xFF, 0xA0, 0x00, 0x00, 0x4F, 0xFF, 0xA0, 0x89, 0x99, 0x10, 0x00,
+		0x00, 0x59, 0x99, 0x7F, 0xFF, 0x90, 0x00, 0x02, 0xFF, 0xFE, 0x0C, 0xFF, 0xF4, 0x00, 0x0C, 0xFF,
+		0xF5, 0x03, 0xFF, 0xFD, 0x00, 0x6F, 0xFF, 0xA0, 0x00, 0x8F, 0xFF, 0x82, 0xEF, 0xFE, 0x10, 0x00,
+		0x0C, 0xFF, 0xFC, 0xFF, 0xF5, 0x00, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x8F,
+		0xFF, 0xFE, 0x10, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xF2,
+		0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xF2, 0x00, 0x00,
+		0x00, 0x00, 0x0A, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00,
+		0x0A, 0xFF, 0xF2, 0x00, 0x00, 0x08, 0x99, 0x99, 0x99, 0x99, 0x99, 0x93, 0x0D, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xF6, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF5, 0x03, 0x44, 0x44, 0x44, 0xAF, 0xFF,
+		0xB0, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xFD, 0x10, 0x00, 0x00, 0x00, 0x3E, 0xFF, 0xE2, 0x00, 0x00,
+		0x00, 0x01, 0xDF, 0xFF, 0x40, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x9F,
+		0xFF, 0x90, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x4F, 0xFF, 0xD1, 0x00,
+		0x00, 0x00, 0x02, 0xEF, 0xFE, 0x20, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xFE, 0xDD, 0xDD, 0xDD, 0xD8,
+		0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x13, 0x33,
+		0x33, 0x24, 0xFF, 0xFF, 0xFC, 0x4F, 0xFF, 0xFF, 0xC4, 0xFF, 0xF1, 0x00, 0x4F, 0xFF, 0x10, 0x04,
+		0xFF, 0xF1, 0x00, 0x4F, 0xFF, 0x10, 0x04, 0xFF, 0xF1, 0x00, 0x4F, 0xFF, 0x10, 0x04, 0xFF, 0xF1,
+		0x00, 0x4F, 0xFF, 0x10, 0x04, 0xFF, 0xF1, 0x00, 0x4F, 0xFF, 0x10, 0x04, 0xFF, 0xF1, 0x00, 0x4F,
+		0xFF, 0x10, 0x04, 0xFF, 0xF1, 0x00, 0x4F, 0xFF, 0x98, 0x74, 0xFF, 0xFF, 0xFC, 0x3A, 0xAA, 0xAA,
+		0x70, 0x89, 0x20, 0x00, 0x0A, 0xF7, 0x00, 0x00, 0x5F, 0xC0, 0x00, 0x01, 0xFF, 0x20, 0x00, 0x0B,
+		0xF7, 0x00, 0x00, 0x6F, 0xB0, 0x00, 0x02, 0xFF, 0x10, 0x00, 0x0C, 0xF6, 0x00, 0x00, 0x7F, 0xA0,
+		0x00, 0x02, 0xFE, 0x10, 0x00, 0x0D, 0xF5, 0x00, 0x00, 0x8F, 0x90, 0x00, 0x03, 0xFE, 0x00, 0x00,
+		0x0E, 0xF4, 0x00, 0x00, 0x9F, 0x90, 0x00, 0x04, 0xFD, 0x00, 0x00, 0x0C, 0xD0, 0x23, 0x33, 0x33,
+		0x1A, 0xFF, 0xFF, 0xF6, 0xAF, 0xFF, 0xFF, 0x60, 0x00, 0xDF, 0xF6, 0x00, 0x0D, 0xFF, 0x60, 0x00,
+		0xDF, 0xF6, 0x00, 0x0D, 0xFF, 0x60, 0x00, 0xDF, 0xF6, 0x00, 0x0D, 0xFF, 0x60, 0x00, 0xDF, 0xF6,
+		0x00, 0x0D, 0xFF, 0x60, 0x00, 0xDF, 0xF6, 0x00, 0x0D, 0xFF, 0x60, 0x00, 0xDF, 0xF6, 0x00, 0x0D,
+		0xFF, 0x60, 0x00, 0xDF, 0xF6, 0x58, 0x8E, 0xFF, 0x6A, 0xFF, 0xFF, 0xF6, 0x6A, 0xAA, 0xAA, 0x40,
+		0x00, 0x00, 0x18, 0x97, 0x00, 0x00, 0x00, 0x00, 0x1C, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x1B, 0xFF,
+		0xFF, 0xF8, 0x00, 0x00, 0x0B, 0xFF, 0x92, 0xBF, 0xF7, 0x00, 0x0A, 0xFE, 0x50, 0x00, 0x7F, 0xF7,
+		0x07, 0xDB, 0x20, 0x00, 0x00, 0x3C, 0xD4, 0x22, 0x22, 0x22, 0x22, 0x22, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0x9F, 0xE2, 0x00, 0x09, 0xFC, 0x00, 0x00, 0x9F, 0x80, 0x00,
+		0x08, 0xA2, 0x00, 0x00, 0x13, 0x32, 0x00, 0x00, 0x02, 0xCE, 0xFF, 0xFF, 0xEA, 0x20, 0x03, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xE2, 0x03, 0xB6, 0x32, 0x36, 0xEF, 0xF8, 0x00, 0x00, 0x00, 0x00, 0xAF, 0xFC,
+		0x00, 0x4A, 0xDF, 0xFF, 0xFF, 0xFE, 0x06, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x0E, 0xFF, 0xC4, 0x11,
+		0xAF, 0xFE, 0x2F, 0xFF, 0x70, 0x00, 0xCF, 0xFE, 0x1F, 0xFF, 0xC2, 0x29, 0xFF, 0xFE, 0x0A, 0xFF,
+		0xFF, 0xFF, 0xDF, 0xFE, 0x01, 0xAF, 0xFF, 0xE5, 0x9F, 0xFE, 0x00, 0x02, 0x43, 0x00, 0x00, 0x00,
+		0x13, 0x33, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30,
+		0x00, 0x00, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x13, 0x20, 0x00,
+		0x05, 0xFF, 0xF3, 0x8F, 0xFF, 0xC3, 0x00, 0x5F, 0xFF, 0xCF, 0xFF, 0xFF, 0xE3, 0x05, 0xFF, 0xFF,
+		0x95, 0x9F, 0xFF, 0xC0, 0x5F, 0xFF, 0x90, 0x00, 0x9F, 0xFF, 0x25, 0xFF, 0xF4, 0x00, 0x04, 0xFF,
+		0xF5, 0x5F, 0xFF, 0x30, 0x00, 0x3F, 0xFF, 0x65, 0xFF, 0xF4, 0x00, 0x04, 0xFF, 0xF5, 0x5F, 0xFF,
+		0x80, 0x00, 0x9F, 0xFF, 0x25, 0xFF, 0xFF, 0x84, 0x8F, 0xFF, 0xC0, 0x5F, 0xFF, 0xDF, 0xFF, 0xFF,
+		0xF4, 0x05, 0xFF, 0xF3, 0xAF, 0xFF, 0xD4, 0x00, 0x00, 0x00, 0x00, 0x24, 0x30, 0x00, 0x00, 0x00,
+		0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x5C, 0xFF, 0xFF, 0xD5, 0x00, 0x9F, 0xFF, 0xFF, 0xFF, 0x80,
+		0x6F, 0xFF, 0xE7, 0x56, 0xB8, 0x0D, 0xFF, 0xE2, 0x00, 0x00, 0x11, 0xFF, 0xFA, 0x00, 0x00, 0x00,
+		0x2F, 0xFF, 0x80, 0x00, 0x00, 0x01, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xE1, 0x00, 0x00,
+		0x00, 0x7F, 0xFF, 0xD6, 0x45, 0x98, 0x00, 0xAF, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x7D, 0xFF, 0xFF,
+		0xE6, 0x00, 0x00, 0x02, 0x44, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x33, 0x20, 0x00, 0x00,
+		0x00, 0x00, 0xDF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0xDF,
+		0xFA, 0x00, 0x00, 0x13, 0x10, 0x0D, 0xFF, 0xA0, 0x01, 0xAF, 0xFF, 0xB2, 0xDF, 0xFA, 0x00, 0xCF,
+		0xFF, 0xFF, 0xDE, 0xFF, 0xA0, 0x7F, 0xFF, 0xC6, 0x7E, 0xFF, 0xFA, 0x0C, 0xFF, 0xE1, 0x00, 0x4F,
+		0xFF, 0xA0, 0xFF, 0xF9, 0x00, 0x00, 0xEF, 0xFA, 0x1F, 0xFF, 0x80, 0x00, 0x0D, 0xFF, 0xA0, 0xFF,
+		0xF9, 0x00, 0x00, 0xEF, 0xFA, 0x0D, 0xFF, 0xD0, 0x00, 0x4F, 0xFF, 0xA0, 0x7F, 0xFF, 0xB4, 0x6D,
+		0xFF, 0xFA, 0x01, 0xDF, 0xFF, 0xFF, 0xEE, 0xFF, 0xA0, 0x02, 0xBF, 0xFF, 0xC3, 0xDF, 0xFA, 0x00,
+		0x00, 0x24, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x00, 0x6D, 0xFF,
+		0xFF, 0xA2, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xFF, 0xE3, 0x00, 0x6F, 0xFF, 0x83, 0x3C, 0xFF, 0xD0,
+		0x0D, 0xFF, 0xB0, 0x00, 0x2F, 0xFF, 0x51, 0xFF, 0xFC, 0xAA, 0xAA, 0xFF, 0xF8, 0x2F, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0x91, 0xFF, 0xFB, 0x77, 0x77, 0x77, 0x74, 0x0D, 0xFF, 0xB0, 0x00, 0x00, 0x02,
+		0x00, 0x7F, 0xFF, 0x82, 0x12, 0x5A, 0xF1, 0x00, 0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0x10, 0x00, 0x7D,
+		0xFF, 0xFF, 0xFC, 0x80, 0x00, 0x00, 0x02, 0x44, 0x31, 0x00, 0x00, 0x00, 0x00, 0x02, 0x33, 0x30,
+		0x00, 0x9E, 0xFF, 0xFD, 0x00, 0x7F, 0xFF, 0xFF, 0xD0, 0x0C, 0xFF, 0xD2, 0x11, 0x00, 0xDF, 0xF9,
+		0x00, 0x09, 0xEF, 0xFF, 0xFE, 0xE8, 0x9F, 0xFF, 0xFF, 0xFF, 0x95, 0x8E, 0xFF, 0xC8, 0x85, 0x00,
+		0xDF, 0xF9, 0x00, 0x00, 0x0D, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xF9, 0x00, 0x00, 0x0D, 0xFF, 0x90,
+		0x00, 0x00, 0xDF, 0xF9, 0x00, 0x00, 0x0D, 0xFF, 0x90, 0x00, 0x00, 0xDF, 0xF9, 0x00, 0x00, 0x0D,
+		0xFF, 0x90, 0x00, 0x00, 0x00, 0x12, 0x10, 0x00, 0x00, 0x00, 0x01, 0xAF, 0xFF, 0xB2, 0xCE, 0xE9,
+		0x00, 0xCF, 0xFF, 0xFF, 0xDE, 0xFF, 0xA0, 0x7F, 0xFF, 0xC6, 0x7E, 0xFF, 0xFA, 0x0D, 0xFF, 0xD1,
+		0x00, 0x4F, 0xFF, 0xA1, 0xFF, 0xF9, 0x00, 0x00, 0xEF, 0xFA, 0x1F, 0xFF, 0x80, 0x00, 0x0D, 0xFF,
+		0xA0, 0xFF, 0xFA, 0x00, 0x00, 0xFF, 0xFA, 0x0C, 0xFF, 0xE1, 0x00, 0x6F, 0xFF, 0xA0, 0x6F, 0xFF,
+		0xD9, 0xAF, 0xFF, 0xFA, 0x00, 0xAF, 0xFF, 0xFF, 0xCE, 0xFF, 0xA0, 0x00, 0x7D, 0xFD, 0x81, 0xEF,
+		0xF9, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0x70, 0x0A, 0x72, 0x01, 0x4D, 0xFF, 0xF2, 0x00, 0xBF,
+		0xFF, 0xFF, 0xFF, 0xF6, 0x00, 0x09, 0xFF, 0xFF, 0xFF, 0xB4, 0x00, 0x00, 0x01, 0x34, 0x54, 0x10,
+		0x00, 0x00, 0x13, 0x33, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF,
+		0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x03, 0x20, 0x00,
+		0x5F, 0xFF, 0x38, 0xEF, 0xFD, 0x40, 0x5F, 0xFF, 0xCF, 0xFF, 0xFF, 0xE1, 0x5F, 0xFF, 0xFB, 0x7C,
+		0xFF, 0xF7, 0x5F, 0xFF, 0xA0, 0x01, 0xFF, 0xF9, 0x5F, 0xFF, 0x40, 0x00, 0xDF, 0xFA, 0x5F, 0xFF,
+		0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA,
+		0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00,
+		0xDF, 0xFA, 0x13, 0x33, 0x05, 0xFF, 0xF3, 0x5F, 0xFF, 0x33, 0xAA, 0xA2, 0x00, 0x00, 0x04, 0xEE,
+		0xE2, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3,
+		0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x01, 0x33, 0x30, 0x05, 0xFF, 0xF3,
+		0x05, 0xFF, 0xF3, 0x03, 0xAA, 0xA2, 0x00, 0x00, 0x00, 0x04, 0xEE, 0xE2, 0x05, 0xFF, 0xF3, 0x05,
+		0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF,
+		0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF3, 0x05, 0xFF, 0xF2, 0x1B, 0xFF, 0xE0,
+		0xFF, 0xFF, 0x80, 0xFF, 0xFA, 0x00, 0x54, 0x20, 0x00, 0x13, 0x33, 0x00, 0x00, 0x00, 0x00, 0x5F,
+		0xFF, 0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00,
+		0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x04, 0xEE, 0xEA, 0x5F, 0xFF, 0x30,
+		0x4F, 0xFF, 0xA0, 0x5F, 0xFF, 0x34, 0xFF, 0xFA, 0x00, 0x5F, 0xFF, 0x7F, 0xFF, 0x90, 0x00, 0x5F,
+		0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF5, 0x00, 0x00, 0x5F, 0xFF, 0xDF, 0xFF, 0x50,
+		0x00, 0x5F, 0xFF, 0x3C, 0xFF, 0xF4, 0x00, 0x5F, 0xFF, 0x31, 0xCF, 0xFF, 0x40, 0x5F, 0xFF, 0x30,
+		0x1D, 0xFF, 0xE4, 0x5F, 0xFF, 0x30, 0x02, 0xDF, 0xFE, 0x13, 0x33, 0x05, 0xFF, 0xF3, 0x5F, 0xFF,
+		0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35,
+		0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF, 0xF3, 0x5F, 0xFF, 0x35, 0xFF,
+		0xF3, 0x00, 0x00, 0x00, 0x13, 0x10, 0x00, 0x01, 0x31, 0x00, 0x05, 0xEE, 0xE3, 0x9F, 0xFF, 0x90,
+		0x1B, 0xFF, 0xFA, 0x10, 0x5F, 0xFF, 0xCF, 0xFF, 0xFF, 0xAD, 0xFF, 0xFF, 0xF9, 0x05, 0xFF, 0xFF,
+		0x97, 0xEF, 0xFF, 0xFA, 0x7E, 0xFF, 0xF1, 0x5F, 0xFF, 0x90, 0x07, 0xFF, 0xFA, 0x00, 0x6F, 0xFF,
+		0x35, 0xFF, 0xF4, 0x00, 0x5F, 0xFF, 0x50, 0x04, 0xFF, 0xF4, 0x5F, 0xFF, 0x30, 0x04, 0xFF, 0xF3,
+		0x00, 0x4F, 0xFF, 0x45, 0xFF, 0xF3, 0x00, 0x4F, 0xFF, 0x30, 0x04, 0xFF, 0xF4, 0x5F, 0xFF, 0x30,
+		0x04, 0xFF, 0xF3, 0x00, 0x4F, 0xFF, 0x45, 0xFF, 0xF3, 0x00, 0x4F, 0xFF, 0x30, 0x04, 0xFF, 0xF4,
+		0x5F, 0xFF, 0x30, 0x04, 0xFF, 0xF3, 0x00, 0x4F, 0xFF, 0x45, 0xFF, 0xF3, 0x00, 0x4F, 0xFF, 0x30,
+		0x04, 0xFF, 0xF4, 0x00, 0x00, 0x00, 0x03, 0x20, 0x00, 0x4E, 0xEE, 0x38, 0xEF, 0xFD, 0x40, 0x5F,
+		0xFF, 0xCF, 0xFF, 0xFF, 0xE1, 0x5F, 0xFF, 0xFB, 0x7C, 0xFF, 0xF7, 0x5F, 0xFF, 0xA0, 0x01, 0xFF,
+		0xF9, 0x5F, 0xFF, 0x40, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30,
+		0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F,
+		0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x5F, 0xFF, 0x30, 0x00, 0xDF, 0xFA, 0x00, 0x00, 0x01, 0x33, 0x10,
+		0x00, 0x00, 0x00, 0x6D, 0xFF, 0xFF, 0xC4, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xFF, 0xF7, 0x00, 0x6F,
+		0xFF, 0xB5, 0x6D, 0xFF, 0xF3, 0x0D, 0xFF, 0xD0, 0x00, 0x3F, 0xFF, 0x91, 0xFF, 0xF9, 0x00, 0x00,
+		0xDF, 0xFC, 0x2F, 0xFF, 0x80, 0x00, 0x0C, 0xFF, 0xD1, 0xFF, 0xF9, 0x00, 0x00, 0xDF, 0xFC, 0x0D,
+		0xFF, 0xD0, 0x00, 0x2F, 0xFF, 0x90, 0x7F, 0xFF, 0xA4, 0x5D, 0xFF, 0xF3, 0x00, 0xBF, 0xFF, 0xFF,
+		0xFF, 0xF8, 0x00, 0x00, 0x8E, 0xFF, 0xFF, 0xD5, 0x00, 0x00, 0x00, 0x03, 0x44, 0x20, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x13, 0x20, 0x00, 0x04, 0xEE, 0xE3, 0x8F, 0xFF, 0xC3, 0x00, 0x5F, 0xFF, 0xCF,
+		0xFF, 0xFF, 0xE3, 0x05, 0xFF, 0xFF, 0x95, 0x9F, 0xFF, 0xC0, 0x5F, 0xFF, 0x90, 0x00, 0x9F, 0xFF,
+		0x25, 0xFF, 0xF4, 0x00, 0x04, 0xFF, 0xF5, 0x5F, 0xFF, 0x30, 0x00, 0x3F, 0xFF, 0x65, 0xFF, 0xF4,
+		0x00, 0x04, 0xFF, 0xF5, 0x5F, 0xFF, 0x80, 0x00, 0x9F, 0xFF, 0x25, 0xFF, 0xFF, 0x84, 0x8F, 0xFF,
+		0xC0, 0x5F, 0xFF, 0xDF, 0xFF, 0xFF, 0xF4, 0x05, 0xFF, 0xF3, 0xAF, 0xFF, 0xD4, 0x00, 0x5F, 0xFF,
+		0x30, 0x24, 0x30, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x00,
+		0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x12, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x12, 0x10, 0x00, 0x00, 0x00, 0x01, 0xAF, 0xFF, 0xB2, 0xCE, 0xE9, 0x00, 0xCF, 0xFF, 0xFF,
+		0xDE, 0xFF, 0xA0, 0x7F, 0xFF, 0xC6, 0x7E, 0xFF, 0xFA, 0x0C, 0xFF, 0xE1, 0x00, 0x4F, 0xFF, 0xA0,
+		0xFF, 0xF9, 0x00, 0x00, 0xEF, 0xFA, 0x1F, 0xFF, 0x80, 0x00, 0x0D, 0xFF, 0xA0, 0xFF, 0xF9, 0x00,
+		0x00, 0xEF, 0xFA, 0x0D, 0xFF, 0xD0, 0x00, 0x4F, 0xFF, 0xA0, 0x7F, 0xFF, 0xB4, 0x6D, 0xFF, 0xFA,
+		0x01, 0xDF, 0xFF, 0xFF, 0xEE, 0xFF, 0xA0, 0x02, 0xBF, 0xFF, 0xC3, 0xDF, 0xFA, 0x00, 0x00, 0x24,
+		0x30, 0x0D, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0xDF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x0D, 0xFF,
+		0xA0, 0x00, 0x00, 0x00, 0x00, 0xDF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x02, 0x22, 0x20, 0x00, 0x00,
+		0x00, 0x13, 0x24, 0xEE, 0xE3, 0x8F, 0xFC, 0x5F, 0xFF, 0xBF, 0xFF, 0xC5, 0xFF, 0xFF, 0xEA, 0xAB,
+		0x5F, 0xFF, 0xD1, 0x00, 0x05, 0xFF, 0xF6, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x05, 0xFF, 0xF3,
+		0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x05, 0xFF, 0xF3, 0x00, 0x00, 0x5F, 0xFF, 0x30, 0x00, 0x05,
+		0xFF, 0xF3, 0x00, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x07, 0xEF, 0xFF, 0xFE, 0xB2, 0x8F, 0xFF,
+		0xFF, 0xFF, 0xF3, 0xDF, 0xF8, 0x32, 0x47, 0xC3, 0xEF, 0xF5, 0x00, 0x00, 0x00, 0xBF, 0xFF, 0xEC,
+		0x96, 0x10, 0x3E, 0xFF, 0xFF, 0xFF, 0xE3, 0x01, 0x59, 0xBD, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0x8F,
+		0xFE, 0xB8, 0x42, 0x12, 0xBF, 0xFD, 0xCF, 0xFF, 0xFF, 0xFF, 0xF7, 0x8E, 0xFF, 0xFF, 0xFE, 0x70,
+		0x00, 0x13, 0x44, 0x20, 0x00, 0x00, 0x11, 0x10, 0x00, 0x00, 0x00, 0xFF, 0xF7, 0x00, 0x00, 0x00,
+		0xFF, 0xF7, 0x00, 0x00, 0x00, 0xFF, 0xF7, 0x00, 0x00, 0xAE, 0xFF, 0xFF, 0xEE, 0xE1, 0xBF, 0xFF,
+		0xFF, 0xFF, 0xF1, 0x68, 0xFF, 0xFC, 0x88, 0x81, 0x00, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0xFF, 0xF7,
+		0x00, 0x00, 0x00, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0xFF, 0xF8, 0x00,
+		0x00, 0x00, 0xDF, 0xFE, 0x87, 0x60, 0x00, 0x8F, 0xFF, 0xFF, 0xC0, 0x00, 0x08, 0xDF, 0xFF, 0xC0,
+		0x6E, 0xEE, 0x10, 0x00, 0xDE, 0xE8, 0x7F, 0xFF, 0x10, 0x00, 0xEF, 0xF8, 0x7F, 0xFF, 0x10, 0x00,
+		0xEF, 0xF8, 0x7F, 0xFF, 0x10, 0x00, 0xEF, 0xF8, 0x7F, 0xFF, 0x10, 0x00, 0xEF, 0xF8, 0x7F, 0xFF,
+		0x10, 0x00, 0xEF, 0xF8, 0x7F, 0xFF, 0x10, 0x01, 0xFF, 0xF8, 0x6F, 0xFF, 0x30, 0x06, 0xFF, 0xF8,
+		0x3F, 0xFF, 0xD6, 0x8F, 0xFF, 0xF8, 0x0C, 0xFF, 0xFF, 0xFD, 0xFF, 0xF8, 0x02, 0xCF, 0xFF, 0xB2,
+		0xEF, 0xF8, 0x00, 0x03, 0x42, 0x00, 0x00, 0x00, 0x7E, 0xED, 0x00, 0x00, 0x0D, 0xEE, 0x82, 0xFF,
+		0xF5, 0x00, 0x04, 0xFF, 0xF3, 0x0B, 0xFF, 0xA0, 0x00, 0xAF, 0xFC, 0x00, 0x5F, 0xFF, 0x10, 0x1E,
+		0xFF, 0x60, 0x00, 0xEF, 0xF6, 0x05, 0xFF, 0xE1, 0x00, 0x08, 0xFF, 0xB0, 0xBF, 0xF9, 0x00, 0x00,
+		0x3F, 0xFF, 0x3F, 0xFF, 0x30, 0x00, 0x00, 0xCF, 0xFD, 0xFF, 0xC0, 0x00, 0x00, 0x06, 0xFF, 0xFF,
+		0xF6, 0x00, 0x00, 0x00, 0x1E, 0xFF, 0xFE, 0x10, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xA0, 0x00, 0x00,
+		0x2E, 0xEE, 0x30, 0x05, 0xEE, 0xC0, 0x00, 0xAE, 0xE9, 0x0E, 0xFF, 0x70, 0x09, 0xFF, 0xF1, 0x00,
+		0xEF, 0xF6, 0x0A, 0xFF, 0xA0, 0x0C, 0xFF, 0xF5, 0x03, 0xFF, 0xF2, 0x06, 0xFF, 0xE0, 0x1F, 0xFE,
+		0xF8, 0x07, 0xFF, 0xD0, 0x02, 0xFF, 0xF3, 0x5F, 0xE8, 0xFC, 0x0B, 0xFF, 0x90, 0x00, 0xDF, 0xF6,
+		0x8F, 0xB4, 0xFF, 0x1E, 0xFF, 0x50, 0x00, 0x9F, 0xFA, 0xCF, 0x81, 0xFF, 0x7F, 0xFF, 0x10, 0x00,
+		0x5F, 0xFE, 0xFF, 0x40, 0xCF, 0xEF, 0xFC, 0x00, 0x00, 0x1F, 0xFF, 0xFF, 0x10, 0x8F, 0xFF, 0xF8,
+		0x00, 0x00, 0x0C, 0xFF, 0xFC, 0x00, 0x4F, 0xFF, 0xF4, 0x00, 0x00, 0x08, 0xFF, 0xF8, 0x00, 0x1F,
+		0xFF, 0xF1, 0x00, 0x3E, 0xEE, 0x70, 0x00, 0x9E, 0xED, 0x20, 0x7F, 0xFF, 0x30, 0x5F, 0xFF, 0x50,
+		0x00, 0xAF, 0xFD, 0x2E, 0xFF, 0x90, 0x00, 0x01, 0xDF, 0xFE, 0xFF, 0xC0, 0x00, 0x00, 0x04, 0xFF,
+		0xFF, 0xE2, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0xF4, 0x00,
+		0x00, 0x02, 0xEF, 0xFD, 0xFF, 0xE1, 0x00, 0x00, 0xCF, 0xFB, 0x1D, 0xFF, 0xB0, 0x00, 0x9F, 0xFE,
+		0x20, 0x3F, 0xFF, 0x70, 0x5F, 0xFF, 0x50, 0x00, 0x7F, 0xFF, 0x40, 0x8E, 0xED, 0x00, 0x00, 0x0D,
+		0xEE, 0x72, 0xFF, 0xF5, 0x00, 0x05, 0xFF, 0xF2, 0x0B, 0xFF, 0xB0, 0x00, 0xAF, 0xFB, 0x00, 0x4F,
+		0xFF, 0x20, 0x0E, 0xFF, 0x50, 0x00, 0xDF, 0xF7, 0x05, 0xFF, 0xE0, 0x00, 0x06, 0xFF, 0xD0, 0xAF,
+		0xF9, 0x00, 0x00, 0x1E, 0xFF, 0x5E, 0xFF, 0x30, 0x00, 0x00, 0x8F, 0xFE, 0xFF, 0xC0, 0x00, 0x00,
+		0x02, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x4F, 0xFF,
+		0xA0, 0x00, 0x00, 0x00, 0x00, 0xEF, 0xF4, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFD, 0x00, 0x00, 0x00,
+		0x0F, 0xFF, 0xFF, 0x50, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x05, 0x54, 0x10,
+		0x00, 0x00, 0x00, 0x0C, 0xEE, 0xEE, 0xEE, 0xEE, 0xA0, 0xDF, 0xFF, 0xFF, 0xFF, 0xFA, 0x07, 0x88,
+		0x88, 0xCF, 0xFF, 0x80, 0x00, 0x00, 0x4F, 0xFF, 0xB0, 0x00, 0x00, 0x4E, 0xFF, 0xB0, 0x00, 0x00,
+		0x3E, 0xFF, 0xB1, 0x00, 0x00, 0x3E, 0xFF, 0xC1, 0x00, 0x00, 0x2E, 0xFF, 0xC1, 0x00, 0x00, 0x1E,
+		0xFF, 0xF9, 0x77, 0x77, 0x51, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0,
+		0x00, 0x00, 0x00, 0x23, 0x32, 0x00, 0x00, 0x4D, 0xFF, 0xFB, 0x00, 0x02, 0xFF, 0xFF, 0xFB, 0x00,
+		0x06, 0xFF, 0xF5, 0x10, 0x00, 0x07, 0xFF, 0xE0, 0x00, 0x00, 0x07, 0xFF, 0xD0, 0x00, 0x00, 0x07,
+		0xFF, 0xD0, 0x00, 0x00, 0x09, 0xFF, 0xC0, 0x00, 0x00, 0x4E, 0xFF, 0xA0, 0x00, 0x7F, 0xFF, 0xFC,
+		0x20, 0x00, 0x7F, 0xFF, 0xFB, 0x20, 0x00, 0x13, 0x7F, 0xFF, 0x90, 0x00, 0x00, 0x09, 0xFF, 0xC0,
+		0x00, 0x00, 0x07, 0xFF, 0xD0, 0x00, 0x00, 0x07, 0xFF, 0xD0, 0x00, 0x00, 0x07, 0xFF, 0xE0, 0x00,
+		0x00, 0x06, 0xFF, 0xF4, 0x00, 0x00, 0x02, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x5E, 0xFF, 0xFB, 0x00,
+		0x00, 0x00, 0x34, 0x43, 0x24, 0x37, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB,
+		0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F, 0xB7, 0xFB, 0x7F,
+		0xB7, 0xFB, 0x5B, 0x80, 0x13, 0x32, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xE7, 0x00, 0x00, 0x7F, 0xFF,
+		0xFF, 0x50, 0x00, 0x00, 0x3E, 0xFF, 0x90, 0x00, 0x00, 0x0A, 0xFF, 0xA0, 0x00, 0x00, 0x09, 0xFF,
+		0xB0, 0x00, 0x00, 0x09, 0xFF, 0xB0, 0x00, 0x00, 0x09, 0xFF, 0xC0, 0x00, 0x00, 0x06, 0xFF, 0xF6,
+		0x10, 0x00, 0x01, 0xAF, 0xFF, 0xFB, 0x00, 0x00, 0x9F, 0xFF, 0xFB, 0x00, 0x06, 0xFF, 0xF9, 0x32,
+		0x00, 0x09, 0xFF, 0xD0, 0x00, 0x00, 0x09, 0xFF, 0xB0, 0x00, 0x00, 0x09, 0xFF, 0xB0, 0x00, 0x00,
+		0x0A, 0xFF, 0xA0, 0x00, 0x00, 0x2E, 0xFF, 0x90, 0x00, 0x7E, 0xFF, 0xFF, 0x50, 0x00, 0x7F, 0xFF,
+		0xF8, 0x00, 0x00, 0x24, 0x43, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x10, 0x7D,
+		0xFF, 0xC6, 0x10, 0x01, 0x79, 0xCF, 0xFF, 0xFF, 0xFF, 0xCC, 0xEF, 0xAD, 0xC6, 0x35, 0x9E, 0xFF,
+		0xFF, 0xC3, 0x60, 0x00, 0x00, 0x15, 0x87, 0x40, 0x00, 0x01, 0x9E, 0xE9, 0x10, 0x0B, 0xFB, 0xBF,
+		0xA0, 0x2F, 0x90, 0x09, 0xF2, 0x4F, 0x60, 0x06, 0xF3, 0x1E, 0xC3, 0x3C, 0xE1, 0x06, 0xFF, 0xFF,
+		0x50, 0x00, 0x37, 0x72, 0x00,
+	],
+	replacement: b'?',
+};
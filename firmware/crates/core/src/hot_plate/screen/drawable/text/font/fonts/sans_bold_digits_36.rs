@@ -0,0 +1,246 @@
+//! The DejaVuSans-Bold.ttf font at 36 pixels, with 4 bits per pixel.
+//!
+//! Generated by the `asset-generator` tool: don't edit it by hand.
+
+use super::super::{BitmapFont, BitsPerPixel, Glyph};
+
+#[rustfmt::skip]
+pub static SANS_BOLD_DIGITS_36: BitmapFont = BitmapFont {
+	line_height: 42,
+	baseline: 34,
+	bits_per_pixel: BitsPerPixel::Four,
+	glyphs: &[
+		Glyph { char: b' ', offset: 0, width: 0, height: 0, left: 0, top: 0, advance: 13 },
+		Glyph { char: b'-', offset: 0, width: 12, height: 6, left: 1, top: 21, advance: 15 },
+		Glyph { char: b'.', offset: 36, width: 7, height: 7, left: 3, top: 27, advance: 14 },
+		Glyph { char: b'0', offset: 61, width: 23, height: 28, left: 1, top: 7, advance: 25 },
+		Glyph { char: b'1', offset: 383, width: 19, height: 27, left: 4, top: 7, advance: 25 },
+		Glyph { char: b'2', offset: 640, width: 20, height: 27, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'3', offset: 910, width: 21, height: 28, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'4', offset: 1204, width: 23, height: 27, left: 1, top: 7, advance: 25 },
+		Glyph { char: b'5', offset: 1515, width: 21, height: 28, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'6', offset: 1809, width: 22, height: 28, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'7', offset: 2117, width: 21, height: 27, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'8', offset: 2401, width: 21, height: 28, left: 2, top: 7, advance: 25 },
+		Glyph { char: b'9', offset: 2695, width: 22, height: 28, left: 1, top: 7, advance: 25 },
+		Glyph { char: b'C', offset: 3003, width: 24, height: 28, left: 1, top: 7, advance: 26 },
+		Glyph { char: 0xB0, offset: 3339, width: 12, height: 12, left: 3, top: 7, advance: 18 },
+	],
+	atlas: &[
+		0x1E, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x03, 0x33,
+		0x33, 0x33, 0x33, 0x33, 0x4C, 0xCC, 0xCC, 0xC5, 0xFF, 0xFF, 0xFF, 0x5F, 0xFF, 0xFF, 0xF5, 0xFF,
+		0xFF, 0xFF, 0x5F, 0xFF, 0xFF, 0xF5, 0xFF, 0xFF, 0xFF, 0x5F, 0xFF, 0xFF, 0xF0, 0x00, 0x00, 0x00,
+		0x01, 0x58, 0xAB, 0xA8, 0x51, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xF9, 0x20, 0x00, 0x00, 0x00, 0x00, 0x4E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x40, 0x00, 0x00,
+		0x00, 0x3E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x00, 0x1D, 0xFF, 0xFF, 0xFF,
+		0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x10, 0x00, 0x08, 0xFF, 0xFF, 0xFF, 0x91, 0x01, 0x8F, 0xFF, 0xFF,
+		0xF9, 0x00, 0x01, 0xEF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xF1, 0x00, 0x6F, 0xFF,
+		0xFF, 0xF3, 0x00, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0x60, 0x0A, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x00,
+		0x0D, 0xFF, 0xFF, 0xFB, 0x00, 0xDF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xE0,
+		0x1F, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x09, 0xFF, 0xFF, 0xFF, 0x12, 0xFF, 0xFF, 0xFF, 0x80,
+		0x00, 0x00, 0x00, 0x8F, 0xFF, 0xFF, 0xF3, 0x4F, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0x07, 0xFF,
+		0xFF, 0xFF, 0x44, 0xFF, 0xFF, 0xFF, 0x70, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xF5, 0x4F, 0xFF,
+		0xFF, 0xF8, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xFF, 0xFF, 0x53, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x00,
+		0x00, 0x7F, 0xFF, 0xFF, 0xF4, 0x2F, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x08, 0xFF, 0xFF, 0xFF,
+		0x30, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xF1, 0x0D, 0xFF, 0xFF, 0xFC,
+		0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFD, 0x00, 0x9F, 0xFF, 0xFF, 0xF0, 0x00, 0x00, 0x00, 0xEF,
+		0xFF, 0xFF, 0xA0, 0x05, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x00, 0x4F, 0xFF, 0xFF, 0xF5, 0x00, 0x0D,
+		0xFF, 0xFF, 0xFC, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFE, 0x10, 0x00, 0x6F, 0xFF, 0xFF, 0xFB, 0x42,
+		0x4B, 0xFF, 0xFF, 0xFF, 0x70, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xC0,
+		0x00, 0x00, 0x02, 0xDF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE2, 0x00, 0x00, 0x00, 0x02, 0xCF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xC2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6D, 0xFF, 0xFF, 0xFF, 0xFD,
+		0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x57, 0x77, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x02, 0x44, 0x44, 0x44, 0x20, 0x00, 0x00, 0x01, 0x47, 0xAE, 0xFF, 0xFF, 0xFF, 0xF9, 0x00,
+		0x00, 0x00, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x0E, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xF9, 0x00, 0x00, 0x00, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x0E, 0xFF,
+		0xEB, 0x8D, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x75, 0x20, 0x00, 0xCF, 0xFF, 0xFF, 0x90, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF,
+		0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0xCF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF,
+		0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0x90, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF,
+		0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0xCF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00,
+		0x8A, 0xAA, 0xAA, 0xEF, 0xFF, 0xFF, 0xDA, 0xAA, 0xAA, 0x6C, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xF9, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x9C, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x90,
+		0x00, 0x00, 0x25, 0x79, 0xAB, 0xA9, 0x74, 0x10, 0x00, 0x00, 0x05, 0xAE, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xE9, 0x20, 0x00, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE4, 0x00, 0x1F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x1F, 0xFF, 0xFF, 0xFF, 0xEF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xD0, 0x1F, 0xFF, 0xC6, 0x20, 0x00, 0x5D, 0xFF, 0xFF, 0xFF, 0xF4, 0x1F, 0xB3, 0x00, 0x00,
+		0x00, 0x01, 0xDF, 0xFF, 0xFF, 0xF9, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xFA,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x2F, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF5, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xE1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xFF, 0xFF,
+		0xFF, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8F, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x09, 0xFF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xBF, 0xFF, 0xFF, 0xFA, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x1C, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x02, 0xDF, 0xFF, 0xFF,
+		0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0xFF, 0xFF, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x00, 0x05,
+		0xFF, 0xFF, 0xFF, 0xE4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0xFF, 0xFE, 0x30, 0x00, 0x00,
+		0x00, 0x00, 0x08, 0xFF, 0xFF, 0xFF, 0xD2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+		0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFE, 0x2F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x01,
+		0x46, 0x8A, 0xAB, 0xA9, 0x85, 0x20, 0x00, 0x00, 0x00, 0x5C, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xC5, 0x00, 0x00, 0x07, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x7F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF8, 0x00, 0x07, 0xFF, 0xFF, 0xFE, 0xEF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xF1, 0x00, 0x7E, 0xA6, 0x20, 0x00, 0x04, 0xBF, 0xFF, 0xFF, 0xFF, 0x60, 0x02, 0x10, 0x00,
+		0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFF, 0xFF,
+		0xFF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4F, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x08, 0xFF, 0xFF, 0xFF, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0xFF, 0xFF, 0xFF,
+		0x80, 0x00, 0x00, 0x01, 0xAA, 0xAB, 0xCF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x1F, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xF9, 0x30, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE8, 0x10,
+		0x00, 0x00, 0x00, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x30, 0x00, 0x00, 0x01, 0xAA, 0xAB,
+		0xCE, 0xFF, 0xFF, 0xFF, 0xFE, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0xEF, 0xFF, 0xFF, 0xF8,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x0A, 0xFF, 0xFF, 0xFF, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xF2,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xFF, 0x18, 0x82, 0x00, 0x00, 0x00, 0x00,
+		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x9F, 0xFC, 0x85, 0x32, 0x23, 0x6C, 0xFF, 0xFF, 0xFF, 0xF9, 0x09,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x20, 0x9F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0x50, 0x09, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x40, 0x00, 0x38,
+		0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xA5, 0x00, 0x00, 0x00, 0x00, 0x03, 0x56, 0x77, 0x76, 0x52,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x44, 0x44, 0x44, 0x20, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+		0xEF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xFF, 0xFA,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x2E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xDF,
+		0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0x7B, 0xFF, 0xFF, 0xFA, 0x00, 0x00,
+		0x00, 0x00, 0x02, 0xEF, 0xFF, 0xC0, 0xBF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0xBF, 0xFF,
+		0xE2, 0x0B, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0xF7, 0x00, 0xBF, 0xFF, 0xFF,
+		0xA0, 0x00, 0x00, 0x00, 0x2E, 0xFF, 0xFB, 0x00, 0x0B, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x0B,
+		0xFF, 0xFE, 0x20, 0x00, 0xBF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0x60, 0x00, 0x0B,
+		0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x02, 0xEF, 0xFF, 0xB0, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xA0, 0x00,
+		0x00, 0xBF, 0xFF, 0xE2, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x5F, 0xFF, 0xF6, 0x00,
+		0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xA0, 0x00, 0x06, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAE, 0xFF, 0xFF,
+		0xFD, 0xAA, 0xA4, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF6, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xF6, 0x13, 0x33, 0x33, 0x33, 0x33, 0x33, 0xCF, 0xFF, 0xFF, 0xB3, 0x33, 0x10, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBF, 0xFF,
+		0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFA, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x01, 0x44, 0x44, 0x44, 0x44,
+		0x44, 0x44, 0x44, 0x44, 0x20, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x00,
+		0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00,
+		0x3F, 0xFF, 0xFF, 0xCB, 0xBB, 0xBB, 0xBB, 0xBB, 0xB7, 0x00, 0x03, 0xFF, 0xFF, 0xF3, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+		0xFF, 0xFF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0x77, 0x99, 0x97,
+		0x51, 0x00, 0x00, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x30, 0x00, 0x00, 0x3F,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0x80, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x03, 0xFD,
+		0x95, 0x21, 0x01, 0x4A, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF,
+		0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xFF, 0xFF, 0x50, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFF,
+		0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF7, 0x23, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFF, 0x53, 0xFA, 0x30, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xFF,
+		0xFF, 0xF1, 0x3F, 0xFF, 0xD8, 0x53, 0x23, 0x6B, 0xFF, 0xFF, 0xFF, 0xFA, 0x03, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x20, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0x40, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x30, 0x00, 0x01, 0x6A, 0xEF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xC6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x67, 0x77, 0x64, 0x10, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x26, 0x8A, 0xAA, 0x97, 0x51, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5C,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xC4, 0x00, 0x00, 0x00, 0x1B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xF6, 0x00, 0x00, 0x02, 0xDF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x1C, 0xFF,
+		0xFF, 0xFF, 0xFE, 0xCB, 0xCE, 0xFF, 0xF6, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xFC, 0x40, 0x00, 0x00,
+		0x38, 0xD6, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x09, 0xFF,
+		0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0xFF, 0xFF, 0xF5, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0xF1, 0x00, 0x34, 0x43, 0x10, 0x00, 0x00, 0x00, 0x7F,
+		0xFF, 0xFF, 0xC2, 0x9E, 0xFF, 0xFF, 0xFC, 0x60, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xEE, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFC, 0x20, 0x00, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE2, 0x00,
+		0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0xBF, 0xFF, 0xFF, 0xFF, 0xE5,
+		0x11, 0x5D, 0xFF, 0xFF, 0xFF, 0x50, 0xBF, 0xFF, 0xFF, 0xFF, 0x30, 0x00, 0x02, 0xFF, 0xFF, 0xFF,
+		0xB0, 0xAF, 0xFF, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xE0, 0x8F, 0xFF, 0xFF, 0xF8,
+		0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xF1, 0x6F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x6F, 0xFF,
+		0xFF, 0xF1, 0x2F, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xF0, 0x0D, 0xFF, 0xFF,
+		0xFA, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xD0, 0x07, 0xFF, 0xFF, 0xFE, 0x10, 0x00, 0x01, 0xEF,
+		0xFF, 0xFF, 0x90, 0x01, 0xEF, 0xFF, 0xFF, 0xB1, 0x00, 0x1A, 0xFF, 0xFF, 0xFF, 0x30, 0x00, 0x5F,
+		0xFF, 0xFF, 0xFF, 0xCC, 0xEF, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x10, 0x00, 0x00,
+		0x00, 0x03, 0xAF, 0xFF, 0xFF, 0xFF, 0xFB, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x46, 0x77,
+		0x64, 0x10, 0x00, 0x00, 0x00, 0x24, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x19,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0x9F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0x39, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0x9F,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x26, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB,
+		0xFF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF5, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x0B, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xFF,
+		0xFF, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xE0, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0xFF, 0xFF,
+		0xFE, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xEF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0xFF, 0xFF, 0xFA,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0xDF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xFF, 0xFF, 0xF4, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+		0xFF, 0xFF, 0xFF, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xD0, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0xFF,
+		0xFF, 0xFE, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xEF, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x8F, 0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0xFF, 0xFF,
+		0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x01, 0x57, 0x9A, 0xBA, 0x97, 0x51, 0x00, 0x00, 0x00, 0x00, 0x2A, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFA, 0x20, 0x00, 0x00, 0x5E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x50,
+		0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x30, 0x0B, 0xFF, 0xFF, 0xFF, 0xFA,
+		0x9A, 0xEF, 0xFF, 0xFF, 0xFB, 0x01, 0xFF, 0xFF, 0xFF, 0xE2, 0x00, 0x02, 0xDF, 0xFF, 0xFF, 0xF1,
+		0x2F, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x06, 0xFF, 0xFF, 0xFF, 0x33, 0xFF, 0xFF, 0xFF, 0x30, 0x00,
+		0x00, 0x3F, 0xFF, 0xFF, 0xF3, 0x1F, 0xFF, 0xFF, 0xF4, 0x00, 0x00, 0x04, 0xFF, 0xFF, 0xFF, 0x10,
+		0xBF, 0xFF, 0xFF, 0x90, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xC0, 0x03, 0xFF, 0xFF, 0xFF, 0x82, 0x02,
+		0x8F, 0xFF, 0xFF, 0xF4, 0x00, 0x05, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE5, 0x00, 0x00,
+		0x02, 0x9E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x92, 0x00, 0x00, 0x00, 0x29, 0xEF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xE9, 0x20, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x70, 0x00, 0x7F,
+		0xFF, 0xFF, 0xFA, 0x64, 0x5A, 0xFF, 0xFF, 0xFF, 0x70, 0x2F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x07,
+		0xFF, 0xFF, 0xFF, 0x27, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xFF, 0xF8, 0xBF, 0xFF,
+		0xFF, 0xA0, 0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xBC, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x09,
+		0xFF, 0xFF, 0xFC, 0xBF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xB9, 0xFF, 0xFF,
+		0xFF, 0x20, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xF9, 0x5F, 0xFF, 0xFF, 0xFD, 0x30, 0x00, 0x2C, 0xFF,
+		0xFF, 0xFF, 0x50, 0xDF, 0xFF, 0xFF, 0xFF, 0xDB, 0xDF, 0xFF, 0xFF, 0xFF, 0xD0, 0x04, 0xEF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF4, 0x00, 0x04, 0xDF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xD4, 0x00, 0x00, 0x01, 0x7C, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD, 0x71, 0x00, 0x00, 0x00, 0x00, 0x01,
+		0x46, 0x77, 0x76, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x79, 0xAA, 0x97, 0x30, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x06, 0xDF, 0xFF, 0xFF, 0xFF, 0xFC, 0x50, 0x00, 0x00, 0x00, 0x02, 0xCF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0x00, 0x00, 0x00, 0x1D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xA0, 0x00, 0x00, 0xBF, 0xFF, 0xFF, 0xFD, 0x99, 0xDF, 0xFF, 0xFF, 0xF7, 0x00, 0x04, 0xFF,
+		0xFF, 0xFF, 0x80, 0x00, 0x09, 0xFF, 0xFF, 0xFE, 0x10, 0x0A, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x01,
+		0xEF, 0xFF, 0xFF, 0x80, 0x0E, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0xAF, 0xFF, 0xFF, 0xD0, 0x1F,
+		0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x00, 0x8F, 0xFF, 0xFF, 0xF3, 0x2F, 0xFF, 0xFF, 0xF6, 0x00, 0x00,
+		0x00, 0x7F, 0xFF, 0xFF, 0xF6, 0x1F, 0xFF, 0xFF, 0xF7, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xFF, 0xF8,
+		0x0E, 0xFF, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFA, 0x0A, 0xFF, 0xFF, 0xFF, 0x40,
+		0x00, 0x05, 0xFF, 0xFF, 0xFF, 0xFB, 0x05, 0xFF, 0xFF, 0xFF, 0xE7, 0x44, 0x8F, 0xFF, 0xFF, 0xFF,
+		0xFB, 0x00, 0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB, 0x00, 0x1D, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0x00, 0x01, 0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0xDD, 0xFF,
+		0xFF, 0xF8, 0x00, 0x00, 0x04, 0xAE, 0xFF, 0xFF, 0xC6, 0x1D, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x00,
+		0x00, 0x12, 0x21, 0x00, 0x1F, 0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F,
+		0xFF, 0xFF, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xEF, 0xFF, 0xFF, 0x70, 0x00, 0x21,
+		0x00, 0x00, 0x00, 0x00, 0x1B, 0xFF, 0xFF, 0xFE, 0x10, 0x00, 0x6F, 0xA5, 0x20, 0x00, 0x27, 0xDF,
+		0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x6F, 0xFF, 0xFF, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00,
+		0x6F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x3A, 0xEF, 0xFF, 0xFF, 0xFF, 0xFE, 0x92, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x02, 0x56, 0x77, 0x75, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x36, 0x8A, 0xBA, 0xA8, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x8E, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xD7, 0x10, 0x00, 0x00, 0x00, 0x8F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE1, 0x00,
+		0x00, 0x1C, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x00, 0x01, 0xCF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x00, 0x0B, 0xFF, 0xFF, 0xFF, 0xFF, 0xB6, 0x43, 0x45,
+		0x8D, 0xFF, 0xF2, 0x00, 0x6F, 0xFF, 0xFF, 0xFF, 0xD3, 0x00, 0x00, 0x00, 0x00, 0x4B, 0xF2, 0x00,
+		0xDF, 0xFF, 0xFF, 0xFD, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x05, 0xFF, 0xFF, 0xFF, 0xF3,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x0D, 0xFF, 0xFF, 0xFF, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F,
+		0xFF, 0xFF, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xFF, 0xFF, 0xFD, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2F,
+		0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xFF, 0xFF, 0xFF, 0x10,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xFF, 0xFF, 0xFF, 0x50, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x09, 0xFF, 0xFF, 0xFF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+		0xFF, 0xFF, 0xFF, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFE,
+		0x30, 0x00, 0x00, 0x00, 0x00, 0x01, 0x81, 0x00, 0x4F, 0xFF, 0xFF, 0xFF, 0xE6, 0x00, 0x00, 0x00,
+		0x01, 0x7D, 0xF2, 0x00, 0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0xEA, 0x76, 0x78, 0xCF, 0xFF, 0xF2, 0x00,
+		0x00, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x09, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x00, 0x00, 0x00, 0x4D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+		0xFF, 0xFF, 0xC1, 0x00, 0x00, 0x00, 0x00, 0x5B, 0xEF, 0xFF, 0xFF, 0xFF, 0xFE, 0x94, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x03, 0x57, 0x77, 0x65, 0x20, 0x00, 0x00, 0x00, 0x06, 0xBE, 0xEB, 0x60,
+		0x00, 0x01, 0xCF, 0xFF, 0xFF, 0xFB, 0x10, 0x0C, 0xFF, 0xFE, 0xEF, 0xFF, 0xB0, 0x5F, 0xFE, 0x50,
+		0x05, 0xEF, 0xF5, 0xAF, 0xF6, 0x00, 0x00, 0x6F, 0xFA, 0xDF, 0xF1, 0x00, 0x00, 0x1F, 0xFC, 0xCF,
+		0xF1, 0x00, 0x00, 0x1F, 0xFC, 0xAF, 0xF7, 0x00, 0x00, 0x7F, 0xF9, 0x4F, 0xFF, 0x83, 0x38, 0xFF,
+		0xF3, 0x0A, 0xFF, 0xFF, 0xFF, 0xFF, 0x90, 0x00, 0x9F, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x03, 0x8B,
+		0xA8, 0x20, 0x00,
+	],
+	replacement: b'?',
+};
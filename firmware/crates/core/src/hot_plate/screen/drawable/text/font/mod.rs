@@ -1,8 +1,76 @@
-use crate::hot_plate::screen::drawable::binary_image::BinaryImage;
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::drawable::{Axis, Pixels},
+	utils::measurement::color::ColorRGB565,
+};
 
 use super::Char;
 
+mod bitmap;
+pub mod fonts;
+mod scaled;
+mod small;
+
+pub use bitmap::*;
+pub use scaled::*;
+pub use small::*;
+
+/// The glyphs used to draw a [`Text`](super::Text).
 pub trait Font {
-	fn get_image_of_char(&self, char: Char) -> BinaryImage<1>;
+	/// Returns the height of the lines of text drawn with this font.
+	fn get_line_height(&self) -> u16;
+	/// Returns the horizontal distance between the start of the provided `char` and the start of the next one, which
+	/// is `0` if the character isn't drawn.
 	fn get_width_of_char(&self, char: Char) -> u16;
+	/// Returns the sum of the widths of the characters of the provided `text`.
+	fn get_width_of_str(&self, text: &str) -> u16 {
+		text.bytes().map(|char| self.get_width_of_char(char)).sum()
+	}
+	/// Draws the glyph of the provided `char` with the `color`, filling the rest of its cell (which is
+	/// [`get_width_of_char`] wide and [`get_line_height`] tall) with the `background_color`.
+	///
+	/// [`get_width_of_char`]: `Self::get_width_of_char`
+	/// [`get_line_height`]: `Self::get_line_height`
+	fn draw_char(
+		&self, char: Char, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels),
+	);
+}
+
+impl<F: Font> Font for &F {
+	fn get_line_height(&self) -> u16 {
+		(*self).get_line_height()
+	}
+
+	fn get_width_of_char(&self, char: Char) -> u16 {
+		(*self).get_width_of_char(char)
+	}
+
+	fn draw_char(
+		&self, char: Char, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels),
+	) {
+		(*self).draw_char(char, color, background_color, draw_fn)
+	}
+}
+
+/// Draws a cell of the provided `size` row by row, joining the pixels next to each other with the same color (returned
+/// by `get_color` for each position in the cell) in a single run.
+fn draw_cell(size: U16x2, mut get_color: impl FnMut(u16, u16) -> ColorRGB565, draw_fn: &mut impl FnMut(Pixels)) {
+	for y in 0..size.y {
+		let mut run_start = 0;
+		let mut run_color = get_color(0, y);
+		for x in 1..=size.x {
+			let color = (x < size.x).then(|| get_color(x, y));
+			if color != Some(run_color) {
+				(draw_fn)(Pixels {
+					offset_position: U16x2 { x: run_start, y },
+					repetitions_count: x - run_start,
+					repetitions_direction: Axis::Horizontal,
+					color: run_color,
+				});
+				run_start = x;
+				run_color = color.unwrap_or(run_color);
+			}
+		}
+	}
 }
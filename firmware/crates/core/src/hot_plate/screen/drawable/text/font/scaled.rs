@@ -0,0 +1,56 @@
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::drawable::{Axis, Pixels},
+	utils::measurement::color::ColorRGB565,
+};
+
+use super::{super::Char, Font};
+
+/// A [`Font`] whose glyphs are `scale_factor` times bigger than the ones of the provided `font`.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::drawable::{Font, ScaledFont, SmallFont};
+/// #
+/// let font = ScaledFont {
+/// 	font: SmallFont,
+/// 	scale_factor: 2,
+/// };
+/// assert_eq!(font.get_line_height(), 2 * SmallFont::GLYPH_HEIGHT);
+/// assert_eq!(font.get_width_of_str("Hot"), 2 * 3 * SmallFont::ADVANCE);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScaledFont<F: Font> {
+	pub font: F,
+	pub scale_factor: u16,
+}
+
+impl<F: Font> Font for ScaledFont<F> {
+	fn get_line_height(&self) -> u16 {
+		self.font.get_line_height() * self.scale_factor
+	}
+
+	fn get_width_of_char(&self, char: Char) -> u16 {
+		self.font.get_width_of_char(char) * self.scale_factor
+	}
+
+	fn draw_char(
+		&self, char: Char, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels),
+	) {
+		self.font
+			.draw_char(char, color, background_color, &mut |mut pixels: Pixels| {
+				pixels.offset_position *= self.scale_factor;
+				pixels.repetitions_count *= self.scale_factor;
+				// Like `Scale`, each run of pixels becomes longer and it's repeated side by side
+				for i in 0..self.scale_factor {
+					let mut copied_pixels = pixels.clone();
+					copied_pixels.offset_position += match pixels.repetitions_direction {
+						Axis::Horizontal => U16x2 { x: 0, y: i },
+						Axis::Vertical => U16x2 { x: i, y: 0 },
+					};
+					(draw_fn)(copied_pixels)
+				}
+			});
+	}
+}
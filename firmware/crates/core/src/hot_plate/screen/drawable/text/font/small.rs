@@ -0,0 +1,133 @@
+use micromath::vector::U16x2;
+
+use crate::{hot_plate::screen::drawable::Pixels, utils::measurement::color::ColorRGB565};
+
+use super::{super::Char, draw_cell, Font};
+
+/// A monospaced [`Font`] built into the firmware, whose glyphs are 5x6 pixels followed by a column of space.
+///
+/// It has the digits, the uppercase letters (the lowercase ones are drawn as uppercase), the degree sign and the most
+/// common punctuation: the other printable ASCII characters are drawn as `?`.
+///
+/// Each byte of a string is a character, so a character that isn't ASCII is made of more bytes: these are skipped
+/// (they have a width of `0`), except for the last byte of `°` (`0xB0`), which is drawn as the degree sign.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::drawable::{Drawable, SmallFont, Text};
+/// #
+/// let text = Text::<8, _>::from_str("25.0°C", SmallFont);
+/// // `°` is made of 2 bytes, but only the second one is drawn
+/// assert_eq!(text.size().x, 6 * SmallFont::ADVANCE);
+/// assert_eq!(text.size().y, SmallFont::GLYPH_HEIGHT);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmallFont;
+
+impl SmallFont {
+	pub const GLYPH_WIDTH: u16 = 5;
+	pub const GLYPH_HEIGHT: u16 = 6;
+	/// The horizontal distance between the start of a character and the start of the next one.
+	pub const ADVANCE: u16 = Self::GLYPH_WIDTH + 1;
+	/// The character drawn as the degree sign.
+	pub const DEGREE_SIGN: Char = 0xB0;
+
+	/// Returns the rows of pixels of the glyph of the provided `char` (the most significant of the 5 bits of a row is
+	/// its leftmost pixel), or `None` if the font doesn't have it.
+	const fn get_rows_of_char(char: Char) -> Option<[u8; Self::GLYPH_HEIGHT as usize]> {
+		Some(match char.to_ascii_uppercase() {
+			b'0' => [0b01110, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+			b'1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b01110],
+			b'2' => [0b01110, 0b10001, 0b00010, 0b00100, 0b01000, 0b11111],
+			b'3' => [0b11110, 0b00001, 0b01110, 0b00001, 0b00001, 0b11110],
+			b'4' => [0b10010, 0b10010, 0b10010, 0b11111, 0b00010, 0b00010],
+			b'5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b11110],
+			b'6' => [0b01110, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+			b'7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000],
+			b'8' => [0b01110, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+			b'9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110],
+			b'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001],
+			b'B' => [0b11110, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+			b'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10001, 0b01110],
+			b'D' => [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110],
+			b'E' => [0b11111, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+			b'F' => [0b11111, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+			b'G' => [0b01110, 0b10000, 0b10111, 0b10001, 0b10001, 0b01110],
+			b'H' => [0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+			b'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+			b'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+			b'K' => [0b10001, 0b10010, 0b11100, 0b10010, 0b10001, 0b10001],
+			b'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+			b'M' => [0b10001, 0b11011, 0b10101, 0b10001, 0b10001, 0b10001],
+			b'N' => [0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+			b'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+			b'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000],
+			b'Q' => [0b01110, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+			b'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10010, 0b10001],
+			b'S' => [0b01111, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+			b'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+			b'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+			b'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+			b'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b11011, 0b10001],
+			b'X' => [0b10001, 0b01010, 0b00100, 0b00100, 0b01010, 0b10001],
+			b'Y' => [0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100],
+			b'Z' => [0b11111, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+			b' ' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000],
+			b'.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00100],
+			b',' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00100, 0b01000],
+			b':' => [0b00000, 0b00100, 0b00000, 0b00000, 0b00100, 0b00000],
+			b'-' => [0b00000, 0b00000, 0b01110, 0b00000, 0b00000, 0b00000],
+			b'+' => [0b00000, 0b00100, 0b01110, 0b00100, 0b00000, 0b00000],
+			b'=' => [0b00000, 0b01110, 0b00000, 0b01110, 0b00000, 0b00000],
+			b'/' => [0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000],
+			b'%' => [0b11001, 0b11010, 0b00100, 0b01011, 0b10011, 0b00000],
+			b'<' => [0b00010, 0b00100, 0b01000, 0b00100, 0b00010, 0b00000],
+			b'>' => [0b01000, 0b00100, 0b00010, 0b00100, 0b01000, 0b00000],
+			b'!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100],
+			b'?' => [0b01110, 0b10001, 0b00110, 0b00100, 0b00000, 0b00100],
+			b'(' => [0b00010, 0b00100, 0b00100, 0b00100, 0b00100, 0b00010],
+			b')' => [0b01000, 0b00100, 0b00100, 0b00100, 0b00100, 0b01000],
+			b'\'' => [0b00100, 0b00100, 0b00000, 0b00000, 0b00000, 0b00000],
+			b'_' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111],
+			SmallFont::DEGREE_SIGN => [0b01100, 0b10010, 0b01100, 0b00000, 0b00000, 0b00000],
+			_ => return None,
+		})
+	}
+}
+
+impl Font for SmallFont {
+	fn get_line_height(&self) -> u16 {
+		Self::GLYPH_HEIGHT
+	}
+
+	fn get_width_of_char(&self, char: Char) -> u16 {
+		match char == Self::DEGREE_SIGN || char.is_ascii_graphic() || char == b' ' {
+			true => Self::ADVANCE,
+			false => 0,
+		}
+	}
+
+	fn draw_char(
+		&self, char: Char, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels),
+	) {
+		let width = self.get_width_of_char(char);
+		let rows = match Self::get_rows_of_char(char) {
+			Some(rows) => rows,
+			None if width != 0 => Self::get_rows_of_char(b'?').unwrap(),
+			None => return,
+		};
+
+		let size = U16x2 {
+			x: width,
+			y: Self::GLYPH_HEIGHT,
+		};
+		draw_cell(
+			size,
+			|x, y| match x < Self::GLYPH_WIDTH && rows[y as usize] & (1 << (Self::GLYPH_WIDTH - 1 - x)) != 0 {
+				true => color,
+				false => background_color,
+			},
+			draw_fn,
+		);
+	}
+}
@@ -0,0 +1,207 @@
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::drawable::{Axis, Drawable, Pixels},
+	utils::measurement::color::ColorRGB565,
+};
+
+use super::{draw_chars, Font};
+
+/// How the lines of a text are placed horizontally inside their box.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+	#[default]
+	Left,
+	Center,
+	Right,
+}
+
+/// A text drawn with a [`Font`] inside a box of the provided size, with its lines placed with an [`Alignment`] and
+/// vertically centered.
+///
+/// The text is split in lines at each `\n` and, if wrapping is enabled (which it is by default), at the spaces before
+/// the words that don't fit in the width of the box. What doesn't fit in the box (like a word wider than the box) is
+/// clipped.
+///
+/// Only the cells of the characters are drawn (with their background color), so the rest of the box must be filled
+/// by the caller.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::drawable::{Alignment, Drawable, Font, SmallFont, TextBox};
+/// # use micromath::vector::U16x2;
+/// #
+/// let size = U16x2 {
+/// 	x: 10 * SmallFont::ADVANCE,
+/// 	y: 3 * SmallFont::GLYPH_HEIGHT,
+/// };
+/// let text_box = TextBox::new("The sensor can't be read", SmallFont, size).with_alignment(Alignment::Center);
+/// assert_eq!(text_box.lines().collect::<Vec<_>>(), ["The sensor", "can't be", "read"]);
+///
+/// // The pixels outside the box are never drawn
+/// let mut is_clipped = true;
+/// TextBox::new("Temperature", SmallFont, size).draw(&mut |pixels| {
+/// 	is_clipped &= pixels.offset_position.x + pixels.repetitions_count <= size.x;
+/// });
+/// assert!(is_clipped);
+/// ```
+pub struct TextBox<'a, F: Font> {
+	text: &'a str,
+	font: F,
+	size: U16x2,
+	alignment: Alignment,
+	is_wrapping: bool,
+	color: ColorRGB565,
+	background_color: ColorRGB565,
+}
+
+impl<'a, F: Font> TextBox<'a, F> {
+	/// Returns a [`TextBox`] of the provided `size` with the `text` aligned to the left, wrapped and drawn in white
+	/// over black.
+	pub fn new(text: &'a str, font: F, size: U16x2) -> Self {
+		Self {
+			text,
+			font,
+			size,
+			alignment: Alignment::Left,
+			is_wrapping: true,
+			color: ColorRGB565::WHITE,
+			background_color: ColorRGB565::BLACK,
+		}
+	}
+
+	/// Returns the same text box with its lines placed with the provided `alignment`.
+	pub fn with_alignment(self, alignment: Alignment) -> Self {
+		Self { alignment, ..self }
+	}
+
+	/// Returns the same text box that splits the lines that don't fit in its width only if `is_wrapping` is `true`.
+	pub fn with_wrapping(self, is_wrapping: bool) -> Self {
+		Self { is_wrapping, ..self }
+	}
+
+	/// Returns the same text box drawn with the provided `color` over the `background_color`.
+	pub fn with_colors(self, color: ColorRGB565, background_color: ColorRGB565) -> Self {
+		Self {
+			color,
+			background_color,
+			..self
+		}
+	}
+
+	/// Returns an iterator over the lines the text is split in.
+	pub fn lines(&self) -> Lines<'a, &F> {
+		Lines {
+			text: self.text,
+			font: &self.font,
+			max_width: self.is_wrapping.then_some(self.size.x),
+		}
+	}
+}
+
+impl<F: Font> Drawable for TextBox<'_, F> {
+	fn size(&self) -> U16x2 {
+		self.size
+	}
+
+	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
+		let line_height = self.font.get_line_height();
+		let height = self.lines().count() as u16 * line_height;
+
+		let mut y = self.size.y.saturating_sub(height) / 2;
+		for line in self.lines() {
+			if y >= self.size.y {
+				break;
+			}
+
+			let free_space = self.size.x.saturating_sub(self.font.get_width_of_str(line));
+			let x = match self.alignment {
+				Alignment::Left => 0,
+				Alignment::Center => free_space / 2,
+				Alignment::Right => free_space,
+			};
+			draw_chars(
+				&self.font,
+				line.bytes(),
+				self.color,
+				self.background_color,
+				&mut |mut pixels: Pixels| {
+					pixels.offset_position += U16x2 { x, y };
+					if let Some(pixels) = clip(pixels, self.size) {
+						(draw_fn)(pixels)
+					}
+				},
+			);
+
+			y += line_height;
+		}
+	}
+}
+
+/// An iterator over the lines of a [`TextBox`] (check [`TextBox::lines`]).
+pub struct Lines<'a, F: Font> {
+	text: &'a str,
+	font: F,
+	/// The width the lines are wrapped at, or `None` if they are split only at each `\n`.
+	max_width: Option<u16>,
+}
+
+impl<'a, F: Font> Lines<'a, F> {
+	/// Returns the text before `end`, and skips the text before `next_start`.
+	fn split(&mut self, end: usize, next_start: usize) -> &'a str {
+		let line = &self.text[..end];
+		self.text = &self.text[next_start..];
+
+		line
+	}
+}
+
+impl<'a, F: Font> Iterator for Lines<'a, F> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.text.is_empty() {
+			return None;
+		}
+
+		let mut width = 0;
+		let mut last_space = None;
+		for (i, char) in self.text.bytes().enumerate() {
+			match char {
+				b'\n' => return Some(self.split(i, i + 1)),
+				b' ' => last_space = Some(i),
+				_ => {},
+			}
+
+			width += self.font.get_width_of_char(char);
+			// The spaces at the end of a line can go past its width
+			if self.max_width.is_some_and(|max_width| width > max_width) && char != b' ' {
+				// A word wider than the whole line can't be split, so it's clipped
+				if let Some(space) = last_space {
+					let end = self.text[..space].trim_end_matches(' ').len();
+					let next_start = self.text.len() - self.text[space..].trim_start_matches(' ').len();
+					return Some(self.split(end, next_start));
+				}
+			}
+		}
+
+		Some(self.split(self.text.len(), self.text.len()))
+	}
+}
+
+/// Returns the part of the `pixels` inside a box of the provided `size` whose top left corner is at `(0, 0)`, or
+/// `None` if they are completely outside it.
+fn clip(mut pixels: Pixels, size: U16x2) -> Option<Pixels> {
+	let position = pixels.offset_position;
+	if position.x >= size.x || position.y >= size.y {
+		return None;
+	}
+
+	let space_left = match pixels.repetitions_direction {
+		Axis::Horizontal => size.x - position.x,
+		Axis::Vertical => size.y - position.y,
+	};
+	pixels.repetitions_count = pixels.repetitions_count.min(space_left);
+
+	Some(pixels)
+}
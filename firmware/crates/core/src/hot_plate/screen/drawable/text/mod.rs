@@ -1,44 +1,86 @@
-use micromath::vector::Vector2d;
+use micromath::vector::U16x2;
 
-pub use self::font::Font;
+pub use self::{
+	buffer::*,
+	font::{fonts, BitmapFont, BitsPerPixel, Font, Glyph, ScaledFont, SmallFont},
+	layout::*,
+};
 
 use super::{Drawable, Pixels};
+use crate::utils::measurement::color::ColorRGB565;
 
+mod buffer;
 mod font;
+mod layout;
 
 type Char = u8;
 
+/// A line of at most `N` bytes of text drawn with a [`Font`], with the glyphs of the provided `color` over the
+/// `background_color` (which are white and black by default).
 pub struct Text<const N: usize, F: Font> {
 	characters: [Char; N],
 	font: F,
+	color: ColorRGB565,
+	background_color: ColorRGB565,
 }
 
 impl<const N: usize, F: Font> Text<N, F> {
 	pub fn from_str(text: &str, font: F) -> Self {
 		let characters = crate::utils::slice_to_array_filled(text.as_bytes(), 0);
-		Self { characters, font }
+		Self {
+			characters,
+			font,
+			color: ColorRGB565::WHITE,
+			background_color: ColorRGB565::BLACK,
+		}
+	}
+
+	/// Returns the same text drawn with the provided `color` over the `background_color`.
+	pub fn with_colors(self, color: ColorRGB565, background_color: ColorRGB565) -> Self {
+		Self {
+			color,
+			background_color,
+			..self
+		}
 	}
 }
 
 impl<const N: usize, F: Font> Drawable for Text<N, F> {
-	fn size(&self) -> micromath::vector::U16x2 {
-		let mut x = 0;
-		for char in self.characters {
-			x += self.font.get_width_of_char(char);
+	/// Returns the sum of the widths of the characters, and the height of a line of the font.
+	fn size(&self) -> U16x2 {
+		U16x2 {
+			x: self
+				.characters
+				.iter()
+				.map(|&char| self.font.get_width_of_char(char))
+				.sum(),
+			y: self.font.get_line_height(),
 		}
-
-		Vector2d { x, y: todo!() }
 	}
 
 	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
-		let mut x = 0;
-		for char in self.characters {
-			self.font.get_image_of_char(char).draw(&mut |mut pixels: Pixels| {
-				pixels.offset_position.x += x;
-				(draw_fn)(pixels)
-			});
-
-			x += self.font.get_width_of_char(char);
+		draw_chars(&self.font, self.characters, self.color, self.background_color, draw_fn)
+	}
+}
+
+/// Draws the provided `chars` one after the other with the `font`.
+fn draw_chars(
+	font: &impl Font, chars: impl IntoIterator<Item = Char>, color: ColorRGB565, background_color: ColorRGB565,
+	draw_fn: &mut impl FnMut(Pixels),
+) {
+	let mut x = 0;
+	for char in chars {
+		// The characters without width (like the ones filling the end of the array of a `Text`) aren't drawn
+		let width = font.get_width_of_char(char);
+		if width == 0 {
+			continue;
 		}
+
+		font.draw_char(char, color, background_color, &mut |mut pixels: Pixels| {
+			pixels.offset_position.x += x;
+			(draw_fn)(pixels)
+		});
+
+		x += width;
 	}
 }
@@ -15,7 +15,8 @@ impl Drawable for Triangle {
 	}
 
 	fn draw(&self, draw_fn: &mut impl FnMut(Pixels)) {
-		for x in 0..self.size {
+		// Each column is 2 pixels shorter than the previous one, so the tip is reached at half the size
+		for x in 0..self.size.div_ceil(2) {
 			(draw_fn)(Pixels {
 				offset_position: U16x2 { x, y: x },
 				repetitions_count: self.size - 2 * x,
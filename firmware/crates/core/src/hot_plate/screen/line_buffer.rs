@@ -0,0 +1,160 @@
+use core::convert::Infallible;
+
+use micromath::vector::U16x2;
+
+use crate::utils::measurement::color::ColorRGB565;
+
+use super::{
+	drawable::{Axis, Drawable},
+	ui::widget::Rect,
+	Canvas,
+};
+
+/// A [`Canvas`] that stores the colors of a band of at most `N` pixels of the screen, so that overlapping drawables
+/// (like a text over a plot) can be composited in memory and sent to the display only once, without the flickering
+/// caused by drawing them one over the other on the display.
+///
+/// What is drawn outside the [`bounds`] of the band is discarded, so a region of the screen bigger than the buffer is
+/// drawn one band at a time (check [`Self::get_bands`]).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::{line_buffer::LineBuffer, ui::widget::Rect, Canvas},
+/// # 	utils::measurement::color::ColorRGB565,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let mut line_buffer = LineBuffer::<32>::new();
+/// let region = Rect::new(10, 10, 8, 10);
+/// // 4 rows of 8 pixels fit in the buffer
+/// assert_eq!(
+/// 	line_buffer.get_bands(region).collect::<Vec<_>>(),
+/// 	[Rect::new(10, 10, 8, 4), Rect::new(10, 14, 8, 4), Rect::new(10, 18, 8, 2)]
+/// );
+///
+/// line_buffer.begin(Rect::new(10, 10, 8, 4), ColorRGB565::BLACK);
+/// // Only the part of the rectangle inside the band is drawn
+/// line_buffer.fill(U16x2 { x: 16, y: 12 }, U16x2 { x: 10, y: 10 }, ColorRGB565::WHITE).unwrap();
+/// let white_pixels = line_buffer.get_pixels().iter().filter(|&&color| color == ColorRGB565::WHITE).count();
+/// assert_eq!(white_pixels, 2 * 2);
+/// ```
+///
+/// [`bounds`]: `Self::get_bounds`
+pub struct LineBuffer<const N: usize> {
+	pixels: [ColorRGB565; N],
+	bounds: Rect,
+}
+
+impl<const N: usize> LineBuffer<N> {
+	/// Returns an empty [`LineBuffer`].
+	pub fn new() -> Self {
+		Self {
+			pixels: [ColorRGB565::BLACK; N],
+			bounds: Rect::default(),
+		}
+	}
+
+	/// Returns the area of the screen the buffer contains.
+	pub fn get_bounds(&self) -> Rect {
+		self.bounds
+	}
+
+	/// Returns the bands of rows (from the top to the bottom) the provided `region` must be split in to draw it in the
+	/// buffer, one at a time.
+	///
+	/// The `region` shouldn't be wider than `N` pixels (otherwise each band is only one row of the first `N` pixels).
+	pub fn get_bands(&self, region: Rect) -> impl Iterator<Item = Rect> {
+		let rows_per_band = (N / region.size.x.max(1) as usize).clamp(1, u16::MAX as usize) as u16;
+		let end = region.position.y + region.size.y;
+
+		(region.position.y..end)
+			.step_by(rows_per_band as usize)
+			.map(move |y| Rect {
+				position: U16x2 {
+					x: region.position.x,
+					y,
+				},
+				size: U16x2 {
+					x: region.size.x,
+					y: rows_per_band.min(end - y),
+				},
+			})
+	}
+
+	/// Starts drawing the provided `bounds` (one of the [`bands`]), filling them with the provided `color`.
+	///
+	/// [`bands`]: `Self::get_bands`
+	pub fn begin(&mut self, mut bounds: Rect, color: ColorRGB565) {
+		bounds.size.x = bounds.size.x.min(N as u16);
+		bounds.size.y = bounds.size.y.min((N / bounds.size.x.max(1) as usize) as u16);
+		self.bounds = bounds;
+		self.get_pixels_mut().fill(color);
+	}
+
+	/// Returns the colors of the pixels inside the [`bounds`], row by row.
+	///
+	/// [`bounds`]: `Self::get_bounds`
+	pub fn get_pixels(&self) -> &[ColorRGB565] {
+		&self.pixels[..self.bounds.area() as usize]
+	}
+
+	fn get_pixels_mut(&mut self) -> &mut [ColorRGB565] {
+		let area = self.bounds.area() as usize;
+		&mut self.pixels[..area]
+	}
+
+	/// Fills the part of the provided `rect` that is inside the bounds with the `color`.
+	fn fill_clipped(&mut self, rect: Rect, color: ColorRGB565) {
+		let Some(rect) = rect.intersection(&self.bounds) else {
+			return;
+		};
+
+		let width = self.bounds.size.x as usize;
+		let offset = rect.position - self.bounds.position;
+		for row in offset.y..offset.y + rect.size.y {
+			let start = row as usize * width + offset.x as usize;
+			self.pixels[start..start + rect.size.x as usize].fill(color);
+		}
+	}
+}
+
+impl<const N: usize> Default for LineBuffer<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const N: usize> Canvas for LineBuffer<N> {
+	type Error = Infallible;
+
+	fn draw(&mut self, position: U16x2, drawable: &impl Drawable) -> Result<(), Self::Error> {
+		drawable.draw(&mut |pixels| {
+			let size = match pixels.repetitions_direction {
+				Axis::Horizontal => U16x2 {
+					x: pixels.repetitions_count,
+					y: 1,
+				},
+				Axis::Vertical => U16x2 {
+					x: 1,
+					y: pixels.repetitions_count,
+				},
+			};
+			self.fill_clipped(
+				Rect {
+					position: position + pixels.offset_position,
+					size,
+				},
+				pixels.color,
+			);
+		});
+
+		Ok(())
+	}
+
+	fn fill(&mut self, position: U16x2, size: U16x2, color: ColorRGB565) -> Result<(), Self::Error> {
+		self.fill_clipped(Rect { position, size }, color);
+
+		Ok(())
+	}
+}
@@ -1,29 +1,87 @@
-use embedded_hal::{digital::OutputPin, spi::SpiDevice};
+use embedded_graphics_core::{
+	draw_target::DrawTarget,
+	geometry::{OriginDimensions, Size},
+	pixelcolor::Rgb565,
+	primitives::Rectangle,
+	Pixel,
+};
+use embedded_hal::digital::OutputPin;
 use micromath::vector::U16x2;
 
-use self::drawable::{Axis, Drawable};
+use crate::utils::measurement::color::ColorRGB565;
 
-use super::drivers::ili9341::{SendError, ILI9341};
+use self::{
+	drawable::{special::Colored, Axis, Drawable, HorizontalLine},
+	line_buffer::LineBuffer,
+	ui::{widget::BACKGROUND_COLOR, HotPlateStatus, NavigationButton, TouchEvent, UiAction},
+};
 
+use super::{
+	drivers::ili9341::{SendError, ILI9341},
+	hal::display_transfer::DisplayTransfer,
+	input::ButtonEvent,
+};
+
+pub mod dirty_regions;
 pub mod drawable;
+pub mod line_buffer;
 pub mod ui;
 
 use ui::default::DefaultUI;
 
-const SCREEN_WIDTH_IN_PIXELS: usize = 320;
-const SCREEN_HEIGHT_IN_PIXELS: usize = 240;
-
-pub struct Screen<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice> {
-	ili9341: ILI9341<DCXPin, ResetPin, Spi>,
+pub const SCREEN_WIDTH_IN_PIXELS: usize = 320;
+pub const SCREEN_HEIGHT_IN_PIXELS: usize = 240;
+/// The number of pixels of the [`LineBuffer`] of the [`Screen`] (8 rows of the whole screen).
+pub const LINE_BUFFER_LENGTH: usize = 8 * SCREEN_WIDTH_IN_PIXELS;
+
+/// Something [`Drawable`]s can be drawn on.
+pub trait Canvas {
+	type Error;
+
+	/// Draws the provided `drawable` with its top left corner at the provided `position`.
+	fn draw(&mut self, position: U16x2, drawable: &impl Drawable) -> Result<(), Self::Error>;
+
+	/// Fills the rectangle with the top left corner at the provided `position` and the provided `size` with the
+	/// `color`.
+	///
+	/// By default it draws an [`HorizontalLine`] for each row, but a canvas can do it faster.
+	fn fill(&mut self, position: U16x2, size: U16x2, color: ColorRGB565) -> Result<(), Self::Error> {
+		self.draw(
+			position,
+			&Colored {
+				draw: HorizontalLine {
+					length: size.x,
+					thickness: size.y,
+				},
+				color,
+			},
+		)
+	}
+}
 
-	ui: Option<DefaultUI>,
+/// The display on which the [`DefaultUI`] is drawn.
+///
+/// At each [`tick`] only the areas of the screen that changed since the previous one are redrawn. If the screen has a
+/// [`LineBuffer`], each of these areas is composited in it one band at a time (with everything that overlaps it)
+/// before being sent to the display, so that each pixel is sent only once. Otherwise the widgets are drawn directly on
+/// the display, one over the other.
+///
+/// [`tick`]: `Self::tick`
+pub struct Screen<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> {
+	ili9341: ILI9341<DCXPin, ResetPin, Transfer>,
+	line_buffer: Option<LineBuffer<LINE_BUFFER_LENGTH>>,
+
+	ui: DefaultUI,
 }
 
-impl<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice> Screen<DCXPin, ResetPin, Spi> {
-	pub const fn new(ili9341: ILI9341<DCXPin, ResetPin, Spi>) -> Self {
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> Screen<DCXPin, ResetPin, Transfer> {
+	/// Returns a [`Screen`] that draws on the provided `ili9341`, compositing what it draws in a [`LineBuffer`] if
+	/// `use_line_buffer` is `true`.
+	pub fn new(ili9341: ILI9341<DCXPin, ResetPin, Transfer>, use_line_buffer: bool) -> Self {
 		Self {
 			ili9341,
-			ui: Some(DefaultUI::new()),
+			line_buffer: use_line_buffer.then(LineBuffer::new),
+			ui: DefaultUI::new(),
 		}
 	}
 
@@ -34,31 +92,132 @@ impl<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice> Screen<DCXPin, Rese
 		}
 	}
 
-	pub fn tick(&mut self) -> Result<(), SendError<DCXPin, Spi>> {
-		if let Some(ui) = self.ui.take() {
-			ui.draw(self)?;
+	/// Returns the user interface shown on the screen.
+	pub fn get_ui(&self) -> &DefaultUI {
+		&self.ui
+	}
 
-			self.ui = Some(ui);
+	/// Updates the user interface with the provided `status` of the hot plate, and draws what changed since the last
+	/// tick.
+	pub fn tick(&mut self, status: &HotPlateStatus) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.ui.update(status);
+
+		let Some(line_buffer) = &mut self.line_buffer else {
+			return self.ui.draw(&mut self.ili9341);
+		};
+		for region in self.ui.get_dirty_regions().iter() {
+			for band in line_buffer.get_bands(region) {
+				line_buffer.begin(band, BACKGROUND_COLOR);
+				let Ok(()) = self.ui.draw_region(line_buffer, band);
+
+				let bounds = line_buffer.get_bounds();
+				self.ili9341
+					.set_window(bounds.position, bounds.position + bounds.size - U16x2 { x: 1, y: 1 })?;
+				self.ili9341.write_pixels(line_buffer.get_pixels().iter().copied())?;
+			}
 		}
+		self.ui.set_drawn();
 
 		Ok(())
 	}
 
-	pub fn draw(&mut self, position: U16x2, drawable: &impl Drawable) -> Result<(), SendError<DCXPin, Spi>> {
+	/// Passes the provided `event` of the touch panel placed over the screen to the user interface, returning what
+	/// the user asked to do (if anything).
+	pub fn handle_touch(&mut self, event: TouchEvent) -> Option<UiAction> {
+		self.ui.handle_touch(event)
+	}
+
+	/// Passes the provided `event` of a [`NavigationButton`] to the user interface, returning what the user asked to do
+	/// (if anything).
+	pub fn handle_button(&mut self, button: NavigationButton, event: ButtonEvent) -> Option<UiAction> {
+		self.ui.handle_button(button, event)
+	}
+
+	/// Shows the provided `message` to the user in an error menu.
+	pub fn show_error(&mut self, message: &'static str) {
+		self.ui.show_error(message);
+	}
+
+	pub fn draw(&mut self, position: U16x2, drawable: &impl Drawable) -> Result<(), SendError<DCXPin, Transfer>> {
+		self.ili9341.draw(position, drawable)
+	}
+}
+
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> OriginDimensions
+	for Screen<DCXPin, ResetPin, Transfer>
+{
+	fn size(&self) -> Size {
+		OriginDimensions::size(&self.ili9341)
+	}
+}
+
+/// Draws the drawables of [`embedded-graphics`] directly on the display (check the implementation for [`ILI9341`]).
+///
+/// What is drawn this way isn't part of the user interface, so it's overwritten as soon as the user interface redraws
+/// that area.
+///
+/// [`embedded-graphics`]: `embedded_graphics_core`
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> DrawTarget
+	for Screen<DCXPin, ResetPin, Transfer>
+{
+	type Color = Rgb565;
+	type Error = SendError<DCXPin, Transfer>;
+
+	fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+	where
+		I: IntoIterator<Item = Pixel<Self::Color>>,
+	{
+		self.ili9341.draw_iter(pixels)
+	}
+
+	fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+	where
+		I: IntoIterator<Item = Self::Color>,
+	{
+		self.ili9341.fill_contiguous(area, colors)
+	}
+
+	fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+		self.ili9341.fill_solid(area, color)
+	}
+
+	fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+		self.ili9341.clear(color)
+	}
+}
+
+impl<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer> Canvas for ILI9341<DCXPin, ResetPin, Transfer> {
+	type Error = SendError<DCXPin, Transfer>;
+
+	fn draw(&mut self, position: U16x2, drawable: &impl Drawable) -> Result<(), Self::Error> {
+		let mut result = Ok(());
 		drawable.draw(&mut |pixels| {
+			// After an error the remaining pixels are skipped
+			if result.is_err() {
+				return;
+			}
+
+			if pixels.repetitions_count == 0 {
+				return;
+			}
+
 			let start = position + pixels.offset_position;
 
 			let mut end = start;
 			match pixels.repetitions_direction {
-				Axis::Horizontal => end.x += pixels.repetitions_count,
-				Axis::Vertical => end.y += pixels.repetitions_count,
+				Axis::Horizontal => end.x += pixels.repetitions_count - 1,
+				Axis::Vertical => end.y += pixels.repetitions_count - 1,
 			};
 
-			self.ili9341.set_window(start, end).unwrap();
-
-			self.ili9341.send_color(pixels.color, pixels.repetitions_count).unwrap();
+			result = self
+				.set_window(start, end)
+				.and_then(|_| self.send_color(pixels.color, pixels.repetitions_count as u32));
 		});
 
-		Ok(())
+		result
+	}
+
+	fn fill(&mut self, position: U16x2, size: U16x2, color: ColorRGB565) -> Result<(), Self::Error> {
+		self.fill_rect(position, size, color)
 	}
 }
@@ -0,0 +1,567 @@
+use core::{fmt, ops::Range, time::Duration};
+
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::{
+		process::{Interpolation, PhasedReflowProfile, ReflowProfile, ReflowProfilePhase},
+		screen::{
+			dirty_regions::DirtyRegions,
+			drawable::{special::Colored, HorizontalLine, SmallFont, VerticalLine},
+			Canvas,
+		},
+	},
+	utils::measurement::{color::ColorRGB565, temperature::Temperature},
+};
+
+use super::widget::{
+	self, get_temperature_color, Alignment, LabelText, Rect, TextFont, BACKGROUND_COLOR, BORDER_COLOR, TEXT_COLOR,
+};
+
+/// The maximum number of columns of pixels of the plot of a [`Chart`].
+pub const CHART_MAX_WIDTH: usize = 300;
+
+/// The phases of a [`PhasedReflowProfile`] in the order they are followed.
+const PHASES: [ReflowProfilePhase; 4] = [
+	ReflowProfilePhase::Preheat,
+	ReflowProfilePhase::Soak,
+	ReflowProfilePhase::Reflow,
+	ReflowProfilePhase::Cooling,
+];
+
+/// A chart of a reflow profile, on which the temperatures measured while following it are drawn.
+///
+/// The plot has a vertical axis with the temperatures in °C and a horizontal axis with the time in seconds, its
+/// background is shaded with the color of the [`ReflowProfilePhase`] each column belongs to, and a vertical cursor
+/// shows the current time of the reflow process. The measured temperatures are drawn with the color of their
+/// temperature (check [`get_temperature_color`]).
+///
+/// After the chart has been completely drawn, only the samples that have been recorded since the last time it has
+/// been drawn and the columns the cursor left or reached are sent to the screen.
+///
+/// It's not a [`Widget`] because it's much bigger than them (and each variant of an enum is as big as the biggest one).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::{
+/// # 	process::{BuiltinReflowProfile, Interpolation},
+/// # 	screen::{drawable::Drawable, ui::{chart::Chart, widget::Rect}, Canvas},
+/// # };
+/// # use firmware_core::utils::measurement::temperature::Temperature;
+/// # use micromath::vector::U16x2;
+/// # use std::{convert::Infallible, time::Duration};
+/// #
+/// /// A canvas that only counts the pixels sent to the screen.
+/// struct PixelsCounter(u32);
+///
+/// impl Canvas for PixelsCounter {
+/// 	type Error = Infallible;
+///
+/// 	fn draw(&mut self, _position: U16x2, drawable: &impl Drawable) -> Result<(), Self::Error> {
+/// 		drawable.draw(&mut |pixels| self.0 += pixels.repetitions_count as u32);
+/// 		Ok(())
+/// 	}
+/// }
+///
+/// let profile = BuiltinReflowProfile::LeadedSn63Pb37;
+/// let mut chart = Chart::new(Rect::new(0, 40, 320, 146));
+/// chart.set_profile(&profile.reflow_profile(), Interpolation::Linear);
+/// chart.set_phases(profile.phased_profile().as_ref());
+///
+/// let mut canvas = PixelsCounter(0);
+/// chart.draw(&mut canvas).unwrap();
+/// // The whole chart is drawn the first time
+/// assert!(canvas.0 >= 320 * 146);
+/// assert!(!chart.is_dirty());
+///
+/// canvas.0 = 0;
+/// chart.record(Duration::from_secs(1), Temperature::from_celsius(27.));
+/// chart.set_cursor(Some(Duration::from_secs(1)));
+/// chart.draw(&mut canvas).unwrap();
+/// // Then only the new sample and the column of the cursor are drawn
+/// assert!(canvas.0 < 2 * 146);
+/// ```
+///
+/// [`Widget`]: `super::widget::Widget`
+pub struct Chart {
+	bounds: Rect,
+	/// The temperature at the top of the plot.
+	max_temperature: Temperature,
+	duration: Duration,
+	/// When each of the [`PHASES`] ends (in seconds since the start of the profile), or `None` if the profile isn't
+	/// divided in phases.
+	phase_ends_in_seconds: Option<[f32; PHASES.len()]>,
+	/// The vertical offset from the top of the chart of the target temperature at each column.
+	target: [u16; CHART_MAX_WIDTH],
+	/// The vertical offset from the top of the chart of the measured temperature at each column.
+	measured: [u16; CHART_MAX_WIDTH],
+	/// The number of columns of the measured temperature that have been drawn.
+	drawn_columns: usize,
+	/// The number of columns of the measured temperature that have been recorded.
+	recorded_columns: usize,
+	/// The column of the current time.
+	cursor: Option<usize>,
+	/// The column where the cursor is on the screen.
+	drawn_cursor: Option<usize>,
+	is_dirty: bool,
+}
+
+impl Chart {
+	/// The offset of a column without a temperature.
+	const NO_SAMPLE: u16 = u16::MAX;
+	/// How many degrees above the peak of the profile the top of the chart is.
+	const MARGIN_ABOVE_PEAK: f32 = 20.;
+	/// The length in pixels of the marks of the ticks on the axes.
+	const TICK_LENGTH: u16 = 3;
+	/// The horizontal offset from the left of the chart of the vertical axis (there's enough space on its left for the
+	/// labels of 3 digits).
+	const AXIS_X: u16 = 3 * SmallFont::ADVANCE + Self::TICK_LENGTH;
+	/// The height in pixels of the area below the plot (occupied by the horizontal axis, its ticks and their labels).
+	const BOTTOM_MARGIN: u16 = 1 + Self::TICK_LENGTH + 1 + SmallFont::GLYPH_HEIGHT;
+	/// The temperatures between the ticks of the vertical axis.
+	const TEMPERATURE_TICK_STEP: u16 = 50;
+	/// The durations in seconds between the ticks of the horizontal axis that can be used (the shortest one that leaves
+	/// enough space for the labels is chosen).
+	const TIME_TICK_STEPS: [u16; 4] = [30, 60, 120, 300];
+	/// The minimum horizontal distance in pixels between 2 ticks of the horizontal axis.
+	const MIN_TIME_TICK_SPACING: f32 = 5. * SmallFont::ADVANCE as f32;
+	pub const TARGET_COLOR: ColorRGB565 = ColorRGB565::GRAY;
+	pub const CURSOR_COLOR: ColorRGB565 = ColorRGB565::YELLOW;
+	pub const AXIS_COLOR: ColorRGB565 = BORDER_COLOR;
+
+	/// Returns an empty [`Chart`].
+	pub fn new(bounds: Rect) -> Self {
+		Self {
+			bounds,
+			max_temperature: Temperature::from_celsius(Self::MARGIN_ABOVE_PEAK),
+			duration: Duration::ZERO,
+			phase_ends_in_seconds: None,
+			target: [Self::NO_SAMPLE; CHART_MAX_WIDTH],
+			measured: [Self::NO_SAMPLE; CHART_MAX_WIDTH],
+			drawn_columns: 0,
+			recorded_columns: 0,
+			cursor: None,
+			drawn_cursor: None,
+			is_dirty: true,
+		}
+	}
+
+	/// Shows the provided `profile` on the chart, removing the measured temperatures and the cursor.
+	pub fn set_profile<const N: usize>(&mut self, profile: &ReflowProfile<N>, interpolation: Interpolation) {
+		let duration_in_seconds = profile.temperature_points.last().map(|&(_, time)| time).unwrap_or(0);
+		let peak_temperature = profile
+			.temperature_points
+			.iter()
+			.map(|&(temperature, _)| temperature.as_celsius())
+			.fold(0., f32::max);
+
+		self.duration = Duration::from_secs(duration_in_seconds as u64);
+		self.max_temperature = Temperature::from_celsius(peak_temperature + Self::MARGIN_ABOVE_PEAK);
+		for column in 0..self.get_columns_count() {
+			let time = self.get_time_of_column(column);
+			self.target[column] = profile
+				.get_temperature_at(time, interpolation)
+				.map(|temperature| self.get_offset_of(temperature))
+				.unwrap_or(Self::NO_SAMPLE);
+		}
+		self.measured = [Self::NO_SAMPLE; CHART_MAX_WIDTH];
+		self.drawn_columns = 0;
+		self.recorded_columns = 0;
+		self.cursor = None;
+		self.is_dirty = true;
+	}
+
+	/// Shades the background of the chart with the color of the phase of the provided profile each column belongs to
+	/// (or removes the shading if it's `None`).
+	///
+	/// The `phased_profile` should be the one the profile passed to [`Self::set_profile`] has been built from.
+	pub fn set_phases(&mut self, phased_profile: Option<&PhasedReflowProfile>) {
+		self.phase_ends_in_seconds = phased_profile.map(|phased_profile| {
+			let mut end = 0.;
+			PHASES.map(|phase| {
+				end += phased_profile.duration_of(phase);
+				end
+			})
+		});
+		self.is_dirty = true;
+	}
+
+	/// Adds the `temperature` measured at the provided `time` since the start of the profile to the chart.
+	pub fn record(&mut self, time: Duration, temperature: Temperature) {
+		// The columns already on the screen aren't changed
+		if let Some(column) = self.get_column_at(time).filter(|&column| column >= self.drawn_columns) {
+			self.measured[column] = self.get_offset_of(temperature);
+			self.recorded_columns = self.recorded_columns.max(column + 1);
+		}
+	}
+
+	/// Moves the cursor to the provided `time` since the start of the profile (or hides it if it's `None`).
+	pub fn set_cursor(&mut self, time: Option<Duration>) {
+		self.cursor = time.and_then(|time| self.get_column_at(time));
+	}
+
+	/// Returns `true` if the chart changed since the last time it has been drawn.
+	pub fn is_dirty(&self) -> bool {
+		self.is_dirty || self.recorded_columns > self.drawn_columns || self.cursor != self.drawn_cursor
+	}
+
+	/// Makes the chart be completely redrawn the next time it's drawn.
+	pub fn set_dirty(&mut self) {
+		self.is_dirty = true;
+	}
+
+	/// Adds to the `regions` the areas of the chart that changed since the last time it has been drawn (the ones
+	/// [`Self::draw`] would send to the screen).
+	pub fn add_dirty_regions(&self, regions: &mut DirtyRegions) {
+		if self.is_dirty {
+			regions.add(self.bounds);
+			return;
+		}
+
+		regions.add(self.get_columns_bounds(self.drawn_columns..self.recorded_columns));
+		if self.cursor != self.drawn_cursor {
+			for column in [self.drawn_cursor, self.cursor].into_iter().flatten() {
+				regions.add(self.get_columns_bounds(column..column + 1));
+			}
+		}
+	}
+
+	/// Marks everything as drawn, so that the chart isn't [`dirty`] anymore until it changes.
+	///
+	/// [`dirty`]: `Self::is_dirty`
+	pub fn set_clean(&mut self) {
+		self.drawn_columns = self.recorded_columns;
+		self.drawn_cursor = self.cursor;
+		self.is_dirty = false;
+	}
+
+	/// Returns the number of columns of the plot.
+	fn get_columns_count(&self) -> usize {
+		(self.bounds.size.x.saturating_sub(Self::AXIS_X + 1) as usize).min(CHART_MAX_WIDTH)
+	}
+
+	/// Returns the number of rows of the plot (the horizontal axis is drawn right below them).
+	fn get_plot_height(&self) -> u16 {
+		self.bounds.size.y.saturating_sub(Self::BOTTOM_MARGIN)
+	}
+
+	/// Returns the area of the screen occupied by the provided `columns` of the plot.
+	fn get_columns_bounds(&self, columns: Range<usize>) -> Rect {
+		Rect {
+			position: self.get_position_of_column(columns.start),
+			size: U16x2 {
+				x: columns.len() as u16,
+				y: self.get_plot_height(),
+			},
+		}
+	}
+
+	/// Returns the position on the screen of the top of the provided `column`.
+	fn get_position_of_column(&self, column: usize) -> U16x2 {
+		self.bounds.position
+			+ U16x2 {
+				x: Self::AXIS_X + 1 + column as u16,
+				y: 0,
+			}
+	}
+
+	fn get_time_of_column(&self, column: usize) -> Duration {
+		self.duration.mul_f32(column as f32 / self.get_columns_count() as f32)
+	}
+
+	/// Returns the column of the provided `time`, or `None` if it's after the end of the profile.
+	fn get_column_at(&self, time: Duration) -> Option<usize> {
+		if self.duration.is_zero() {
+			return None;
+		}
+
+		let column = (time.as_secs_f32() / self.duration.as_secs_f32() * self.get_columns_count() as f32) as usize;
+		(column < self.get_columns_count()).then_some(column)
+	}
+
+	fn get_offset_of(&self, temperature: Temperature) -> u16 {
+		let ratio = (temperature.as_celsius() / self.max_temperature.as_celsius()).clamp(0., 1.);
+
+		((1. - ratio) * self.get_plot_height().saturating_sub(1) as f32) as u16
+	}
+
+	/// Returns the temperature of the provided `offset` from the top of the plot (the inverse of
+	/// [`Self::get_offset_of`]).
+	fn get_temperature_of(&self, offset: u16) -> Temperature {
+		let ratio = 1. - offset as f32 / self.get_plot_height().saturating_sub(1).max(1) as f32;
+
+		Temperature::from_celsius(ratio * self.max_temperature.as_celsius())
+	}
+
+	/// Returns the color of the background of the provided `column`, which depends on the phase it belongs to.
+	fn get_background_of(&self, column: usize) -> ColorRGB565 {
+		let Some(phase_ends_in_seconds) = self.phase_ends_in_seconds else {
+			return BACKGROUND_COLOR;
+		};
+		let time = self.get_time_of_column(column).as_secs_f32();
+
+		match phase_ends_in_seconds.iter().position(|&end| time < end) {
+			Some(index) => get_phase_color(PHASES[index]),
+			None => BACKGROUND_COLOR,
+		}
+	}
+
+	/// Draws on the `canvas` the whole chart if it's been [`set dirty`], otherwise only the measured temperatures that
+	/// have been recorded and the columns the cursor moved from and to since the last time it has been drawn.
+	///
+	/// [`set dirty`]: `Self::set_dirty`
+	pub fn draw<C: Canvas>(&mut self, canvas: &mut C) -> Result<(), C::Error> {
+		if self.is_dirty {
+			self.draw_axes(canvas)?;
+			for column in 0..self.get_columns_count() {
+				self.draw_column(canvas, column, self.get_background_of(column))?;
+			}
+
+			self.drawn_columns = self.recorded_columns;
+			self.drawn_cursor = None;
+			self.is_dirty = false;
+		}
+
+		for column in self.drawn_columns..self.recorded_columns {
+			self.draw_measured_segment(canvas, column)?;
+		}
+		self.drawn_columns = self.recorded_columns;
+
+		if self.cursor != self.drawn_cursor {
+			if let Some(column) = self.drawn_cursor {
+				self.draw_column(canvas, column, self.get_background_of(column))?;
+			}
+			if let Some(column) = self.cursor {
+				self.draw_column(canvas, column, Self::CURSOR_COLOR)?;
+			}
+			self.drawn_cursor = self.cursor;
+		}
+
+		Ok(())
+	}
+
+	/// Draws on the `canvas` the whole chart as it is now, or at least the part of it inside the provided `region`
+	/// (the canvas must discard what is drawn outside of it), without marking it as drawn (check [`Self::set_clean`]).
+	pub fn render<C: Canvas>(&self, canvas: &mut C, region: Rect) -> Result<(), C::Error> {
+		let plot_bounds = self.get_columns_bounds(0..self.get_columns_count());
+		if region.intersection(&self.bounds).is_none() {
+			return Ok(());
+		}
+		if plot_bounds.intersection(&region) != Some(region) {
+			self.draw_axes(canvas)?;
+		}
+
+		let Some(region) = region.intersection(&plot_bounds) else {
+			return Ok(());
+		};
+		let first_column = (region.position.x - plot_bounds.position.x) as usize;
+		for column in first_column..first_column + region.size.x as usize {
+			let background = match self.cursor == Some(column) {
+				true => Self::CURSOR_COLOR,
+				false => self.get_background_of(column),
+			};
+			self.draw_column(canvas, column, background)?;
+		}
+
+		Ok(())
+	}
+
+	/// Draws everything outside the plot: the axes, their ticks and the labels of the ticks.
+	fn draw_axes<C: Canvas>(&self, canvas: &mut C) -> Result<(), C::Error> {
+		let plot_height = self.get_plot_height();
+		let plot_width = self.get_columns_count() as u16;
+		let at = |x, y| self.bounds.position + U16x2 { x, y };
+		let horizontal_line = |length| Colored {
+			draw: HorizontalLine { length, thickness: 1 },
+			color: Self::AXIS_COLOR,
+		};
+		let vertical_line = |length| Colored {
+			draw: VerticalLine { length, thickness: 1 },
+			color: Self::AXIS_COLOR,
+		};
+
+		// The areas on the left of, below and on the right of the plot
+		widget::fill(
+			canvas,
+			Rect {
+				position: at(0, 0),
+				size: U16x2 {
+					x: Self::AXIS_X,
+					y: self.bounds.size.y,
+				},
+			},
+			BACKGROUND_COLOR,
+		)?;
+		widget::fill(
+			canvas,
+			Rect {
+				position: at(Self::AXIS_X, plot_height),
+				size: U16x2 {
+					x: self.bounds.size.x - Self::AXIS_X,
+					y: self.bounds.size.y - plot_height,
+				},
+			},
+			BACKGROUND_COLOR,
+		)?;
+		widget::fill(
+			canvas,
+			Rect {
+				position: at(Self::AXIS_X + 1 + plot_width, 0),
+				size: U16x2 {
+					x: self.bounds.size.x - (Self::AXIS_X + 1 + plot_width),
+					y: plot_height,
+				},
+			},
+			BACKGROUND_COLOR,
+		)?;
+
+		canvas.draw(at(Self::AXIS_X, 0), &vertical_line(plot_height + 1))?;
+		canvas.draw(at(Self::AXIS_X, plot_height), &horizontal_line(plot_width + 1))?;
+
+		let label_bounds = |x, y, width| Rect {
+			position: at(x, y),
+			size: U16x2 {
+				x: width,
+				y: SmallFont::GLYPH_HEIGHT,
+			},
+		};
+		draw_label(
+			canvas,
+			label_bounds(0, 0, Self::AXIS_X),
+			format_args!("°C"),
+			Alignment::Left,
+		)?;
+		let mut temperature = Self::TEMPERATURE_TICK_STEP;
+		while (temperature as f32) < self.max_temperature.as_celsius() {
+			let y = self.get_offset_of(Temperature::from_celsius(temperature as f32));
+			// The labels too close to the top would overlap the unit
+			if y < 2 * SmallFont::GLYPH_HEIGHT {
+				break;
+			}
+
+			canvas.draw(
+				at(Self::AXIS_X - Self::TICK_LENGTH, y),
+				&horizontal_line(Self::TICK_LENGTH),
+			)?;
+			draw_label(
+				canvas,
+				label_bounds(0, y - SmallFont::GLYPH_HEIGHT / 2, Self::AXIS_X - Self::TICK_LENGTH - 1),
+				format_args!("{}", temperature),
+				Alignment::Right,
+			)?;
+			temperature += Self::TEMPERATURE_TICK_STEP;
+		}
+
+		let Some(time_step) = self.get_time_tick_step() else {
+			return Ok(());
+		};
+		let label_width = 5 * SmallFont::ADVANCE;
+		let mut time = time_step;
+		while let Some(column) = self.get_column_at(Duration::from_secs(time as u64)) {
+			let x = Self::AXIS_X + 1 + column as u16;
+			canvas.draw(at(x, plot_height + 1), &vertical_line(Self::TICK_LENGTH))?;
+			// The label is centered on the tick, but it's kept inside the bounds of the chart
+			let label_x = x.saturating_sub(label_width / 2).min(self.bounds.size.x - label_width);
+			draw_label(
+				canvas,
+				label_bounds(label_x, plot_height + 1 + Self::TICK_LENGTH + 1, label_width),
+				format_args!("{}s", time),
+				Alignment::Center,
+			)?;
+			time += time_step;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the shortest of the [`TIME_TICK_STEPS`] whose ticks are far enough from each other, or `None` if there
+	/// isn't any.
+	///
+	/// [`TIME_TICK_STEPS`]: `Self::TIME_TICK_STEPS`
+	fn get_time_tick_step(&self) -> Option<u16> {
+		let pixels_per_second = self.get_columns_count() as f32 / self.duration.as_secs_f32();
+
+		Self::TIME_TICK_STEPS
+			.into_iter()
+			.find(|&step| step as f32 * pixels_per_second >= Self::MIN_TIME_TICK_SPACING)
+	}
+
+	/// Draws a whole column of the plot: its `background`, the segment of the target curve and the one of the measured
+	/// curve (if it has been recorded).
+	fn draw_column<C: Canvas>(&self, canvas: &mut C, column: usize, background: ColorRGB565) -> Result<(), C::Error> {
+		canvas.draw(
+			self.get_position_of_column(column),
+			&Colored {
+				draw: VerticalLine {
+					length: self.get_plot_height(),
+					thickness: 1,
+				},
+				color: background,
+			},
+		)?;
+		self.draw_segment(canvas, &self.target, column, Self::TARGET_COLOR)?;
+		self.draw_measured_segment(canvas, column)
+	}
+
+	/// Draws the provided `column` of the measured curve with the color of its temperature.
+	fn draw_measured_segment<C: Canvas>(&self, canvas: &mut C, column: usize) -> Result<(), C::Error> {
+		let color = get_temperature_color(self.get_temperature_of(self.measured[column]));
+
+		self.draw_segment(canvas, &self.measured, column, color)
+	}
+
+	/// Draws the provided `column` of a curve, connecting it to the previous column with a vertical segment.
+	fn draw_segment<C: Canvas>(
+		&self, canvas: &mut C, offsets: &[u16; CHART_MAX_WIDTH], column: usize, color: ColorRGB565,
+	) -> Result<(), C::Error> {
+		let offset = offsets[column];
+		if offset == Self::NO_SAMPLE {
+			return Ok(());
+		}
+
+		let previous_offset = match column.checked_sub(1).map(|column| offsets[column]) {
+			Some(previous_offset) if previous_offset != Self::NO_SAMPLE => previous_offset,
+			_ => offset,
+		};
+		let top = offset.min(previous_offset);
+		let bottom = offset.max(previous_offset);
+
+		canvas.draw(
+			self.get_position_of_column(column) + U16x2 { x: 0, y: top },
+			&Colored {
+				draw: VerticalLine {
+					length: bottom - top + 1,
+					thickness: 1,
+				},
+				color,
+			},
+		)
+	}
+}
+
+/// Draws the formatted `arguments` (check [`format_args!`]) with the unscaled [`SmallFont`] inside the `bounds`.
+fn draw_label<C: Canvas>(
+	canvas: &mut C, bounds: Rect, arguments: fmt::Arguments, alignment: Alignment,
+) -> Result<(), C::Error> {
+	widget::draw_text(
+		canvas,
+		bounds,
+		LabelText::from_fmt(arguments).as_str(),
+		TextFont::Small(1),
+		alignment,
+		TEXT_COLOR,
+	)
+}
+
+/// Returns the color of the background of the columns of the [`Chart`] that belong to the provided `phase`, which is
+/// dimmed so that the curves (whose colors go from blue to red) stand out.
+fn get_phase_color(phase: ReflowProfilePhase) -> ColorRGB565 {
+	let color = match phase {
+		ReflowProfilePhase::Preheat => ColorRGB565::NAVY,
+		ReflowProfilePhase::Soak => ColorRGB565::DARK_GREEN,
+		ReflowProfilePhase::Reflow => ColorRGB565::PURPLE,
+		ReflowProfilePhase::Cooling => ColorRGB565::DARK_CYAN,
+	};
+
+	color.lerp(BACKGROUND_COLOR, 0.5)
+}
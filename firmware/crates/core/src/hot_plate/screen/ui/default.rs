@@ -1,57 +1,674 @@
-use embedded_hal::{digital::OutputPin, spi::SpiDevice};
-use micromath::vector::U16x2;
-
-use crate::hot_plate::{
-	drivers::ili9341::SendError,
-	screen::{
-		drawable::{special::Flipped, Axis, Triangle},
-		Screen,
+use core::{fmt, time::Duration};
+
+use crate::{
+	hot_plate::{
+		input::ButtonEvent,
+		process::{BuiltinReflowProfile, Interpolation, ReflowState},
+		screen::{
+			dirty_regions::DirtyRegions,
+			drawable::{fonts, images, CompressedImage, Font},
+			Canvas, SCREEN_HEIGHT_IN_PIXELS, SCREEN_WIDTH_IN_PIXELS,
+		},
 	},
+	utils::measurement::{color::ColorRGB565, duration::ClockDuration, temperature::Temperature},
 };
 
-use super::Menu;
+use super::{
+	chart::Chart,
+	focus::Focus,
+	message,
+	widget::{
+		self, Alignment, Button, Icon, Label, ListSelector, Rect, Spinner, TextFont, Widget, WidgetEvent, TEXT_FONT,
+	},
+	HotPlateStatus, Menu, MenuStack, NavigationButton, TouchEvent, UiAction,
+};
+
+/// The built-in profiles in the order they are shown in the [`Menu::ProfileSelect`].
+const PROFILES: [BuiltinReflowProfile; 4] = [
+	BuiltinReflowProfile::LeadedSn63Pb37,
+	BuiltinReflowProfile::LeadFreeSac305,
+	BuiltinReflowProfile::LowTemperatureSn42Bi58,
+	BuiltinReflowProfile::ReworkPreheat,
+];
+const PROFILE_NAMES: [&str; PROFILES.len()] = [
+	PROFILES[0].name(),
+	PROFILES[1].name(),
+	PROFILES[2].name(),
+	PROFILES[3].name(),
+];
+/// The interpolations in the order they are shown in the [`Menu::Settings`].
+const INTERPOLATIONS: [Interpolation; 2] = [Interpolation::Linear, Interpolation::Smooth];
+const INTERPOLATION_NAMES: [&str; INTERPOLATIONS.len()] = ["Linear", "Smooth"];
+
+/// The size of the whole screen.
+const SCREEN: Rect = Rect::new(0, 0, SCREEN_WIDTH_IN_PIXELS as u16, SCREEN_HEIGHT_IN_PIXELS as u16);
+const TITLE_BOUNDS: Rect = Rect::new(0, 8, SCREEN.size.x, 24);
+const TITLE_FONT: TextFont = TextFont::Bitmap(&fonts::SANS_BOLD_20);
+/// The font of the description of a profile in the [`Menu::ProfileSelect`], which is split in 2 lines.
+const DESCRIPTION_FONT: TextFont = TextFont::Bitmap(&fonts::SANS_12);
+/// The font of the current temperature in the [`Menu::Home`].
+const TEMPERATURE_FONT: TextFont = TextFont::Bitmap(&fonts::SANS_BOLD_DIGITS_36);
+/// The bounds of the 2 buttons at the bottom of a menu.
+const LEFT_BUTTON_BOUNDS: Rect = Rect::new(10, 190, 145, 40);
+const RIGHT_BUTTON_BOUNDS: Rect = Rect::new(165, 190, 145, 40);
+const CHART_BOUNDS: Rect = Rect::new(0, 40, SCREEN.size.x, 146);
 
+/// The indices of the widgets of the [`Menu::Home`] the user interface updates or reacts to.
+mod home {
+	pub const TEMPERATURE: usize = 1;
+	pub const PROFILE: usize = 2;
+	pub const STATE: usize = 3;
+	pub const START: usize = 4;
+	pub const PROFILES: usize = 5;
+	pub const SETTINGS: usize = 6;
+	pub const STATUS_ICON: usize = 7;
+	pub const COUNT: usize = 8;
+}
+
+/// The indices of the widgets of the [`Menu::ProfileSelect`] the user interface updates or reacts to.
+mod profile_select {
+	pub const SELECTOR: usize = 1;
+	pub const DESCRIPTION: usize = 2;
+	pub const DETAILS: usize = 3;
+	pub const SELECT: usize = 4;
+	pub const BACK: usize = 5;
+	pub const COUNT: usize = 6;
+}
+
+/// The indices of the widgets of the [`Menu::Reflowing`] the user interface updates or reacts to.
+mod reflowing {
+	pub const STATE: usize = 0;
+	pub const REMAINING_TIME: usize = 1;
+	pub const CURRENT_TEMPERATURE: usize = 2;
+	pub const TARGET_TEMPERATURE: usize = 3;
+	pub const PAUSE: usize = 4;
+	pub const ABORT: usize = 5;
+	pub const COUNT: usize = 6;
+}
+
+/// The indices of the widgets of the [`Menu::Settings`] the user interface updates or reacts to.
+mod settings {
+	pub const INTERPOLATION: usize = 2;
+	pub const AUTOTUNE_TEMPERATURE: usize = 4;
+	pub const AUTOTUNE: usize = 5;
+	pub const BACK: usize = 6;
+	pub const COUNT: usize = 7;
+}
+
+/// The indices of the widgets of the [`Menu::Error`] the user interface updates or reacts to.
+mod error {
+	pub const MESSAGE: usize = 1;
+	pub const OK: usize = 2;
+	pub const COUNT: usize = 4;
+}
+
+/// The widgets of all the menus.
+struct Pages {
+	home: [Widget; home::COUNT],
+	profile_select: [Widget; profile_select::COUNT],
+	reflowing: [Widget; reflowing::COUNT],
+	settings: [Widget; settings::COUNT],
+	error: [Widget; error::COUNT],
+}
+
+impl Pages {
+	fn new() -> Self {
+		let title = |text| Widget::Label(Label::new(TITLE_BOUNDS, text, TITLE_FONT, Alignment::Center));
+		let line = |y, font: TextFont| Rect::new(0, y, SCREEN.size.x, font.get_line_height());
+		let label = |bounds, font| Widget::Label(Label::new(bounds, "", font, Alignment::Center));
+
+		Self {
+			home: [
+				Widget::Icon(Icon::new(Rect::new(0, 2, SCREEN.size.x, 40), Some(&images::LOGO))),
+				label(
+					Rect::new(60, 44, 200, TEMPERATURE_FONT.get_line_height()),
+					TEMPERATURE_FONT,
+				),
+				label(line(88, TEXT_FONT), TEXT_FONT),
+				label(line(108, TEXT_FONT), TEXT_FONT),
+				Widget::Button(Button::new(Rect::new(10, 136, 300, 44), "Start")),
+				Widget::Button(Button::new(LEFT_BUTTON_BOUNDS, "Profile")),
+				Widget::Button(Button::new(RIGHT_BUTTON_BOUNDS, "Settings")),
+				Widget::Icon(Icon::new(Rect::new(20, 49, 32, 32), None)),
+			],
+			profile_select: [
+				title("Profile"),
+				Widget::ListSelector(ListSelector::new(Rect::new(10, 50, 300, 40), &PROFILE_NAMES, 0)),
+				label(Rect::new(10, 96, 300, 28), DESCRIPTION_FONT),
+				label(line(130, TEXT_FONT), TEXT_FONT),
+				Widget::Button(Button::new(LEFT_BUTTON_BOUNDS, "Select")),
+				Widget::Button(Button::new(RIGHT_BUTTON_BOUNDS, "Back")),
+			],
+			reflowing: [
+				Widget::Label(Label::new(Rect::new(4, 1, 150, 19), "", TEXT_FONT, Alignment::Left)),
+				Widget::Label(Label::new(Rect::new(166, 1, 150, 19), "", TEXT_FONT, Alignment::Right)),
+				Widget::Label(Label::new(Rect::new(4, 20, 150, 19), "", TEXT_FONT, Alignment::Left)),
+				Widget::Label(Label::new(Rect::new(166, 20, 150, 19), "", TEXT_FONT, Alignment::Right)),
+				Widget::Button(Button::new(LEFT_BUTTON_BOUNDS, "Pause")),
+				Widget::Button(Button::new(RIGHT_BUTTON_BOUNDS, "Abort")),
+			],
+			settings: [
+				title("Settings"),
+				Widget::Label(Label::new(
+					Rect::new(10, 50, 120, 20),
+					"Curve",
+					TEXT_FONT,
+					Alignment::Left,
+				)),
+				Widget::ListSelector(ListSelector::new(Rect::new(130, 40, 180, 40), &INTERPOLATION_NAMES, 0)),
+				Widget::Label(Label::new(
+					Rect::new(10, 100, 120, 20),
+					"Autotune",
+					TEXT_FONT,
+					Alignment::Left,
+				)),
+				Widget::Spinner(Spinner::new(Rect::new(130, 90, 180, 40), 150, 100..=250, 10, "°C")),
+				Widget::Button(Button::new(LEFT_BUTTON_BOUNDS, "Tune")),
+				Widget::Button(Button::new(RIGHT_BUTTON_BOUNDS, "Back")),
+			],
+			error: [
+				title("Error"),
+				label(Rect::new(10, 84, 300, 100), TEXT_FONT),
+				Widget::Button(Button::new(Rect::new(85, 190, 150, 40), "Ok")),
+				Widget::Icon(Icon::new(Rect::new(0, 44, SCREEN.size.x, 36), Some(&images::WARNING))),
+			],
+		}
+	}
+
+	fn get(&self, menu: Menu) -> &[Widget] {
+		match menu {
+			Menu::Home => &self.home,
+			Menu::ProfileSelect => &self.profile_select,
+			Menu::Reflowing => &self.reflowing,
+			Menu::Settings => &self.settings,
+			Menu::Error => &self.error,
+		}
+	}
+
+	fn get_mut(&mut self, menu: Menu) -> &mut [Widget] {
+		match menu {
+			Menu::Home => &mut self.home,
+			Menu::ProfileSelect => &mut self.profile_select,
+			Menu::Reflowing => &mut self.reflowing,
+			Menu::Settings => &mut self.settings,
+			Menu::Error => &mut self.error,
+		}
+	}
+}
+
+/// The user interface of the hot plate, made of [`Menu`]s of widgets the user interacts with through the touch panel.
+///
+/// The [`Menu::Reflowing`] is opened when a reflow process starts, and the [`Menu::Error`] when the process is aborted
+/// because of an error.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::{
+/// # 	process::{BuiltinReflowProfile, Interpolation, ReflowState},
+/// # 	screen::ui::{default::DefaultUI, HotPlateStatus, Menu, TouchEvent, UiAction},
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let mut status = HotPlateStatus {
+/// 	reflow_state: ReflowState::Idle,
+/// 	selected_reflow_profile: BuiltinReflowProfile::DEFAULT,
+/// 	interpolation: Interpolation::Linear,
+/// 	current_temperature: None,
+/// 	target_temperature: None,
+/// 	reflow_time: None,
+/// 	is_autotune_in_progress: false,
+/// };
+/// let mut ui = DefaultUI::new();
+/// ui.update(&status);
+/// assert_eq!(ui.get_current_menu(), Menu::Home);
+///
+/// let start_button = ui.find_button("Start").unwrap();
+/// let center = start_button.position + U16x2 { x: start_button.size.x / 2, y: start_button.size.y / 2 };
+/// assert_eq!(ui.handle_touch(TouchEvent::Press(center)), None);
+/// assert_eq!(ui.handle_touch(TouchEvent::Release), Some(UiAction::StartReflow));
+///
+/// // When the hot plate starts the reflow, the UI shows its progress
+/// status.reflow_state = ReflowState::Preheating;
+/// ui.update(&status);
+/// assert_eq!(ui.get_current_menu(), Menu::Reflowing);
+/// ```
 pub struct DefaultUI {
-	current_menu: Menu,
+	menus: MenuStack,
+	/// The menu that is on the screen, or `None` if the screen must be cleared before drawing the current one.
+	drawn_menu: Option<Menu>,
+	focus: Focus,
+	pages: Pages,
+	/// The chart of the [`Menu::Reflowing`].
+	chart: Chart,
+	/// The status received by the last [`update`](Self::update).
+	status: Option<HotPlateStatus>,
 }
 
 impl DefaultUI {
-	pub const fn new() -> Self {
+	pub fn new() -> Self {
 		Self {
-			current_menu: Menu::Home,
-		}
-	}
-
-	pub fn draw<DCXPin: OutputPin, ResetPin: OutputPin, Spi: SpiDevice>(
-		&self, screen: &mut Screen<DCXPin, ResetPin, Spi>,
-	) -> Result<(), SendError<DCXPin, Spi>> {
-		match self.current_menu {
-			Menu::Home => {
-				const TRIANGLE_X: u16 = 10;
-				const TRIANGLE_Y: u16 = 140;
-				const TRIANGLE_SIZE: u16 = 20;
-
-				screen.draw(
-					U16x2 {
-						x: TRIANGLE_X,
-						y: TRIANGLE_Y,
-					},
-					&Triangle { size: TRIANGLE_SIZE },
-				)?;
-				screen.draw(
-					U16x2 {
-						x: screen.size().x - TRIANGLE_X - TRIANGLE_SIZE,
-						y: TRIANGLE_Y,
-					},
-					&Flipped {
-						draw: Triangle { size: TRIANGLE_SIZE },
-						axis: Axis::Horizontal,
-					},
-				)?;
+			menus: MenuStack::new(),
+			drawn_menu: None,
+			focus: Focus::new(),
+			pages: Pages::new(),
+			chart: Chart::new(CHART_BOUNDS),
+			status: None,
+		}
+	}
+
+	/// Returns the menu shown on the screen.
+	pub fn get_current_menu(&self) -> Menu {
+		self.menus.get_current()
+	}
+
+	/// Returns the widgets of the menu shown on the screen.
+	pub fn get_widgets(&self) -> &[Widget] {
+		self.pages.get(self.get_current_menu())
+	}
+
+	/// Returns the bounds of the [`Button`] with the provided `text` in the menu shown on the screen, or `None` if
+	/// there isn't any.
+	pub fn find_button(&self, text: &str) -> Option<Rect> {
+		self.get_widgets().iter().find_map(|widget| match widget {
+			Widget::Button(button) if button.get_text().eq_ignore_ascii_case(text) => Some(widget.get_bounds()),
+			_ => None,
+		})
+	}
+
+	/// Opens the provided `menu` on top of the current one.
+	pub fn open(&mut self, menu: Menu) {
+		self.menus.push(menu);
+		self.focus = Focus::new();
+	}
+
+	/// Goes back to the previous menu (if the current one isn't [`Menu::Home`]).
+	pub fn back(&mut self) {
+		self.menus.pop();
+		self.focus = Focus::new();
+	}
+
+	/// Opens the [`Menu::Error`] showing the provided `message`.
+	pub fn show_error(&mut self, message: &'static str) {
+		label_mut(&mut self.pages.error, error::MESSAGE).set_text(message);
+		// If an error was already shown it's replaced
+		self.menus.push(Menu::Error);
+		self.focus = Focus::new();
+		self.drawn_menu = None;
+	}
+
+	/// Updates the widgets with the provided `status` of the hot plate.
+	pub fn update(&mut self, status: &HotPlateStatus) {
+		let previous_state = self
+			.status
+			.map(|status| status.reflow_state)
+			.unwrap_or(ReflowState::Idle);
+		let state = status.reflow_state;
+		self.status = Some(*status);
+
+		if !previous_state.is_active() && state.is_active() {
+			let profile = status.selected_reflow_profile;
+			self.chart.set_profile(&profile.reflow_profile(), status.interpolation);
+			self.chart.set_phases(profile.phased_profile().as_ref());
+			self.open(Menu::Reflowing);
+		}
+		if let (true, ReflowState::Aborted(reason)) = (previous_state != state, state) {
+			if let Some(message) = message::abort_reason(reason) {
+				self.show_error(message);
+			}
+		}
+
+		self.update_home(status);
+		self.update_reflowing(status);
+		self.update_settings(status);
+	}
+
+	fn update_home(&mut self, status: &HotPlateStatus) {
+		let widgets = &mut self.pages.home;
+
+		let temperature = label_mut(widgets, home::TEMPERATURE);
+		temperature.set_text_fmt(format_args!("{}", DisplayTemperature(status.current_temperature)));
+		temperature.set_color(get_status_color(status));
+		label_mut(widgets, home::PROFILE)
+			.set_text_fmt(format_args!("Profile: {}", status.selected_reflow_profile.name()));
+		label_mut(widgets, home::STATE).set_text(match status.is_autotune_in_progress {
+			true => "Autotuning",
+			false => reflow_state_name(status.reflow_state),
+		});
+		icon_mut(widgets, home::STATUS_ICON).set_image(get_status_icon(status));
+	}
+
+	fn update_reflowing(&mut self, status: &HotPlateStatus) {
+		let widgets = &mut self.pages.reflowing;
+
+		label_mut(widgets, reflowing::STATE).set_text(reflow_state_name(status.reflow_state));
+		let remaining_time = status.reflow_time.map(|time| {
+			let duration_in_seconds = status.selected_reflow_profile.duration_in_seconds() as u64;
+			duration_in_seconds.saturating_sub(time.as_secs())
+		});
+		match remaining_time {
+			Some(seconds) => label_mut(widgets, reflowing::REMAINING_TIME)
+				.set_text_fmt(format_args!("{} left", ClockDuration(Duration::from_secs(seconds)))),
+			None => label_mut(widgets, reflowing::REMAINING_TIME).set_text(""),
+		}
+		let current_temperature = label_mut(widgets, reflowing::CURRENT_TEMPERATURE);
+		current_temperature.set_text_fmt(format_args!("Now {}", DisplayTemperature(status.current_temperature)));
+		current_temperature.set_color(get_status_color(status));
+		label_mut(widgets, reflowing::TARGET_TEMPERATURE)
+			.set_text_fmt(format_args!("Target {}", DisplayTemperature(status.target_temperature)));
+
+		if let (Some(time), Some(temperature)) = (status.reflow_time, status.current_temperature) {
+			self.chart.record(time, temperature);
+		}
+		self.chart.set_cursor(status.reflow_time);
+
+		let pause = button_mut(widgets, reflowing::PAUSE);
+		pause.set_text(match status.reflow_state {
+			ReflowState::Paused => "Resume",
+			_ => "Pause",
+		});
+		// While cooling the heater is off, so the plate can't be kept at the target temperature
+		pause.set_enabled(matches!(
+			status.reflow_state,
+			ReflowState::Preheating | ReflowState::Running | ReflowState::Paused
+		));
+		button_mut(widgets, reflowing::ABORT).set_text(match status.reflow_state.is_active() {
+			true => "Abort",
+			false => "Home",
+		});
+	}
+
+	fn update_settings(&mut self, status: &HotPlateStatus) {
+		let widgets = &mut self.pages.settings;
+
+		if let Some(index) = INTERPOLATIONS
+			.iter()
+			.position(|&interpolation| interpolation == status.interpolation)
+		{
+			list_selector_mut(widgets, settings::INTERPOLATION).set_selected(index);
+		}
+		button_mut(widgets, settings::AUTOTUNE).set_text(match status.is_autotune_in_progress {
+			true => "Tuning...",
+			false => "Tune",
+		});
+	}
+
+	/// Reacts to an `event` of the touch panel, returning what the user asked the hot plate to do (if anything).
+	pub fn handle_touch(&mut self, event: TouchEvent) -> Option<UiAction> {
+		let menu = self.get_current_menu();
+		let (index, event) = self.focus.handle_touch(self.pages.get_mut(menu), event)?;
+
+		self.on_widget_event(menu, index, event)
+	}
+
+	/// Reacts to an `event` of one of the [`NavigationButton`]s, returning what the user asked the hot plate to do (if
+	/// anything).
+	///
+	/// A [`ButtonEvent::LongPress`] of any button is an [`UiAction::EmergencyAbort`]. Otherwise the
+	/// [`NavigationButton::Next`] focuses the next widget (the previous one if it's double pressed), and the
+	/// [`NavigationButton::Select`] activates the focused one.
+	pub fn handle_button(&mut self, button: NavigationButton, event: ButtonEvent) -> Option<UiAction> {
+		match (button, event) {
+			(_, ButtonEvent::LongPress) => return Some(UiAction::EmergencyAbort),
+			(NavigationButton::Next, ButtonEvent::ShortPress | ButtonEvent::Repeat) => self.focus_next(),
+			(NavigationButton::Next, ButtonEvent::DoublePress) => self.focus_previous(),
+			(NavigationButton::Select, _) => return self.activate_focused(),
+		}
+
+		None
+	}
+
+	/// Focuses the next widget of the current menu the user can interact with.
+	pub fn focus_next(&mut self) {
+		let menu = self.get_current_menu();
+		self.focus.focus_next(self.pages.get_mut(menu));
+	}
+
+	/// Focuses the previous widget of the current menu the user can interact with.
+	pub fn focus_previous(&mut self) {
+		let menu = self.get_current_menu();
+		self.focus.focus_previous(self.pages.get_mut(menu));
+	}
+
+	/// Activates the focused widget, returning what the user asked the hot plate to do (if anything).
+	pub fn activate_focused(&mut self) -> Option<UiAction> {
+		let menu = self.get_current_menu();
+		let (index, event) = self.focus.activate_focused(self.pages.get_mut(menu))?;
+
+		self.on_widget_event(menu, index, event)
+	}
+
+	fn on_widget_event(&mut self, menu: Menu, index: usize, event: WidgetEvent) -> Option<UiAction> {
+		let status = self.status?;
+
+		match (menu, index, event) {
+			(Menu::Home, home::START, WidgetEvent::Activated) => return Some(UiAction::StartReflow),
+			(Menu::Home, home::PROFILES, WidgetEvent::Activated) => {
+				let selected = PROFILES
+					.iter()
+					.position(|&profile| profile == status.selected_reflow_profile)
+					.unwrap_or(0);
+				list_selector_mut(&mut self.pages.profile_select, profile_select::SELECTOR).set_selected(selected);
+				self.update_profile_details();
+				self.open(Menu::ProfileSelect);
+			},
+			(Menu::Home, home::SETTINGS, WidgetEvent::Activated) => self.open(Menu::Settings),
+
+			(Menu::ProfileSelect, profile_select::SELECTOR, WidgetEvent::ValueChanged) => self.update_profile_details(),
+			(Menu::ProfileSelect, profile_select::SELECT, WidgetEvent::Activated) => {
+				self.back();
+				return Some(UiAction::SelectReflowProfile(self.get_profile_to_select()));
+			},
+			(Menu::ProfileSelect, profile_select::BACK, WidgetEvent::Activated) => self.back(),
+
+			(Menu::Reflowing, reflowing::PAUSE, WidgetEvent::Activated) => {
+				return match status.reflow_state {
+					ReflowState::Paused => Some(UiAction::ResumeReflow),
+					_ => Some(UiAction::PauseReflow),
+				};
 			},
-			Menu::Reflowing => todo!(),
+			(Menu::Reflowing, reflowing::ABORT, WidgetEvent::Activated) => match status.reflow_state.is_active() {
+				true => return Some(UiAction::AbortReflow),
+				false => self.back(),
+			},
+
+			(Menu::Settings, settings::INTERPOLATION, WidgetEvent::ValueChanged) => {
+				let selected = list_selector_mut(&mut self.pages.settings, settings::INTERPOLATION).get_selected();
+				return Some(UiAction::SetInterpolation(INTERPOLATIONS[selected]));
+			},
+			(Menu::Settings, settings::AUTOTUNE, WidgetEvent::Activated) => {
+				let Widget::Spinner(spinner) = &self.pages.settings[settings::AUTOTUNE_TEMPERATURE] else {
+					unreachable!()
+				};
+				return Some(UiAction::StartAutotune {
+					target_temperature: Temperature::from_celsius(spinner.get_value() as f32),
+				});
+			},
+			(Menu::Settings, settings::BACK, WidgetEvent::Activated) => self.back(),
+
+			(Menu::Error, error::OK, WidgetEvent::Activated) => self.back(),
+			_ => (),
+		}
+
+		None
+	}
+
+	/// Returns the profile shown by the selector of the [`Menu::ProfileSelect`].
+	fn get_profile_to_select(&mut self) -> BuiltinReflowProfile {
+		PROFILES[list_selector_mut(&mut self.pages.profile_select, profile_select::SELECTOR).get_selected()]
+	}
+
+	/// Shows the details of the profile shown by the selector of the [`Menu::ProfileSelect`].
+	fn update_profile_details(&mut self) {
+		let profile = self.get_profile_to_select();
+		let widgets = &mut self.pages.profile_select;
+
+		label_mut(widgets, profile_select::DESCRIPTION).set_text(profile.description());
+		label_mut(widgets, profile_select::DETAILS).set_text_fmt(format_args!(
+			"Peak {}, {}",
+			profile.peak_temperature(),
+			ClockDuration(Duration::from_secs(profile.duration_in_seconds() as u64))
+		));
+	}
+
+	/// Draws on the `canvas` the widgets of the current menu that changed since the last time they have been drawn
+	/// (or the whole menu if it has just been opened).
+	pub fn draw<C: Canvas>(&mut self, canvas: &mut C) -> Result<(), C::Error> {
+		let menu = self.get_current_menu();
+		let widgets = self.pages.get_mut(menu);
+		if self.drawn_menu != Some(menu) {
+			widget::fill(canvas, SCREEN, widget::BACKGROUND_COLOR)?;
+			widgets.iter_mut().for_each(Widget::set_dirty);
+			self.chart.set_dirty();
+			self.drawn_menu = Some(menu);
+		}
+
+		for (index, widget) in widgets.iter_mut().enumerate() {
+			if widget.is_dirty() {
+				widget.draw(canvas, self.focus.get_highlight(index))?;
+			}
+		}
+		if menu == Menu::Reflowing && self.chart.is_dirty() {
+			self.chart.draw(canvas)?;
 		}
 
 		Ok(())
 	}
+
+	/// Returns the areas of the screen that changed since the last time they have been drawn (the whole screen if the
+	/// current menu has just been opened).
+	pub fn get_dirty_regions(&self) -> DirtyRegions {
+		let mut regions = DirtyRegions::new();
+		let menu = self.get_current_menu();
+		if self.drawn_menu != Some(menu) {
+			regions.add(SCREEN);
+			return regions;
+		}
+
+		for widget in self.get_widgets().iter().filter(|widget| widget.is_dirty()) {
+			regions.add(widget.get_bounds());
+		}
+		if menu == Menu::Reflowing {
+			self.chart.add_dirty_regions(&mut regions);
+		}
+
+		regions
+	}
+
+	/// Draws on the `canvas` everything of the current menu that is inside the provided `region`, which must have
+	/// already been filled with the [`background color`] (the canvas must discard what is drawn outside of it).
+	///
+	/// Nothing is marked as drawn: after all the [`dirty regions`] have been drawn call [`Self::set_drawn`].
+	///
+	/// [`background color`]: `widget::BACKGROUND_COLOR`
+	/// [`dirty regions`]: `Self::get_dirty_regions`
+	pub fn draw_region<C: Canvas>(&self, canvas: &mut C, region: Rect) -> Result<(), C::Error> {
+		let menu = self.get_current_menu();
+		for (index, widget) in self.get_widgets().iter().enumerate() {
+			if widget.get_bounds().intersection(&region).is_some() {
+				widget.render(canvas, self.focus.get_highlight(index))?;
+			}
+		}
+		if menu == Menu::Reflowing {
+			self.chart.render(canvas, region)?;
+		}
+
+		Ok(())
+	}
+
+	/// Marks the whole current menu as drawn, after its [`dirty regions`] have been drawn with [`Self::draw_region`].
+	///
+	/// [`dirty regions`]: `Self::get_dirty_regions`
+	pub fn set_drawn(&mut self) {
+		let menu = self.get_current_menu();
+		if self.drawn_menu != Some(menu) {
+			self.chart.set_dirty();
+			self.drawn_menu = Some(menu);
+		}
+
+		self.pages.get_mut(menu).iter_mut().for_each(Widget::set_clean);
+		if menu == Menu::Reflowing {
+			self.chart.set_clean();
+		}
+	}
+}
+
+impl Default for DefaultUI {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Returns the color of the current temperature of the plate (check [`widget::get_temperature_color`]), which is the
+/// [`text color`] if it's unknown.
+///
+/// [`text color`]: `widget::TEXT_COLOR`
+fn get_status_color(status: &HotPlateStatus) -> ColorRGB565 {
+	status
+		.current_temperature
+		.map(widget::get_temperature_color)
+		.unwrap_or(widget::TEXT_COLOR)
+}
+
+/// Returns the icon of what the plate is doing: heating, cooling down or having been stopped by an error (or `None` if
+/// it's not doing anything, or if the user stopped it).
+fn get_status_icon(status: &HotPlateStatus) -> Option<&'static CompressedImage> {
+	match status.reflow_state {
+		_ if status.is_autotune_in_progress => Some(&images::HEATER),
+		ReflowState::Preheating | ReflowState::Running => Some(&images::HEATER),
+		ReflowState::Cooling => Some(&images::FAN),
+		ReflowState::Aborted(reason) if message::abort_reason(reason).is_some() => Some(&images::WARNING),
+		ReflowState::Idle | ReflowState::Paused | ReflowState::Done | ReflowState::Aborted(_) => None,
+	}
+}
+
+/// Shows a temperature in degrees celsius without decimals, or `--` if it's `None`.
+struct DisplayTemperature(Option<Temperature>);
+
+impl fmt::Display for DisplayTemperature {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			Some(temperature) => write!(f, "{temperature}"),
+			None => f.write_str("--°C"),
+		}
+	}
+}
+
+fn reflow_state_name(state: ReflowState) -> &'static str {
+	match state {
+		ReflowState::Idle => "Idle",
+		ReflowState::Preheating => "Preheating",
+		ReflowState::Running => "Running",
+		ReflowState::Paused => "Paused",
+		ReflowState::Cooling => "Cooling",
+		ReflowState::Done => "Done",
+		ReflowState::Aborted(_) => "Aborted",
+	}
+}
+
+fn label_mut(widgets: &mut [Widget], index: usize) -> &mut Label {
+	match &mut widgets[index] {
+		Widget::Label(label) => label,
+		_ => unreachable!("The widget at {index} is not a label"),
+	}
+}
+
+fn button_mut(widgets: &mut [Widget], index: usize) -> &mut Button {
+	match &mut widgets[index] {
+		Widget::Button(button) => button,
+		_ => unreachable!("The widget at {index} is not a button"),
+	}
+}
+
+fn list_selector_mut(widgets: &mut [Widget], index: usize) -> &mut ListSelector {
+	match &mut widgets[index] {
+		Widget::ListSelector(list_selector) => list_selector,
+		_ => unreachable!("The widget at {index} is not a list selector"),
+	}
+}
+
+fn icon_mut(widgets: &mut [Widget], index: usize) -> &mut Icon {
+	match &mut widgets[index] {
+		Widget::Icon(icon) => icon,
+		_ => unreachable!("The widget at {index} is not an icon"),
+	}
 }
@@ -0,0 +1,158 @@
+use micromath::vector::U16x2;
+
+use super::{
+	widget::{hit_test, Highlight, Widget, WidgetEvent},
+	TouchEvent,
+};
+
+/// The state of the interaction of the user with the widgets of a menu: which one is focused and which one is being
+/// pressed.
+///
+/// A widget is activated when the finger is lifted from it after having pressed it (so the user can cancel a press
+/// moving the finger away from the widget before lifting it).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::ui::{
+/// # 	focus::Focus,
+/// # 	widget::{Alignment, Button, Highlight, Label, Rect, TextFont, Widget, WidgetEvent},
+/// # 	TouchEvent,
+/// # };
+/// # use micromath::vector::U16x2;
+/// #
+/// let mut widgets = [
+/// 	Widget::Label(Label::new(Rect::new(0, 0, 320, 20), "Title", TextFont::Small(2), Alignment::Center)),
+/// 	Widget::Button(Button::new(Rect::new(10, 100, 100, 40), "Start")),
+/// 	Widget::Button(Button::new(Rect::new(120, 100, 100, 40), "Stop")),
+/// ];
+/// let mut focus = Focus::new();
+///
+/// // Labels can't be pressed
+/// assert_eq!(focus.handle_touch(&mut widgets, TouchEvent::Press(U16x2 { x: 50, y: 10 })), None);
+/// assert_eq!(focus.handle_touch(&mut widgets, TouchEvent::Release), None);
+///
+/// assert_eq!(focus.handle_touch(&mut widgets, TouchEvent::Press(U16x2 { x: 150, y: 120 })), None);
+/// assert_eq!(focus.get_highlight(2), Highlight::Pressed);
+/// assert_eq!(focus.handle_touch(&mut widgets, TouchEvent::Release), Some((2, WidgetEvent::Activated)));
+/// assert_eq!(focus.get_highlight(2), Highlight::Focused);
+///
+/// // Moving the finger away from the button before lifting it cancels the press
+/// focus.handle_touch(&mut widgets, TouchEvent::Press(U16x2 { x: 50, y: 120 }));
+/// focus.handle_touch(&mut widgets, TouchEvent::Move(U16x2 { x: 50, y: 200 }));
+/// assert_eq!(focus.handle_touch(&mut widgets, TouchEvent::Release), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Focus {
+	focused: Option<usize>,
+	pressed: Option<usize>,
+	/// The last point touched by the user, or `None` if the panel isn't being touched.
+	touch_position: Option<U16x2>,
+}
+
+impl Focus {
+	/// Returns a [`Focus`] without any focused widget.
+	pub const fn new() -> Self {
+		Self {
+			focused: None,
+			pressed: None,
+			touch_position: None,
+		}
+	}
+
+	/// Returns the index of the focused widget, or `None` if no widget is focused.
+	pub fn get_focused(&self) -> Option<usize> {
+		self.focused
+	}
+
+	/// Returns how the widget at the provided `index` must be highlighted.
+	pub fn get_highlight(&self, index: usize) -> Highlight {
+		if self.pressed == Some(index) {
+			Highlight::Pressed
+		} else if self.focused == Some(index) {
+			Highlight::Focused
+		} else {
+			Highlight::None
+		}
+	}
+
+	/// Reacts to an `event` of the touch panel, returning the index of the widget something happened to and what
+	/// happened to it.
+	pub fn handle_touch(&mut self, widgets: &mut [Widget], event: TouchEvent) -> Option<(usize, WidgetEvent)> {
+		match event {
+			TouchEvent::Press(position) => {
+				self.touch_position = Some(position);
+				if let Some(index) = hit_test(widgets, position) {
+					self.set_focused(widgets, Some(index));
+					self.set_pressed(widgets, Some(index));
+				}
+
+				None
+			},
+			TouchEvent::Move(position) => {
+				self.touch_position = Some(position);
+
+				None
+			},
+			TouchEvent::Release => {
+				let position = self.touch_position.take()?;
+				let pressed = self.pressed?;
+				self.set_pressed(widgets, None);
+
+				let widget = &mut widgets[pressed];
+				match widget.get_bounds().contains(position) {
+					true => widget.handle_touch(position).map(|event| (pressed, event)),
+					false => None,
+				}
+			},
+		}
+	}
+
+	/// Focuses the next [`interactive`] widget (going back to the first one after the last one).
+	///
+	/// [`interactive`]: `Widget::is_interactive`
+	pub fn focus_next(&mut self, widgets: &mut [Widget]) {
+		let start = self.focused.map(|focused| focused + 1).unwrap_or(0);
+		let next = (0..widgets.len())
+			.map(|offset| (start + offset) % widgets.len())
+			.find(|&index| widgets[index].is_interactive());
+		self.set_focused(widgets, next);
+	}
+
+	/// Focuses the previous [`interactive`] widget (going to the last one before the first one).
+	///
+	/// [`interactive`]: `Widget::is_interactive`
+	pub fn focus_previous(&mut self, widgets: &mut [Widget]) {
+		let start = self.focused.unwrap_or(0) + widgets.len();
+		let previous = (1..=widgets.len())
+			.map(|offset| (start - offset) % widgets.len())
+			.find(|&index| widgets[index].is_interactive());
+		self.set_focused(widgets, previous);
+	}
+
+	/// [`Activates`] the focused widget, returning its index and what happened to it.
+	///
+	/// [`Activates`]: `Widget::activate`
+	pub fn activate_focused(&mut self, widgets: &mut [Widget]) -> Option<(usize, WidgetEvent)> {
+		let focused = self.focused?;
+
+		widgets[focused].activate().map(|event| (focused, event))
+	}
+
+	fn set_focused(&mut self, widgets: &mut [Widget], focused: Option<usize>) {
+		Self::set_highlighted(&mut self.focused, widgets, focused);
+	}
+
+	fn set_pressed(&mut self, widgets: &mut [Widget], pressed: Option<usize>) {
+		Self::set_highlighted(&mut self.pressed, widgets, pressed);
+	}
+
+	/// Changes the widget at `current` to the `new` one, redrawing both of them to update their highlight.
+	fn set_highlighted(current: &mut Option<usize>, widgets: &mut [Widget], new: Option<usize>) {
+		if *current != new {
+			for index in [*current, new].into_iter().flatten() {
+				widgets[index].set_dirty();
+			}
+			*current = new;
+		}
+	}
+}
@@ -0,0 +1,71 @@
+//! The messages shown to the user in the [`Menu::Error`] when something goes wrong.
+//!
+//! [`Menu::Error`]: `super::Menu::Error`
+
+use crate::hot_plate::{
+	process::{AbortReason, CommandError},
+	temperature::{autotune::AutotuneError, safety::TemperatureError, PidUpdateError, ReadTemperatureError},
+};
+
+/// Returns the message that explains why a reflow process has been aborted, or `None` if the user asked for it (so
+/// there's nothing to explain).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::{process::AbortReason, screen::ui::message};
+/// #
+/// assert_eq!(message::abort_reason(AbortReason::Requested), None);
+/// assert!(message::abort_reason(AbortReason::MaxStretchExceeded).is_some());
+/// ```
+pub fn abort_reason(reason: AbortReason) -> Option<&'static str> {
+	match reason {
+		AbortReason::Requested => None,
+		AbortReason::TemperatureControl(error) => Some(pid_update_error(error)),
+		AbortReason::MaxStretchExceeded => Some("The plate lagged behind the profile for too long"),
+	}
+}
+
+/// Returns the message that explains why the temperature of the plate can't be controlled anymore.
+pub fn pid_update_error(error: PidUpdateError) -> &'static str {
+	match error {
+		PidUpdateError::CantReadTemperature(ReadTemperatureError::CantReadSensor(_)) => {
+			"A temperature sensor can't be read"
+		},
+		PidUpdateError::CantReadTemperature(ReadTemperatureError::NoReadingToFuse) => {
+			"There's no temperature sensor to read"
+		},
+		PidUpdateError::ReadTemperatureIsWrong(errors) => {
+			// The most specific error is shown
+			if errors.contains(TemperatureError::SensorsDisagree) {
+				"The temperature sensors disagree"
+			} else if errors.contains(TemperatureError::CurrentTemperatureOutsideAllowedRange) {
+				"The temperature is outside the allowed range"
+			} else if errors.contains(TemperatureError::TargetTemperatureOutsideAllowedRange) {
+				"The target is outside the allowed range"
+			} else if errors.contains(TemperatureError::CantRiseFastEnoughToTargetTemperature) {
+				"The plate isn't heating fast enough"
+			} else {
+				"The plate can't keep the target temperature"
+			}
+		},
+		PidUpdateError::SetCartridgeHeaterPercentage => "The heater can't be driven",
+	}
+}
+
+/// Returns the message that explains why a command given through the user interface couldn't be executed.
+pub fn command_error(error: CommandError) -> &'static str {
+	match error {
+		CommandError::NotAllowed { .. } => "It's not allowed right now",
+		CommandError::AutotuneInProgress => "An autotune is in progress",
+		CommandError::NoAutotuneInProgress => "There's no autotune in progress",
+	}
+}
+
+/// Returns the message that explains why an autotune failed.
+pub fn autotune_error(error: AutotuneError) -> &'static str {
+	match error {
+		AutotuneError::TimedOut => "The autotune took too long",
+		AutotuneError::TemperatureControl(error) => pid_update_error(error),
+		AutotuneError::GainScheduleFull => "The gain schedule is full",
+	}
+}
@@ -1,6 +1,86 @@
+use core::time::Duration;
+
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::process::{BuiltinReflowProfile, Interpolation, ReflowState},
+	utils::measurement::temperature::Temperature,
+};
+
+pub mod chart;
 pub mod default;
+pub mod focus;
+pub mod message;
+mod navigation;
+pub mod widget;
 
+pub use navigation::*;
+
+/// A screen of the user interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Menu {
+	/// The first menu, from which a reflow process is started.
 	Home,
+	/// Where the [`BuiltinReflowProfile`] to follow is chosen.
+	ProfileSelect,
+	/// Shows the progress of the reflow process, with a chart of the temperature of the plate.
 	Reflowing,
+	Settings,
+	/// Shows what went wrong.
+	Error,
+}
+
+/// Something that happened on the touch panel placed over the screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TouchEvent {
+	/// The panel started being touched at the provided point.
+	Press(U16x2),
+	/// The finger touching the panel moved to the provided point.
+	Move(U16x2),
+	/// The finger has been lifted from the panel.
+	Release,
+}
+
+/// A physical button used to interact with the user interface without touching the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NavigationButton {
+	/// Focuses the next widget (or the previous one with a double press).
+	Next,
+	/// Activates the focused widget.
+	Select,
+}
+
+/// Something the user asked the hot plate to do through the user interface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UiAction {
+	StartReflow,
+	PauseReflow,
+	ResumeReflow,
+	AbortReflow,
+	SelectReflowProfile(BuiltinReflowProfile),
+	SetInterpolation(Interpolation),
+	/// Starts an autotune at the provided target temperature (the other parameters are taken from the
+	/// [`UiConfig`]).
+	///
+	/// [`UiConfig`]: `crate::hot_plate::config::ui::UiConfig`
+	StartAutotune {
+		target_temperature: Temperature,
+	},
+	/// Stops the reflow process and the autotune (if any of them is in progress).
+	EmergencyAbort,
+}
+
+/// What the user interface shows about the hot plate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HotPlateStatus {
+	pub reflow_state: ReflowState,
+	pub selected_reflow_profile: BuiltinReflowProfile,
+	pub interpolation: Interpolation,
+	pub current_temperature: Option<Temperature>,
+	pub target_temperature: Option<Temperature>,
+	/// How much time passed since the start of the reflow process, or `None` if there isn't an [`active`] one.
+	///
+	/// [`active`]: `ReflowState::is_active`
+	pub reflow_time: Option<Duration>,
+	pub is_autotune_in_progress: bool,
 }
@@ -0,0 +1,97 @@
+use super::Menu;
+
+/// The maximum number of menus a [`MenuStack`] can hold.
+pub const MENU_STACK_CAPACITY: usize = 8;
+
+/// The menus the user navigated through: the one at the top is shown on the screen, and going back shows the one below
+/// it. The menu at the bottom is always [`Menu::Home`], and it can't be closed.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::ui::{Menu, MenuStack};
+/// #
+/// let mut menus = MenuStack::new();
+/// assert_eq!(menus.get_current(), Menu::Home);
+/// assert_eq!(menus.pop(), None);
+///
+/// menus.push(Menu::Settings);
+/// menus.push(Menu::Error);
+/// assert_eq!(menus.get_current(), Menu::Error);
+/// assert_eq!(menus.pop(), Some(Menu::Error));
+/// assert_eq!(menus.get_current(), Menu::Settings);
+///
+/// // Opening a menu that is already open closes the menus above it, instead of opening it twice
+/// menus.push(Menu::ProfileSelect);
+/// menus.push(Menu::Home);
+/// assert_eq!(menus.get_depth(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MenuStack {
+	menus: [Menu; MENU_STACK_CAPACITY],
+	depth: usize,
+}
+
+impl MenuStack {
+	/// Returns a [`MenuStack`] with only [`Menu::Home`].
+	pub const fn new() -> Self {
+		Self {
+			menus: [Menu::Home; MENU_STACK_CAPACITY],
+			depth: 1,
+		}
+	}
+
+	/// Returns the menu at the top of the stack, which is the one shown on the screen.
+	pub fn get_current(&self) -> Menu {
+		self.menus[self.depth - 1]
+	}
+
+	/// Returns the number of menus in the stack (which is at least `1`).
+	pub fn get_depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Returns `true` if the provided `menu` is in the stack.
+	pub fn contains(&self, menu: Menu) -> bool {
+		self.menus[..self.depth].contains(&menu)
+	}
+
+	/// Opens the provided `menu` on top of the current one.
+	///
+	/// If the `menu` is already in the stack, the menus above it are closed instead, while if the stack is full the
+	/// current menu is replaced.
+	pub fn push(&mut self, menu: Menu) {
+		if let Some(index) = self.menus[..self.depth].iter().position(|&open_menu| open_menu == menu) {
+			self.depth = index + 1;
+			return;
+		}
+
+		if self.depth == MENU_STACK_CAPACITY {
+			self.depth -= 1;
+		}
+		self.menus[self.depth] = menu;
+		self.depth += 1;
+	}
+
+	/// Closes the current menu going back to the previous one, returning the closed menu, or `None` if the current
+	/// menu is [`Menu::Home`].
+	pub fn pop(&mut self) -> Option<Menu> {
+		if self.depth == 1 {
+			return None;
+		}
+
+		self.depth -= 1;
+
+		Some(self.menus[self.depth])
+	}
+
+	/// Closes all the menus except [`Menu::Home`].
+	pub fn go_home(&mut self) {
+		self.depth = 1;
+	}
+}
+
+impl Default for MenuStack {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,751 @@
+use core::{fmt, ops::RangeInclusive, ptr};
+
+use micromath::vector::U16x2;
+
+use crate::{
+	hot_plate::screen::{
+		drawable::{
+			fonts, BitmapFont, CompressedImage, Drawable, Font, Pixels, Rectangle, ScaledFont, ShapeStyle, SmallFont,
+			TextBox, TextBuffer,
+		},
+		Canvas,
+	},
+	utils::measurement::{color::ColorRGB565, temperature::Temperature},
+};
+
+pub use crate::hot_plate::screen::drawable::Alignment;
+
+pub const BACKGROUND_COLOR: ColorRGB565 = ColorRGB565::BLACK;
+pub const BORDER_COLOR: ColorRGB565 = ColorRGB565::GRAY;
+pub const FOCUS_COLOR: ColorRGB565 = ColorRGB565::YELLOW;
+pub const PRESSED_COLOR: ColorRGB565 = ColorRGB565::WHITE;
+pub const TEXT_COLOR: ColorRGB565 = ColorRGB565::WHITE;
+/// The color of the text of a [`Button`] that is disabled.
+pub const DISABLED_TEXT_COLOR: ColorRGB565 = ColorRGB565::DARK_GRAY;
+/// The temperature in degrees celsius shown in blue by [`get_temperature_color`] (about the one of the room).
+pub const COLD_TEMPERATURE_IN_CELSIUS: f32 = 25.;
+/// The temperature in degrees celsius shown in red by [`get_temperature_color`] (about the peak of a reflow).
+pub const HOT_TEMPERATURE_IN_CELSIUS: f32 = 250.;
+/// The thickness in pixels of the border of the widgets the user can interact with.
+pub const BORDER_THICKNESS: u16 = 2;
+/// The font of the texts of the widgets the user can interact with.
+pub const TEXT_FONT: TextFont = TextFont::Bitmap(&fonts::SANS_16);
+
+/// The maximum number of bytes of the text of a [`Label`] or of a [`Button`].
+pub const LABEL_TEXT_CAPACITY: usize = 56;
+
+/// The font a text of a widget is drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextFont {
+	/// The [`SmallFont`] scaled by the provided factor.
+	Small(u16),
+	Bitmap(&'static BitmapFont),
+}
+
+impl TextFont {
+	fn scale_small_font(scale_factor: u16) -> ScaledFont<SmallFont> {
+		ScaledFont {
+			font: SmallFont,
+			scale_factor,
+		}
+	}
+}
+
+impl Font for TextFont {
+	fn get_line_height(&self) -> u16 {
+		match *self {
+			Self::Small(scale) => Self::scale_small_font(scale).get_line_height(),
+			Self::Bitmap(font) => font.get_line_height(),
+		}
+	}
+
+	fn get_width_of_char(&self, char: u8) -> u16 {
+		match *self {
+			Self::Small(scale) => Self::scale_small_font(scale).get_width_of_char(char),
+			Self::Bitmap(font) => font.get_width_of_char(char),
+		}
+	}
+
+	fn draw_char(&self, char: u8, color: ColorRGB565, background_color: ColorRGB565, draw_fn: &mut impl FnMut(Pixels)) {
+		match *self {
+			Self::Small(scale) => Self::scale_small_font(scale).draw_char(char, color, background_color, draw_fn),
+			Self::Bitmap(font) => font.draw_char(char, color, background_color, draw_fn),
+		}
+	}
+}
+
+/// A rectangular area of the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+	/// The top left corner.
+	pub position: U16x2,
+	pub size: U16x2,
+}
+
+impl Rect {
+	pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+		Self {
+			position: U16x2 { x, y },
+			size: U16x2 { x: width, y: height },
+		}
+	}
+
+	/// Returns `true` if the provided `point` is inside this rectangle.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::screen::ui::widget::Rect;
+	/// # use micromath::vector::U16x2;
+	/// #
+	/// let rect = Rect::new(10, 20, 30, 40);
+	/// assert!(rect.contains(U16x2 { x: 10, y: 20 }));
+	/// assert!(rect.contains(U16x2 { x: 39, y: 59 }));
+	/// assert!(!rect.contains(U16x2 { x: 40, y: 30 }));
+	/// assert!(!rect.contains(U16x2 { x: 9, y: 30 }));
+	/// ```
+	pub fn contains(&self, point: U16x2) -> bool {
+		let end = self.position + self.size;
+		(self.position.x..end.x).contains(&point.x) && (self.position.y..end.y).contains(&point.y)
+	}
+
+	/// Returns `true` if the rectangle doesn't contain any point.
+	pub fn is_empty(&self) -> bool {
+		self.size.x == 0 || self.size.y == 0
+	}
+
+	/// Returns the number of points inside the rectangle.
+	pub fn area(&self) -> u32 {
+		self.size.x as u32 * self.size.y as u32
+	}
+
+	/// Returns the area this rectangle and the `other` one have in common, or `None` if they don't overlap.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::screen::ui::widget::Rect;
+	/// #
+	/// let rect = Rect::new(10, 20, 30, 40);
+	/// assert_eq!(rect.intersection(&Rect::new(30, 0, 100, 30)), Some(Rect::new(30, 20, 10, 10)));
+	/// assert_eq!(rect.intersection(&Rect::new(40, 20, 10, 10)), None);
+	/// ```
+	pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+		let start = U16x2 {
+			x: self.position.x.max(other.position.x),
+			y: self.position.y.max(other.position.y),
+		};
+		let self_end = self.position + self.size;
+		let other_end = other.position + other.size;
+		let end = U16x2 {
+			x: self_end.x.min(other_end.x),
+			y: self_end.y.min(other_end.y),
+		};
+
+		(start.x < end.x && start.y < end.y).then(|| Rect {
+			position: start,
+			size: end - start,
+		})
+	}
+
+	/// Returns the smallest rectangle that contains both this rectangle and the `other` one.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::screen::ui::widget::Rect;
+	/// #
+	/// let rect = Rect::new(10, 20, 30, 40);
+	/// assert_eq!(rect.union(&Rect::new(50, 0, 10, 10)), Rect::new(10, 0, 50, 60));
+	/// ```
+	pub fn union(&self, other: &Rect) -> Rect {
+		let start = U16x2 {
+			x: self.position.x.min(other.position.x),
+			y: self.position.y.min(other.position.y),
+		};
+		let self_end = self.position + self.size;
+		let other_end = other.position + other.size;
+		let end = U16x2 {
+			x: self_end.x.max(other_end.x),
+			y: self_end.y.max(other_end.y),
+		};
+
+		Rect {
+			position: start,
+			size: end - start,
+		}
+	}
+}
+
+/// How a widget is highlighted because of the interaction of the user with it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Highlight {
+	#[default]
+	None,
+	/// The widget is the one the user interacted with last.
+	Focused,
+	/// The user is touching the widget right now.
+	Pressed,
+}
+
+/// Something that happened to a widget because of the interaction of the user with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WidgetEvent {
+	/// A [`Button`] has been pressed.
+	Activated,
+	/// The value of a [`Spinner`] or the option of a [`ListSelector`] has been changed.
+	ValueChanged,
+}
+
+/// A text of at most [`LABEL_TEXT_CAPACITY`] bytes stored on the stack, which can be written using [`write!`].
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::ui::widget::LabelText;
+/// # use core::fmt::Write;
+/// #
+/// let mut text = LabelText::new("Target ");
+/// write!(text, "{:.0}°C", 149.7).unwrap();
+/// assert_eq!(text.as_str(), "Target 150°C");
+/// ```
+pub type LabelText = TextBuffer<LABEL_TEXT_CAPACITY>;
+
+/// A text the user can't interact with.
+pub struct Label {
+	bounds: Rect,
+	text: LabelText,
+	font: TextFont,
+	alignment: Alignment,
+	color: ColorRGB565,
+	is_dirty: bool,
+}
+
+impl Label {
+	/// Returns a [`Label`] that shows the provided `text` inside the `bounds` (split in more lines if it's too wide, and
+	/// vertically centered), drawn with the provided `font` in the [`TEXT_COLOR`].
+	pub fn new(bounds: Rect, text: &str, font: TextFont, alignment: Alignment) -> Self {
+		Self {
+			bounds,
+			text: LabelText::new(text),
+			font,
+			alignment,
+			color: TEXT_COLOR,
+			is_dirty: true,
+		}
+	}
+
+	pub fn get_text(&self) -> &str {
+		self.text.as_str()
+	}
+
+	/// Changes the text of the label (it's redrawn only if it's different from the current one).
+	pub fn set_text(&mut self, text: &str) {
+		self.set_label_text(LabelText::new(text));
+	}
+
+	/// Changes the text of the label to the formatted `arguments` (check [`format_args!`]).
+	pub fn set_text_fmt(&mut self, arguments: fmt::Arguments) {
+		self.set_label_text(LabelText::from_fmt(arguments));
+	}
+
+	pub fn get_color(&self) -> ColorRGB565 {
+		self.color
+	}
+
+	/// Changes the color of the text of the label (it's redrawn only if it's different from the current one).
+	pub fn set_color(&mut self, color: ColorRGB565) {
+		if self.color != color {
+			self.color = color;
+			self.is_dirty = true;
+		}
+	}
+
+	fn set_label_text(&mut self, text: LabelText) {
+		if self.text != text {
+			self.text = text;
+			self.is_dirty = true;
+		}
+	}
+
+	fn draw<C: Canvas>(&self, canvas: &mut C) -> Result<(), C::Error> {
+		fill(canvas, self.bounds, BACKGROUND_COLOR)?;
+		draw_text(
+			canvas,
+			self.bounds,
+			self.get_text(),
+			self.font,
+			self.alignment,
+			self.color,
+		)
+	}
+}
+
+/// A text inside a border, that the user can press while it's enabled.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::ui::widget::{Button, Rect, Widget, WidgetEvent};
+/// # use micromath::vector::U16x2;
+/// #
+/// let mut button = Widget::Button(Button::new(Rect::new(0, 0, 100, 40), "Pause"));
+/// assert_eq!(button.handle_touch(U16x2 { x: 50, y: 20 }), Some(WidgetEvent::Activated));
+///
+/// let Widget::Button(pause) = &mut button else { unreachable!() };
+/// pause.set_enabled(false);
+/// assert!(!button.is_interactive());
+/// assert_eq!(button.handle_touch(U16x2 { x: 50, y: 20 }), None);
+/// assert_eq!(button.activate(), None);
+/// ```
+pub struct Button {
+	label: Label,
+	is_enabled: bool,
+}
+
+impl Button {
+	/// Returns an enabled [`Button`].
+	pub fn new(bounds: Rect, text: &str) -> Self {
+		Self {
+			label: Label::new(bounds, text, TEXT_FONT, Alignment::Center),
+			is_enabled: true,
+		}
+	}
+
+	pub fn get_text(&self) -> &str {
+		self.label.get_text()
+	}
+
+	/// Changes the text of the button (it's redrawn only if it's different from the current one).
+	pub fn set_text(&mut self, text: &str) {
+		self.label.set_text(text);
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		self.is_enabled
+	}
+
+	/// Enables or disables the button: a disabled button is drawn with the [`DISABLED_TEXT_COLOR`], and the user can't
+	/// focus it or press it.
+	pub fn set_enabled(&mut self, is_enabled: bool) {
+		self.is_enabled = is_enabled;
+		self.label.set_color(match is_enabled {
+			true => TEXT_COLOR,
+			false => DISABLED_TEXT_COLOR,
+		});
+	}
+
+	fn draw<C: Canvas>(&self, canvas: &mut C, highlight: Highlight) -> Result<(), C::Error> {
+		self.label.draw(canvas)?;
+		draw_border(canvas, self.label.bounds, highlight)
+	}
+}
+
+/// A number the user can decrease pressing the `-` on its left, or increase pressing the `+` on its right.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::screen::ui::widget::{Rect, Spinner, Widget, WidgetEvent};
+/// # use micromath::vector::U16x2;
+/// #
+/// let mut spinner = Widget::Spinner(Spinner::new(Rect::new(0, 0, 200, 40), 150, 100..=250, 10, "°C"));
+///
+/// // Press the `+`
+/// assert_eq!(spinner.handle_touch(U16x2 { x: 190, y: 20 }), Some(WidgetEvent::ValueChanged));
+/// // Press the value, which does nothing
+/// assert_eq!(spinner.handle_touch(U16x2 { x: 100, y: 20 }), None);
+///
+/// let Widget::Spinner(spinner) = spinner else { unreachable!() };
+/// assert_eq!(spinner.get_value(), 160);
+/// ```
+pub struct Spinner {
+	bounds: Rect,
+	value: i16,
+	range: RangeInclusive<i16>,
+	step: i16,
+	unit: &'static str,
+	is_dirty: bool,
+}
+
+impl Spinner {
+	/// Returns a [`Spinner`] that starts at `value`, that can be changed by `step` inside the provided `range`, and
+	/// that is shown followed by the `unit`.
+	pub fn new(bounds: Rect, value: i16, range: RangeInclusive<i16>, step: i16, unit: &'static str) -> Self {
+		Self {
+			bounds,
+			value: value.clamp(*range.start(), *range.end()),
+			range,
+			step,
+			unit,
+			is_dirty: true,
+		}
+	}
+
+	pub fn get_value(&self) -> i16 {
+		self.value
+	}
+
+	/// Sets the value, clamping it in the range of the spinner.
+	pub fn set_value(&mut self, value: i16) {
+		let value = value.clamp(*self.range.start(), *self.range.end());
+		if self.value != value {
+			self.value = value;
+			self.is_dirty = true;
+		}
+	}
+
+	/// Increases the value by the step, returning `false` if it's already at the end of the range.
+	pub fn increment(&mut self) -> bool {
+		let value = self.value;
+		self.set_value(value.saturating_add(self.step));
+
+		self.value != value
+	}
+
+	/// Decreases the value by the step, returning `false` if it's already at the start of the range.
+	pub fn decrement(&mut self) -> bool {
+		let value = self.value;
+		self.set_value(value.saturating_sub(self.step));
+
+		self.value != value
+	}
+
+	fn draw<C: Canvas>(&self, canvas: &mut C, highlight: Highlight) -> Result<(), C::Error> {
+		let value = LabelText::from_fmt(format_args!("{}{}", self.value, self.unit));
+
+		draw_with_arrows(canvas, self.bounds, highlight, ("-", "+"), value.as_str())
+	}
+}
+
+/// One of a list of options, that the user can change pressing the `<` on its left or the `>` on its right.
+pub struct ListSelector {
+	bounds: Rect,
+	options: &'static [&'static str],
+	selected: usize,
+	is_dirty: bool,
+}
+
+impl ListSelector {
+	/// Returns a [`ListSelector`] of the provided `options` (which mustn't be empty), with the one at the index
+	/// `selected` selected.
+	pub fn new(bounds: Rect, options: &'static [&'static str], selected: usize) -> Self {
+		Self {
+			bounds,
+			options,
+			selected: selected.min(options.len().saturating_sub(1)),
+			is_dirty: true,
+		}
+	}
+
+	/// Returns the index of the selected option.
+	pub fn get_selected(&self) -> usize {
+		self.selected
+	}
+
+	pub fn set_selected(&mut self, selected: usize) {
+		if self.selected != selected && selected < self.options.len() {
+			self.selected = selected;
+			self.is_dirty = true;
+		}
+	}
+
+	/// Selects the next option (going back to the first one after the last one).
+	pub fn select_next(&mut self) {
+		self.set_selected((self.selected + 1) % self.options.len());
+	}
+
+	/// Selects the previous option (going to the last one before the first one).
+	pub fn select_previous(&mut self) {
+		self.set_selected((self.selected + self.options.len() - 1) % self.options.len());
+	}
+
+	fn draw<C: Canvas>(&self, canvas: &mut C, highlight: Highlight) -> Result<(), C::Error> {
+		let option = self.options.get(self.selected).copied().unwrap_or_default();
+
+		draw_with_arrows(canvas, self.bounds, highlight, ("<", ">"), option)
+	}
+}
+
+/// An image the user can't interact with, drawn in the center of its bounds.
+pub struct Icon {
+	bounds: Rect,
+	image: Option<&'static CompressedImage>,
+	is_dirty: bool,
+}
+
+impl Icon {
+	/// Returns an [`Icon`] that shows the provided `image` (or nothing if it's `None`) in the center of the `bounds`,
+	/// which should be big enough to contain it.
+	pub fn new(bounds: Rect, image: Option<&'static CompressedImage>) -> Self {
+		Self {
+			bounds,
+			image,
+			is_dirty: true,
+		}
+	}
+
+	pub fn get_image(&self) -> Option<&'static CompressedImage> {
+		self.image
+	}
+
+	/// Changes the image of the icon (it's redrawn only if it's different from the current one).
+	pub fn set_image(&mut self, image: Option<&'static CompressedImage>) {
+		// The images are compared by address, since comparing their data would be much slower
+		if self.image.map(ptr::from_ref) != image.map(ptr::from_ref) {
+			self.image = image;
+			self.is_dirty = true;
+		}
+	}
+
+	fn draw<C: Canvas>(&self, canvas: &mut C) -> Result<(), C::Error> {
+		fill(canvas, self.bounds, BACKGROUND_COLOR)?;
+		let Some(image) = self.image else {
+			return Ok(());
+		};
+
+		let size = image.size();
+		let offset = U16x2 {
+			x: self.bounds.size.x.saturating_sub(size.x) / 2,
+			y: self.bounds.size.y.saturating_sub(size.y) / 2,
+		};
+		canvas.draw(self.bounds.position + offset, image)
+	}
+}
+
+/// An element of the user interface.
+pub enum Widget {
+	Label(Label),
+	Button(Button),
+	Spinner(Spinner),
+	ListSelector(ListSelector),
+	Icon(Icon),
+}
+
+impl Widget {
+	/// Returns the area of the screen occupied by the widget.
+	pub fn get_bounds(&self) -> Rect {
+		match self {
+			Self::Label(label) => label.bounds,
+			Self::Button(button) => button.label.bounds,
+			Self::Spinner(spinner) => spinner.bounds,
+			Self::ListSelector(list_selector) => list_selector.bounds,
+			Self::Icon(icon) => icon.bounds,
+		}
+	}
+
+	/// Returns `true` if the user can interact with the widget (so it can be focused).
+	pub fn is_interactive(&self) -> bool {
+		match self {
+			Self::Button(button) => button.is_enabled,
+			Self::Spinner(_) | Self::ListSelector(_) => true,
+			Self::Label(_) | Self::Icon(_) => false,
+		}
+	}
+
+	/// Returns `true` if the widget changed since the last time it has been drawn.
+	pub fn is_dirty(&self) -> bool {
+		match self {
+			Self::Label(label) => label.is_dirty,
+			Self::Button(button) => button.label.is_dirty,
+			Self::Spinner(spinner) => spinner.is_dirty,
+			Self::ListSelector(list_selector) => list_selector.is_dirty,
+			Self::Icon(icon) => icon.is_dirty,
+		}
+	}
+
+	/// Makes the widget be completely redrawn the next time it's drawn.
+	pub fn set_dirty(&mut self) {
+		match self {
+			Self::Label(label) => label.is_dirty = true,
+			Self::Button(button) => button.label.is_dirty = true,
+			Self::Spinner(spinner) => spinner.is_dirty = true,
+			Self::ListSelector(list_selector) => list_selector.is_dirty = true,
+			Self::Icon(icon) => icon.is_dirty = true,
+		}
+	}
+
+	/// Reacts to the user lifting the finger from the provided `point` (which is inside the [`bounds`]) after having
+	/// pressed the widget, returning what happened to the widget.
+	///
+	/// [`bounds`]: `Self::get_bounds`
+	pub fn handle_touch(&mut self, point: U16x2) -> Option<WidgetEvent> {
+		let bounds = self.get_bounds();
+		let arrow_touched = || {
+			let x = point.x.checked_sub(bounds.position.x)?;
+			let arrow_width = get_arrow_width(bounds);
+			match (x < arrow_width, x >= bounds.size.x.saturating_sub(arrow_width)) {
+				(true, _) => Some(false),
+				(_, true) => Some(true),
+				_ => None,
+			}
+		};
+
+		match self {
+			Self::Button(button) => button.is_enabled.then_some(WidgetEvent::Activated),
+			Self::Spinner(spinner) => {
+				let has_changed = match arrow_touched()? {
+					true => spinner.increment(),
+					false => spinner.decrement(),
+				};
+				has_changed.then_some(WidgetEvent::ValueChanged)
+			},
+			Self::ListSelector(list_selector) => {
+				match arrow_touched()? {
+					true => list_selector.select_next(),
+					false => list_selector.select_previous(),
+				}
+				Some(WidgetEvent::ValueChanged)
+			},
+			Self::Label(_) | Self::Icon(_) => None,
+		}
+	}
+
+	/// Interacts with the widget without touching it (for example using a physical button): an enabled [`Button`] is
+	/// pressed, the value of a [`Spinner`] is increased (starting again from the start of the range at the end of the
+	/// range) and the next option of a [`ListSelector`] is selected.
+	pub fn activate(&mut self) -> Option<WidgetEvent> {
+		match self {
+			Self::Button(button) => button.is_enabled.then_some(WidgetEvent::Activated),
+			Self::Spinner(spinner) => {
+				if !spinner.increment() {
+					spinner.set_value(*spinner.range.start());
+				}
+				Some(WidgetEvent::ValueChanged)
+			},
+			Self::ListSelector(list_selector) => {
+				list_selector.select_next();
+				Some(WidgetEvent::ValueChanged)
+			},
+			Self::Label(_) | Self::Icon(_) => None,
+		}
+	}
+
+	/// Draws the widget on the `canvas` with the provided `highlight`.
+	pub fn draw<C: Canvas>(&mut self, canvas: &mut C, highlight: Highlight) -> Result<(), C::Error> {
+		self.render(canvas, highlight)?;
+		self.set_clean();
+
+		Ok(())
+	}
+
+	/// Like [`Self::draw`], but the widget isn't marked as drawn (so it can be drawn multiple times, for example one
+	/// piece at a time on a [`LineBuffer`]).
+	///
+	/// [`LineBuffer`]: `crate::hot_plate::screen::line_buffer::LineBuffer`
+	pub fn render<C: Canvas>(&self, canvas: &mut C, highlight: Highlight) -> Result<(), C::Error> {
+		match self {
+			Self::Label(label) => label.draw(canvas),
+			Self::Button(button) => button.draw(canvas, highlight),
+			Self::Spinner(spinner) => spinner.draw(canvas, highlight),
+			Self::ListSelector(list_selector) => list_selector.draw(canvas, highlight),
+			Self::Icon(icon) => icon.draw(canvas),
+		}
+	}
+
+	/// Marks the widget as drawn, so that it's not [`dirty`] anymore until it changes.
+	///
+	/// [`dirty`]: `Self::is_dirty`
+	pub fn set_clean(&mut self) {
+		match self {
+			Self::Label(label) => label.is_dirty = false,
+			Self::Button(button) => button.label.is_dirty = false,
+			Self::Spinner(spinner) => spinner.is_dirty = false,
+			Self::ListSelector(list_selector) => list_selector.is_dirty = false,
+			Self::Icon(icon) => icon.is_dirty = false,
+		}
+	}
+}
+
+/// Returns the index of the [`interactive`] widget at the provided `point`, or `None` if there isn't any.
+///
+/// [`interactive`]: `Widget::is_interactive`
+pub fn hit_test(widgets: &[Widget], point: U16x2) -> Option<usize> {
+	widgets
+		.iter()
+		.position(|widget| widget.is_interactive() && widget.get_bounds().contains(point))
+}
+
+/// Fills the provided `rect` with the provided `color`.
+pub fn fill<C: Canvas>(canvas: &mut C, rect: Rect, color: ColorRGB565) -> Result<(), C::Error> {
+	if rect.size.x == 0 || rect.size.y == 0 {
+		return Ok(());
+	}
+
+	canvas.fill(rect.position, rect.size, color)
+}
+
+fn draw_border<C: Canvas>(canvas: &mut C, rect: Rect, highlight: Highlight) -> Result<(), C::Error> {
+	let color = match highlight {
+		Highlight::None => BORDER_COLOR,
+		Highlight::Focused => FOCUS_COLOR,
+		Highlight::Pressed => PRESSED_COLOR,
+	};
+	canvas.draw(
+		rect.position,
+		&Rectangle {
+			size: rect.size,
+			style: ShapeStyle::outlined(color, BORDER_THICKNESS),
+		},
+	)
+}
+
+/// Draws the provided `text` with the `font` and the `color` inside the `bounds`, with its lines placed with the
+/// provided `alignment` and vertically centered (check [`TextBox`]).
+pub fn draw_text<C: Canvas>(
+	canvas: &mut C, bounds: Rect, text: &str, font: TextFont, alignment: Alignment, color: ColorRGB565,
+) -> Result<(), C::Error> {
+	canvas.draw(
+		bounds.position,
+		&TextBox::new(text, font, bounds.size)
+			.with_alignment(alignment)
+			.with_colors(color, BACKGROUND_COLOR),
+	)
+}
+
+/// Returns the color the provided `temperature` of the plate is shown with: from blue at
+/// [`COLD_TEMPERATURE_IN_CELSIUS`] to red at [`HOT_TEMPERATURE_IN_CELSIUS`] (check [`ColorRGB565::heat_map`]).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{
+/// # 	hot_plate::screen::ui::widget::get_temperature_color,
+/// # 	utils::measurement::{color::ColorRGB565, temperature::Temperature},
+/// # };
+/// #
+/// assert_eq!(get_temperature_color(Temperature::from_celsius(20.)), ColorRGB565::BLUE);
+/// assert_eq!(get_temperature_color(Temperature::from_celsius(260.)), ColorRGB565::RED);
+/// ```
+pub fn get_temperature_color(temperature: Temperature) -> ColorRGB565 {
+	ColorRGB565::heat_map(
+		temperature,
+		Temperature::from_celsius(COLD_TEMPERATURE_IN_CELSIUS),
+		Temperature::from_celsius(HOT_TEMPERATURE_IN_CELSIUS),
+	)
+}
+
+/// Returns the width of the arrows on the sides of a [`Spinner`] or of a [`ListSelector`] with the provided `bounds`.
+fn get_arrow_width(bounds: Rect) -> u16 {
+	bounds.size.y.min(bounds.size.x / 3)
+}
+
+/// Draws a widget with a `value` between 2 `arrows`.
+fn draw_with_arrows<C: Canvas>(
+	canvas: &mut C, bounds: Rect, highlight: Highlight, arrows: (&str, &str), value: &str,
+) -> Result<(), C::Error> {
+	let arrow_width = get_arrow_width(bounds);
+	let side = |x| Rect {
+		position: bounds.position + U16x2 { x, y: 0 },
+		size: U16x2 {
+			x: arrow_width,
+			y: bounds.size.y,
+		},
+	};
+
+	fill(canvas, bounds, BACKGROUND_COLOR)?;
+	draw_text(canvas, side(0), arrows.0, TEXT_FONT, Alignment::Center, TEXT_COLOR)?;
+	draw_text(canvas, bounds, value, TEXT_FONT, Alignment::Center, TEXT_COLOR)?;
+	draw_text(
+		canvas,
+		side(bounds.size.x - arrow_width),
+		arrows.1,
+		TEXT_FONT,
+		Alignment::Center,
+		TEXT_COLOR,
+	)?;
+	draw_border(canvas, bounds, highlight)
+}
@@ -0,0 +1,340 @@
+//! Automatic tuning of the gains of a [`PidController`] using the [`relay method`] of Åström and Hägglund.
+//!
+//! Instead of being controlled by the PID, the heater is turned on and off like a thermostat (a relay) around the
+//! [`AutotuneConfig::target_temperature`]. This makes the temperature of the plate oscillate: the amplitude and the
+//! period of the oscillations tell how the plate responds to the heater, and they are used to compute the ultimate
+//! gain and the ultimate period of the plate, from which the [`PidGains`] are derived using a [`TuningRule`].
+//!
+//! Use [`PidController::tick_autotune`] to run an [`Autotune`].
+//!
+//! [`relay method`]: <https://en.wikipedia.org/wiki/Relay_tuning>
+//! [`PidController`]: `super::TemperaturePidController`
+//! [`PidController::tick_autotune`]: `super::TemperaturePidController::tick_autotune`
+//! [`PidGains`]: `super::TemperaturePidGains`
+
+use core::f32::consts::PI;
+
+use micromath::F32Ext;
+
+use super::{PidUpdateError, TemperaturePidGains};
+use crate::utils::{math::Percentage, measurement::temperature::Temperature};
+
+/// The parameters of an [`Autotune`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutotuneConfig {
+	/// The temperature around which the plate oscillates.
+	pub target_temperature: Temperature,
+	/// The heat percentage of the heater when the relay is on.
+	pub relay_high: Percentage,
+	/// The heat percentage of the heater when the relay is off.
+	pub relay_low: Percentage,
+	/// How many degrees the temperature must be above (or below) the target temperature to turn the relay off (or on).
+	///
+	/// It prevents the relay from switching continuously because of the noise of the thermistor.
+	pub hysteresis: f32,
+	/// How many oscillations are measured (and averaged) to compute the result. At least 1 oscillation is always
+	/// measured, even if this is `0`.
+	pub cycles_count: u8,
+	/// If the autotune lasts more than this amount of seconds, it fails.
+	pub max_duration_in_seconds: f32,
+}
+
+/// Rules that compute the [`PidGains`] of a system from its ultimate gain and ultimate period.
+///
+/// [`PidGains`]: `TemperaturePidGains`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TuningRule {
+	/// The classic [`Ziegler–Nichols`] rule: fast, but with a quarter wave decay (so it overshoots).
+	///
+	/// [`Ziegler–Nichols`]: <https://en.wikipedia.org/wiki/Ziegler%E2%80%93Nichols_method>
+	ZieglerNichols,
+	/// The Pessen integral rule: faster and more aggressive than [`Self::ZieglerNichols`].
+	PessenIntegral,
+	/// A variation of [`Self::ZieglerNichols`] with less overshoot.
+	SomeOvershoot,
+	/// A variation of [`Self::ZieglerNichols`] that should never overshoot.
+	NoOvershoot,
+	/// The Tyreus–Luyben rule: slower than [`Self::ZieglerNichols`] but more stable, which suits slow thermal
+	/// systems.
+	TyreusLuyben,
+}
+
+impl TuningRule {
+	/// Returns the `(proportional gain, integral time, derivative time)` factors of this rule, that multiplied
+	/// respectively by the ultimate gain, the ultimate period and the ultimate period give the parameters of the PID.
+	const fn get_factors(self) -> (f32, f32, f32) {
+		match self {
+			Self::ZieglerNichols => (0.6, 0.5, 0.125),
+			Self::PessenIntegral => (0.7, 0.4, 0.15),
+			Self::SomeOvershoot => (0.33, 0.5, 0.33),
+			Self::NoOvershoot => (0.2, 0.5, 0.33),
+			Self::TyreusLuyben => (0.45, 2.2, 0.159),
+		}
+	}
+}
+
+/// What an [`Autotune`] measured about the plate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutotuneResult {
+	/// The proportional gain at which the plate would oscillate with a constant amplitude.
+	pub ultimate_gain: f32,
+	/// The period in seconds of the oscillations of the plate at the [`Self::ultimate_gain`].
+	pub ultimate_period_in_seconds: f32,
+}
+
+impl AutotuneResult {
+	/// Returns the [`PidGains`] computed using the provided `rule`.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::temperature::autotune::*;
+	/// #
+	/// let result = AutotuneResult {
+	/// 	ultimate_gain: 10.,
+	/// 	ultimate_period_in_seconds: 80.,
+	/// };
+	///
+	/// let gains = result.get_gains(TuningRule::ZieglerNichols);
+	/// assert_eq!(gains.p, 6.);
+	/// assert_eq!(gains.i, 6. / 40.);
+	/// assert_eq!(gains.d, 6. * 10.);
+	/// ```
+	///
+	/// [`PidGains`]: `TemperaturePidGains`
+	pub fn get_gains(&self, rule: TuningRule) -> TemperaturePidGains {
+		let (proportional_factor, integral_time_factor, derivative_time_factor) = rule.get_factors();
+
+		let p = proportional_factor * self.ultimate_gain;
+		let integral_time = integral_time_factor * self.ultimate_period_in_seconds;
+		let derivative_time = derivative_time_factor * self.ultimate_period_in_seconds;
+
+		TemperaturePidGains {
+			p,
+			i: p / integral_time,
+			d: p * derivative_time,
+		}
+	}
+}
+
+/// The state of an [`Autotune`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AutotuneState {
+	/// The plate is being heated up to the target temperature for the first time.
+	HeatingUp,
+	/// The relay is making the temperature oscillate, and `completed_cycles` out of
+	/// [`AutotuneConfig::cycles_count`] oscillations have been measured.
+	Oscillating { completed_cycles: u8 },
+	/// The autotune completed successfully.
+	Finished(AutotuneResult),
+}
+
+/// Tunes the gains of a [`PidController`] using the relay method. Check the [`module's documentation`](self).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::temperature::autotune::*, utils::{math::Percentage, measurement::temperature::Temperature}};
+/// #
+/// let mut autotune = Autotune::new(AutotuneConfig {
+/// 	target_temperature: Temperature::from_celsius(150.),
+/// 	relay_high: Percentage::FULL,
+/// 	relay_low: Percentage::ZERO,
+/// 	hysteresis: 1.,
+/// 	cycles_count: 3,
+/// 	max_duration_in_seconds: 1_000.,
+/// });
+///
+/// // Simulate a plate whose temperature oscillates with an amplitude of 5°C and a period of 40 seconds
+/// let mut time = 0.;
+/// while !autotune.is_finished() {
+/// 	let temperature = 150. - 5. * (time * core::f32::consts::TAU / 40.).cos();
+/// 	autotune.update(Temperature::from_celsius(temperature), 0.1).unwrap();
+/// 	time += 0.1;
+/// }
+///
+/// let result = autotune.get_result().unwrap();
+/// assert!((result.ultimate_period_in_seconds - 40.).abs() < 0.5);
+/// assert!((result.ultimate_gain - 4. * 50. / (core::f32::consts::PI * (25_f32 - 1.).sqrt())).abs() < 0.1);
+///
+/// // At least 1 oscillation is measured
+/// let mut autotune = Autotune::new(AutotuneConfig { cycles_count: 0, ..autotune.get_config() });
+/// let mut time = 0.;
+/// while !autotune.is_finished() {
+/// 	let temperature = 150. - 5. * (time * core::f32::consts::TAU / 40.).cos();
+/// 	autotune.update(Temperature::from_celsius(temperature), 0.1).unwrap();
+/// 	time += 0.1;
+/// }
+/// assert!((autotune.get_result().unwrap().ultimate_period_in_seconds - 40.).abs() < 0.5);
+/// ```
+///
+/// [`PidController`]: `super::TemperaturePidController`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Autotune {
+	config: AutotuneConfig,
+	state: AutotuneState,
+	is_relay_on: bool,
+
+	elapsed_seconds: f32,
+
+	/// The time at which the relay has been turned on the last time.
+	last_relay_on_time: Option<f32>,
+	/// The highest and lowest temperatures in celsius measured since the relay has been turned on the last time.
+	cycle_extremes: (f32, f32),
+
+	amplitudes_sum: f32,
+	periods_sum: f32,
+}
+
+impl Autotune {
+	/// Returns an [`Autotune`] that has still to start.
+	pub fn new(config: AutotuneConfig) -> Self {
+		Self {
+			config,
+			state: AutotuneState::HeatingUp,
+			is_relay_on: true,
+			elapsed_seconds: 0.,
+			last_relay_on_time: None,
+			cycle_extremes: (f32::MIN, f32::MAX),
+			amplitudes_sum: 0.,
+			periods_sum: 0.,
+		}
+	}
+
+	pub fn get_config(&self) -> AutotuneConfig {
+		self.config
+	}
+
+	pub fn get_state(&self) -> AutotuneState {
+		self.state
+	}
+
+	/// Returns `true` if the autotune has completed successfully.
+	pub fn is_finished(&self) -> bool {
+		matches!(self.state, AutotuneState::Finished(_))
+	}
+
+	/// Returns the result of the autotune, or `None` if it hasn't finished yet.
+	pub fn get_result(&self) -> Option<AutotuneResult> {
+		match self.state {
+			AutotuneState::Finished(result) => Some(result),
+			_ => None,
+		}
+	}
+
+	/// Returns how much of the autotune has been completed.
+	pub fn get_progress(&self) -> Percentage {
+		match self.state {
+			AutotuneState::HeatingUp => Percentage::ZERO,
+			AutotuneState::Oscillating { completed_cycles } => {
+				Percentage::from_0_to_1(completed_cycles as f32 / self.config.cycles_count.max(1) as f32)
+					.unwrap_or(Percentage::FULL)
+			},
+			AutotuneState::Finished(_) => Percentage::FULL,
+		}
+	}
+
+	/// Updates the autotune with the `current_temperature` of the plate, measured `delta_time` seconds after the
+	/// previous one, and returns the heat percentage the heater should be set to.
+	///
+	/// If the autotune has [`finished`], the returned percentage is always `0%`.
+	///
+	/// [`finished`]: `Self::is_finished`
+	pub fn update(&mut self, current_temperature: Temperature, delta_time: f32) -> Result<Percentage, AutotuneError> {
+		if self.is_finished() {
+			return Ok(Percentage::ZERO);
+		}
+
+		self.elapsed_seconds += delta_time;
+		if self.elapsed_seconds > self.config.max_duration_in_seconds {
+			return Err(AutotuneError::TimedOut);
+		}
+
+		let current_temperature = current_temperature.as_celsius();
+		let target_temperature = self.config.target_temperature.as_celsius();
+		self.cycle_extremes = (
+			self.cycle_extremes.0.max(current_temperature),
+			self.cycle_extremes.1.min(current_temperature),
+		);
+
+		if self.is_relay_on && current_temperature > target_temperature + self.config.hysteresis {
+			self.is_relay_on = false;
+		} else if !self.is_relay_on && current_temperature < target_temperature - self.config.hysteresis {
+			self.is_relay_on = true;
+			self.on_relay_turned_on(current_temperature);
+		}
+
+		Ok(match self.is_relay_on && !self.is_finished() {
+			true => self.config.relay_high,
+			false => self.config.relay_low,
+		})
+	}
+
+	/// Each time the relay is turned on an oscillation is completed.
+	fn on_relay_turned_on(&mut self, current_temperature: f32) {
+		let completed_cycles = match (self.state, self.last_relay_on_time) {
+			// The first oscillation is discarded because it's influenced by the heat up
+			(AutotuneState::HeatingUp, _) | (_, None) => 0,
+			(AutotuneState::Oscillating { completed_cycles }, Some(last_relay_on_time)) => {
+				let (highest_temperature, lowest_temperature) = self.cycle_extremes;
+				self.amplitudes_sum += (highest_temperature - lowest_temperature) / 2.;
+				self.periods_sum += self.elapsed_seconds - last_relay_on_time;
+
+				completed_cycles + 1
+			},
+			(AutotuneState::Finished(_), _) => return,
+		};
+
+		self.last_relay_on_time = Some(self.elapsed_seconds);
+		self.cycle_extremes = (current_temperature, current_temperature);
+
+		self.state = match completed_cycles >= self.config.cycles_count.max(1) {
+			true => AutotuneState::Finished(self.compute_result(completed_cycles)),
+			false => AutotuneState::Oscillating { completed_cycles },
+		};
+	}
+
+	fn compute_result(&self, completed_cycles: u8) -> AutotuneResult {
+		let amplitude = self.amplitudes_sum / completed_cycles as f32;
+		let relay_amplitude = (self.config.relay_high.into_0_to_100() - self.config.relay_low.into_0_to_100()) / 2.;
+		// The hysteresis of the relay makes the oscillations bigger than they would be with an ideal relay
+		let ideal_relay_amplitude_squared = amplitude * amplitude - self.config.hysteresis * self.config.hysteresis;
+		let amplitude = match ideal_relay_amplitude_squared > 0. {
+			true => precise_sqrt(ideal_relay_amplitude_squared),
+			false => amplitude,
+		};
+
+		AutotuneResult {
+			ultimate_gain: 4. * relay_amplitude / (PI * amplitude),
+			ultimate_period_in_seconds: self.periods_sum / completed_cycles as f32,
+		}
+	}
+}
+
+/// Returns the square root of `value`, refining the rough approximation of [`micromath`] with a couple of iterations
+/// of [`Newton's method`].
+///
+/// [`Newton's method`]: <https://en.wikipedia.org/wiki/Newton%27s_method#Square_root>
+fn precise_sqrt(value: f32) -> f32 {
+	let mut sqrt = F32Ext::sqrt(value);
+	for _ in 0..2 {
+		sqrt = (sqrt + value / sqrt) / 2.;
+	}
+
+	sqrt
+}
+
+/// An error that can occur during an [`Autotune`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AutotuneError {
+	/// The autotune lasted more than [`AutotuneConfig::max_duration_in_seconds`].
+	TimedOut,
+	/// The [`PidController`] couldn't read the temperature or drive the heater, or the [`TemperatureSafety`] checks
+	/// failed.
+	///
+	/// [`PidController`]: `super::TemperaturePidController`
+	/// [`TemperatureSafety`]: `super::safety::TemperatureSafety`
+	TemperatureControl(PidUpdateError),
+	/// The autotune completed, but its result couldn't be added to the [`GainSchedule`] because it's full.
+	///
+	/// [`GainSchedule`]: `super::gain_schedule::GainSchedule`
+	GainScheduleFull,
+}
@@ -0,0 +1,241 @@
+//! The math of the [`PID control`] used by the [`PidController`], independent of the hardware.
+//!
+//! [`PID control`]: https://en.wikipedia.org/wiki/Proportional%E2%80%93integral%E2%80%93derivative_controller
+//! [`PidController`]: `super::TemperaturePidController`
+
+use core::ops::RangeInclusive;
+
+use super::TemperaturePidGains;
+use crate::utils::math::{self, Percentage};
+
+/// How the [`PidControl`] prevents the [`integral windup`]: when the output is saturated (for example during a long
+/// heat up, where the heater is at 100% for minutes) the integral term would keep growing, making the output
+/// overshoot a lot once the target is reached.
+///
+/// [`integral windup`]: <https://en.wikipedia.org/wiki/Integral_windup>
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AntiWindup {
+	/// The integral term grows without limits.
+	None,
+	/// The integral term is kept inside the [`output limits`](PidControlConfig::output_limits).
+	#[default]
+	Clamping,
+	/// When the output saturates, the integral term is driven back by the difference between the saturated output
+	/// and the unsaturated one, divided by the provided tracking time constant: the smaller it is, the faster the
+	/// integral term is driven back. If it's not positive the integral term isn't driven back (like with [`Self::None`]).
+	BackCalculation { tracking_time_in_seconds: f32 },
+	/// The integral term stops growing while the output is saturated and the error would make it saturate even more.
+	ConditionalIntegration,
+}
+
+/// What the derivative term of the [`PidControl`] is computed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DerivativeSource {
+	/// The derivative of the error. The output has a spike (a "derivative kick") whenever the setpoint changes
+	/// suddenly.
+	Error,
+	/// The derivative of the measurement (with the opposite sign), which is the same as the derivative of the error
+	/// when the setpoint is constant, but it doesn't kick when the setpoint changes.
+	#[default]
+	Measurement,
+}
+
+/// The parameters of a [`PidControl`], apart from its gains.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PidControlConfig {
+	/// The range in which the output is kept, where `0%` is [`PidControl::MIN_OUTPUT`] and `100%` is
+	/// [`PidControl::MAX_OUTPUT`].
+	pub output_limits: RangeInclusive<Percentage>,
+	pub anti_windup: AntiWindup,
+	pub derivative_source: DerivativeSource,
+	/// The time constant of the [`low-pass filter`] applied to the derivative term, which would otherwise amplify the
+	/// noise of the measurement. If it's `0` the derivative isn't filtered.
+	///
+	/// [`low-pass filter`]: <https://en.wikipedia.org/wiki/Low-pass_filter#Simple_infinite_impulse_response_filter>
+	pub derivative_filter_time_constant_in_seconds: f32,
+}
+
+impl Default for PidControlConfig {
+	fn default() -> Self {
+		Self {
+			output_limits: Percentage::ZERO..=Percentage::FULL,
+			anti_windup: AntiWindup::default(),
+			derivative_source: DerivativeSource::default(),
+			derivative_filter_time_constant_in_seconds: 0.,
+		}
+	}
+}
+
+/// A [`PID control`] that works in continuous time: the integral and derivative terms take into account how much
+/// time passes between 2 [`updates`].
+///
+/// The `i` gain is applied to the integral of the error over seconds, and the `d` gain to the rate of change of the
+/// error (or of the measurement, check [`DerivativeSource`]) per second.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::hot_plate::temperature::{control::*, TemperaturePidGains};
+/// #
+/// let mut control = PidControl::new(
+/// 	TemperaturePidGains { p: 10., i: 1., d: 0. },
+/// 	PidControlConfig {
+/// 		anti_windup: AntiWindup::ConditionalIntegration,
+/// 		..Default::default()
+/// 	},
+/// );
+/// control.set_setpoint(100.);
+///
+/// // The output is limited to 100 even if the proportional term alone is 1000
+/// assert_eq!(control.update(0., 0., 1.), 100.);
+///
+/// // The integral term didn't grow while the output was saturated
+/// assert_eq!(control.update(99., 0., 1.), 10. + 1.);
+///
+/// // A tracking time that isn't positive doesn't drive back the integral term
+/// let mut control = PidControl::new(
+/// 	TemperaturePidGains { p: 10., i: 1., d: 0. },
+/// 	PidControlConfig {
+/// 		anti_windup: AntiWindup::BackCalculation { tracking_time_in_seconds: 0. },
+/// 		..Default::default()
+/// 	},
+/// );
+/// control.set_setpoint(100.);
+/// assert_eq!(control.update(0., 0., 1.), 100.);
+/// assert_eq!(control.update(99., 0., 1.), 100.);
+/// assert_eq!(control.update(105., 0., 1.), -50. + 100. + 1. - 5.);
+/// ```
+///
+/// [`PID control`]: <https://en.wikipedia.org/wiki/Proportional%E2%80%93integral%E2%80%93derivative_controller>
+/// [`updates`]: `Self::update`
+pub struct PidControl {
+	gains: TemperaturePidGains,
+	config: PidControlConfig,
+	setpoint: f32,
+
+	integral_term: f32,
+	last_measurement: Option<f32>,
+	last_error: Option<f32>,
+	filtered_derivative: f32,
+}
+
+impl PidControl {
+	/// The output corresponding to `0%`.
+	pub const MIN_OUTPUT: f32 = 0.;
+	/// The output corresponding to `100%`.
+	pub const MAX_OUTPUT: f32 = 100.;
+
+	pub fn new(gains: TemperaturePidGains, config: PidControlConfig) -> Self {
+		Self {
+			gains,
+			config,
+			setpoint: 0.,
+			integral_term: 0.,
+			last_measurement: None,
+			last_error: None,
+			filtered_derivative: 0.,
+		}
+	}
+
+	pub fn get_gains(&self) -> TemperaturePidGains {
+		self.gains
+	}
+
+	pub fn set_gains(&mut self, gains: TemperaturePidGains) {
+		self.gains = gains;
+	}
+
+	pub fn get_config(&self) -> &PidControlConfig {
+		&self.config
+	}
+
+	pub fn set_config(&mut self, config: PidControlConfig) {
+		self.config = config;
+	}
+
+	pub fn get_setpoint(&self) -> f32 {
+		self.setpoint
+	}
+
+	pub fn set_setpoint(&mut self, setpoint: f32) {
+		self.setpoint = setpoint;
+	}
+
+	/// Returns the range the output is kept in.
+	pub fn get_output_limits(&self) -> RangeInclusive<f32> {
+		let to_output =
+			|percentage: &Percentage| math::lerp(percentage.into_0_to_1(), Self::MIN_OUTPUT..=Self::MAX_OUTPUT);
+
+		to_output(self.config.output_limits.start())..=to_output(self.config.output_limits.end())
+	}
+
+	/// Forgets the history of the control (the integral term and the previous measurement), so that the next
+	/// [`update`](Self::update) starts from scratch.
+	pub fn reset(&mut self) {
+		self.integral_term = 0.;
+		self.last_measurement = None;
+		self.last_error = None;
+		self.filtered_derivative = 0.;
+	}
+
+	/// Returns the output of the control given the current `measurement`, taken `delta_time` seconds after the
+	/// previous one.
+	///
+	/// The `feed_forward` is added to the output of the PID before limiting it (so it's taken into account by the
+	/// [`AntiWindup`] too).
+	pub fn update(&mut self, measurement: f32, feed_forward: f32, delta_time: f32) -> f32 {
+		let error = self.setpoint - measurement;
+		let output_limits = self.get_output_limits();
+
+		let proportional_term = self.gains.p * error;
+		let derivative_term = self.gains.d * self.update_derivative(measurement, error, delta_time);
+
+		let integral_term = self.integral_term + self.gains.i * error * delta_time;
+		let unsaturated_output = proportional_term + integral_term + derivative_term + feed_forward;
+		let output = math::constrain(unsaturated_output, output_limits.clone());
+
+		self.integral_term = match self.config.anti_windup {
+			AntiWindup::Clamping => math::constrain(integral_term, output_limits),
+			AntiWindup::BackCalculation {
+				tracking_time_in_seconds,
+			} if tracking_time_in_seconds > 0. => {
+				integral_term + (output - unsaturated_output) * delta_time / tracking_time_in_seconds
+			},
+			AntiWindup::None | AntiWindup::BackCalculation { .. } => integral_term,
+			AntiWindup::ConditionalIntegration => {
+				let is_winding_up =
+					(unsaturated_output > output && error > 0.) || (unsaturated_output < output && error < 0.);
+				match is_winding_up {
+					true => self.integral_term,
+					false => integral_term,
+				}
+			},
+		};
+
+		output
+	}
+
+	/// Returns the filtered derivative of the [`DerivativeSource`].
+	fn update_derivative(&mut self, measurement: f32, error: f32, delta_time: f32) -> f32 {
+		let raw_derivative = match (self.config.derivative_source, self.last_measurement, self.last_error) {
+			_ if delta_time <= 0. => 0.,
+			(DerivativeSource::Measurement, Some(last_measurement), _) => {
+				-(measurement - last_measurement) / delta_time
+			},
+			(DerivativeSource::Error, _, Some(last_error)) => (error - last_error) / delta_time,
+			_ => 0.,
+		};
+		self.last_measurement = Some(measurement);
+		self.last_error = Some(error);
+
+		let time_constant = self.config.derivative_filter_time_constant_in_seconds;
+		self.filtered_derivative = match time_constant > 0. {
+			true => {
+				let smoothing_factor = delta_time / (time_constant + delta_time);
+				self.filtered_derivative + smoothing_factor * (raw_derivative - self.filtered_derivative)
+			},
+			false => raw_derivative,
+		};
+
+		self.filtered_derivative
+	}
+}
@@ -0,0 +1,48 @@
+use crate::utils::measurement::temperature::Temperature;
+
+/// The parameters of a model of the plate used to predict the output of the heater required to follow the target
+/// temperature, which is added to the output of the PID so that the PID only has to correct the error of the model.
+///
+/// The predicted output (in the same units of the PID output) is the sum of:
+/// - the output required to compensate the heat lost to the ambient, which is proportional to the difference between
+///   the target temperature and the [`Self::ambient_temperature`];
+/// - the output required to make the temperature change at the rate the target temperature is going to change in the
+///   next [`Self::look_ahead_in_seconds`] seconds.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::temperature::feed_forward::FeedForwardConfig, utils::measurement::temperature::Temperature};
+/// #
+/// let feed_forward = FeedForwardConfig {
+/// 	ambient_temperature: Temperature::from_celsius(25.),
+/// 	heat_loss_per_degree: 0.4,
+/// 	output_per_degree_per_second: 20.,
+/// 	look_ahead_in_seconds: 5.,
+/// };
+///
+/// // To keep the plate at 125°C the heater must compensate 100 degrees of heat loss
+/// assert_eq!(feed_forward.get_output(Temperature::from_celsius(125.), 0.), 40.);
+/// // To also heat it up by 1.5°C every second it needs more output
+/// assert_eq!(feed_forward.get_output(Temperature::from_celsius(125.), 1.5), 70.);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeedForwardConfig {
+	/// The temperature of the air around the plate.
+	pub ambient_temperature: Temperature,
+	/// The output required to keep the plate 1 degree above the [`Self::ambient_temperature`].
+	pub heat_loss_per_degree: f32,
+	/// The additional output required to raise the temperature of the plate by 1 degree per second.
+	pub output_per_degree_per_second: f32,
+	/// How many seconds in the future the target temperature is looked at to compute the rate at which it changes.
+	pub look_ahead_in_seconds: f32,
+}
+
+impl FeedForwardConfig {
+	/// Returns the output the model predicts is required to keep the plate at the `target_temperature` while it
+	/// changes at the rate of `target_temperature_slope` degrees per second.
+	pub fn get_output(&self, target_temperature: Temperature, target_temperature_slope: f32) -> f32 {
+		let heat_loss = (target_temperature - self.ambient_temperature).as_kelvin() * self.heat_loss_per_degree;
+
+		heat_loss + target_temperature_slope * self.output_per_degree_per_second
+	}
+}
@@ -0,0 +1,262 @@
+//! Combines the readings of the multiple sensors of the hot plate in a single [`Temperature`] used by the
+//! [`PidController`], and detects when they disagree too much (for example because one of them came loose and is
+//! measuring the temperature of the air instead of the one of the plate).
+//!
+//! [`PidController`]: `super::TemperaturePidController`
+
+use embedded_hal::i2c::I2c;
+use enumset::{EnumSet, EnumSetType};
+
+use crate::{
+	hot_plate::{
+		drivers::{mlx90614::Mlx90614, thermistor::Thermistor},
+		hal::adc::{Adc, AdcPin},
+	},
+	utils::measurement::temperature::Temperature,
+};
+
+/// The number of [`TemperatureSensor`]s of the hot plate.
+pub const SENSORS_COUNT: usize = 4;
+
+/// A sensor that measures the temperature of the hot plate.
+#[derive(EnumSetType, Debug, Hash)]
+pub enum TemperatureSensor {
+	/// The first thermistor attached to the plate (`THERMISTOR_1` on the controller board).
+	PlateThermistor1,
+	/// The second thermistor attached to the plate (`THERMISTOR_2` on the controller board).
+	PlateThermistor2,
+	/// The thermistor on the controller board (`Board_Thermistor`).
+	BoardThermistor,
+	/// The [`MLX90614`] infrared thermometer, which measures the temperature of the surface it's pointed to (for
+	/// example the board that is being soldered).
+	///
+	/// [`MLX90614`]: `Mlx90614`
+	InfraredThermometer,
+}
+
+/// The last [`Temperature`] read from each [`TemperatureSensor`] (`None` if the sensor hasn't been read).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SensorReadings {
+	temperatures: [Option<Temperature>; SENSORS_COUNT],
+}
+
+impl SensorReadings {
+	/// Returns [`SensorReadings`] where no sensor has been read.
+	pub const fn new() -> Self {
+		Self {
+			temperatures: [None; SENSORS_COUNT],
+		}
+	}
+
+	/// Returns the temperature read from the provided `sensor`, or `None` if it hasn't been read.
+	pub fn get(&self, sensor: TemperatureSensor) -> Option<Temperature> {
+		self.temperatures[sensor as usize]
+	}
+
+	pub fn set(&mut self, sensor: TemperatureSensor, temperature: Option<Temperature>) {
+		self.temperatures[sensor as usize] = temperature;
+	}
+
+	/// Returns an iterator over the temperatures read from the sensors in `sensors` (skipping the ones that haven't
+	/// been read).
+	pub fn iter(
+		&self, sensors: EnumSet<TemperatureSensor>,
+	) -> impl Iterator<Item = (TemperatureSensor, Temperature)> + '_ {
+		sensors
+			.iter()
+			.filter_map(|sensor| self.get(sensor).map(|temperature| (sensor, temperature)))
+	}
+}
+
+/// The weight of each [`TemperatureSensor`] when using [`FusionMode::Weighted`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SensorWeights {
+	pub plate_thermistor_1: f32,
+	pub plate_thermistor_2: f32,
+	pub board_thermistor: f32,
+	pub infrared_thermometer: f32,
+}
+
+impl SensorWeights {
+	/// Returns the weight of the provided `sensor`.
+	pub fn get(&self, sensor: TemperatureSensor) -> f32 {
+		match sensor {
+			TemperatureSensor::PlateThermistor1 => self.plate_thermistor_1,
+			TemperatureSensor::PlateThermistor2 => self.plate_thermistor_2,
+			TemperatureSensor::BoardThermistor => self.board_thermistor,
+			TemperatureSensor::InfraredThermometer => self.infrared_thermometer,
+		}
+	}
+}
+
+/// How the readings of the sensors are combined in a single [`Temperature`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FusionMode {
+	/// The mean of the readings.
+	Average,
+	/// The [`median`] of the readings (the mean of the 2 middle ones if their number is even), which ignores a single
+	/// wrong reading when there are at least 3 sensors.
+	///
+	/// [`median`]: <https://en.wikipedia.org/wiki/Median>
+	Median,
+	/// The highest reading: a sensor that reads a temperature lower than the real one (like a loose thermistor) can't
+	/// make the heater push more power in the plate.
+	Max,
+	/// The weighted mean of the readings.
+	Weighted(SensorWeights),
+}
+
+/// Which sensors are read and how their readings are combined.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::temperature::fusion::*, utils::measurement::temperature::Temperature};
+/// # use enumset::EnumSet;
+/// #
+/// // The board thermistor measures the board that is being soldered (which is colder than the plate while it heats
+/// // up), so it's only monitored
+/// let mut fusion = SensorFusionConfig {
+/// 	sensors: TemperatureSensor::PlateThermistor1 | TemperatureSensor::PlateThermistor2,
+/// 	monitored_sensors: EnumSet::only(TemperatureSensor::BoardThermistor),
+/// 	mode: FusionMode::Median,
+/// 	max_disagreement: 15.,
+/// };
+///
+/// let mut readings = SensorReadings::new();
+/// readings.set(TemperatureSensor::PlateThermistor1, Some(Temperature::from_celsius(150.)));
+/// readings.set(TemperatureSensor::PlateThermistor2, Some(Temperature::from_celsius(152.)));
+/// readings.set(TemperatureSensor::BoardThermistor, Some(Temperature::from_celsius(90.)));
+/// assert_eq!(fusion.fuse(&readings), Some(Temperature::from_celsius(151.)));
+/// assert!(!fusion.do_sensors_disagree(&readings));
+///
+/// // The second thermistor came loose and is measuring the air around the plate
+/// readings.set(TemperatureSensor::PlateThermistor2, Some(Temperature::from_celsius(30.)));
+/// assert!(fusion.do_sensors_disagree(&readings));
+///
+/// fusion.mode = FusionMode::Max;
+/// assert_eq!(fusion.fuse(&readings), Some(Temperature::from_celsius(150.)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SensorFusionConfig {
+	/// The sensors whose readings are combined.
+	pub sensors: EnumSet<TemperatureSensor>,
+	/// Other sensors that are read together with the [`Self::sensors`], without combining their readings (so they
+	/// are only shown to the user, like the temperature of the board that is being soldered).
+	pub monitored_sensors: EnumSet<TemperatureSensor>,
+	pub mode: FusionMode,
+	/// The maximum difference in degrees between the highest and the lowest reading of the [`Self::sensors`] before
+	/// they are considered in [`disagreement`].
+	///
+	/// [`disagreement`]: `Self::do_sensors_disagree`
+	pub max_disagreement: f32,
+}
+
+impl SensorFusionConfig {
+	/// Returns the [`Temperature`] obtained combining the `readings` of the [`Self::sensors`] using the
+	/// [`Self::mode`], or `None` if none of them has been read (or if their weights sum up to `0`).
+	pub fn fuse(&self, readings: &SensorReadings) -> Option<Temperature> {
+		let mut temperatures = [0_f32; SENSORS_COUNT];
+		let mut count = 0;
+		for (_, temperature) in readings.iter(self.sensors) {
+			temperatures[count] = temperature.as_kelvin();
+			count += 1;
+		}
+		let temperatures = &mut temperatures[..count];
+		if temperatures.is_empty() {
+			return None;
+		}
+
+		let fused_temperature = match self.mode {
+			FusionMode::Average => temperatures.iter().sum::<f32>() / count as f32,
+			FusionMode::Median => {
+				temperatures.sort_unstable_by(f32::total_cmp);
+				match count % 2 {
+					0 => (temperatures[count / 2 - 1] + temperatures[count / 2]) / 2.,
+					_ => temperatures[count / 2],
+				}
+			},
+			FusionMode::Max => temperatures.iter().copied().fold(f32::MIN, f32::max),
+			FusionMode::Weighted(weights) => {
+				let (weighted_sum, weights_sum) =
+					readings
+						.iter(self.sensors)
+						.fold((0., 0.), |(weighted_sum, weights_sum), (sensor, temperature)| {
+							let weight = weights.get(sensor);
+							(weighted_sum + temperature.as_kelvin() * weight, weights_sum + weight)
+						});
+				if weights_sum <= 0. {
+					return None;
+				}
+				weighted_sum / weights_sum
+			},
+		};
+
+		Some(Temperature::from_kelvin(fused_temperature))
+	}
+
+	/// Returns the difference in degrees between the highest and the lowest of the `readings` of the
+	/// [`Self::sensors`] (`0` if less than 2 of them have been read).
+	pub fn get_disagreement(&self, readings: &SensorReadings) -> f32 {
+		let (min, max) = readings
+			.iter(self.sensors)
+			.map(|(_, temperature)| temperature.as_kelvin())
+			.fold((f32::MAX, f32::MIN), |(min, max), temperature| {
+				(min.min(temperature), max.max(temperature))
+			});
+
+		match min <= max {
+			true => max - min,
+			false => 0.,
+		}
+	}
+
+	/// Returns `true` if the [`disagreement`] of the `readings` is more than [`Self::max_disagreement`].
+	///
+	/// [`disagreement`]: `Self::get_disagreement`
+	pub fn do_sensors_disagree(&self, readings: &SensorReadings) -> bool {
+		self.get_disagreement(readings) > self.max_disagreement
+	}
+}
+
+/// The sensors that measure the temperature of the hot plate.
+pub struct TemperatureSensors<A: Adc, P1: AdcPin<A>, P2: AdcPin<A>, PB: AdcPin<A>, IR: I2c> {
+	pub plate_thermistor_1: Thermistor<A, P1>,
+	pub plate_thermistor_2: Thermistor<A, P2>,
+	pub board_thermistor: Thermistor<A, PB>,
+	/// The infrared thermometer is optional: it's `None` if it isn't mounted.
+	pub infrared_thermometer: Option<Mlx90614<IR>>,
+}
+
+impl<A: Adc, P1: AdcPin<A>, P2: AdcPin<A>, PB: AdcPin<A>, IR: I2c> TemperatureSensors<A, P1, P2, PB, IR> {
+	/// Reads the temperature of the [`sensors`] and of the [`monitored sensors`] of the provided `fusion`.
+	///
+	/// Returns `Err(TemperatureSensor)` with the first of the [`sensors`] it has been impossible to read (or that
+	/// isn't mounted), while the [`monitored sensors`] that can't be read are just left without a reading.
+	///
+	/// [`sensors`]: `SensorFusionConfig::sensors`
+	/// [`monitored sensors`]: `SensorFusionConfig::monitored_sensors`
+	pub fn read(&mut self, adc: &mut A, fusion: &SensorFusionConfig) -> Result<SensorReadings, TemperatureSensor> {
+		let mut readings = SensorReadings::new();
+		for sensor in fusion.sensors {
+			readings.set(sensor, Some(self.read_sensor(adc, sensor).ok_or(sensor)?));
+		}
+		for sensor in fusion.monitored_sensors - fusion.sensors {
+			readings.set(sensor, self.read_sensor(adc, sensor));
+		}
+
+		Ok(readings)
+	}
+
+	/// Reads the temperature of the provided `sensor`, returning `None` if it's impossible.
+	pub fn read_sensor(&mut self, adc: &mut A, sensor: TemperatureSensor) -> Option<Temperature> {
+		match sensor {
+			TemperatureSensor::PlateThermistor1 => self.plate_thermistor_1.read_temperature(adc).ok(),
+			TemperatureSensor::PlateThermistor2 => self.plate_thermistor_2.read_temperature(adc).ok(),
+			TemperatureSensor::BoardThermistor => self.board_thermistor.read_temperature(adc).ok(),
+			TemperatureSensor::InfraredThermometer => self
+				.infrared_thermometer
+				.as_mut()
+				.and_then(|infrared_thermometer| infrared_thermometer.read_object_temperature().ok()),
+		}
+	}
+}
@@ -0,0 +1,141 @@
+use super::TemperaturePidGains;
+use crate::utils::{math, measurement::temperature::Temperature};
+
+/// The maximum number of entries of a [`GainSchedule`].
+pub const GAIN_SCHEDULE_CAPACITY: usize = 8;
+
+/// A table of [`PidGains`], each one tuned for a specific temperature, used to change the gains of the PID based on
+/// the temperature the plate should be at (the dynamics of the plate change a lot with the temperature, for example
+/// because the heat lost by radiation grows quickly).
+///
+/// Between 2 entries the gains are linearly interpolated, while below the first entry (or above the last one) the
+/// gains of the first (or last) entry are used.
+///
+/// You can fill it by hand, or let the [`autotune`] add an entry at its target temperature each time it completes.
+///
+/// # Examples
+/// ```
+/// # use firmware_core::{hot_plate::temperature::{gain_schedule::*, TemperaturePidGains}, utils::measurement::temperature::Temperature};
+/// #
+/// let mut gain_schedule = GainSchedule::new();
+/// assert_eq!(gain_schedule.get_gains_at(Temperature::from_celsius(100.)), None);
+///
+/// gain_schedule.insert(Temperature::from_celsius(200.), TemperaturePidGains { p: 20., i: 2., d: 10. }).unwrap();
+/// gain_schedule.insert(Temperature::from_celsius(100.), TemperaturePidGains { p: 10., i: 1., d: 10. }).unwrap();
+///
+/// assert_eq!(gain_schedule.get_gains_at(Temperature::from_celsius(50.)), Some(TemperaturePidGains { p: 10., i: 1., d: 10. }));
+/// assert_eq!(gain_schedule.get_gains_at(Temperature::from_celsius(150.)), Some(TemperaturePidGains { p: 15., i: 1.5, d: 10. }));
+/// assert_eq!(gain_schedule.get_gains_at(Temperature::from_celsius(250.)), Some(TemperaturePidGains { p: 20., i: 2., d: 10. }));
+/// ```
+///
+/// [`PidGains`]: `TemperaturePidGains`
+/// [`autotune`]: `super::autotune`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GainSchedule {
+	/// The entries sorted by temperature. Only the first `len` are valid.
+	entries: [(Temperature, TemperaturePidGains); GAIN_SCHEDULE_CAPACITY],
+	len: usize,
+}
+
+impl GainSchedule {
+	/// Returns an empty [`GainSchedule`].
+	pub const fn new() -> Self {
+		Self {
+			entries: [(
+				Temperature::from_kelvin(0.),
+				TemperaturePidGains { p: 0., i: 0., d: 0. },
+			); GAIN_SCHEDULE_CAPACITY],
+			len: 0,
+		}
+	}
+
+	/// Returns a [`GainSchedule`] with the provided entries (in any order).
+	pub fn from_entries(entries: &[(Temperature, TemperaturePidGains)]) -> Result<Self, GainScheduleFull> {
+		let mut gain_schedule = Self::new();
+		for &(temperature, gains) in entries {
+			gain_schedule.insert(temperature, gains)?;
+		}
+
+		Ok(gain_schedule)
+	}
+
+	/// Returns the entries of the schedule sorted by temperature.
+	pub fn get_entries(&self) -> &[(Temperature, TemperaturePidGains)] {
+		&self.entries[..self.len]
+	}
+
+	/// Returns `true` if the schedule has no entries.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Adds an entry with the provided `gains` at the provided `temperature`, replacing the existing entry at the same
+	/// temperature if there's one.
+	///
+	/// Returns `Err(GainScheduleFull)` if the schedule already has [`GAIN_SCHEDULE_CAPACITY`] entries.
+	pub fn insert(&mut self, temperature: Temperature, gains: TemperaturePidGains) -> Result<(), GainScheduleFull> {
+		let index = self
+			.get_entries()
+			.iter()
+			.position(|&(entry_temperature, _)| entry_temperature >= temperature)
+			.unwrap_or(self.len);
+
+		if index < self.len && self.entries[index].0 == temperature {
+			self.entries[index].1 = gains;
+			return Ok(());
+		}
+		if self.len == GAIN_SCHEDULE_CAPACITY {
+			return Err(GainScheduleFull);
+		}
+
+		self.entries.copy_within(index..self.len, index + 1);
+		self.entries[index] = (temperature, gains);
+		self.len += 1;
+
+		Ok(())
+	}
+
+	/// Removes all the entries of the schedule.
+	pub fn clear(&mut self) {
+		self.len = 0;
+	}
+
+	/// Returns the gains to use at the provided `temperature`, or `None` if the schedule is empty.
+	pub fn get_gains_at(&self, temperature: Temperature) -> Option<TemperaturePidGains> {
+		let entries = self.get_entries();
+		let (first, last) = (entries.first()?, entries.last()?);
+		if temperature <= first.0 {
+			return Some(first.1);
+		}
+		if temperature >= last.0 {
+			return Some(last.1);
+		}
+
+		let next_index = entries
+			.iter()
+			.position(|&(entry_temperature, _)| entry_temperature >= temperature)?;
+		let (start_temperature, start_gains) = entries[next_index - 1];
+		let (end_temperature, end_gains) = entries[next_index];
+
+		let progress = math::map(
+			temperature.as_kelvin(),
+			start_temperature.as_kelvin()..=end_temperature.as_kelvin(),
+			0_f32..=1_f32,
+		);
+		Some(TemperaturePidGains {
+			p: math::lerp(progress, start_gains.p..=end_gains.p),
+			i: math::lerp(progress, start_gains.i..=end_gains.i),
+			d: math::lerp(progress, start_gains.d..=end_gains.d),
+		})
+	}
+}
+
+impl Default for GainSchedule {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// A [`GainSchedule`] can't have more than [`GAIN_SCHEDULE_CAPACITY`] entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GainScheduleFull;
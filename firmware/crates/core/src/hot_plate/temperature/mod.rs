@@ -1,6 +1,12 @@
+pub mod autotune;
+pub mod control;
+pub mod feed_forward;
+pub mod fusion;
+pub mod gain_schedule;
 mod pid;
 pub mod safety;
 
 pub use pid::{
-	PidController as TemperaturePidController, PidGains as TemperaturePidGains, TickError as PidUpdateError,
+	PidController as TemperaturePidController, PidGains as TemperaturePidGains, ReadTemperatureError,
+	TickError as PidUpdateError,
 };
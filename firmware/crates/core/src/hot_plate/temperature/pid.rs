@@ -1,12 +1,21 @@
+use core::time::Duration;
+
+use embedded_hal::i2c::I2c;
 use enumset::EnumSet;
-use pid::Pid;
 
-use super::safety::{self, TemperatureSafety};
+use super::{
+	autotune::{Autotune, AutotuneError},
+	control::{PidControl, PidControlConfig},
+	feed_forward::FeedForwardConfig,
+	fusion::{SensorFusionConfig, SensorReadings, TemperatureSensor, TemperatureSensors},
+	gain_schedule::GainSchedule,
+	safety::{self, TemperatureSafety},
+};
 use crate::{
 	hot_plate::{
-		drivers::{cartridge_heater::CartridgeHeater, thermistor::Thermistor},
+		drivers::cartridge_heater::CartridgeHeater,
 		hal::{
-			adc::{Adc, AdcPin, ReadPercentageError},
+			adc::{Adc, AdcPin},
 			pwm::PwmPin,
 		},
 	},
@@ -25,72 +34,158 @@ use crate::{
 /// [`create`]: `Self::new`
 /// [`choose the target temperature`]: `Self::set_target_temperature`
 /// [`tick`]: `Self::tick`
-pub struct PidController<CHP: PwmPin, TADC: Adc, TP: AdcPin<TADC>> {
-	thermistor: Thermistor<TADC, TP>,
+pub struct PidController<CHP: PwmPin, TADC: Adc, T1P: AdcPin<TADC>, T2P: AdcPin<TADC>, BTP: AdcPin<TADC>, IR: I2c> {
+	sensors: TemperatureSensors<TADC, T1P, T2P, BTP, IR>,
+	sensor_fusion: SensorFusionConfig,
 	cartridge_heater: CartridgeHeater<CHP>,
-	pid_control: Pid<f32>,
+	pid_control: PidControl,
+	pid_gains: PidGains,
+	gain_schedule: GainSchedule,
 	safety: TemperatureSafety,
+	feed_forward: Option<FeedForwardConfig>,
+	target_temperature_slope: f32,
 
 	last_current_temperature_sample: Option<Temperature>,
+	last_sensor_readings: SensorReadings,
 }
 
-impl<CHP: PwmPin, TADC: Adc, TP: AdcPin<TADC>> PidController<CHP, TADC, TP> {
+impl<CHP: PwmPin, TADC: Adc, T1P: AdcPin<TADC>, T2P: AdcPin<TADC>, BTP: AdcPin<TADC>, IR: I2c>
+	PidController<CHP, TADC, T1P, T2P, BTP, IR>
+{
 	/// The minimum limit output by the PID control. Take this in consideration when setting the `PidGains`.
-	pub const PID_CONTROL_MIN_LIMIT: f32 = 0.;
+	pub const PID_CONTROL_MIN_LIMIT: f32 = PidControl::MIN_OUTPUT;
 	/// The maximum limit output by the PID control. Take this in consideration when setting the `PidGains`.
-	pub const PID_CONTROL_MAX_LIMIT: f32 = 100.;
+	pub const PID_CONTROL_MAX_LIMIT: f32 = PidControl::MAX_OUTPUT;
 
 	/// Returns a [`PidController`] that will control the `cartridge heater`'s current based on the [`set target temperature`]
-	/// and the [`current temperature`] read in the provided `thermistor` using the provided gains.
+	/// and the [`current temperature`] read from the provided `sensors` (combined using the provided `sensor_fusion`)
+	/// using the provided gains.
 	///
 	/// [`set target temperature`]: `Self::set_target_temperature`
 	/// [`current temperature`]: `Self::get_current_temperature`
 	pub fn new(
-		thermistor: Thermistor<TADC, TP>, cartridge_heater: CartridgeHeater<CHP>, pid_gains: PidGains,
-		safety: TemperatureSafety,
+		sensors: TemperatureSensors<TADC, T1P, T2P, BTP, IR>, sensor_fusion: SensorFusionConfig,
+		cartridge_heater: CartridgeHeater<CHP>, pid_gains: PidGains, safety: TemperatureSafety,
 	) -> Self {
-		let mut pid_control = Pid::new(0., Self::PID_CONTROL_MAX_LIMIT);
-		pid_control.p(pid_gains.p, Self::PID_CONTROL_MAX_LIMIT);
-		pid_control.i(pid_gains.i, Self::PID_CONTROL_MAX_LIMIT);
-		pid_control.d(pid_gains.d, Self::PID_CONTROL_MAX_LIMIT);
-
 		Self {
-			thermistor,
+			sensors,
+			sensor_fusion,
 			cartridge_heater,
-			pid_control,
+			pid_control: PidControl::new(pid_gains, PidControlConfig::default()),
+			pid_gains,
+			gain_schedule: GainSchedule::new(),
 			safety,
+			feed_forward: None,
+			target_temperature_slope: 0.,
 			last_current_temperature_sample: None,
+			last_sensor_readings: SensorReadings::new(),
 		}
 	}
 
 	/// Returns the [`PidGains`] previously set on this PID controller.
 	pub fn get_pid_gains(&self) -> PidGains {
-		PidGains {
-			p: self.pid_control.kp,
-			i: self.pid_control.ki,
-			d: self.pid_control.kd,
-		}
+		self.pid_gains
 	}
 
 	/// Set the PID gains of this controller. Check [`Self::PID_CONTROL_MIN_LIMIT`] and [`Self::PID_CONTROL_MAX_LIMIT`] to see in what
 	/// range the values should be.
+	///
+	/// They are used only if the [`gain schedule`] is empty.
+	///
+	/// [`gain schedule`]: `Self::get_gain_schedule`
 	pub fn set_pid_gains(&mut self, pid_gains: &PidGains) {
-		self.pid_control.kp = pid_gains.p;
-		self.pid_control.ki = pid_gains.i;
-		self.pid_control.kd = pid_gains.d;
+		self.pid_gains = *pid_gains;
+		self.pid_control.set_gains(*pid_gains);
 	}
 
-	/// Reads the current [`Temperature`] of the PID controller.
+	/// Returns the [`PidGains`] the PID control is using right now: the ones of the [`gain schedule`] at the
+	/// [`target temperature`], or the ones [`set`] on the controller if the schedule is empty.
 	///
-	/// Returns `Ok(Temperature)` if the read was succesful, otherwise `Err(ReadPercentageError)`.
-	pub fn get_current_temperature(&mut self, adc: &mut TADC) -> Result<Temperature, ReadPercentageError<TADC, TP>> {
-		match self.thermistor.read_temperature(adc) {
-			Ok(temperature) => {
-				self.last_current_temperature_sample = Some(temperature);
-				Ok(temperature)
-			},
-			Err(error) => Err(error),
-		}
+	/// [`gain schedule`]: `Self::get_gain_schedule`
+	/// [`target temperature`]: `Self::get_target_temperature`
+	/// [`set`]: `Self::set_pid_gains`
+	pub fn get_current_pid_gains(&self) -> PidGains {
+		self.gain_schedule
+			.get_gains_at(self.get_target_temperature())
+			.unwrap_or(self.pid_gains)
+	}
+
+	/// Returns the [`GainSchedule`] used to choose the gains based on the target temperature.
+	pub fn get_gain_schedule(&self) -> &GainSchedule {
+		&self.gain_schedule
+	}
+
+	/// Returns the [`GainSchedule`] used to choose the gains based on the target temperature, so that you can add
+	/// entries to it.
+	pub fn get_gain_schedule_mut(&mut self) -> &mut GainSchedule {
+		&mut self.gain_schedule
+	}
+
+	/// Sets the [`GainSchedule`] used to choose the gains based on the target temperature. If it's empty, the gains
+	/// [`set`] on the controller are used.
+	///
+	/// [`set`]: `Self::set_pid_gains`
+	pub fn set_gain_schedule(&mut self, gain_schedule: GainSchedule) {
+		self.gain_schedule = gain_schedule;
+	}
+
+	/// Returns the [`PidControlConfig`] of the PID control (its output limits, anti-windup and derivative options).
+	pub fn get_pid_control_config(&self) -> &PidControlConfig {
+		self.pid_control.get_config()
+	}
+
+	/// Sets the [`PidControlConfig`] of the PID control (its output limits, anti-windup and derivative options).
+	pub fn set_pid_control_config(&mut self, config: PidControlConfig) {
+		self.pid_control.set_config(config);
+	}
+
+	/// Returns the model of the plate used to compute the feed-forward term of the output, or `None` if the output
+	/// only depends on the PID control.
+	pub fn get_feed_forward(&self) -> Option<FeedForwardConfig> {
+		self.feed_forward
+	}
+
+	/// Sets the model of the plate used to compute the feed-forward term that is added to the output of the PID
+	/// control (check [`FeedForwardConfig`]), or disables it if `feed_forward` is `None`.
+	pub fn set_feed_forward(&mut self, feed_forward: Option<FeedForwardConfig>) {
+		self.feed_forward = feed_forward;
+	}
+
+	/// Returns the [`SensorFusionConfig`] used to combine the readings of the sensors in the current temperature.
+	pub fn get_sensor_fusion(&self) -> SensorFusionConfig {
+		self.sensor_fusion
+	}
+
+	/// Sets the [`SensorFusionConfig`] used to combine the readings of the sensors in the current temperature.
+	pub fn set_sensor_fusion(&mut self, sensor_fusion: SensorFusionConfig) {
+		self.sensor_fusion = sensor_fusion;
+	}
+
+	/// Reads the current [`Temperature`] of the PID controller, combining the readings of the sensors as specified by
+	/// the [`sensor fusion`].
+	///
+	/// Returns `Ok(Temperature)` if the read was succesful, otherwise `Err(ReadTemperatureError)`.
+	///
+	/// [`sensor fusion`]: `Self::get_sensor_fusion`
+	pub fn get_current_temperature(&mut self, adc: &mut TADC) -> Result<Temperature, ReadTemperatureError> {
+		let readings = self
+			.sensors
+			.read(adc, &self.sensor_fusion)
+			.map_err(ReadTemperatureError::CantReadSensor)?;
+		self.last_sensor_readings = readings;
+
+		let temperature = self
+			.sensor_fusion
+			.fuse(&readings)
+			.ok_or(ReadTemperatureError::NoReadingToFuse)?;
+		self.last_current_temperature_sample = Some(temperature);
+
+		Ok(temperature)
+	}
+
+	/// Gets the readings of the single sensors the last time [`Self::get_current_temperature`] has been successfull.
+	pub fn get_last_sensor_readings(&self) -> &SensorReadings {
+		&self.last_sensor_readings
 	}
 
 	/// Gets the [`Temperature`] read using [`Self::get_current_temperature`] the last time you called that function.
@@ -103,7 +198,7 @@ impl<CHP: PwmPin, TADC: Adc, TP: AdcPin<TADC>> PidController<CHP, TADC, TP> {
 
 	/// Returns the [`Temperature`] the PID controller is trying to reach.
 	pub fn get_target_temperature(&self) -> Temperature {
-		Temperature::from_kelvin(self.pid_control.setpoint)
+		Temperature::from_kelvin(self.pid_control.get_setpoint())
 	}
 
 	/// Sets the [`Temperature`] the PID controller will try to reach.
@@ -111,40 +206,129 @@ impl<CHP: PwmPin, TADC: Adc, TP: AdcPin<TADC>> PidController<CHP, TADC, TP> {
 	/// # Warning
 	/// You need to call [`PidController::tick`] after this to effectively make the PID controller work to reach it.
 	pub fn set_target_temperature(&mut self, target_temperature: Temperature) {
-		self.pid_control.setpoint(target_temperature.as_kelvin() as f32);
+		self.pid_control.set_setpoint(target_temperature.as_kelvin());
 	}
 
-	/// Make the PID controller work to try to reach its [`target temperature`].
+	/// Sets the rate in degrees per second at which the [`target temperature`] is going to change, which is used by
+	/// the [`feed-forward`] term.
 	///
 	/// [`target temperature`]: `Self::get_target_temperature`
-	pub fn tick(&mut self, delta_time: f32, adc: &mut TADC) -> Result<(), TickError> {
+	/// [`feed-forward`]: `Self::set_feed_forward`
+	pub fn set_target_temperature_slope(&mut self, target_temperature_slope: f32) {
+		self.target_temperature_slope = target_temperature_slope;
+	}
+
+	/// Turns off the cartridge heater, and resets the state of the PID control so that it starts from scratch the next
+	/// time you [`tick`] it.
+	///
+	/// [`tick`]: `Self::tick`
+	pub fn turn_off_heater(&mut self) -> Result<(), TickError> {
+		self.pid_control.reset();
+
+		self.cartridge_heater
+			.set_heat_percentage(Percentage::ZERO)
+			.map_err(|_| TickError::SetCartridgeHeaterPercentage)
+	}
+
+	/// Drives the heater using the provided [`Autotune`] instead of the PID control, while still checking that the
+	/// temperature is safe (using the [`autotune's target temperature`] as target temperature).
+	///
+	/// You must continually call this until the [`autotune has finished`], and then you can apply its result to this
+	/// controller using [`Self::set_pid_gains`] or by adding it to the [`gain schedule`]. Once it has finished, the
+	/// heater is turned off.
+	///
+	/// [`autotune's target temperature`]: `super::autotune::AutotuneConfig::target_temperature`
+	/// [`autotune has finished`]: `Autotune::is_finished`
+	/// [`gain schedule`]: `Self::get_gain_schedule_mut`
+	pub fn tick_autotune(
+		&mut self, autotune: &mut Autotune, delta_time: f32, adc: &mut TADC,
+	) -> Result<(), AutotuneError> {
+		self.set_target_temperature(autotune.get_config().target_temperature);
+
 		let current_temperature = self
-			.get_current_temperature(adc)
-			.map_err(|_| TickError::CantReadTemperature)?;
+			.read_safe_temperature(delta_time, adc)
+			.map_err(AutotuneError::TemperatureControl)?;
+		let heat_percentage = autotune.update(current_temperature, delta_time)?;
 
-		let safety_errors =
-			self.safety
-				.is_temperature_safe(current_temperature, self.get_target_temperature(), delta_time);
-		if !safety_errors.is_empty() {
-			return Err(TickError::ReadTemperatureIsWrong(safety_errors));
+		if autotune.is_finished() {
+			return self.turn_off_heater().map_err(AutotuneError::TemperatureControl);
 		}
 
-		let mut pwm_value = self
+		self.cartridge_heater
+			.set_heat_percentage(heat_percentage)
+			.map_err(|_| AutotuneError::TemperatureControl(TickError::SetCartridgeHeaterPercentage))
+	}
+
+	/// Make the PID controller work to try to reach its [`target temperature`].
+	///
+	/// [`target temperature`]: `Self::get_target_temperature`
+	pub fn tick(&mut self, delta_time: f32, adc: &mut TADC) -> Result<(), TickError> {
+		let current_temperature = self.read_safe_temperature(delta_time, adc)?;
+
+		let feed_forward = self
+			.feed_forward
+			.map(|feed_forward| feed_forward.get_output(self.get_target_temperature(), self.target_temperature_slope))
+			.unwrap_or(0.);
+		self.pid_control.set_gains(self.get_current_pid_gains());
+		let pwm_value = self
 			.pid_control
-			.next_control_output(current_temperature.as_kelvin() as f32)
-			.output;
-		pwm_value = math::map(
+			.update(current_temperature.as_kelvin(), feed_forward, delta_time);
+		let pwm_value = math::map(
 			pwm_value,
 			Self::PID_CONTROL_MIN_LIMIT..=Self::PID_CONTROL_MAX_LIMIT,
 			0_f32..=1_f32,
 		);
 
 		self.cartridge_heater
-			.set_heat_percentage(Percentage::from_0_to_1(pwm_value as f32).unwrap())
+			.set_heat_percentage(Percentage::from_0_to_1(pwm_value).unwrap())
 			.map_err(|_| TickError::SetCartridgeHeaterPercentage)?;
 
 		Ok(())
 	}
+
+	/// Keeps the cartridge heater turned off while still reading the [`current temperature`] and checking that it's in
+	/// the allowed range and that the sensors agree.
+	///
+	/// You must continually call this instead of [`tick`] while there isn't a temperature to reach.
+	///
+	/// [`current temperature`]: `Self::get_last_sample_of_current_temperature`
+	/// [`tick`]: `Self::tick`
+	pub fn tick_heater_off(&mut self, adc: &mut TADC) -> Result<(), TickError> {
+		self.turn_off_heater()?;
+
+		let current_temperature = self
+			.get_current_temperature(adc)
+			.map_err(TickError::CantReadTemperature)?;
+
+		let mut safety_errors = self.safety.is_temperature_safe_without_target(current_temperature);
+		if self.sensor_fusion.do_sensors_disagree(&self.last_sensor_readings) {
+			safety_errors.insert(safety::TemperatureError::SensorsDisagree);
+		}
+		if !safety_errors.is_empty() {
+			return Err(TickError::ReadTemperatureIsWrong(safety_errors));
+		}
+
+		Ok(())
+	}
+
+	/// Reads the current temperature and checks that it's safe compared to the target temperature.
+	fn read_safe_temperature(&mut self, delta_time: f32, adc: &mut TADC) -> Result<Temperature, TickError> {
+		let current_temperature = self
+			.get_current_temperature(adc)
+			.map_err(TickError::CantReadTemperature)?;
+
+		let mut safety_errors =
+			self.safety
+				.is_temperature_safe(current_temperature, self.get_target_temperature(), delta_time);
+		if self.sensor_fusion.do_sensors_disagree(&self.last_sensor_readings) {
+			safety_errors.insert(safety::TemperatureError::SensorsDisagree);
+		}
+		if !safety_errors.is_empty() {
+			return Err(TickError::ReadTemperatureIsWrong(safety_errors));
+		}
+
+		Ok(current_temperature)
+	}
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -152,19 +336,19 @@ impl<CHP: PwmPin, TADC: Adc, TP: AdcPin<TADC>> PidController<CHP, TADC, TP> {
 ///
 /// [`tick`]: PidController::tick
 pub enum TickError {
-	/// It has been impossible to [`read`] the thermistor's temperature.
+	/// It has been impossible to [`read`] the current temperature.
 	///
-	/// [`read`]: `Thermistor::read_temperature`
-	CantReadTemperature,
+	/// [`read`]: `PidController::get_current_temperature`
+	CantReadTemperature(ReadTemperatureError),
 
-	/// The thermistor's `temperature` has been [`read`], but it's an irregular value.
+	/// The current `temperature` has been [`read`], but it's an irregular value.
 	///
 	/// **It could be that the thermistor is damaged, or its connection to the microcontroller is damaged...**
 	/// It could also be a false positive: but it's always better to abort the print and turn off the heaters
 	/// to prevent fire hazards. Then if it was a false positive, it means that the parameters passed
 	/// to [`Safety::new`] are too strict.
 	///
-	/// [`read`]: `Thermistor::read_temperature`
+	/// [`read`]: `PidController::get_current_temperature`
 	/// [`Safety::new`]: `safety::TemperatureSafety::new`
 	ReadTemperatureIsWrong(EnumSet<safety::TemperatureError>),
 
@@ -174,8 +358,22 @@ pub enum TickError {
 	SetCartridgeHeaterPercentage,
 }
 
+/// An error that occurred when calling [`get_current_temperature`] on a PID controller.
+///
+/// [`get_current_temperature`]: PidController::get_current_temperature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadTemperatureError {
+	/// It has been impossible to read the temperature of this sensor.
+	CantReadSensor(TemperatureSensor),
+	/// No sensor has been read (or their weights sum up to `0`), so there's no temperature to combine.
+	NoReadingToFuse,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// Values of the `proportional`, `integral` and `derivative` gains of a PID controller.
+///
+/// The integral gain is applied to the integral of the error over seconds, while the derivative gain is applied to the
+/// rate of change per second (check [`PidControl`]).
 pub struct PidGains {
 	/// [`Proportial component`](https://en.wikipedia.org/wiki/Proportional%E2%80%93integral%E2%80%93derivative_controller#Proportional).
 	pub p: f32,
@@ -184,3 +382,25 @@ pub struct PidGains {
 	/// [`Derivative component`](https://en.wikipedia.org/wiki/Proportional%E2%80%93integral%E2%80%93derivative_controller#Derivative).
 	pub d: f32,
 }
+
+impl PidGains {
+	/// Returns the gains that behave like the provided per-tick gains (where the integral gain is applied to the sum of
+	/// the errors and the derivative gain to the change of the error since the previous tick) when ticking every
+	/// `tick_period`.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::hot_plate::temperature::TemperaturePidGains;
+	/// # use core::time::Duration;
+	/// #
+	/// let gains = TemperaturePidGains::from_per_tick(20., 2., 50., Duration::from_millis(100));
+	/// assert_eq!(gains.p, 20.);
+	/// assert!((gains.i - 20.).abs() < 0.001);
+	/// assert!((gains.d - 5.).abs() < 0.001);
+	/// ```
+	pub fn from_per_tick(p: f32, i: f32, d: f32, tick_period: Duration) -> Self {
+		let tick_period = tick_period.as_secs_f32();
+
+		Self { p, i: i / tick_period, d: d * tick_period }
+	}
+}
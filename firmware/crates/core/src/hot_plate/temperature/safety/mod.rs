@@ -63,6 +63,28 @@ impl TemperatureSafety {
 
 		errors
 	}
+
+	/// Returns a set of all the errors that happened while there's no target temperature (for example because the
+	/// heater is turned off), so only the [`current temperature`] is checked. If no error has happened the set is empty.
+	///
+	/// The checks that follow the target temperature start from scratch the next time you call
+	/// [`Self::is_temperature_safe`].
+	///
+	/// [`current temperature`]: `TemperatureError::CurrentTemperatureOutsideAllowedRange`
+	pub fn is_temperature_safe_without_target(
+		&mut self, current_temperature: Temperature,
+	) -> EnumSet<TemperatureError> {
+		let mut errors = EnumSet::empty();
+
+		if !self.allowed_temperature_range.is_temperature_safe(current_temperature) {
+			errors.insert(TemperatureError::CurrentTemperatureOutsideAllowedRange);
+		}
+
+		self.keep_target_temperature.reset();
+		self.rise_to_target_temperature.reset();
+
+		errors
+	}
 }
 
 #[derive(enumset::EnumSetType, Debug, Hash)]
@@ -89,4 +111,10 @@ pub enum TemperatureError {
 	///
 	/// [`this`]: temperature_change::modes::RisingMode
 	CantRiseFastEnoughToTargetTemperature,
+
+	/// The sensors that measure the temperature of the plate read temperatures that differ more than the
+	/// [`max disagreement`] (it could be that one of them isn't attached to the plate anymore).
+	///
+	/// [`max disagreement`]: `crate::hot_plate::temperature::fusion::SensorFusionConfig::max_disagreement`
+	SensorsDisagree,
 }
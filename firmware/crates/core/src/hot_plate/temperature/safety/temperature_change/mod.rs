@@ -75,6 +75,12 @@ impl<ProtectionMode: ProtectionModeTrait> TemperatureChangeSafety<ProtectionMode
 		true
 	}
 
+	/// Forgets the last target temperature, so that the next call to [`Self::is_temperature_safe`] starts from scratch.
+	pub fn reset(&mut self) {
+		self.last_target_temperature = None;
+		self.stop_timer();
+	}
+
 	fn stop_timer(&mut self) {
 		self.current_timer_in_seconds = None;
 	}
@@ -76,3 +76,20 @@ impl core::fmt::Debug for Percentage {
 		write!(f, "{}%", self.0 * 100.)
 	}
 }
+
+/// Shows the percentage from `0%` to `100%`, with the number of decimals of the precision of the formatter (`0` by
+/// default).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::utils::math::Percentage;
+/// #
+/// let percentage = Percentage::from_0_to_1(0.4567).unwrap();
+/// assert_eq!(format!("{percentage}"), "46%");
+/// assert_eq!(format!("{percentage:.1}"), "45.7%");
+/// ```
+impl core::fmt::Display for Percentage {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{:.*}%", f.precision().unwrap_or(0), self.into_0_to_100())
+	}
+}
@@ -1,5 +1,11 @@
-#[derive(Clone, Copy)]
-/// A color value in [`RGB565`] format.
+use embedded_graphics_core::pixelcolor::{raw::RawU16, IntoStorage, Rgb565};
+use micromath::F32Ext;
+
+use super::temperature::Temperature;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A color value in [`RGB565`] format: 5 bits of red in the most significant bits, 6 bits of green and 5 bits of blue
+/// in the least significant bits.
 ///
 /// RGB565: https://en.wikipedia.org/wiki/List_of_monochrome_and_RGB_color_formats#16-bit_RGB_(also_known_as_RGB565)
 pub struct ColorRGB565(u16);
@@ -7,11 +13,216 @@ pub struct ColorRGB565(u16);
 impl ColorRGB565 {
 	/// The white color represented as RGB565.
 	pub const WHITE: Self = Self(u16::MAX);
+	pub const BLACK: Self = Self(0);
+	pub const GRAY: Self = Self(0x8410);
+	pub const LIGHT_GRAY: Self = Self(0xC618);
+	pub const DARK_GRAY: Self = Self(0x4208);
+	pub const RED: Self = Self(0xF800);
+	pub const GREEN: Self = Self(0x07E0);
+	pub const BLUE: Self = Self(0x001F);
+	pub const YELLOW: Self = Self(0xFFE0);
+	pub const CYAN: Self = Self(0x07FF);
+	pub const MAGENTA: Self = Self(0xF81F);
+	pub const ORANGE: Self = Self(0xFD20);
+	pub const MAROON: Self = Self(0x7800);
+	pub const OLIVE: Self = Self(0x7BE0);
+	pub const NAVY: Self = Self(0x000F);
+	pub const DARK_GREEN: Self = Self(0x03E0);
+	pub const PURPLE: Self = Self(0x780F);
+	pub const DARK_CYAN: Self = Self(0x03EF);
+
+	/// The hue in degrees of the color of the coldest temperature of a [`heat map`] (blue).
+	///
+	/// [`heat map`]: `Self::heat_map`
+	const COLD_HUE: f32 = 240.;
+
+	/// Returns the color with the provided channels, which are truncated to 5 bits for `red` and `blue` and to 6 bits
+	/// for `green`.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::new(31, 63, 31), ColorRGB565::WHITE);
+	/// assert_eq!(ColorRGB565::new(0, 0, 15), ColorRGB565::NAVY);
+	/// ```
+	pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+		Self(((red as u16 & 0x1F) << 11) | ((green as u16 & 0x3F) << 5) | (blue as u16 & 0x1F))
+	}
+
+	/// Returns the color closest to the one with the provided 8-bit channels (like the ones of the colors of the web).
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::from_rgb888(255, 165, 0), ColorRGB565::ORANGE);
+	/// assert_eq!(ColorRGB565::from_rgb888(0x80, 0x80, 0x80), ColorRGB565::GRAY);
+	/// assert_eq!(ColorRGB565::from_rgb888(255, 255, 255).to_rgb888(), (255, 255, 255));
+	/// ```
+	pub const fn from_rgb888(red: u8, green: u8, blue: u8) -> Self {
+		Self::new(
+			scale(red, 0xFF, 0x1F),
+			scale(green, 0xFF, 0x3F),
+			scale(blue, 0xFF, 0x1F),
+		)
+	}
+
+	/// Returns the color with the provided `hue` (in degrees, so `0` is red, `120` is green and `240` is blue),
+	/// `saturation` and `value` (which go from `0` to `1`).
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::from_hsv(0., 1., 1.), ColorRGB565::RED);
+	/// assert_eq!(ColorRGB565::from_hsv(300., 1., 1.), ColorRGB565::MAGENTA);
+	/// assert_eq!(ColorRGB565::from_hsv(-240., 1., 1.), ColorRGB565::GREEN);
+	/// assert_eq!(ColorRGB565::from_hsv(180., 0., 1.), ColorRGB565::WHITE);
+	/// assert_eq!(ColorRGB565::from_hsv(60., 1., 0.), ColorRGB565::BLACK);
+	/// ```
+	pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+		let (saturation, value) = (saturation.clamp(0., 1.), value.clamp(0., 1.));
+		let sector = (hue - 360. * F32Ext::floor(hue / 360.)) / 60.;
+
+		let chroma = value * saturation;
+		let second = chroma * (1. - F32Ext::abs(sector % 2. - 1.));
+		let (red, green, blue) = match sector as u8 {
+			0 => (chroma, second, 0.),
+			1 => (second, chroma, 0.),
+			2 => (0., chroma, second),
+			3 => (0., second, chroma),
+			4 => (second, 0., chroma),
+			_ => (chroma, 0., second),
+		};
+		let minimum = value - chroma;
+		let to_channel = |channel: f32| ((channel + minimum) * 255. + 0.5) as u8;
+
+		Self::from_rgb888(to_channel(red), to_channel(green), to_channel(blue))
+	}
+
+	/// Returns the color of the provided `temperature` in a heat map that goes from blue for the temperatures at or
+	/// below `cold`, through cyan, green and yellow, to red for the ones at or above `hot`.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::{color::ColorRGB565, temperature::Temperature};
+	/// #
+	/// let (cold, hot) = (Temperature::from_celsius(25.), Temperature::from_celsius(225.));
+	/// assert_eq!(ColorRGB565::heat_map(Temperature::from_celsius(20.), cold, hot), ColorRGB565::BLUE);
+	/// assert_eq!(ColorRGB565::heat_map(Temperature::from_celsius(125.), cold, hot), ColorRGB565::GREEN);
+	/// assert_eq!(ColorRGB565::heat_map(Temperature::from_celsius(250.), cold, hot), ColorRGB565::RED);
+	/// ```
+	pub fn heat_map(temperature: Temperature, cold: Temperature, hot: Temperature) -> Self {
+		let range = hot.as_celsius() - cold.as_celsius();
+		let ratio = match range > 0. {
+			true => ((temperature.as_celsius() - cold.as_celsius()) / range).clamp(0., 1.),
+			false => (temperature.as_celsius() >= hot.as_celsius()) as u8 as f32,
+		};
+
+		Self::from_hsv((1. - ratio) * Self::COLD_HUE, 1., 1.)
+	}
+
+	/// Returns the 5 bits of the red channel.
+	pub const fn get_red(&self) -> u8 {
+		(self.0 >> 11) as u8
+	}
+
+	/// Returns the 6 bits of the green channel.
+	pub const fn get_green(&self) -> u8 {
+		((self.0 >> 5) & 0x3F) as u8
+	}
+
+	/// Returns the 5 bits of the blue channel.
+	pub const fn get_blue(&self) -> u8 {
+		(self.0 & 0x1F) as u8
+	}
 
-	/// Converts this color to its byte representation and returns it.
-	pub fn as_bytes(&self) -> [u8; 2] {
-		self.0.to_ne_bytes()
+	/// Returns the red, green and blue channels of this color scaled to 8 bits.
+	pub const fn to_rgb888(&self) -> (u8, u8, u8) {
+		(
+			scale(self.get_red(), 0x1F, 0xFF),
+			scale(self.get_green(), 0x3F, 0xFF),
+			scale(self.get_blue(), 0x1F, 0xFF),
+		)
 	}
+
+	/// Returns the color that is `amount` of the way from this color to the `other` one, where `amount` goes from `0`
+	/// (this color) to `1` (the `other` color).
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::BLACK.lerp(ColorRGB565::WHITE, 0.5), ColorRGB565::new(16, 32, 16));
+	/// assert_eq!(ColorRGB565::RED.lerp(ColorRGB565::BLUE, 0.), ColorRGB565::RED);
+	/// assert_eq!(ColorRGB565::RED.lerp(ColorRGB565::BLUE, 2.), ColorRGB565::BLUE);
+	/// ```
+	pub fn lerp(self, other: Self, amount: f32) -> Self {
+		let amount = amount.clamp(0., 1.);
+		let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount + 0.5) as u8;
+
+		Self::new(
+			mix(self.get_red(), other.get_red()),
+			mix(self.get_green(), other.get_green()),
+			mix(self.get_blue(), other.get_blue()),
+		)
+	}
+
+	/// Returns the color of a pixel of this color covered by the `other` color with the provided `opacity`, which goes
+	/// from `0` (transparent) to `u8::MAX` (opaque).
+	///
+	/// Unlike [`Self::lerp`] it doesn't need floating point numbers, so it's faster to call for each pixel.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::BLACK.blend(ColorRGB565::WHITE, u8::MAX), ColorRGB565::WHITE);
+	/// assert_eq!(ColorRGB565::BLACK.blend(ColorRGB565::WHITE, 0), ColorRGB565::BLACK);
+	/// assert_eq!(
+	/// 	ColorRGB565::BLACK.blend(ColorRGB565::WHITE, 128),
+	/// 	ColorRGB565::BLACK.lerp(ColorRGB565::WHITE, 0.5)
+	/// );
+	/// ```
+	pub const fn blend(self, other: Self, opacity: u8) -> Self {
+		Self::new(
+			mix(self.get_red(), other.get_red(), opacity),
+			mix(self.get_green(), other.get_green(), opacity),
+			mix(self.get_blue(), other.get_blue(), opacity),
+		)
+	}
+
+	/// Returns the bytes of this color in the order the ILI9341 expects them, which is big-endian (the byte with the
+	/// red channel first) regardless of the endianness of the CPU.
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// #
+	/// assert_eq!(ColorRGB565::RED.as_bytes(), [0xF8, 0x00]);
+	/// assert_eq!(ColorRGB565::from_bytes(ColorRGB565::ORANGE.as_bytes()), ColorRGB565::ORANGE);
+	/// ```
+	pub const fn as_bytes(&self) -> [u8; 2] {
+		self.0.to_be_bytes()
+	}
+
+	/// Returns the color of the provided bytes in the order the ILI9341 sends them (check [`Self::as_bytes`]).
+	pub const fn from_bytes(bytes: [u8; 2]) -> Self {
+		Self(u16::from_be_bytes(bytes))
+	}
+}
+
+/// Returns the provided `channel` that goes from `0` to `from_max` scaled to go from `0` to `to_max`, rounded.
+const fn scale(channel: u8, from_max: u16, to_max: u16) -> u8 {
+	((channel as u16 * to_max + from_max / 2) / from_max) as u8
+}
+
+/// Returns the `from` channel covered by the `to` channel with the provided `opacity` (check [`ColorRGB565::blend`]).
+const fn mix(from: u8, to: u8, opacity: u8) -> u8 {
+	let transparency = u8::MAX - opacity;
+
+	((from as u16 * transparency as u16 + to as u16 * opacity as u16 + u8::MAX as u16 / 2) / u8::MAX as u16) as u8
 }
 
 impl Default for ColorRGB565 {
@@ -20,3 +231,26 @@ impl Default for ColorRGB565 {
 		Self::WHITE
 	}
 }
+
+impl From<Rgb565> for ColorRGB565 {
+	/// Converts a color of [`embedded-graphics`](embedded_graphics_core) to a [`ColorRGB565`].
+	///
+	/// # Examples
+	/// ```
+	/// # use firmware_core::utils::measurement::color::ColorRGB565;
+	/// # use embedded_graphics_core::pixelcolor::{Rgb565, RgbColor};
+	/// #
+	/// assert_eq!(ColorRGB565::from(Rgb565::YELLOW), ColorRGB565::YELLOW);
+	/// assert_eq!(Rgb565::from(ColorRGB565::BLUE), Rgb565::BLUE);
+	/// assert_eq!(Rgb565::from(ColorRGB565::from(Rgb565::new(3, 40, 17))), Rgb565::new(3, 40, 17));
+	/// ```
+	fn from(color: Rgb565) -> Self {
+		Self(color.into_storage())
+	}
+}
+
+impl From<ColorRGB565> for Rgb565 {
+	fn from(color: ColorRGB565) -> Self {
+		Self::from(RawU16::new(color.0))
+	}
+}
@@ -1,6 +1,7 @@
 use core::{
-	fmt::Debug,
+	fmt::{Debug, Display},
 	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+	time::Duration,
 };
 
 use super::frequency::Frequency;
@@ -259,3 +260,33 @@ impl DivAssign<f32> for SmallDuration {
 		*self = *self / rhs
 	}
 }
+
+/// Shows a [`Duration`] like a clock, as `m:ss` or as `h:mm:ss` if it's at least an hour long (the fractions of a
+/// second are trunked).
+///
+/// The width of the formatter is the minimum number of digits of the minutes (or of the hours).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::utils::measurement::duration::ClockDuration;
+/// # use core::time::Duration;
+/// #
+/// assert_eq!(format!("{}", ClockDuration(Duration::from_secs(155))), "2:35");
+/// assert_eq!(format!("{:2} remaining", ClockDuration(Duration::from_secs(155))), "02:35 remaining");
+/// assert_eq!(format!("{}", ClockDuration(Duration::from_millis(3_725_900))), "1:02:05");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockDuration(pub Duration);
+
+impl Display for ClockDuration {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let seconds = self.0.as_secs();
+		let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+		let width = f.width().unwrap_or(1);
+
+		match hours {
+			0 => write!(f, "{minutes:0width$}:{seconds:02}"),
+			_ => write!(f, "{hours:0width$}:{minutes:02}:{seconds:02}"),
+		}
+	}
+}
@@ -1,5 +1,5 @@
 use core::{
-	fmt::Debug,
+	fmt::{Debug, Display},
 	ops::{Add, Sub},
 };
 
@@ -70,3 +70,20 @@ impl Debug for Temperature {
 		write!(f, "{} °C", self.as_celsius())
 	}
 }
+
+/// Shows the temperature in degrees Celsius, with the number of decimals of the precision of the formatter (`0` by
+/// default).
+///
+/// # Examples
+/// ```
+/// # use firmware_core::utils::measurement::temperature::Temperature;
+/// #
+/// let temperature = Temperature::from_celsius(238.43);
+/// assert_eq!(format!("{temperature}"), "238°C");
+/// assert_eq!(format!("{temperature:.1}"), "238.4°C");
+/// ```
+impl Display for Temperature {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{:.*}°C", f.precision().unwrap_or(0), self.as_celsius())
+	}
+}
@@ -0,0 +1,99 @@
+//! Runs the PID autotune of the firmware on a simulated hot plate, and prints the gains it computes.
+//!
+//! If multiple target temperatures are provided, an autotune is run at each of them one after the other, and the
+//! resulting gain schedule is printed at the end.
+//!
+//! Usage: `cargo run -p simulator --example autotune -- [target temperatures in celsius...]`
+
+use std::time::Duration;
+
+use firmware_core::{
+	hot_plate::{
+		temperature::autotune::{AutotuneConfig, AutotuneState, TuningRule},
+		HotPlate,
+	},
+	utils::{math::Percentage, measurement::temperature::Temperature},
+};
+use simulator::{
+	config, peripherals::SimulatedPeripherals, simulation::Simulation, thermal_model::ThermalModelParameters,
+};
+
+const DEFAULT_TARGET_CELSIUS: f32 = 150.;
+const TICK_PERIOD: Duration = Duration::from_millis(100);
+
+fn main() {
+	let mut targets_celsius: Vec<f32> = std::env::args()
+		.skip(1)
+		.map(|argument| argument.parse().expect("The target temperature must be a number"))
+		.collect();
+	if targets_celsius.is_empty() {
+		targets_celsius.push(DEFAULT_TARGET_CELSIUS);
+	}
+
+	let simulation = Simulation::new(ThermalModelParameters::default());
+	let configuration = config::configuration();
+	let mut hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+
+	for target_celsius in targets_celsius {
+		if !autotune(&simulation, &mut hot_plate, target_celsius) {
+			return;
+		}
+	}
+
+	println!("Gain schedule:");
+	for (temperature, gains) in hot_plate.get_gain_schedule().get_entries() {
+		println!("{:?}: {:?}", temperature, gains);
+	}
+}
+
+/// Runs an autotune at the provided target temperature, returning `false` if it failed.
+fn autotune(simulation: &Simulation, hot_plate: &mut HotPlate<SimulatedPeripherals>, target_celsius: f32) -> bool {
+	hot_plate
+		.start_autotune(
+			AutotuneConfig {
+				target_temperature: Temperature::from_celsius(target_celsius),
+				relay_high: Percentage::FULL,
+				relay_low: Percentage::ZERO,
+				hysteresis: 1.,
+				cycles_count: 5,
+				max_duration_in_seconds: 1_800.,
+			},
+			TuningRule::TyreusLuyben,
+		)
+		.unwrap();
+
+	let mut last_state = None;
+	loop {
+		simulation.advance(TICK_PERIOD);
+		if let Err(error) = hot_plate.tick() {
+			eprintln!("The autotune stopped at {:?}: {:?}", simulation.get_time(), error);
+			return false;
+		}
+
+		let autotune = hot_plate.get_autotune().unwrap();
+		let state = autotune.get_state();
+		if Some(state) != last_state {
+			println!(
+				"{:.1}s: {:?} ({:?}), plate at {:?}",
+				simulation.get_time().as_secs_f32(),
+				state,
+				autotune.get_progress(),
+				simulation.get_plate_temperature()
+			);
+			last_state = Some(state);
+		}
+
+		if let AutotuneState::Finished(result) = state {
+			for rule in [
+				TuningRule::ZieglerNichols,
+				TuningRule::PessenIntegral,
+				TuningRule::SomeOvershoot,
+				TuningRule::NoOvershoot,
+				TuningRule::TyreusLuyben,
+			] {
+				println!("{:?}: {:?}", rule, result.get_gains(rule));
+			}
+			return true;
+		}
+	}
+}
@@ -0,0 +1,224 @@
+//! Measures the traffic the firmware sends to the simulated display to draw some common things (including the
+//! drawables of `embedded-graphics`), and estimates how long it would take to send it over the SPI bus of the real
+//! display.
+//!
+//! Usage: `cargo run -p simulator --example display_benchmark -- [SPI clock in MHz]`
+
+use std::time::Duration;
+
+use embedded_graphics::{
+	mono_font::{ascii::FONT_10X20, MonoTextStyleBuilder},
+	pixelcolor::Rgb565,
+	prelude::*,
+	primitives::{Circle, PrimitiveStyle},
+	text::Text,
+};
+use embedded_hal::digital::OutputPin;
+use firmware_core::{
+	hot_plate::{
+		drivers::ili9341::{Orientation, ILI9341},
+		hal::{display_transfer::DisplayTransfer, system_time::Clock},
+		peripherals::Peripherals,
+		screen::{
+			drawable::{
+				images, special::Colored, Circle as FilledCircle, HorizontalLine, Line, RoundedRectangle, ShapeStyle,
+			},
+			Canvas,
+		},
+		HotPlate,
+	},
+	utils::measurement::color::ColorRGB565,
+};
+use micromath::vector::U16x2;
+use simulator::{
+	config, display::SpiStatistics, peripherals::SimulatedPeripherals, simulation::Simulation,
+	thermal_model::ThermalModelParameters,
+};
+
+const DEFAULT_SPI_CLOCK_IN_MHZ: f32 = 40.;
+const TICK_PERIOD: Duration = Duration::from_millis(100);
+
+fn main() {
+	let spi_clock_in_mhz = std::env::args()
+		.nth(1)
+		.map(|argument| argument.parse().expect("The SPI clock must be a number"))
+		.unwrap_or(DEFAULT_SPI_CLOCK_IN_MHZ);
+
+	println!(
+		"{:<32} {:>12} {:>12} {:>10} {:>10} {:>10}",
+		"", "transactions", "bytes", "commands", "pixels", "time"
+	);
+	let print = |name: &str, statistics: SpiStatistics| {
+		let time_in_millis = statistics.bytes_written as f32 * 8. / (spi_clock_in_mhz * 1_000.);
+		println!(
+			"{:<32} {:>12} {:>12} {:>10} {:>10} {:>8.2}ms",
+			name,
+			statistics.transactions,
+			statistics.bytes_written,
+			statistics.commands,
+			statistics.pixels_written,
+			time_in_millis
+		);
+	};
+
+	let simulation = Simulation::new(ThermalModelParameters::default());
+	let mut peripherals = simulation.peripherals(&config::configuration().pid);
+	let clock = Clock::new(peripherals.take_system_time().unwrap());
+	let mut ili9341 = ILI9341::new(
+		peripherals.take_lcd_dcx_pin().unwrap(),
+		peripherals.take_lcd_reset_pin().unwrap(),
+		peripherals.take_lcd_transfer().unwrap(),
+		&clock,
+		Orientation::Landscape,
+	)
+	.unwrap();
+	let size = ili9341.size();
+
+	print(
+		"Full screen fill",
+		measure(&simulation, || {
+			ili9341
+				.fill_rect(U16x2 { x: 0, y: 0 }, size, ColorRGB565::BLUE)
+				.unwrap()
+		}),
+	);
+	print(
+		"Full screen fill by rows",
+		measure(&simulation, || {
+			for y in 0..size.y {
+				draw_row(&mut ili9341, y, size.x);
+			}
+		}),
+	);
+	print(
+		"Full screen of different pixels",
+		measure(&simulation, || {
+			ili9341
+				.set_window(U16x2 { x: 0, y: 0 }, size - U16x2 { x: 1, y: 1 })
+				.unwrap();
+			let palette = [
+				ColorRGB565::RED,
+				ColorRGB565::GREEN,
+				ColorRGB565::BLUE,
+				ColorRGB565::YELLOW,
+			];
+			let pixels_count = size.x as usize * size.y as usize;
+			ili9341
+				.write_pixels(palette.into_iter().cycle().take(pixels_count))
+				.unwrap();
+		}),
+	);
+	print(
+		"embedded-graphics filled circle",
+		measure(&simulation, || {
+			Circle::new(Point::new(60, 20), 200)
+				.into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+				.draw(&mut ili9341)
+				.unwrap();
+		}),
+	);
+	print(
+		"Filled circle",
+		measure(&simulation, || {
+			let circle = FilledCircle {
+				diameter: 200,
+				style: ShapeStyle::filled(ColorRGB565::RED),
+			};
+			ili9341.draw(U16x2 { x: 60, y: 20 }, &circle).unwrap();
+		}),
+	);
+	print(
+		"Outlined rounded rectangle",
+		measure(&simulation, || {
+			let rectangle = RoundedRectangle {
+				size: U16x2 { x: 145, y: 40 },
+				corner_radius: 8,
+				style: ShapeStyle::outlined(ColorRGB565::GRAY, 2).with_fill(ColorRGB565::BLACK),
+			};
+			ili9341.draw(U16x2 { x: 10, y: 190 }, &rectangle).unwrap();
+		}),
+	);
+	print(
+		"Diagonal line",
+		measure(&simulation, || {
+			let line = Line {
+				start: U16x2 { x: 0, y: 0 },
+				end: U16x2 { x: 319, y: 239 },
+				thickness: 2,
+				color: ColorRGB565::WHITE,
+			};
+			ili9341.draw(U16x2 { x: 0, y: 0 }, &line).unwrap();
+		}),
+	);
+	print(
+		"Compressed logo",
+		measure(&simulation, || {
+			ili9341.draw(U16x2 { x: 61, y: 2 }, &images::LOGO).unwrap();
+		}),
+	);
+	print(
+		"embedded-graphics text",
+		measure(&simulation, || {
+			let style = MonoTextStyleBuilder::new()
+				.font(&FONT_10X20)
+				.text_color(Rgb565::WHITE)
+				.background_color(Rgb565::BLACK)
+				.build();
+			Text::new("Reflow in progress", Point::new(10, 30), style)
+				.draw(&mut ili9341)
+				.unwrap();
+		}),
+	);
+	drop(ili9341);
+	for use_line_buffer in [false, true] {
+		println!();
+		println!(
+			"User interface {} the line buffer",
+			if use_line_buffer { "with" } else { "without" }
+		);
+
+		let simulation = Simulation::new(ThermalModelParameters::default());
+		let mut configuration = config::configuration();
+		configuration.ui.use_line_buffer = use_line_buffer;
+		let mut hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+		print("First draw", tick(&simulation, &mut hot_plate, 1));
+		print("Idle tick", tick(&simulation, &mut hot_plate, 1));
+		hot_plate.start_reflow().unwrap();
+		print("Start of a reflow", tick(&simulation, &mut hot_plate, 1));
+		print("10 ticks during a reflow", tick(&simulation, &mut hot_plate, 10));
+	}
+}
+
+/// Returns the traffic sent to the display of the `simulation` while running `draw`.
+fn measure(simulation: &Simulation, draw: impl FnOnce()) -> SpiStatistics {
+	let before = simulation.get_display().get_statistics();
+	draw();
+	simulation.get_display().get_statistics() - before
+}
+
+/// Returns the traffic sent to the display of the `simulation` while ticking the `hot_plate` `ticks_count` times.
+fn tick(simulation: &Simulation, hot_plate: &mut HotPlate<SimulatedPeripherals>, ticks_count: usize) -> SpiStatistics {
+	measure(simulation, || {
+		for _ in 0..ticks_count {
+			simulation.advance(TICK_PERIOD);
+			hot_plate.tick().unwrap();
+		}
+	})
+}
+
+/// Draws a row of pixels as a [`Drawable`], the way the user interface does.
+///
+/// [`Drawable`]: `firmware_core::hot_plate::screen::drawable::Drawable`
+fn draw_row<DCXPin: OutputPin, ResetPin: OutputPin, Transfer: DisplayTransfer>(
+	ili9341: &mut ILI9341<DCXPin, ResetPin, Transfer>, y: u16, length: u16,
+) {
+	ili9341
+		.draw(
+			U16x2 { x: 0, y },
+			&Colored {
+				draw: HorizontalLine { length, thickness: 1 },
+				color: ColorRGB565::GREEN,
+			},
+		)
+		.unwrap();
+}
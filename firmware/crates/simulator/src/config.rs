@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use firmware_core::{
+	hot_plate::{
+		config::{reflow::ReflowConfig, temperature::*, ui::UiConfig, Configuration},
+		drivers::{
+			ili9341::Orientation,
+			mlx90614,
+			xpt2046::{Calibration, Xpt2046Config},
+		},
+		input::{ButtonConfig, RepeatConfig},
+		process::{GuaranteedDwellConfig, Interpolation},
+		temperature::{
+			autotune::{AutotuneConfig, TuningRule},
+			control::{AntiWindup, DerivativeSource, PidControlConfig},
+			feed_forward::FeedForwardConfig,
+			fusion::{FusionMode, SensorFusionConfig, TemperatureSensor},
+			gain_schedule::GainSchedule,
+			safety::temperature_change::TemperatureChangeConfig,
+			TemperaturePidGains,
+		},
+	},
+	utils::{math::Percentage, measurement::temperature::Temperature},
+};
+use micromath::vector::U16x2;
+
+use crate::peripherals::touch::SimulatedTouchPanel;
+
+/// The tick period at which the PID gains were tuned, when they were still applied once per tick: they are converted
+/// from it, so the PID behaves as tuned when the simulation advances 100 ms per tick (the default of the binary).
+const PID_GAINS_TUNING_TICK_PERIOD: Duration = Duration::from_millis(100);
+
+/// Returns the [`Configuration`] used by the firmware in the simulation, which is the same as the one of the real
+/// hot plate except for the [`feed-forward`] model, which matches the [`default parameters`] of the simulated plate.
+///
+/// # Examples
+/// With the feed-forward model the PID has less work to do, so the plate follows the profile more closely.
+/// ```
+/// # use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+/// # use firmware_core::hot_plate::{process::ReflowState, temperature::TemperaturePidGains, HotPlate};
+/// # use std::time::Duration;
+/// #
+/// let mean_tracking_error = |use_feed_forward: bool| {
+/// 	let simulation = Simulation::new(ThermalModelParameters::default());
+/// 	let mut configuration = config::configuration();
+/// 	// Soft gains, that let the plate lag behind the profile without the help of the feed-forward
+/// 	configuration.pid.pid_gains = TemperaturePidGains { p: 5., i: 0.01, d: 0. };
+/// 	configuration.pid.safety.keep_target_temperature_config.hysteresis = 20.;
+/// 	configuration.reflow.guaranteed_dwell = None;
+/// 	if !use_feed_forward {
+/// 		configuration.pid.feed_forward = None;
+/// 	}
+///
+/// 	let mut hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+/// 	hot_plate.start_reflow().unwrap();
+///
+/// 	let (mut errors_sum, mut samples_count) = (0., 0);
+/// 	while matches!(hot_plate.get_reflow_state(), ReflowState::Preheating | ReflowState::Running) {
+/// 		simulation.advance(Duration::from_millis(100));
+/// 		hot_plate.tick().unwrap();
+///
+/// 		if let Some(target_temperature) = hot_plate.get_target_temperature() {
+/// 			errors_sum += (target_temperature - simulation.get_plate_temperature()).as_kelvin().abs();
+/// 			samples_count += 1;
+/// 		}
+/// 	}
+/// 	assert_eq!(hot_plate.get_reflow_state(), ReflowState::Cooling);
+///
+/// 	errors_sum / samples_count as f32
+/// };
+///
+/// assert!(mean_tracking_error(true) < mean_tracking_error(false));
+/// ```
+///
+/// [`feed-forward`]: `PidConfig::feed_forward`
+/// [`default parameters`]: `crate::thermal_model::ThermalModelParameters::default`
+pub fn configuration() -> Configuration {
+	Configuration {
+		fan_min_duty_cycle_to_move: Percentage::from_0_to_100(20.).unwrap(),
+		pid: PidConfig {
+			pid_gains: TemperaturePidGains::from_per_tick(20., 2., 50., PID_GAINS_TUNING_TICK_PERIOD),
+			gain_schedule: GainSchedule::new(),
+			control: PidControlConfig {
+				output_limits: Percentage::ZERO..=Percentage::FULL,
+				anti_windup: AntiWindup::Clamping,
+				derivative_source: DerivativeSource::Measurement,
+				derivative_filter_time_constant_in_seconds: 0.5,
+			},
+			thermistor: ThermistorConfig {
+				beta: 3_950,
+				resistance_at_t0: 100_000,
+				other_resistance: 4_700,
+			},
+			board_thermistor: ThermistorConfig {
+				beta: 3_950,
+				resistance_at_t0: 10_000,
+				other_resistance: 10_000,
+			},
+			infrared_thermometer_address: mlx90614::DEFAULT_ADDRESS,
+			sensor_fusion: SensorFusionConfig {
+				sensors: TemperatureSensor::PlateThermistor1 | TemperatureSensor::PlateThermistor2,
+				monitored_sensors: TemperatureSensor::BoardThermistor | TemperatureSensor::InfraredThermometer,
+				mode: FusionMode::Average,
+				max_disagreement: 10.,
+			},
+			safety: SafetyConfig {
+				allowed_temperature_range: Temperature::from_celsius(0.)..=Temperature::from_celsius(270.),
+				keep_target_temperature_config: TemperatureChangeConfig {
+					period_in_seconds: 20.,
+					hysteresis: 2.,
+				},
+				rise_to_target_temperature_config: TemperatureChangeConfig {
+					period_in_seconds: 90.,
+					hysteresis: 2.,
+				},
+				rise_to_target_temperature_samples_count: 45,
+			},
+			feed_forward: Some(FeedForwardConfig {
+				ambient_temperature: Temperature::from_celsius(25.),
+				heat_loss_per_degree: 0.36,
+				output_per_degree_per_second: 24.,
+				look_ahead_in_seconds: 5.,
+			}),
+		},
+		reflow: ReflowConfig {
+			guaranteed_dwell: Some(GuaranteedDwellConfig {
+				tolerance: 5.,
+				max_stretch_in_seconds: 60.,
+			}),
+			interpolation: Interpolation::Linear,
+		},
+		ui: UiConfig {
+			display_orientation: Orientation::Landscape,
+			use_line_buffer: true,
+			touch: Xpt2046Config {
+				calibration: {
+					let points = [(30, 30), (290, 120), (160, 210)].map(|(x, y)| {
+						let point = U16x2 { x, y };
+						(SimulatedTouchPanel::get_raw_position(point), point)
+					});
+					Calibration::from_points(points).unwrap()
+				},
+				..Default::default()
+			},
+			autotune: AutotuneConfig {
+				target_temperature: Temperature::from_celsius(150.),
+				relay_high: Percentage::FULL,
+				relay_low: Percentage::ZERO,
+				hysteresis: 1.,
+				cycles_count: 5,
+				max_duration_in_seconds: 1_800.,
+			},
+			autotune_tuning_rule: TuningRule::TyreusLuyben,
+			next_button: ButtonConfig {
+				debounce_in_seconds: 0.02,
+				long_press_in_seconds: Some(2.),
+				double_press_interval_in_seconds: Some(0.3),
+				repeat: None,
+			},
+			select_button: ButtonConfig {
+				debounce_in_seconds: 0.02,
+				long_press_in_seconds: None,
+				double_press_interval_in_seconds: None,
+				repeat: Some(RepeatConfig {
+					delay_in_seconds: 0.6,
+					interval_in_seconds: 0.15,
+				}),
+			},
+		},
+	}
+}
@@ -0,0 +1,391 @@
+use std::ops::{RangeInclusive, Sub};
+
+use firmware_core::hot_plate::drivers::ili9341::{memory_access_control, Command, PANEL_HEIGHT, PANEL_WIDTH};
+
+/// The number of pixels in a row of the [`SimulatedDisplay`].
+pub const DISPLAY_WIDTH: u16 = 320;
+/// The number of pixels in a column of the [`SimulatedDisplay`].
+pub const DISPLAY_HEIGHT: u16 = 240;
+
+/// An in-memory emulation of an [`ILI9341`] controller that interprets the bytes sent over SPI and keeps the
+/// resulting image in a framebuffer.
+///
+/// The commands that draw on the display, that rotate the image (`MemoryAccessControl`), that turn the panel on and
+/// off (sleep and display on/off), that invert its colors, that set the brightness, that scroll it and that enter the
+/// partial mode are interpreted, the others are only counted in the [`statistics`].
+///
+/// The panel is mounted in landscape (so it's [`DISPLAY_WIDTH`] pixels wide), and like the panels sold with the
+/// ILI9341 its subpixels are in BGR order.
+///
+/// # Examples
+/// ```
+/// # use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+/// # use firmware_core::hot_plate::{drivers::ili9341::Orientation, HotPlate};
+/// #
+/// let simulation = Simulation::new(ThermalModelParameters::default());
+/// assert!(!simulation.get_display().is_on());
+///
+/// let configuration = config::configuration();
+/// let hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+///
+/// // The firmware initialized the display
+/// let display = simulation.get_display();
+/// assert!(display.is_on());
+/// assert_eq!(
+/// 	display.get_memory_access_control(),
+/// 	Orientation::Landscape.memory_access_control()
+/// );
+/// ```
+///
+/// [`ILI9341`]: `firmware_core::hot_plate::drivers::ili9341::ILI9341`
+/// [`statistics`]: `Self::get_statistics`
+pub struct SimulatedDisplay {
+	/// The memory of the controller, with a color for each pixel of the panel in its native portrait orientation.
+	framebuffer: Vec<u16>,
+	statistics: SpiStatistics,
+
+	is_data_selected: bool,
+	is_in_reset: bool,
+
+	current_command: Option<u8>,
+	parameters: Vec<u8>,
+
+	columns: RangeInclusive<u16>,
+	pages: RangeInclusive<u16>,
+	cursor: (u16, u16),
+	pending_color_byte: Option<u8>,
+
+	registers: Registers,
+}
+
+/// Counters of the traffic sent to a [`SimulatedDisplay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpiStatistics {
+	/// Number of SPI transactions (one for each call to any `SpiDevice` method).
+	pub transactions: usize,
+	/// Number of bytes written over SPI (both commands and data).
+	pub bytes_written: usize,
+	/// Number of command bytes written over SPI.
+	pub commands: usize,
+	/// Number of pixels written in the framebuffer.
+	pub pixels_written: usize,
+}
+
+impl Sub for SpiStatistics {
+	type Output = Self;
+
+	/// Returns the traffic sent between the `rhs` statistics and these ones.
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self {
+			transactions: self.transactions - rhs.transactions,
+			bytes_written: self.bytes_written - rhs.bytes_written,
+			commands: self.commands - rhs.commands,
+			pixels_written: self.pixels_written - rhs.pixels_written,
+		}
+	}
+}
+
+/// The state of the [`SimulatedDisplay`] that is reset by a hardware or a software reset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Registers {
+	memory_access_control: u8,
+	is_sleeping: bool,
+	is_display_on: bool,
+	is_inverted: bool,
+	brightness: u8,
+	/// The rows of the panel that scroll.
+	scrolling_rows: RangeInclusive<u16>,
+	/// The row of the memory shown at the top of the scrolling rows.
+	scroll_start: u16,
+	/// The rows of the panel shown in partial mode.
+	partial_rows: RangeInclusive<u16>,
+	is_partial_mode: bool,
+}
+
+impl Default for Registers {
+	fn default() -> Self {
+		Self {
+			memory_access_control: 0,
+			is_sleeping: true,
+			is_display_on: false,
+			is_inverted: false,
+			brightness: 0,
+			scrolling_rows: 0..=PANEL_HEIGHT - 1,
+			scroll_start: 0,
+			partial_rows: 0..=PANEL_HEIGHT - 1,
+			is_partial_mode: false,
+		}
+	}
+}
+
+impl SimulatedDisplay {
+	pub(crate) fn new() -> Self {
+		Self {
+			framebuffer: vec![0; PANEL_WIDTH as usize * PANEL_HEIGHT as usize],
+			statistics: SpiStatistics::default(),
+			is_data_selected: true,
+			is_in_reset: false,
+			current_command: None,
+			parameters: Vec::new(),
+			columns: 0..=PANEL_WIDTH - 1,
+			pages: 0..=PANEL_HEIGHT - 1,
+			cursor: (0, 0),
+			pending_color_byte: None,
+			registers: Registers::default(),
+		}
+	}
+
+	/// Returns the raw RGB565 value of the pixel shown at the provided coordinates, or `None` if they are outside the
+	/// display.
+	///
+	/// The pixels are black while the display [`is off`] and outside the partial area in partial mode, and their
+	/// colors are inverted while the inversion is on.
+	///
+	/// [`is off`]: `Self::is_on`
+	pub fn get_pixel(&self, x: u16, y: u16) -> Option<u16> {
+		if x >= DISPLAY_WIDTH || y >= DISPLAY_HEIGHT {
+			return None;
+		}
+
+		// The panel is mounted rotated, so its rows are the columns of the display
+		let (panel_x, panel_row) = (y, x);
+		let registers = &self.registers;
+		if !self.is_on() || (registers.is_partial_mode && !registers.partial_rows.contains(&panel_row)) {
+			return Some(0);
+		}
+
+		let memory_row = match registers.scrolling_rows.contains(&panel_row) {
+			true => {
+				let first_row = *registers.scrolling_rows.start() as i32;
+				let rows_count = registers.scrolling_rows.len() as i32;
+				let offset = registers.scroll_start as i32 - first_row + panel_row as i32 - first_row;
+				(first_row + offset.rem_euclid(rows_count)) as u16
+			},
+			false => panel_row,
+		};
+		let mut color = self.framebuffer[memory_row as usize * PANEL_WIDTH as usize + panel_x as usize];
+		// The subpixels of the panel are in BGR order, so without the flag the red and the blue are exchanged
+		if registers.memory_access_control & memory_access_control::BGR_ORDER == 0 {
+			color = (color & 0x1F) << 11 | (color & 0x07E0) | color >> 11;
+		}
+		if registers.is_inverted {
+			color = !color;
+		}
+
+		Some(color)
+	}
+
+	/// Returns `true` if the display is out of the sleep mode and it's turned on.
+	pub fn is_on(&self) -> bool {
+		!self.registers.is_sleeping && self.registers.is_display_on
+	}
+
+	/// Returns `true` if the colors of the display are inverted.
+	pub fn is_inverted(&self) -> bool {
+		self.registers.is_inverted
+	}
+
+	/// Returns the brightness of the backlight set with the `WriteDisplayBrightness` command.
+	pub fn get_brightness(&self) -> u8 {
+		self.registers.brightness
+	}
+
+	/// Returns the parameter of the last `MemoryAccessControl` command, which rotates the image.
+	pub fn get_memory_access_control(&self) -> u8 {
+		self.registers.memory_access_control
+	}
+
+	/// Returns the counters of the traffic sent to the display since its creation or since the last time you called
+	/// [`Self::reset_statistics`].
+	pub fn get_statistics(&self) -> SpiStatistics {
+		self.statistics
+	}
+
+	/// Sets all the counters of [`Self::get_statistics`] to `0`.
+	pub fn reset_statistics(&mut self) {
+		self.statistics = SpiStatistics::default();
+	}
+
+	pub(crate) fn set_data_selected(&mut self, is_data_selected: bool) {
+		self.is_data_selected = is_data_selected;
+	}
+
+	pub(crate) fn set_in_reset(&mut self, is_in_reset: bool) {
+		if is_in_reset {
+			self.reset();
+		}
+		self.is_in_reset = is_in_reset;
+	}
+
+	/// Brings the display back to the state it has after it's powered on (only the framebuffer isn't changed).
+	fn reset(&mut self) {
+		self.current_command = None;
+		self.parameters.clear();
+		self.columns = 0..=PANEL_WIDTH - 1;
+		self.pages = 0..=PANEL_HEIGHT - 1;
+		self.pending_color_byte = None;
+		self.registers = Registers::default();
+	}
+
+	pub(crate) fn on_transaction(&mut self) {
+		self.statistics.transactions += 1;
+	}
+
+	pub(crate) fn receive(&mut self, bytes: &[u8]) {
+		self.statistics.bytes_written += bytes.len();
+		if self.is_in_reset {
+			return;
+		}
+
+		for &byte in bytes {
+			if self.is_data_selected {
+				self.receive_data(byte);
+			} else {
+				self.receive_command(byte);
+			}
+		}
+	}
+
+	fn receive_command(&mut self, command: u8) {
+		const SOFTWARE_RESET: u8 = Command::SoftwareReset as u8;
+		const MEMORY_WRITE: u8 = Command::MemoryWrite as u8;
+		const ENTER_SLEEP_MODE: u8 = Command::EnterSleepMode as u8;
+		const SLEEP_OUT: u8 = Command::SleepOut as u8;
+		const PARTIAL_MODE_ON: u8 = Command::PartialModeOn as u8;
+		const NORMAL_DISPLAY_MODE_ON: u8 = Command::NormalDisplayModeOn as u8;
+		const DISPLAY_INVERSION_OFF: u8 = Command::DisplayInversionOff as u8;
+		const DISPLAY_INVERSION_ON: u8 = Command::DisplayInversionOn as u8;
+		const DISPLAY_OFF: u8 = Command::DisplayOff as u8;
+		const DISPLAY_ON: u8 = Command::DisplayOn as u8;
+
+		self.statistics.commands += 1;
+
+		self.current_command = Some(command);
+		self.parameters.clear();
+		self.pending_color_byte = None;
+
+		let registers = &mut self.registers;
+		match command {
+			SOFTWARE_RESET => self.reset(),
+			MEMORY_WRITE => self.cursor = (*self.columns.start(), *self.pages.start()),
+			ENTER_SLEEP_MODE => registers.is_sleeping = true,
+			SLEEP_OUT => registers.is_sleeping = false,
+			PARTIAL_MODE_ON => registers.is_partial_mode = true,
+			NORMAL_DISPLAY_MODE_ON => registers.is_partial_mode = false,
+			DISPLAY_INVERSION_OFF => registers.is_inverted = false,
+			DISPLAY_INVERSION_ON => registers.is_inverted = true,
+			DISPLAY_OFF => registers.is_display_on = false,
+			DISPLAY_ON => registers.is_display_on = true,
+			_ => {},
+		}
+	}
+
+	fn receive_data(&mut self, byte: u8) {
+		const COLUMN_ADDRESS_SET: u8 = Command::ColumnAddressSet as u8;
+		const PAGE_ADDRESS_SET: u8 = Command::PageAddressSet as u8;
+		const MEMORY_WRITE: u8 = Command::MemoryWrite as u8;
+		const WRITE_MEMORY_CONTINUE: u8 = Command::WriteMemoryContinue as u8;
+
+		match self.current_command {
+			Some(COLUMN_ADDRESS_SET) => {
+				if let Some(range) = self.receive_address_range(byte) {
+					self.columns = range;
+				}
+			},
+			Some(PAGE_ADDRESS_SET) => {
+				if let Some(range) = self.receive_address_range(byte) {
+					self.pages = range;
+				}
+			},
+			Some(MEMORY_WRITE | WRITE_MEMORY_CONTINUE) => match self.pending_color_byte.take() {
+				// The ILI9341 expects the most significant byte of each color first
+				Some(high_byte) => self.write_pixel(u16::from_be_bytes([high_byte, byte])),
+				None => self.pending_color_byte = Some(byte),
+			},
+			_ => {
+				self.parameters.push(byte);
+				self.receive_parameters();
+			},
+		}
+	}
+
+	/// Applies the parameters of the current command once all of them have been received.
+	fn receive_parameters(&mut self) {
+		const PARTIAL_AREA: u8 = Command::PartialArea as u8;
+		const VERTICAL_SCROLL_DEFINITION: u8 = Command::VerticalScrollDefinition as u8;
+		const MEMORY_ACCESS_CONTROL: u8 = Command::MemoryAccessControl as u8;
+		const VERTICAL_SCROLLING_START_ADDRESS: u8 = Command::VerticalScrollingStartAddress as u8;
+		const WRITE_DISPLAY_BRIGHTNESS: u8 = Command::WriteDisplayBrightness as u8;
+
+		let parameter = |index: usize| u16::from_be_bytes([self.parameters[2 * index], self.parameters[2 * index + 1]]);
+		let registers = &mut self.registers;
+		match (self.current_command, self.parameters.len()) {
+			(Some(PARTIAL_AREA), 4) => registers.partial_rows = parameter(0)..=parameter(1),
+			(Some(VERTICAL_SCROLL_DEFINITION), 6) => {
+				let (top_fixed_rows, scrolling_rows) = (parameter(0), parameter(1));
+				registers.scrolling_rows = top_fixed_rows..=(top_fixed_rows + scrolling_rows).saturating_sub(1);
+			},
+			(Some(MEMORY_ACCESS_CONTROL), 1) => registers.memory_access_control = self.parameters[0],
+			(Some(VERTICAL_SCROLLING_START_ADDRESS), 2) => registers.scroll_start = parameter(0),
+			(Some(WRITE_DISPLAY_BRIGHTNESS), 1) => registers.brightness = self.parameters[0],
+			_ => {},
+		}
+	}
+
+	fn receive_address_range(&mut self, byte: u8) -> Option<RangeInclusive<u16>> {
+		self.parameters.push(byte);
+		(self.parameters.len() == 4).then(|| {
+			let start = u16::from_be_bytes([self.parameters[0], self.parameters[1]]);
+			let end = u16::from_be_bytes([self.parameters[2], self.parameters[3]]);
+			self.parameters.clear();
+
+			start..=end
+		})
+	}
+
+	fn write_pixel(&mut self, color: u16) {
+		let (x, y) = self.cursor;
+		if let Some((panel_x, panel_row)) = self.get_panel_position(x, y) {
+			self.framebuffer[panel_row as usize * PANEL_WIDTH as usize + panel_x as usize] = color;
+		}
+		self.statistics.pixels_written += 1;
+
+		self.cursor = if x >= *self.columns.end() {
+			let next_y = if y >= *self.pages.end() {
+				*self.pages.start()
+			} else {
+				y + 1
+			};
+			(*self.columns.start(), next_y)
+		} else {
+			(x + 1, y)
+		};
+	}
+
+	/// Returns the position on the panel (in its native portrait orientation) of the pixel at the provided column and
+	/// page addresses, rotating them as set by the `MemoryAccessControl` command. Returns `None` if the addresses are
+	/// outside the panel.
+	fn get_panel_position(&self, column: u16, page: u16) -> Option<(u16, u16)> {
+		use memory_access_control::*;
+
+		let flags = self.registers.memory_access_control;
+		let (x, row) = match flags & ROW_COLUMN_EXCHANGE != 0 {
+			true => (page, column),
+			false => (column, page),
+		};
+		if x >= PANEL_WIDTH || row >= PANEL_HEIGHT {
+			return None;
+		}
+
+		Some((
+			match flags & COLUMN_ADDRESS_ORDER != 0 {
+				true => PANEL_WIDTH - 1 - x,
+				false => x,
+			},
+			match flags & ROW_ADDRESS_ORDER != 0 {
+				true => PANEL_HEIGHT - 1 - row,
+				false => row,
+			},
+		))
+	}
+}
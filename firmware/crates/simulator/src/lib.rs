@@ -0,0 +1,17 @@
+//! A simulation of the hot plate that runs on the host, so that the firmware can be tested without the real
+//! hardware.
+//!
+//! A [`Simulation`] keeps a [`thermal model`] of the plate and provides some [`peripherals`] that implement
+//! the [`Peripherals`] trait, so they can be used to create an [`HotPlate`] which is then ticked in virtual time.
+//!
+//! [`Simulation`]: `simulation::Simulation`
+//! [`thermal model`]: `thermal_model::ThermalModel`
+//! [`peripherals`]: `peripherals::SimulatedPeripherals`
+//! [`Peripherals`]: `firmware_core::hot_plate::peripherals::Peripherals`
+//! [`HotPlate`]: `firmware_core::hot_plate::HotPlate`
+
+pub mod config;
+pub mod display;
+pub mod peripherals;
+pub mod simulation;
+pub mod thermal_model;
@@ -0,0 +1,61 @@
+//! Runs the firmware on a simulated hot plate, starts the default reflow profile and prints the temperature trajectory to the standard output as CSV.
+//!
+//! Usage: `cargo run -p simulator -- [simulated seconds] [tick period in milliseconds]`
+
+use std::time::Duration;
+
+use firmware_core::hot_plate::HotPlate;
+use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+
+const DEFAULT_SIMULATED_SECONDS: u64 = 420;
+const DEFAULT_TICK_PERIOD_IN_MILLIS: u64 = 100;
+/// Print a line of the CSV every this amount of ticks.
+const TICKS_PER_SAMPLE: u64 = 10;
+
+fn main() {
+	let mut arguments = std::env::args().skip(1);
+	let simulated_seconds = arguments
+		.next()
+		.map(|argument| argument.parse().expect("The simulated seconds must be an integer"))
+		.unwrap_or(DEFAULT_SIMULATED_SECONDS);
+	let tick_period = Duration::from_millis(
+		arguments
+			.next()
+			.map(|argument| argument.parse().expect("The tick period must be an integer"))
+			.unwrap_or(DEFAULT_TICK_PERIOD_IN_MILLIS),
+	);
+
+	let simulation = Simulation::new(ThermalModelParameters::default());
+	let configuration = config::configuration();
+	let mut hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+
+	hot_plate.start_reflow().unwrap();
+
+	println!("time_s,target_celsius,plate_celsius,heater_percentage,fan_percentage,state");
+	let ticks_count = Duration::from_secs(simulated_seconds).as_nanos() / tick_period.as_nanos();
+	for tick in 0..ticks_count as u64 {
+		simulation.advance(tick_period);
+		if let Err(error) = hot_plate.tick() {
+			eprintln!("The hot plate stopped at {:?}: {:?}", simulation.get_time(), error);
+			break;
+		}
+		while let Some(event) = hot_plate.poll_reflow_event() {
+			eprintln!("{:.1}s: {:?}", simulation.get_time().as_secs_f32(), event);
+		}
+
+		if tick % TICKS_PER_SAMPLE == 0 {
+			println!(
+				"{:.1},{:.2},{:.2},{:.1},{:.1},{:?}",
+				simulation.get_time().as_secs_f32(),
+				hot_plate
+					.get_target_temperature()
+					.map(|temperature| temperature.as_celsius())
+					.unwrap_or(f32::NAN),
+				simulation.get_plate_temperature().as_celsius(),
+				simulation.get_heater_duty_cycle().into_0_to_100(),
+				simulation.get_fan_duty_cycle().into_0_to_100(),
+				hot_plate.get_reflow_state(),
+			);
+		}
+	}
+}
@@ -0,0 +1,95 @@
+use std::{cell::RefCell, convert::Infallible, ops::Div, rc::Rc};
+
+use firmware_core::{
+	hot_plate::{
+		config::temperature::ThermistorConfig,
+		drivers::thermistor::T0,
+		hal::adc::{Adc, AdcPin},
+		temperature::fusion::TemperatureSensor,
+	},
+	utils::math::Percentage,
+};
+
+use crate::simulation::SimulationState;
+
+/// A simulated 12 bit ADC.
+pub struct SimulatedAdc;
+
+impl SimulatedAdc {
+	/// The resolution of the ADC in bits.
+	pub const RESOLUTION_BITS: u32 = 12;
+}
+
+impl Adc for SimulatedAdc {
+	type ReadableValue = AdcValue;
+
+	fn max_readable_value(&self) -> Self::ReadableValue {
+		AdcValue((1 << Self::RESOLUTION_BITS) - 1)
+	}
+}
+
+/// A raw value read by the [`SimulatedAdc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AdcValue(pub u16);
+
+impl Div for AdcValue {
+	type Output = Result<Percentage, ()>;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		Percentage::from_0_to_1(self.0 as f32 / rhs.0 as f32)
+	}
+}
+
+/// A simulated thermistor connected to the [`SimulatedAdc`] in a voltage divider, that measures the temperature of
+/// the simulated plate (or the ambient temperature if it has been [`detached`] from the plate).
+///
+/// It's the inverse of [`Thermistor`], so if they are created with the same parameters the temperature read by the
+/// firmware matches the one of the simulated plate (except for the quantization error of the ADC).
+///
+/// [`detached`]: `crate::simulation::Simulation::set_sensor_detached`
+/// [`Thermistor`]: `firmware_core::hot_plate::drivers::thermistor::Thermistor`
+pub struct SimulatedThermistorPin {
+	state: Rc<RefCell<SimulationState>>,
+	sensor: TemperatureSensor,
+	beta: f32,
+	resistance_at_t0: f32,
+	other_resistance: f32,
+}
+
+impl SimulatedThermistorPin {
+	pub(crate) fn new(
+		state: Rc<RefCell<SimulationState>>, sensor: TemperatureSensor, thermistor: &ThermistorConfig,
+	) -> Self {
+		Self {
+			state,
+			sensor,
+			beta: thermistor.beta as f32,
+			resistance_at_t0: thermistor.resistance_at_t0 as f32,
+			other_resistance: thermistor.other_resistance as f32,
+		}
+	}
+}
+
+impl AdcPin<SimulatedAdc> for SimulatedThermistorPin {
+	type Error = Infallible;
+
+	fn read(&mut self, adc: &mut SimulatedAdc) -> Result<AdcValue, Self::Error> {
+		let state = self.state.borrow();
+		let temperature = match state.detached_sensors[self.sensor as usize] {
+			true => state.thermal_model.get_parameters().ambient_temperature,
+			false => state.thermal_model.get_sensor_temperature(),
+		};
+
+		// Beta equation of the thermistor, and then the formula of the voltage divider
+		let resistance =
+			self.resistance_at_t0 * f32::exp(self.beta * (1. / temperature.as_kelvin() - 1. / T0.as_kelvin()));
+		let sample = resistance / (resistance + self.other_resistance);
+
+		let max_readable_value = adc.max_readable_value().0;
+		Ok(AdcValue(
+			(sample * max_readable_value as f32)
+				.round()
+				.clamp(0., max_readable_value as f32) as u16,
+		))
+	}
+}
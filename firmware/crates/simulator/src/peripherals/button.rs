@@ -0,0 +1,35 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+use firmware_core::hot_plate::{hal::button::Button, screen::ui::NavigationButton};
+
+use crate::simulation::SimulationState;
+
+/// A simulated push button, which is pressed and released using [`Simulation::press_button`] and
+/// [`Simulation::release_button`]. Its contacts don't bounce.
+///
+/// [`Simulation::press_button`]: `crate::simulation::Simulation::press_button`
+/// [`Simulation::release_button`]: `crate::simulation::Simulation::release_button`
+pub struct SimulatedButton {
+	state: Rc<RefCell<SimulationState>>,
+	button: NavigationButton,
+}
+
+impl SimulatedButton {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>, button: NavigationButton) -> Self {
+		Self { state, button }
+	}
+}
+
+impl std::fmt::Debug for SimulatedButton {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_tuple("SimulatedButton").field(&self.button).finish()
+	}
+}
+
+impl Button for SimulatedButton {
+	type Error = Infallible;
+
+	fn is_pressed(&mut self) -> Result<bool, Self::Error> {
+		Ok(self.state.borrow().pressed_buttons.contains(&self.button))
+	}
+}
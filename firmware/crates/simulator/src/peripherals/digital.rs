@@ -0,0 +1,60 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+use embedded_hal::digital::{ErrorType, OutputPin};
+
+use crate::simulation::SimulationState;
+
+/// Which output pin of the board a [`SimulatedOutputPin`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputPinKind {
+	/// The pin that selects if the bytes sent to the display are commands or data.
+	LcdDCX,
+	/// The pin that resets the display when low.
+	LcdReset,
+}
+
+/// A simulated digital output pin connected to the [`SimulatedDisplay`].
+///
+/// [`SimulatedDisplay`]: `crate::display::SimulatedDisplay`
+pub struct SimulatedOutputPin {
+	state: Rc<RefCell<SimulationState>>,
+	kind: OutputPinKind,
+}
+
+impl SimulatedOutputPin {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>, kind: OutputPinKind) -> Self {
+		Self { state, kind }
+	}
+
+	fn set_state(&mut self, is_high: bool) {
+		let display = &mut self.state.borrow_mut().display;
+		match self.kind {
+			OutputPinKind::LcdDCX => display.set_data_selected(is_high),
+			OutputPinKind::LcdReset => display.set_in_reset(!is_high),
+		}
+	}
+}
+
+impl std::fmt::Debug for SimulatedOutputPin {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_tuple("SimulatedOutputPin").field(&self.kind).finish()
+	}
+}
+
+impl ErrorType for SimulatedOutputPin {
+	type Error = Infallible;
+}
+
+impl OutputPin for SimulatedOutputPin {
+	fn set_low(&mut self) -> Result<(), Self::Error> {
+		self.set_state(false);
+
+		Ok(())
+	}
+
+	fn set_high(&mut self) -> Result<(), Self::Error> {
+		self.set_state(true);
+
+		Ok(())
+	}
+}
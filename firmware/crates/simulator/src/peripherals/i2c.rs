@@ -0,0 +1,158 @@
+use std::{cell::RefCell, rc::Rc};
+
+use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation, SevenBitAddress};
+use firmware_core::{
+	hot_plate::drivers::mlx90614::{self, pec, Register},
+	utils::measurement::temperature::Temperature,
+};
+
+use crate::simulation::SimulationState;
+
+/// A simulated I2C bus connected to the [`SimulatedMlx90614`].
+///
+/// # Examples
+/// ```
+/// # use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+/// # use firmware_core::{
+/// # 	hot_plate::{drivers::mlx90614::{self, Mlx90614}, hal::system_time::Clock, peripherals::Peripherals},
+/// # 	utils::measurement::temperature::Temperature,
+/// # };
+/// #
+/// let simulation = Simulation::new(ThermalModelParameters::default());
+/// let mut peripherals = simulation.peripherals(&config::configuration().pid);
+/// let clock = Clock::new(peripherals.take_system_time().unwrap());
+/// let mut infrared_thermometer =
+/// 	Mlx90614::new(peripherals.take_infrared_thermometer_i2c().unwrap(), mlx90614::DEFAULT_ADDRESS);
+///
+/// simulation.set_plate_temperature(Temperature::from_celsius(150.));
+/// let object_temperature = infrared_thermometer.read_object_temperature().unwrap();
+/// assert!((object_temperature.as_celsius() - 150.).abs() <= 0.02);
+///
+/// infrared_thermometer.write_emissivity(0.95, &clock).unwrap();
+/// assert!((infrared_thermometer.read_emissivity().unwrap() - 0.95).abs() < 0.001);
+/// assert!(infrared_thermometer.write_emissivity(1.5, &clock).is_err());
+///
+/// // The noise on the bus corrupts the data, but the PEC catches it
+/// simulation.set_infrared_thermometer_noise(true);
+/// assert!(matches!(infrared_thermometer.read_object_temperature(), Err(mlx90614::Error::WrongPec)));
+///
+/// // There's no sensor at this address
+/// let mut peripherals = simulation.peripherals(&config::configuration().pid);
+/// let mut infrared_thermometer = Mlx90614::new(peripherals.take_infrared_thermometer_i2c().unwrap(), 0x10);
+/// assert!(matches!(infrared_thermometer.read_ambient_temperature(), Err(mlx90614::Error::I2c(_))));
+/// ```
+pub struct SimulatedI2c {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl SimulatedI2c {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>) -> Self {
+		Self { state }
+	}
+}
+
+impl ErrorType for SimulatedI2c {
+	type Error = ErrorKind;
+}
+
+impl I2c<SevenBitAddress> for SimulatedI2c {
+	fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+		let mut state = self.state.borrow_mut();
+		let SimulationState {
+			infrared_thermometer,
+			thermal_model,
+			..
+		} = &mut *state;
+
+		if address != infrared_thermometer.address {
+			return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+		}
+
+		let mut command = None;
+		for operation in operations {
+			match operation {
+				Operation::Write(write) => match write {
+					[written_command] => command = Some(*written_command),
+					[written_command, low_byte, high_byte, received_pec] => {
+						if *received_pec != pec(&[address << 1, *written_command, *low_byte, *high_byte]) {
+							return Err(ErrorKind::Other);
+						}
+						infrared_thermometer.write(*written_command, u16::from_le_bytes([*low_byte, *high_byte]));
+					},
+					_ => return Err(ErrorKind::Other),
+				},
+				Operation::Read(read) => {
+					let command = command.ok_or(ErrorKind::Other)?;
+					let ambient_temperature = thermal_model.get_parameters().ambient_temperature;
+					let word = infrared_thermometer
+						.read(command, thermal_model.get_temperature(), ambient_temperature)
+						.ok_or(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))?;
+
+					let [low_byte, high_byte] = word.to_le_bytes();
+					let pec = pec(&[address << 1, command, address << 1 | 1, low_byte, high_byte]);
+					let mut response = [low_byte, high_byte, pec];
+					if infrared_thermometer.is_noisy {
+						response[0] ^= 1;
+					}
+
+					for (byte, response) in read.iter_mut().zip(response.into_iter().chain(std::iter::repeat(0xFF))) {
+						*byte = response;
+					}
+				},
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// A simulated [`MLX90614`] pointed at the simulated plate.
+///
+/// [`MLX90614`]: `firmware_core::hot_plate::drivers::mlx90614::Mlx90614`
+pub struct SimulatedMlx90614 {
+	address: u8,
+	emissivity: u16,
+	/// If it's `true`, a bit of each word read from the sensor is flipped after computing its PEC.
+	pub(crate) is_noisy: bool,
+}
+
+impl SimulatedMlx90614 {
+	pub(crate) fn new() -> Self {
+		Self {
+			address: mlx90614::DEFAULT_ADDRESS,
+			emissivity: u16::MAX,
+			is_noisy: false,
+		}
+	}
+
+	/// Returns the emissivity saved in the EEPROM of the sensor.
+	pub fn get_emissivity(&self) -> f32 {
+		self.emissivity as f32 / u16::MAX as f32
+	}
+
+	/// Returns the word at the provided `command`, or `None` if the sensor doesn't have it.
+	fn read(&self, command: u8, plate_temperature: Temperature, ambient_temperature: Temperature) -> Option<u16> {
+		let temperature_word = |temperature: Temperature| (temperature.as_kelvin() / 0.02).round() as u16;
+
+		match command {
+			command if command == Register::AmbientTemperature as u8 => Some(temperature_word(ambient_temperature)),
+			command if command == Register::ObjectTemperature1 as u8 => Some(temperature_word(plate_temperature)),
+			command if command == Register::Emissivity as u8 => Some(self.emissivity),
+			command if command == Register::SmbusAddress as u8 => Some(self.address as u16),
+			_ => None,
+		}
+	}
+
+	/// Writes the `word` in the EEPROM cell at the provided `command`. Like in the real sensor, a cell must be erased
+	/// (written with `0`) before it can be written with another value.
+	fn write(&mut self, command: u8, word: u16) {
+		let cell = match command {
+			command if command == Register::Emissivity as u8 => &mut self.emissivity,
+			_ => return,
+		};
+
+		if word == 0 || *cell == 0 {
+			*cell = word;
+		}
+	}
+}
@@ -0,0 +1,167 @@
+use std::{cell::RefCell, rc::Rc};
+
+use firmware_core::hot_plate::{
+	config::temperature::PidConfig, hal::display_transfer::SpiTransfer, peripherals::Peripherals,
+	screen::ui::NavigationButton, temperature::fusion::TemperatureSensor,
+};
+
+use self::{
+	adc::{SimulatedAdc, SimulatedThermistorPin},
+	button::SimulatedButton,
+	digital::{OutputPinKind, SimulatedOutputPin},
+	i2c::SimulatedI2c,
+	pwm::{PwmPinKind, SimulatedPwmPin},
+	spi::SimulatedSpi,
+	system_time::SimulatedSystemTime,
+	touch::{SimulatedTouchInterruptPin, SimulatedTouchSpi},
+};
+use crate::simulation::SimulationState;
+
+pub mod adc;
+pub mod button;
+pub mod digital;
+pub mod i2c;
+pub mod pwm;
+pub mod spi;
+pub mod system_time;
+pub mod touch;
+
+/// The number of bytes the simulated display receives in each SPI transaction.
+pub const LCD_TRANSFER_BUFFER_LENGTH: usize = 1024;
+
+/// The peripherals of a simulated hot plate. Check [`Simulation::peripherals`].
+///
+/// [`Simulation::peripherals`]: `crate::simulation::Simulation::peripherals`
+pub struct SimulatedPeripherals {
+	lcd_dcx_pin: Option<<Self as Peripherals>::LcdDCXPin>,
+	lcd_reset_pin: Option<<Self as Peripherals>::LcdResetPin>,
+	lcd_transfer: Option<<Self as Peripherals>::LcdTransfer>,
+	touch_spi: Option<<Self as Peripherals>::TouchSpi>,
+	touch_interrupt_pin: Option<<Self as Peripherals>::TouchInterruptPin>,
+	next_button: Option<<Self as Peripherals>::Button>,
+	select_button: Option<<Self as Peripherals>::Button>,
+
+	fan_pin: Option<<Self as Peripherals>::FanPin>,
+	heater_pin: Option<<Self as Peripherals>::HeaterPin>,
+	adc: Option<<Self as Peripherals>::ADC>,
+	thermistor1_pin: Option<<Self as Peripherals>::Thermistor1Pin>,
+	thermistor2_pin: Option<<Self as Peripherals>::Thermistor2Pin>,
+	board_thermistor_pin: Option<<Self as Peripherals>::BoardThermistorPin>,
+	infrared_thermometer_i2c: Option<<Self as Peripherals>::InfraredThermometerI2c>,
+
+	system_time: Option<<Self as Peripherals>::SystemTime>,
+}
+
+impl SimulatedPeripherals {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>, pid: &PidConfig) -> Self {
+		Self {
+			lcd_dcx_pin: Some(SimulatedOutputPin::new(Rc::clone(&state), OutputPinKind::LcdDCX)),
+			lcd_reset_pin: Some(SimulatedOutputPin::new(Rc::clone(&state), OutputPinKind::LcdReset)),
+			lcd_transfer: Some(SpiTransfer::new(SimulatedSpi::new(Rc::clone(&state)))),
+			touch_spi: Some(SimulatedTouchSpi::new(Rc::clone(&state))),
+			touch_interrupt_pin: Some(SimulatedTouchInterruptPin::new(Rc::clone(&state))),
+			next_button: Some(SimulatedButton::new(Rc::clone(&state), NavigationButton::Next)),
+			select_button: Some(SimulatedButton::new(Rc::clone(&state), NavigationButton::Select)),
+			fan_pin: Some(SimulatedPwmPin::new(Rc::clone(&state), PwmPinKind::Fan)),
+			heater_pin: Some(SimulatedPwmPin::new(Rc::clone(&state), PwmPinKind::Heater)),
+			adc: Some(SimulatedAdc),
+			thermistor1_pin: Some(SimulatedThermistorPin::new(
+				Rc::clone(&state),
+				TemperatureSensor::PlateThermistor1,
+				&pid.thermistor,
+			)),
+			thermistor2_pin: Some(SimulatedThermistorPin::new(
+				Rc::clone(&state),
+				TemperatureSensor::PlateThermistor2,
+				&pid.thermistor,
+			)),
+			board_thermistor_pin: Some(SimulatedThermistorPin::new(
+				Rc::clone(&state),
+				TemperatureSensor::BoardThermistor,
+				&pid.board_thermistor,
+			)),
+			infrared_thermometer_i2c: Some(SimulatedI2c::new(Rc::clone(&state))),
+			system_time: Some(SimulatedSystemTime::new(state)),
+		}
+	}
+}
+
+impl Peripherals for SimulatedPeripherals {
+	type LcdDCXPin = SimulatedOutputPin;
+	type LcdResetPin = SimulatedOutputPin;
+	type LcdTransfer = SpiTransfer<SimulatedSpi, LCD_TRANSFER_BUFFER_LENGTH>;
+	type TouchSpi = SimulatedTouchSpi;
+	type TouchInterruptPin = SimulatedTouchInterruptPin;
+	type Button = SimulatedButton;
+
+	type FanPin = SimulatedPwmPin;
+
+	type HeaterPin = SimulatedPwmPin;
+	type ADC = SimulatedAdc;
+	type Thermistor1Pin = SimulatedThermistorPin;
+	type Thermistor2Pin = SimulatedThermistorPin;
+	type BoardThermistorPin = SimulatedThermistorPin;
+	type InfraredThermometerI2c = SimulatedI2c;
+
+	type SystemTime = SimulatedSystemTime;
+
+	fn take_lcd_dcx_pin(&mut self) -> Option<Self::LcdDCXPin> {
+		self.lcd_dcx_pin.take()
+	}
+
+	fn take_lcd_reset_pin(&mut self) -> Option<Self::LcdResetPin> {
+		self.lcd_reset_pin.take()
+	}
+
+	fn take_lcd_transfer(&mut self) -> Option<Self::LcdTransfer> {
+		self.lcd_transfer.take()
+	}
+
+	fn take_touch_spi(&mut self) -> Option<Self::TouchSpi> {
+		self.touch_spi.take()
+	}
+
+	fn take_touch_interrupt_pin(&mut self) -> Option<Self::TouchInterruptPin> {
+		self.touch_interrupt_pin.take()
+	}
+
+	fn take_next_button(&mut self) -> Option<Self::Button> {
+		self.next_button.take()
+	}
+
+	fn take_select_button(&mut self) -> Option<Self::Button> {
+		self.select_button.take()
+	}
+
+	fn take_fan_pin(&mut self) -> Option<Self::FanPin> {
+		self.fan_pin.take()
+	}
+
+	fn take_heater_pin(&mut self) -> Option<Self::HeaterPin> {
+		self.heater_pin.take()
+	}
+
+	fn take_adc(&mut self) -> Option<Self::ADC> {
+		self.adc.take()
+	}
+
+	fn take_thermistor1_pin(&mut self) -> Option<Self::Thermistor1Pin> {
+		self.thermistor1_pin.take()
+	}
+
+	fn take_thermistor2_pin(&mut self) -> Option<Self::Thermistor2Pin> {
+		self.thermistor2_pin.take()
+	}
+
+	fn take_board_thermistor_pin(&mut self) -> Option<Self::BoardThermistorPin> {
+		self.board_thermistor_pin.take()
+	}
+
+	fn take_infrared_thermometer_i2c(&mut self) -> Option<Self::InfraredThermometerI2c> {
+		self.infrared_thermometer_i2c.take()
+	}
+
+	fn take_system_time(&mut self) -> Option<Self::SystemTime> {
+		self.system_time.take()
+	}
+}
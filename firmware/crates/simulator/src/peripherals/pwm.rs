@@ -0,0 +1,54 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+use firmware_core::{
+	hot_plate::hal::pwm::PwmPin,
+	utils::{math::Percentage, measurement::frequency::Frequency},
+};
+
+use crate::simulation::SimulationState;
+
+/// Which device of the board a [`SimulatedPwmPin`] is driving.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PwmPinKind {
+	Heater,
+	Fan,
+}
+
+/// A simulated PWM pin that drives the heater or the fan of the simulated plate.
+pub struct SimulatedPwmPin {
+	state: Rc<RefCell<SimulationState>>,
+	kind: PwmPinKind,
+}
+
+impl SimulatedPwmPin {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>, kind: PwmPinKind) -> Self {
+		Self { state, kind }
+	}
+}
+
+impl PwmPin for SimulatedPwmPin {
+	type Error = Infallible;
+
+	fn get_duty_cycle(&self) -> Percentage {
+		let state = self.state.borrow();
+		match self.kind {
+			PwmPinKind::Heater => state.heater_duty_cycle,
+			PwmPinKind::Fan => state.fan_duty_cycle,
+		}
+	}
+
+	fn set_duty_cycle(&mut self, percentage: Percentage) -> Result<(), Self::Error> {
+		let mut state = self.state.borrow_mut();
+		match self.kind {
+			PwmPinKind::Heater => state.heater_duty_cycle = percentage,
+			PwmPinKind::Fan => state.fan_duty_cycle = percentage,
+		}
+
+		Ok(())
+	}
+
+	fn set_frequency(&mut self, _: Frequency) -> Result<(), Self::Error> {
+		// The thermal model only depends on the average power, so the frequency doesn't matter
+		Ok(())
+	}
+}
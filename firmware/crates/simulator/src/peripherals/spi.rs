@@ -0,0 +1,48 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc, time::Duration};
+
+use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+
+use crate::simulation::SimulationState;
+
+/// A simulated SPI device connected to the [`SimulatedDisplay`].
+///
+/// [`SimulatedDisplay`]: `crate::display::SimulatedDisplay`
+pub struct SimulatedSpi {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl SimulatedSpi {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>) -> Self {
+		Self { state }
+	}
+}
+
+impl ErrorType for SimulatedSpi {
+	type Error = Infallible;
+}
+
+impl SpiDevice for SimulatedSpi {
+	fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+		let mut state = self.state.borrow_mut();
+		state.display.on_transaction();
+
+		for operation in operations {
+			match operation {
+				Operation::Write(write) => state.display.receive(write),
+				Operation::Transfer(read, write) => {
+					state.display.receive(write);
+					read.fill(0);
+				},
+				Operation::TransferInPlace(buffer) => {
+					state.display.receive(buffer);
+					buffer.fill(0);
+				},
+				// The display doesn't send anything back
+				Operation::Read(read) => read.fill(0),
+				Operation::DelayNs(nanos) => state.advance(Duration::from_nanos(*nanos as u64)),
+			}
+		}
+
+		Ok(())
+	}
+}
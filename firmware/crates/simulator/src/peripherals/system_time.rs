@@ -0,0 +1,30 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use firmware_core::hot_plate::hal::system_time::SystemTime;
+
+use crate::simulation::SimulationState;
+
+/// The virtual clock of the simulation.
+///
+/// The time only passes when you call [`Simulation::advance`] or when the firmware calls [`SystemTime::delay`].
+///
+/// [`Simulation::advance`]: `crate::simulation::Simulation::advance`
+pub struct SimulatedSystemTime {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl SimulatedSystemTime {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>) -> Self {
+		Self { state }
+	}
+}
+
+impl SystemTime for SimulatedSystemTime {
+	fn now(&self) -> Duration {
+		self.state.borrow().time
+	}
+
+	fn delay(&self, duration: Duration) {
+		self.state.borrow_mut().advance(duration);
+	}
+}
@@ -0,0 +1,179 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+use firmware_core::hot_plate::{
+	drivers::xpt2046::{Channel, MAX_RAW_VALUE},
+	hal::interrupt::{InterruptPin, Trigger},
+	screen::{SCREEN_HEIGHT_IN_PIXELS, SCREEN_WIDTH_IN_PIXELS},
+};
+use micromath::vector::U16x2;
+
+use crate::simulation::SimulationState;
+
+/// A simulated resistive touch panel placed over the [`SimulatedDisplay`], read by a simulated [`XPT2046`].
+///
+/// Like in a real panel, the raw axes don't match the ones of the display (the x axis is inverted), so the firmware
+/// needs a [`Calibration`] to map them to the pixels of the screen.
+///
+/// [`SimulatedDisplay`]: `crate::display::SimulatedDisplay`
+/// [`XPT2046`]: `firmware_core::hot_plate::drivers::xpt2046::Xpt2046`
+/// [`Calibration`]: `firmware_core::hot_plate::drivers::xpt2046::Calibration`
+pub struct SimulatedTouchPanel {
+	touched_point: Option<U16x2>,
+	pen_irq_callback: Option<Box<dyn FnMut() + Send>>,
+}
+
+impl SimulatedTouchPanel {
+	/// The raw sample at the left border of the screen.
+	const RAW_LEFT: f32 = 3_800.;
+	/// The raw sample at the right border of the screen.
+	const RAW_RIGHT: f32 = 300.;
+	/// The raw sample at the top border of the screen.
+	const RAW_TOP: f32 = 250.;
+	/// The raw sample at the bottom border of the screen.
+	const RAW_BOTTOM: f32 = 3_750.;
+	/// The pressure samples of a finger on the panel.
+	const TOUCHED_Z1: u16 = 1_200;
+	const TOUCHED_Z2: u16 = 3_000;
+
+	pub(crate) fn new() -> Self {
+		Self {
+			touched_point: None,
+			pen_irq_callback: None,
+		}
+	}
+
+	/// Returns the point of the screen that is being touched, or `None` if the panel isn't being touched.
+	pub fn get_touched_point(&self) -> Option<U16x2> {
+		self.touched_point
+	}
+
+	/// Returns the raw sample the controller reads when the provided `point` of the screen is touched.
+	pub fn get_raw_position(point: U16x2) -> U16x2 {
+		let map = |value: u16, max: usize, start: f32, end: f32| {
+			(start + (end - start) * value as f32 / (max - 1) as f32).round() as u16
+		};
+
+		U16x2 {
+			x: map(point.x, SCREEN_WIDTH_IN_PIXELS, Self::RAW_LEFT, Self::RAW_RIGHT),
+			y: map(point.y, SCREEN_HEIGHT_IN_PIXELS, Self::RAW_TOP, Self::RAW_BOTTOM),
+		}
+	}
+
+	/// Touches the panel at the provided `point` of the screen (or releases it if it's `None`), returning the
+	/// callback that must be called to notify the firmware that the panel has been touched.
+	pub(crate) fn touch(&mut self, point: Option<U16x2>) -> Option<&mut Box<dyn FnMut() + Send>> {
+		let was_touched = self.touched_point.is_some();
+		self.touched_point = point;
+
+		match (was_touched, point.is_some()) {
+			(false, true) => self.pen_irq_callback.as_mut(),
+			_ => None,
+		}
+	}
+
+	/// Returns the sample of the provided `channel` the controller reads right now.
+	fn sample(&self, channel: Channel) -> u16 {
+		let Some(point) = self.touched_point else {
+			// Without a touch the panel isn't pressed at all
+			return match channel {
+				Channel::Z2 => MAX_RAW_VALUE,
+				_ => 0,
+			};
+		};
+
+		let raw_position = Self::get_raw_position(point);
+		match channel {
+			Channel::X => raw_position.x,
+			Channel::Y => raw_position.y,
+			Channel::Z1 => Self::TOUCHED_Z1,
+			Channel::Z2 => Self::TOUCHED_Z2,
+		}
+	}
+}
+
+/// A simulated SPI device connected to the XPT2046 of the [`SimulatedTouchPanel`].
+pub struct SimulatedTouchSpi {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl SimulatedTouchSpi {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>) -> Self {
+		Self { state }
+	}
+
+	/// Returns the bytes the controller sends back while receiving the provided ones.
+	fn respond(&self, write: &[u8], read: &mut [u8]) {
+		read.fill(0);
+
+		let Some(&command) = write.first() else {
+			return;
+		};
+		let channel = match (command >> 4) & 0b111 {
+			0b101 => Channel::X,
+			0b001 => Channel::Y,
+			0b011 => Channel::Z1,
+			0b100 => Channel::Z2,
+			_ => return,
+		};
+
+		// The 12 bits of the conversion are sent after the command, starting from the most significant one
+		let sample = self.state.borrow().touch_panel.sample(channel) << 3;
+		for (byte, sample_byte) in read.iter_mut().skip(1).zip(sample.to_be_bytes()) {
+			*byte = sample_byte;
+		}
+	}
+}
+
+impl ErrorType for SimulatedTouchSpi {
+	type Error = Infallible;
+}
+
+impl SpiDevice for SimulatedTouchSpi {
+	fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+		for operation in operations {
+			match operation {
+				Operation::Transfer(read, write) => self.respond(write, read),
+				Operation::TransferInPlace(buffer) => {
+					let write = buffer.to_vec();
+					self.respond(&write, buffer);
+				},
+				Operation::Read(read) => read.fill(0),
+				Operation::Write(_) | Operation::DelayNs(_) => (),
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// A simulated interrupt pin connected to the `PENIRQ` output of the XPT2046 of the [`SimulatedTouchPanel`].
+///
+/// The callback is called (outside of any ISR) when the panel starts being touched.
+pub struct SimulatedTouchInterruptPin {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl SimulatedTouchInterruptPin {
+	pub(crate) fn new(state: Rc<RefCell<SimulationState>>) -> Self {
+		Self { state }
+	}
+}
+
+impl InterruptPin for SimulatedTouchInterruptPin {
+	type Error = Infallible;
+
+	unsafe fn subscribe_to_interrupt(
+		&mut self, when_to_trigger: Trigger, callback: impl FnMut() + Send + 'static,
+	) -> Result<(), Self::Error> {
+		// The PENIRQ output goes low when the panel is touched
+		if matches!(
+			when_to_trigger,
+			Trigger::NegativeEdge | Trigger::AnyEdge | Trigger::LowLevel
+		) {
+			self.state.borrow_mut().touch_panel.pen_irq_callback = Some(Box::new(callback));
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,234 @@
+use std::{
+	cell::{Ref, RefCell},
+	collections::HashSet,
+	rc::Rc,
+	time::Duration,
+};
+
+use firmware_core::{
+	hot_plate::{
+		config::temperature::PidConfig,
+		screen::ui::NavigationButton,
+		temperature::fusion::{TemperatureSensor, SENSORS_COUNT},
+	},
+	utils::{math::Percentage, measurement::temperature::Temperature},
+};
+use micromath::vector::U16x2;
+
+use crate::{
+	display::SimulatedDisplay,
+	peripherals::{i2c::SimulatedMlx90614, touch::SimulatedTouchPanel, SimulatedPeripherals},
+	thermal_model::{ThermalModel, ThermalModelParameters},
+};
+
+/// A simulated hot plate running on virtual time.
+///
+/// The [`peripherals`] returned by this struct share their state with it, so after you move them into an
+/// [`HotPlate`] you can still [`advance the time`] and observe what the firmware is doing to the plate.
+///
+/// # Examples
+/// ```
+/// # use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+/// # use firmware_core::hot_plate::HotPlate;
+/// # use std::time::Duration;
+/// #
+/// let simulation = Simulation::new(ThermalModelParameters::default());
+/// let configuration = config::configuration();
+/// let mut hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+///
+/// for _ in 0..100 {
+/// 	simulation.advance(Duration::from_millis(100));
+/// 	hot_plate.tick().unwrap();
+/// }
+///
+/// assert!(simulation.get_time() >= Duration::from_secs(10));
+/// ```
+///
+/// [`peripherals`]: `Self::peripherals`
+/// [`HotPlate`]: `firmware_core::hot_plate::HotPlate`
+/// [`advance the time`]: `Self::advance`
+pub struct Simulation {
+	state: Rc<RefCell<SimulationState>>,
+}
+
+impl Simulation {
+	/// Returns a [`Simulation`] of a plate with the provided parameters, that starts at the ambient temperature at
+	/// time `0`.
+	pub fn new(thermal_model_parameters: ThermalModelParameters) -> Self {
+		Self {
+			state: Rc::new(RefCell::new(SimulationState {
+				time: Duration::ZERO,
+				thermal_model: ThermalModel::new(thermal_model_parameters),
+				heater_duty_cycle: Percentage::ZERO,
+				fan_duty_cycle: Percentage::ZERO,
+				display: SimulatedDisplay::new(),
+				detached_sensors: [false; SENSORS_COUNT],
+				infrared_thermometer: SimulatedMlx90614::new(),
+				touch_panel: SimulatedTouchPanel::new(),
+				pressed_buttons: HashSet::new(),
+			})),
+		}
+	}
+
+	/// Returns a set of [`SimulatedPeripherals`] connected to this simulation.
+	///
+	/// The simulated thermistors will behave like real ones with the parameters of the thermistors of the provided
+	/// `pid` configuration.
+	pub fn peripherals(&self, pid: &PidConfig) -> SimulatedPeripherals {
+		SimulatedPeripherals::new(Rc::clone(&self.state), pid)
+	}
+
+	/// Makes `delta_time` pass in the simulation, heating or cooling the plate based on the current duty cycles of the
+	/// heater and the fan.
+	pub fn advance(&self, delta_time: Duration) {
+		self.state.borrow_mut().advance(delta_time);
+	}
+
+	/// Returns the amount of virtual time that passed since the creation of the simulation.
+	pub fn get_time(&self) -> Duration {
+		self.state.borrow().time
+	}
+
+	/// Returns the real temperature of the plate (not the one read by the firmware through the thermistor).
+	pub fn get_plate_temperature(&self) -> Temperature {
+		self.state.borrow().thermal_model.get_temperature()
+	}
+
+	/// Sets the real temperature of the plate.
+	pub fn set_plate_temperature(&self, temperature: Temperature) {
+		self.state.borrow_mut().thermal_model.set_temperature(temperature);
+	}
+
+	/// Detaches the provided `sensor` from the plate (or attaches it back), like a loose thermistor that measures the
+	/// ambient temperature instead of the one of the plate.
+	pub fn set_sensor_detached(&self, sensor: TemperatureSensor, is_detached: bool) {
+		self.state.borrow_mut().detached_sensors[sensor as usize] = is_detached;
+	}
+
+	/// Makes the communication with the simulated infrared thermometer noisy (or not anymore), so that the words read
+	/// from it are corrupted.
+	pub fn set_infrared_thermometer_noise(&self, is_noisy: bool) {
+		self.state.borrow_mut().infrared_thermometer.is_noisy = is_noisy;
+	}
+
+	/// Returns the simulated infrared thermometer pointed at the plate.
+	pub fn get_infrared_thermometer(&self) -> Ref<'_, SimulatedMlx90614> {
+		Ref::map(self.state.borrow(), |state| &state.infrared_thermometer)
+	}
+
+	/// Touches the simulated touch panel at the provided `point` of the screen, keeping it pressed until it's
+	/// [`released`]. If the panel wasn't already touched, the callback subscribed to its interrupt pin is called.
+	///
+	/// # Examples
+	/// ```
+	/// # use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+	/// # use firmware_core::hot_plate::{
+	/// # 	drivers::xpt2046::{Calibration, Xpt2046, Xpt2046Config},
+	/// # 	peripherals::Peripherals,
+	/// # };
+	/// # use micromath::vector::U16x2;
+	/// # use std::sync::atomic::AtomicBool;
+	/// #
+	/// static PEN_DOWN: AtomicBool = AtomicBool::new(false);
+	///
+	/// let simulation = Simulation::new(ThermalModelParameters::default());
+	/// let mut peripherals = simulation.peripherals(&config::configuration().pid);
+	/// let mut touch = Xpt2046::new(
+	/// 	peripherals.take_touch_spi().unwrap(),
+	/// 	peripherals.take_touch_interrupt_pin().unwrap(),
+	/// 	&PEN_DOWN,
+	/// 	Xpt2046Config::default(),
+	/// )
+	/// .unwrap();
+	/// assert!(!touch.is_pen_down());
+	///
+	/// // Calibrate the panel by touching 3 points of the screen
+	/// let screen_points = [U16x2 { x: 30, y: 20 }, U16x2 { x: 290, y: 120 }, U16x2 { x: 160, y: 220 }];
+	/// let calibration_points = screen_points.map(|screen_point| {
+	/// 	simulation.touch(screen_point);
+	/// 	let raw_point = touch.read_raw_touch().unwrap().unwrap().position;
+	/// 	simulation.release_touch();
+	/// 	(raw_point, screen_point)
+	/// });
+	/// touch.set_calibration(Calibration::from_points(calibration_points).unwrap());
+	///
+	/// simulation.touch(U16x2 { x: 100, y: 50 });
+	/// assert!(touch.is_pen_down());
+	/// let position = touch.read_touch().unwrap().unwrap().position;
+	/// assert!(position.x.abs_diff(100) <= 1 && position.y.abs_diff(50) <= 1);
+	///
+	/// simulation.release_touch();
+	/// assert!(touch.read_touch().unwrap().is_none());
+	/// assert!(!touch.is_pen_down());
+	/// ```
+	///
+	/// [`released`]: `Self::release_touch`
+	pub fn touch(&self, point: U16x2) {
+		self.set_touched_point(Some(point));
+	}
+
+	/// Stops touching the simulated touch panel.
+	pub fn release_touch(&self) {
+		self.set_touched_point(None);
+	}
+
+	/// Returns the simulated touch panel placed over the display.
+	pub fn get_touch_panel(&self) -> Ref<'_, SimulatedTouchPanel> {
+		Ref::map(self.state.borrow(), |state| &state.touch_panel)
+	}
+
+	/// Presses the provided physical `button`, keeping it pressed until it's [`released`].
+	///
+	/// [`released`]: `Self::release_button`
+	pub fn press_button(&self, button: NavigationButton) {
+		self.state.borrow_mut().pressed_buttons.insert(button);
+	}
+
+	/// Releases the provided physical `button`.
+	pub fn release_button(&self, button: NavigationButton) {
+		self.state.borrow_mut().pressed_buttons.remove(&button);
+	}
+
+	fn set_touched_point(&self, point: Option<U16x2>) {
+		let mut state = self.state.borrow_mut();
+		if let Some(callback) = state.touch_panel.touch(point) {
+			callback();
+		}
+	}
+
+	/// Returns the duty cycle the firmware set on the heater.
+	pub fn get_heater_duty_cycle(&self) -> Percentage {
+		self.state.borrow().heater_duty_cycle
+	}
+
+	/// Returns the duty cycle the firmware set on the fan.
+	pub fn get_fan_duty_cycle(&self) -> Percentage {
+		self.state.borrow().fan_duty_cycle
+	}
+
+	/// Returns the display the firmware is drawing on.
+	pub fn get_display(&self) -> Ref<'_, SimulatedDisplay> {
+		Ref::map(self.state.borrow(), |state| &state.display)
+	}
+}
+
+pub(crate) struct SimulationState {
+	pub(crate) time: Duration,
+	pub(crate) thermal_model: ThermalModel,
+	pub(crate) heater_duty_cycle: Percentage,
+	pub(crate) fan_duty_cycle: Percentage,
+	pub(crate) display: SimulatedDisplay,
+	/// The sensors that are measuring the ambient temperature instead of the one of the plate.
+	pub(crate) detached_sensors: [bool; SENSORS_COUNT],
+	pub(crate) infrared_thermometer: SimulatedMlx90614,
+	pub(crate) touch_panel: SimulatedTouchPanel,
+	pub(crate) pressed_buttons: HashSet<NavigationButton>,
+}
+
+impl SimulationState {
+	pub(crate) fn advance(&mut self, delta_time: Duration) {
+		self.thermal_model
+			.step(self.heater_duty_cycle, self.fan_duty_cycle, delta_time);
+		self.time += delta_time;
+	}
+}
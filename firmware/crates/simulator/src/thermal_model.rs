@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use firmware_core::utils::{math::Percentage, measurement::temperature::Temperature};
+
+/// The physical parameters of the plate used by the [`ThermalModel`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalModelParameters {
+	/// The energy required to raise the temperature of the plate by `1K`, in `J/K`.
+	pub heat_capacity: f32,
+	/// The power lost to the ambient for every degree of difference between the plate and the ambient, in `W/K`.
+	pub conductance_to_ambient: f32,
+	/// The additional power lost to the ambient when the fan is at full speed, in `W/K`.
+	pub fan_conductance_to_ambient: f32,
+	/// The power given to the plate by the heater when its duty cycle is `100%`, in `W`.
+	pub heater_power: f32,
+	/// The temperature of the air around the plate.
+	pub ambient_temperature: Temperature,
+	/// How many seconds the thermistor takes to follow the temperature of the plate (its [`time constant`]).
+	///
+	/// [`time constant`]: <https://en.wikipedia.org/wiki/Time_constant>
+	pub sensor_time_constant_in_seconds: f32,
+}
+
+impl Default for ThermalModelParameters {
+	/// Returns the parameters of a plate that can reach about `300°C` when the heater is at full power.
+	fn default() -> Self {
+		Self {
+			heat_capacity: 60.,
+			conductance_to_ambient: 0.9,
+			fan_conductance_to_ambient: 1.5,
+			heater_power: 250.,
+			ambient_temperature: Temperature::from_celsius(25.),
+			sensor_time_constant_in_seconds: 3.,
+		}
+	}
+}
+
+/// A [`first-order`] thermal model of the plate: the heater gives power to the plate, which loses it to the ambient
+/// proportionally to the difference of their temperatures.
+///
+/// The thermistor doesn't measure the temperature of the plate instantly: its [`sensor temperature`] follows the one
+/// of the plate with a delay.
+///
+/// # Examples
+/// ```
+/// # use simulator::thermal_model::*;
+/// # use firmware_core::utils::math::Percentage;
+/// # use std::time::Duration;
+/// #
+/// let mut model = ThermalModel::new(ThermalModelParameters::default());
+/// let ambient_temperature = model.get_temperature();
+///
+/// model.step(Percentage::FULL, Percentage::ZERO, Duration::from_secs(10));
+/// assert!(model.get_temperature() > ambient_temperature);
+///
+/// // Without heating the plate cools down towards the ambient temperature
+/// model.step(Percentage::ZERO, Percentage::FULL, Duration::from_secs(10_000));
+/// assert!((model.get_temperature().as_celsius() - ambient_temperature.as_celsius()).abs() < 0.1);
+/// ```
+///
+/// [`first-order`]: <https://en.wikipedia.org/wiki/Newton%27s_law_of_cooling>
+/// [`sensor temperature`]: `Self::get_sensor_temperature`
+pub struct ThermalModel {
+	parameters: ThermalModelParameters,
+	temperature: Temperature,
+	sensor_temperature: Temperature,
+}
+
+impl ThermalModel {
+	/// The maximum time a single integration step can last. Longer steps are split in smaller ones to keep the
+	/// integration stable.
+	const MAX_INTEGRATION_STEP: Duration = Duration::from_millis(10);
+
+	/// Returns a [`ThermalModel`] of a plate that starts at the ambient temperature.
+	pub fn new(parameters: ThermalModelParameters) -> Self {
+		Self {
+			temperature: parameters.ambient_temperature,
+			sensor_temperature: parameters.ambient_temperature,
+			parameters,
+		}
+	}
+
+	/// Returns the parameters this model has been created with.
+	pub fn get_parameters(&self) -> ThermalModelParameters {
+		self.parameters
+	}
+
+	/// Returns the current temperature of the plate.
+	pub fn get_temperature(&self) -> Temperature {
+		self.temperature
+	}
+
+	/// Returns the current temperature of the thermistor attached to the plate.
+	pub fn get_sensor_temperature(&self) -> Temperature {
+		self.sensor_temperature
+	}
+
+	/// Sets the current temperature of the plate (and of the thermistor attached to it).
+	pub fn set_temperature(&mut self, temperature: Temperature) {
+		self.temperature = temperature;
+		self.sensor_temperature = temperature;
+	}
+
+	/// Returns the temperature the plate would settle at if the heater and the fan were kept at the provided duty
+	/// cycles forever.
+	pub fn steady_state_temperature(&self, heater_duty_cycle: Percentage, fan_duty_cycle: Percentage) -> Temperature {
+		Temperature::from_kelvin(
+			self.parameters.ambient_temperature.as_kelvin()
+				+ self.parameters.heater_power * heater_duty_cycle.into_0_to_1()
+					/ self.total_conductance_to_ambient(fan_duty_cycle),
+		)
+	}
+
+	/// Makes `delta_time` pass with the heater and the fan kept at the provided duty cycles.
+	pub fn step(&mut self, heater_duty_cycle: Percentage, fan_duty_cycle: Percentage, mut delta_time: Duration) {
+		let heater_power = self.parameters.heater_power * heater_duty_cycle.into_0_to_1();
+		let conductance = self.total_conductance_to_ambient(fan_duty_cycle);
+
+		while !delta_time.is_zero() {
+			let step = delta_time.min(Self::MAX_INTEGRATION_STEP);
+			delta_time -= step;
+
+			let lost_power = conductance * (self.temperature - self.parameters.ambient_temperature).as_kelvin();
+			let temperature_change = (heater_power - lost_power) / self.parameters.heat_capacity * step.as_secs_f32();
+			self.temperature = Temperature::from_kelvin(self.temperature.as_kelvin() + temperature_change);
+
+			let sensor_temperature_change = (self.temperature - self.sensor_temperature).as_kelvin()
+				* (step.as_secs_f32() / self.parameters.sensor_time_constant_in_seconds).min(1.);
+			self.sensor_temperature =
+				Temperature::from_kelvin(self.sensor_temperature.as_kelvin() + sensor_temperature_change);
+		}
+	}
+
+	fn total_conductance_to_ambient(&self, fan_duty_cycle: Percentage) -> f32 {
+		self.parameters.conductance_to_ambient
+			+ self.parameters.fan_conductance_to_ambient * fan_duty_cycle.into_0_to_1()
+	}
+}
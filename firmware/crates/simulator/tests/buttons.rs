@@ -0,0 +1,27 @@
+//! The user drives the hot plate through the physical navigation buttons.
+
+mod common;
+
+use common::{new_hot_plate, tick};
+use firmware_core::hot_plate::{
+	process::{AbortReason, ReflowState},
+	screen::ui::NavigationButton,
+};
+
+#[test]
+fn a_long_press_aborts_the_reflow() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+	hot_plate.start_reflow().unwrap();
+
+	// Keeping a button pressed for some seconds is an emergency stop
+	simulation.press_button(NavigationButton::Next);
+	for _ in 0..30 {
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+	simulation.release_button(NavigationButton::Next);
+
+	assert_eq!(
+		hot_plate.get_reflow_state(),
+		ReflowState::Aborted(AbortReason::Requested)
+	);
+}
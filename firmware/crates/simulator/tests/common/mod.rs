@@ -0,0 +1,31 @@
+//! Helpers shared by the integration tests, which run the firmware on a [`Simulation`].
+
+use std::time::Duration;
+
+use firmware_core::hot_plate::{HotPlate, PeripheralsTickError};
+use simulator::{
+	config, peripherals::SimulatedPeripherals, simulation::Simulation, thermal_model::ThermalModelParameters,
+};
+
+pub type SimulatedHotPlate = HotPlate<SimulatedPeripherals>;
+
+/// The virtual time that passes between 2 ticks of the hot plate.
+pub const TICK_PERIOD: Duration = Duration::from_millis(100);
+
+/// Returns a [`Simulation`] of a plate with the default parameters, and an [`HotPlate`] that runs on it with the
+/// configuration of the simulator.
+pub fn new_hot_plate() -> (Simulation, SimulatedHotPlate) {
+	let simulation = Simulation::new(ThermalModelParameters::default());
+	let configuration = config::configuration();
+	let hot_plate = HotPlate::new(simulation.peripherals(&configuration.pid), configuration).unwrap();
+
+	(simulation, hot_plate)
+}
+
+/// Makes a [`TICK_PERIOD`] pass in the `simulation`, then ticks the `hot_plate`.
+pub fn tick(
+	simulation: &Simulation, hot_plate: &mut SimulatedHotPlate,
+) -> Result<(), PeripheralsTickError<SimulatedPeripherals>> {
+	simulation.advance(TICK_PERIOD);
+	hot_plate.tick()
+}
@@ -0,0 +1,46 @@
+//! The ILI9341 driver checks the buffers of its transfer instead of waiting forever for them to have room for a pixel.
+
+use firmware_core::hot_plate::{
+	drivers::ili9341::{InitError, Orientation, SendError, ILI9341},
+	hal::{display_transfer::DisplayTransfer, system_time::Clock},
+	peripherals::Peripherals,
+};
+use simulator::{config, simulation::Simulation, thermal_model::ThermalModelParameters};
+
+/// A transfer that only lends the first byte of the buffers of the wrapped one.
+struct OneByteTransfer<T: DisplayTransfer>(T);
+
+impl<T: DisplayTransfer> DisplayTransfer for OneByteTransfer<T> {
+	type Error = T::Error;
+
+	fn get_buffer(&mut self) -> Result<&mut [u8], Self::Error> {
+		Ok(&mut self.0.get_buffer()?[..1])
+	}
+
+	fn send(&mut self, length: usize) -> Result<(), Self::Error> {
+		self.0.send(length)
+	}
+
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		self.0.flush()
+	}
+}
+
+#[test]
+fn a_transfer_shorter_than_a_pixel_is_an_error() {
+	let simulation = Simulation::new(ThermalModelParameters::default());
+	let mut peripherals = simulation.peripherals(&config::configuration().pid);
+	let clock = Clock::new(peripherals.take_system_time().unwrap());
+
+	let result = ILI9341::new(
+		peripherals.take_lcd_dcx_pin().unwrap(),
+		peripherals.take_lcd_reset_pin().unwrap(),
+		OneByteTransfer(peripherals.take_lcd_transfer().unwrap()),
+		&clock,
+		Orientation::default(),
+	);
+	assert!(matches!(
+		result,
+		Err(InitError::Send(SendError::BufferTooShort { length: 1 }))
+	));
+}
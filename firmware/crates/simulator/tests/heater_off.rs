@@ -0,0 +1,64 @@
+//! The heater is off whenever the plate doesn't have to be heated, but the temperature is still monitored.
+
+mod common;
+
+use common::{new_hot_plate, tick};
+use firmware_core::{
+	hot_plate::process::{CommandError, ReflowState},
+	utils::measurement::temperature::Temperature,
+};
+
+#[test]
+fn the_temperature_is_monitored_without_a_reflow_process() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+
+	simulation.set_plate_temperature(Temperature::from_celsius(60.));
+	tick(&simulation, &mut hot_plate).unwrap();
+	assert_eq!(hot_plate.get_reflow_state(), ReflowState::Idle);
+	assert_eq!(hot_plate.get_current_temperature().unwrap().as_celsius().round(), 60.);
+
+	// The plate is too hot even if the heater is off
+	simulation.set_plate_temperature(Temperature::from_celsius(300.));
+	assert!(tick(&simulation, &mut hot_plate).is_err());
+	assert_eq!(simulation.get_heater_duty_cycle().into_0_to_1(), 0.);
+}
+
+#[test]
+fn the_heater_is_off_while_cooling() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+
+	hot_plate.start_reflow().unwrap();
+	while hot_plate.get_reflow_state() != ReflowState::Cooling {
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+	while hot_plate.get_reflow_state() == ReflowState::Cooling {
+		assert_eq!(simulation.get_fan_duty_cycle().into_0_to_1(), 1.);
+		assert_eq!(simulation.get_heater_duty_cycle().into_0_to_1(), 0.);
+
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+	assert_eq!(hot_plate.get_reflow_state(), ReflowState::Done);
+}
+
+#[test]
+fn the_heater_stays_off_when_pausing_while_cooling() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+
+	hot_plate.start_reflow().unwrap();
+	while hot_plate.get_reflow_state() != ReflowState::Cooling {
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+
+	// The plate can't be kept at the target temperature against the fan, so the process can't be paused
+	assert_eq!(
+		hot_plate.pause_reflow(),
+		Err(CommandError::NotAllowed {
+			state: ReflowState::Cooling
+		})
+	);
+	for _ in 0..100 {
+		tick(&simulation, &mut hot_plate).unwrap();
+		assert_eq!(hot_plate.get_reflow_state(), ReflowState::Cooling);
+		assert_eq!(simulation.get_heater_duty_cycle().into_0_to_1(), 0.);
+	}
+}
@@ -0,0 +1,30 @@
+//! The firmware stops heating when the sensors of the plate disagree.
+
+mod common;
+
+use common::{new_hot_plate, tick};
+use firmware_core::hot_plate::{
+	process::{AbortReason, ReflowState},
+	temperature::{fusion::TemperatureSensor, safety::TemperatureError, PidUpdateError},
+};
+
+#[test]
+fn a_loose_thermistor_aborts_the_reflow() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+
+	hot_plate.start_reflow().unwrap();
+	while simulation.get_plate_temperature().as_celsius() < 100. {
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+
+	// The second thermistor comes loose: instead of heating the plate more, the firmware stops the reflow
+	simulation.set_sensor_detached(TemperatureSensor::PlateThermistor2, true);
+	assert!(tick(&simulation, &mut hot_plate).is_err());
+
+	assert!(matches!(
+		hot_plate.get_reflow_state(),
+		ReflowState::Aborted(AbortReason::TemperatureControl(PidUpdateError::ReadTemperatureIsWrong(errors)))
+			if errors.contains(TemperatureError::SensorsDisagree)
+	));
+	assert_eq!(simulation.get_heater_duty_cycle().into_0_to_1(), 0.);
+}
@@ -0,0 +1,57 @@
+//! The user drives the hot plate through the touch panel.
+
+mod common;
+
+use common::{new_hot_plate, tick};
+use firmware_core::hot_plate::{
+	process::ReflowState,
+	screen::ui::{widget::Widget, Menu},
+};
+use micromath::vector::U16x2;
+
+#[test]
+fn tapping_start_starts_a_reflow_and_shows_its_progress() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+	tick(&simulation, &mut hot_plate).unwrap();
+	assert_eq!(hot_plate.get_current_menu(), Menu::Home);
+
+	let start_button = hot_plate.get_ui().find_button("Start").unwrap();
+	simulation.touch(start_button.position + U16x2 { x: 20, y: 20 });
+	tick(&simulation, &mut hot_plate).unwrap();
+	simulation.release_touch();
+	tick(&simulation, &mut hot_plate).unwrap();
+
+	assert!(hot_plate.get_reflow_state().is_active());
+	assert_eq!(hot_plate.get_current_menu(), Menu::Reflowing);
+}
+
+#[test]
+fn pause_is_disabled_while_cooling() {
+	let (simulation, mut hot_plate) = new_hot_plate();
+	let is_pause_enabled = |hot_plate: &common::SimulatedHotPlate| {
+		hot_plate.get_ui().get_widgets().iter().any(
+			|widget| matches!(widget, Widget::Button(button) if button.get_text() == "Pause" && button.is_enabled()),
+		)
+	};
+
+	hot_plate.start_reflow().unwrap();
+	tick(&simulation, &mut hot_plate).unwrap();
+	assert_eq!(hot_plate.get_current_menu(), Menu::Reflowing);
+	assert!(is_pause_enabled(&hot_plate));
+
+	while hot_plate.get_reflow_state() != ReflowState::Cooling {
+		tick(&simulation, &mut hot_plate).unwrap();
+	}
+	tick(&simulation, &mut hot_plate).unwrap();
+	assert!(!is_pause_enabled(&hot_plate));
+
+	// Tapping it does nothing
+	let pause_button = hot_plate.get_ui().find_button("Pause").unwrap();
+	simulation.touch(pause_button.position + U16x2 { x: 20, y: 20 });
+	tick(&simulation, &mut hot_plate).unwrap();
+	simulation.release_touch();
+	tick(&simulation, &mut hot_plate).unwrap();
+
+	assert_eq!(hot_plate.get_reflow_state(), ReflowState::Cooling);
+	assert_eq!(simulation.get_heater_duty_cycle().into_0_to_1(), 0.);
+}
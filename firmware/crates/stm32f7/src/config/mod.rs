@@ -1,21 +1,56 @@
+use core::time::Duration;
+
 use firmware_core::{
 	hot_plate::{
-		config::{temperature::*, Configuration},
-		temperature::{safety::temperature_change::TemperatureChangeConfig, TemperaturePidGains},
+		config::{reflow::ReflowConfig, temperature::*, ui::UiConfig, Configuration},
+		drivers::{ili9341::Orientation, mlx90614, xpt2046::Xpt2046Config},
+		input::{ButtonConfig, RepeatConfig},
+		process::{GuaranteedDwellConfig, Interpolation},
+		temperature::{
+			autotune::{AutotuneConfig, TuningRule},
+			control::{AntiWindup, DerivativeSource, PidControlConfig},
+			fusion::{FusionMode, SensorFusionConfig, TemperatureSensor},
+			gain_schedule::GainSchedule,
+			safety::temperature_change::TemperatureChangeConfig,
+			TemperaturePidGains,
+		},
 	},
 	utils::{math::Percentage, measurement::temperature::Temperature},
 };
 
+/// The tick period at which the PID gains were tuned, when they were still applied once per tick: they are converted
+/// from it, so the PID behaves as tuned as long as the hot plate ticks about every 100 ms.
+const PID_GAINS_TUNING_TICK_PERIOD: Duration = Duration::from_millis(100);
+
 pub fn configuration() -> Configuration {
 	Configuration {
 		fan_min_duty_cycle_to_move: Percentage::from_0_to_100(20.).unwrap(),
 		pid: PidConfig {
-			pid_gains: TemperaturePidGains { p: 20., i: 2., d: 50. },
+			pid_gains: TemperaturePidGains::from_per_tick(20., 2., 50., PID_GAINS_TUNING_TICK_PERIOD),
+			gain_schedule: GainSchedule::new(),
+			control: PidControlConfig {
+				output_limits: Percentage::ZERO..=Percentage::FULL,
+				anti_windup: AntiWindup::Clamping,
+				derivative_source: DerivativeSource::Measurement,
+				derivative_filter_time_constant_in_seconds: 0.5,
+			},
 			thermistor: ThermistorConfig {
 				beta: 3_950,
 				resistance_at_t0: 100_000,
 				other_resistance: 4_700,
 			},
+			board_thermistor: ThermistorConfig {
+				beta: 3_950,
+				resistance_at_t0: 10_000,
+				other_resistance: 10_000,
+			},
+			infrared_thermometer_address: mlx90614::DEFAULT_ADDRESS,
+			sensor_fusion: SensorFusionConfig {
+				sensors: TemperatureSensor::PlateThermistor1 | TemperatureSensor::PlateThermistor2,
+				monitored_sensors: TemperatureSensor::BoardThermistor | TemperatureSensor::InfraredThermometer,
+				mode: FusionMode::Average,
+				max_disagreement: 10.,
+			},
 			safety: SafetyConfig {
 				allowed_temperature_range: Temperature::from_celsius(0.)..=Temperature::from_celsius(270.),
 				keep_target_temperature_config: TemperatureChangeConfig {
@@ -28,6 +63,44 @@ pub fn configuration() -> Configuration {
 				},
 				rise_to_target_temperature_samples_count: 45,
 			},
+			feed_forward: None,
+		},
+		reflow: ReflowConfig {
+			guaranteed_dwell: Some(GuaranteedDwellConfig {
+				tolerance: 5.,
+				max_stretch_in_seconds: 60.,
+			}),
+			interpolation: Interpolation::Linear,
+		},
+		ui: UiConfig {
+			display_orientation: Orientation::Landscape,
+			use_line_buffer: true,
+			// The panel must be calibrated by touching 3 known points of the screen (check `Calibration::from_points`)
+			touch: Xpt2046Config::default(),
+			autotune: AutotuneConfig {
+				target_temperature: Temperature::from_celsius(150.),
+				relay_high: Percentage::FULL,
+				relay_low: Percentage::ZERO,
+				hysteresis: 1.,
+				cycles_count: 5,
+				max_duration_in_seconds: 1_800.,
+			},
+			autotune_tuning_rule: TuningRule::TyreusLuyben,
+			next_button: ButtonConfig {
+				debounce_in_seconds: 0.02,
+				long_press_in_seconds: Some(2.),
+				double_press_interval_in_seconds: Some(0.3),
+				repeat: None,
+			},
+			select_button: ButtonConfig {
+				debounce_in_seconds: 0.02,
+				long_press_in_seconds: None,
+				double_press_interval_in_seconds: None,
+				repeat: Some(RepeatConfig {
+					delay_in_seconds: 0.6,
+					interval_in_seconds: 0.15,
+				}),
+			},
 		},
 	}
 }
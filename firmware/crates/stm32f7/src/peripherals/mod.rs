@@ -1,5 +1,5 @@
 use cortex_m::Peripherals as CortexPeripherals;
-use firmware_core::hot_plate::peripherals::Peripherals as PeripheralsTrait;
+use firmware_core::hot_plate::{hal::display_transfer::SpiTransfer, peripherals::Peripherals as PeripheralsTrait};
 use stm32f7xx_hal::{
 	gpio::{GpioExt, Output, Pin},
 	pac::{Peripherals as Stm32Peripherals, SPI1, TIM1, TIM8},
@@ -16,12 +16,19 @@ mod system_time;
 pub struct Peripherals {
 	lcd_dcx_pin: Option<<Self as PeripheralsTrait>::LcdDCXPin>,
 	lcd_reset_pin: Option<<Self as PeripheralsTrait>::LcdResetPin>,
-	lcd_spi: Option<<Self as PeripheralsTrait>::LcdSpi>,
+	lcd_transfer: Option<<Self as PeripheralsTrait>::LcdTransfer>,
+	touch_spi: Option<<Self as PeripheralsTrait>::TouchSpi>,
+	touch_interrupt_pin: Option<<Self as PeripheralsTrait>::TouchInterruptPin>,
+	next_button: Option<<Self as PeripheralsTrait>::Button>,
+	select_button: Option<<Self as PeripheralsTrait>::Button>,
 
 	fan_pin: Option<<Self as PeripheralsTrait>::FanPin>,
 	heater_pin: Option<<Self as PeripheralsTrait>::HeaterPin>,
 	adc: Option<<Self as PeripheralsTrait>::ADC>,
 	thermistor1_pin: Option<<Self as PeripheralsTrait>::Thermistor1Pin>,
+	thermistor2_pin: Option<<Self as PeripheralsTrait>::Thermistor2Pin>,
+	board_thermistor_pin: Option<<Self as PeripheralsTrait>::BoardThermistorPin>,
+	infrared_thermometer_i2c: Option<<Self as PeripheralsTrait>::InfraredThermometerI2c>,
 
 	system_time: Option<<Self as PeripheralsTrait>::SystemTime>,
 }
@@ -31,7 +38,13 @@ impl PeripheralsTrait for Peripherals {
 
 	type LcdResetPin = Pin<'A', 2, Output>;
 
-	type LcdSpi = SPI1;
+	type LcdTransfer = SpiTransfer<SPI1, 1024>;
+
+	type TouchSpi;
+
+	type TouchInterruptPin;
+
+	type Button;
 
 	type FanPin = PwmPin<TIM1, C1>;
 
@@ -41,6 +54,12 @@ impl PeripheralsTrait for Peripherals {
 
 	type Thermistor1Pin;
 
+	type Thermistor2Pin;
+
+	type BoardThermistorPin;
+
+	type InfraredThermometerI2c;
+
 	type SystemTime = SystemTime<1_000_000>;
 
 	fn take_lcd_dcx_pin(&mut self) -> Option<Self::LcdDCXPin> {
@@ -51,8 +70,24 @@ impl PeripheralsTrait for Peripherals {
 		self.lcd_reset_pin.take()
 	}
 
-	fn take_lcd_spi(&mut self) -> Option<Self::LcdSpi> {
-		self.lcd_spi.take()
+	fn take_lcd_transfer(&mut self) -> Option<Self::LcdTransfer> {
+		self.lcd_transfer.take()
+	}
+
+	fn take_touch_spi(&mut self) -> Option<Self::TouchSpi> {
+		self.touch_spi.take()
+	}
+
+	fn take_touch_interrupt_pin(&mut self) -> Option<Self::TouchInterruptPin> {
+		self.touch_interrupt_pin.take()
+	}
+
+	fn take_next_button(&mut self) -> Option<Self::Button> {
+		self.next_button.take()
+	}
+
+	fn take_select_button(&mut self) -> Option<Self::Button> {
+		self.select_button.take()
 	}
 
 	fn take_fan_pin(&mut self) -> Option<Self::FanPin> {
@@ -71,6 +106,18 @@ impl PeripheralsTrait for Peripherals {
 		self.thermistor1_pin.take()
 	}
 
+	fn take_thermistor2_pin(&mut self) -> Option<Self::Thermistor2Pin> {
+		self.thermistor2_pin.take()
+	}
+
+	fn take_board_thermistor_pin(&mut self) -> Option<Self::BoardThermistorPin> {
+		self.board_thermistor_pin.take()
+	}
+
+	fn take_infrared_thermometer_i2c(&mut self) -> Option<Self::InfraredThermometerI2c> {
+		self.infrared_thermometer_i2c.take()
+	}
+
 	fn take_system_time(&mut self) -> Option<Self::SystemTime> {
 		self.system_time.take()
 	}
@@ -98,11 +145,20 @@ impl Peripherals {
 		Self {
 			lcd_dcx_pin: Some(gpio_a.pa3.into_push_pull_output()),
 			lcd_reset_pin: Some(gpio_a.pa2.into_push_pull_output()),
-			lcd_spi: Some(stm_peripherals.SPI1),
+			lcd_transfer: Some(SpiTransfer::new(stm_peripherals.SPI1)),
+			touch_spi: Some(),
+			touch_interrupt_pin: Some(),
+			// SW1 and SW2 of the board are wired to `NRESET` and `BOOT`: the navigation buttons can be added on the extra
+			// pins (wrapped in an `ActiveLowButton`)
+			next_button: None,
+			select_button: None,
 			fan_pin: Some(gpio_c.pc13.into_alternate()),
 			heater_pin: Some(PwmPin::new(heater_pwm)),
 			adc: Some(),
 			thermistor1_pin: Some(),
+			thermistor2_pin: Some(),
+			board_thermistor_pin: Some(),
+			infrared_thermometer_i2c: Some(),
 			system_time: Some(),
 		}
 	}